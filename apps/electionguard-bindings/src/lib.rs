@@ -11,7 +11,7 @@ use eg::{
     hashes_ext::HashesExt,
     joint_election_public_key::JointElectionPublicKey,
 };
-use election_common::{decode, encode};
+use election_common::{decode, encode, missing_guardian_keys};
 use rand::{thread_rng, Rng};
 use serde::{Deserialize, Serialize};
 use std::{collections::BTreeMap, convert::TryFrom};
@@ -19,6 +19,11 @@ use tsify::Tsify;
 use util::csprng::Csprng;
 use wasm_bindgen::prelude::*;
 
+/// The domain separator prepended to the primary nonce when deriving the seed
+/// of the [`Csprng`] used for encrypting a ballot, see
+/// [`get_encrypted_ballot`].
+const CSPRNG_SEED_DOMAIN: &[u8] = b"electionguard-bindings/ballot-csprng";
+
 /// The missing typescript types for election guard structs.
 #[wasm_bindgen(typescript_custom_section)]
 const TS_APPEND_CONTENT: &'static str = r#"
@@ -77,17 +82,37 @@ pub struct EncryptedBallotContext {
     pub election_parameters:  ElectionParameters,
     /// The guardian public keys, which are registered in the election contract.
     pub guardian_public_keys: Vec<Vec<u8>>,
+    /// The one-based index of the ballot style in the election manifest to
+    /// use for the ballot. Defaults to the first ballot style.
+    #[serde(default)]
+    #[tsify(optional)]
+    pub ballot_style_index:   Option<u32>,
+    /// The 32 byte primary nonce to encrypt the ballot with. All nonces used
+    /// in the encryption, as well as the randomness of the proofs of the
+    /// ballot, are derived from this, so supplying it allows re-deriving the
+    /// encrypted ballot. Defaults to random bytes.
+    #[serde(default)]
+    #[tsify(optional)]
+    pub primary_nonce:        Option<Vec<u8>>,
 }
 
 impl TryFrom<EncryptedBallotContext> for PreVotingData {
     type Error = JsError;
 
     fn try_from(value: EncryptedBallotContext) -> Result<Self, Self::Error> {
-        let guardian_public_keys: Vec<GuardianPublicKey> = value
-            .guardian_public_keys
-            .iter()
-            .map(|bytes| decode::<GuardianPublicKey>(bytes))
-            .collect::<Result<_, _>>()?;
+        let guardian_public_keys = decode_guardian_public_keys(&value.guardian_public_keys)?;
+        let missing = missing_guardian_keys(&value.election_parameters, &guardian_public_keys);
+        if !missing.is_empty() {
+            return Err(JsError::new(&format!(
+                "The guardian setup is incomplete. No public keys are registered for the \
+                 guardians with indices {}.",
+                missing
+                    .iter()
+                    .map(usize::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )));
+        }
         let joint_election_public_key =
             JointElectionPublicKey::compute(&value.election_parameters, &guardian_public_keys)
                 .map_err(|e| {
@@ -123,20 +148,78 @@ impl TryFrom<EncryptedBallotContext> for PreVotingData {
     }
 }
 
+/// Decode the guardian public keys as registered in the election contract.
+fn decode_guardian_public_keys(keys: &[Vec<u8>]) -> Result<Vec<GuardianPublicKey>, JsError> {
+    let keys = keys
+        .iter()
+        .map(|bytes| decode::<GuardianPublicKey>(bytes))
+        .collect::<Result<_, _>>()?;
+    Ok(keys)
+}
+
+/// The guardian setup of an election, from which the joint election public
+/// key is computed.
+#[derive(Debug, Serialize, Deserialize, Clone, Tsify)]
+#[tsify(from_wasm_abi)]
+pub struct GuardianSetup {
+    /// The election parameters. These should be generated externally for each
+    /// election.
+    pub election_parameters:  ElectionParameters,
+    /// The guardian public keys, which are registered in the election contract.
+    pub guardian_public_keys: Vec<Vec<u8>>,
+}
+
+/// The outcome of computing the joint election public key with
+/// [`compute_joint_public_key`].
+#[derive(Debug, Serialize, Tsify)]
+#[tsify(into_wasm_abi)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum JointPublicKeyResult {
+    /// The joint election public key, encoded in the same format as the
+    /// guardian public keys.
+    Ok { key: Vec<u8> },
+    /// The guardian setup is incomplete, as no public keys are registered for
+    /// the guardians with the given one-based indices.
+    MissingGuardians { indices: Vec<usize> },
+}
+
+/// Compute the joint election public key from the guardian public keys. An
+/// incomplete guardian setup is reported as
+/// [`JointPublicKeyResult::MissingGuardians`], so that it can be detected
+/// before attempting to encrypt a ballot.
+#[wasm_bindgen(js_name = "computeJointPublicKey")]
+pub fn compute_joint_public_key(setup: GuardianSetup) -> Result<JointPublicKeyResult, JsError> {
+    let guardian_public_keys = decode_guardian_public_keys(&setup.guardian_public_keys)?;
+    let missing = missing_guardian_keys(&setup.election_parameters, &guardian_public_keys);
+    if !missing.is_empty() {
+        return Ok(JointPublicKeyResult::MissingGuardians { indices: missing });
+    }
+    let key = JointElectionPublicKey::compute(&setup.election_parameters, &guardian_public_keys)
+        .map_err(|e| {
+            JsError::new(&format!(
+                "Could not compute joint election public key: {}",
+                e
+            ))
+        })?;
+    Ok(JointPublicKeyResult::Ok { key: encode(&key)? })
+}
+
 /// Wrapper around a vector of bool flags, representing a selection of
 /// candidates for a single election guard contest.
 #[derive(Debug, Serialize, Deserialize, Tsify)]
 #[tsify(from_wasm_abi)]
 pub struct SingleContestSelection(pub Vec<bool>);
 
-impl From<SingleContestSelection> for BTreeMap<ContestIndex, ContestSelection> {
-    fn from(value: SingleContestSelection) -> Self {
-        let mut map = Self::new();
-        // We only ever have one contest, so we unwrap the value created from 1u8.
-        let index = ContestIndex::from_one_based_index_const(1).unwrap();
+impl SingleContestSelection {
+    /// Convert the selection to the selections of a ballot with the contest
+    /// `index` as its only contest.
+    fn into_contest_selections(
+        self,
+        index: ContestIndex,
+    ) -> BTreeMap<ContestIndex, ContestSelection> {
+        let mut map = BTreeMap::new();
         let value = ContestSelection::new(
-            value
-                .0
+            self.0
                 .into_iter()
                 .map(ContestSelectionPlaintext::from)
                 .collect(),
@@ -148,32 +231,108 @@ impl From<SingleContestSelection> for BTreeMap<ContestIndex, ContestSelection> {
     }
 }
 
+impl From<SingleContestSelection> for BTreeMap<ContestIndex, ContestSelection> {
+    fn from(value: SingleContestSelection) -> Self {
+        // We only ever have one contest, so we unwrap the value created from 1u8.
+        let index = ContestIndex::from_one_based_index_const(1).unwrap();
+        value.into_contest_selections(index)
+    }
+}
+
 /// Get an encrypted ballot from a selection of candidates. The value returned
 /// matches the ballot format expected by the election contract entrypoint for
-/// registering ballots.
+/// registering ballots. If a primary nonce is supplied in the `context`, the
+/// same inputs always give the same ballot.
 #[wasm_bindgen(js_name = "getEncryptedBallot")]
 pub fn get_encrypted_ballot(
     selections: SingleContestSelection,
     context: EncryptedBallotContext,
     device_uuid: String,
 ) -> Result<js_sys::Uint8Array, JsError> {
+    let ballot_style_index = context.ballot_style_index.unwrap_or(1);
+    let (primary_nonce, seed): ([u8; 32], Vec<u8>) = match context.primary_nonce.as_deref() {
+        Some(nonce) => {
+            let nonce: [u8; 32] = nonce.try_into().map_err(|_| {
+                JsError::new(&format!(
+                    "The primary nonce must be 32 bytes, but is {} bytes",
+                    nonce.len()
+                ))
+            })?;
+            // The proofs of the ballot are generated with the csprng, so it is
+            // derived from the nonce for the ballot to be re-derivable.
+            (nonce, [CSPRNG_SEED_DOMAIN, &nonce].concat())
+        }
+        // Random is fine when the encryption of the ballot need not be re-derived.
+        None => {
+            let mut rng = thread_rng();
+            (rng.gen(), rng.gen::<[u8; 32]>().to_vec())
+        }
+    };
     let pre_voting_data: PreVotingData = context.try_into()?;
+
+    // Find the single contest of the ballot style, which the selections are for.
+    let ballot_style = BallotStyleIndex::from_one_based_index(ballot_style_index).ok();
+    let Some((ballot_style, style)) = ballot_style.and_then(|index| {
+        let style = pre_voting_data.manifest.ballot_styles.get(index)?;
+        Some((index, style))
+    }) else {
+        return Err(JsError::new(&format!(
+            "Ballot style {ballot_style_index} does not exist in the election manifest"
+        )));
+    };
+    let mut contests = style.contests.iter();
+    let (Some(&contest), None) = (contests.next(), contests.next()) else {
+        return Err(JsError::new(&format!(
+            "Ballot style {ballot_style_index} must contain exactly one contest"
+        )));
+    };
+
+    let Some(selection_limit) = pre_voting_data
+        .manifest
+        .contests
+        .get(contest)
+        .map(|contest| contest.selection_limit)
+    else {
+        return Err(JsError::new(&format!(
+            "The contest of ballot style {ballot_style_index} does not exist in the election \
+             manifest"
+        )));
+    };
+    let selected = selections.0.iter().filter(|&&selected| selected).count();
+    if selected > selection_limit {
+        return Err(JsError::new(&format!(
+            "At most {selection_limit} candidates can be selected, but {selected} are selected"
+        )));
+    }
+
     let device = Device::new(&device_uuid, pre_voting_data);
 
-    let seed: [u8; 32] = thread_rng().gen();
     let mut csprng = Csprng::new(&seed);
 
-    // Random is fine here, as we don't need to re-derive encryption of ballots
-    let primary_nonce: [u8; 32] = thread_rng().gen();
-
     let ballot = BallotEncrypted::new_from_selections(
-        BallotStyleIndex::from_one_based_index_unchecked(1),
+        ballot_style,
         &device,
         &mut csprng,
         primary_nonce.as_ref(),
-        &selections.into(),
+        &selections.into_contest_selections(contest),
     )?;
 
     let js_value = js_sys::Uint8Array::from(encode(&ballot)?.as_slice());
     Ok(js_value)
 }
+
+/// Verify an encrypted ballot, as returned by [`get_encrypted_ballot`], against
+/// the election context. This is the same check the ballot is subject to when
+/// the election is tallied, so ballots for which this returns `false` are not
+/// counted. Ballots that cannot be decoded are also reported as invalid.
+#[wasm_bindgen(js_name = "verifyEncryptedBallot")]
+pub fn verify_encrypted_ballot(
+    ballot: &[u8],
+    context: EncryptedBallotContext,
+) -> Result<bool, JsError> {
+    let pre_voting_data: PreVotingData = context.try_into()?;
+    let Ok(ballot) = decode::<BallotEncrypted>(ballot) else {
+        return Ok(false);
+    };
+    Ok(ballot.verify(&pre_voting_data))
+}
@@ -0,0 +1,266 @@
+//! Tests of the ballot encryption exposed to javascript. These are run in node
+//! with `yarn test`.
+use chrono::TimeZone;
+use eg::{
+    ballot::{BallotEncrypted, BallotTallyBuilder},
+    ballot_style::BallotStyle,
+    election_manifest::{Contest, ContestIndex, ContestOption, ElectionManifest},
+    election_parameters::ElectionParameters,
+    election_record::PreVotingData,
+    guardian::GuardianIndex,
+    guardian_secret_key::GuardianSecretKey,
+    varying_parameters::{BallotChaining, VaryingParameters},
+};
+use election_common::{decode, encode};
+use electionguard_bindings::{
+    compute_joint_public_key, get_encrypted_ballot, verify_encrypted_ballot,
+    EncryptedBallotContext, GuardianSetup, JointPublicKeyResult, SingleContestSelection,
+};
+use util::csprng::Csprng;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// The context of an election with a single guardian. The manifest has a
+/// contest with two options for each contest referenced by `ballot_styles`,
+/// which are given as the one-based indices of the contests of each style.
+fn election_context(ballot_styles: &[&[u32]]) -> EncryptedBallotContext {
+    let contest_count = ballot_styles
+        .iter()
+        .flat_map(|contests| contests.iter().copied())
+        .max()
+        .unwrap_or(1);
+    let contests: Vec<_> = (1..=contest_count)
+        .map(|c| Contest {
+            label:           format!("Contest {c}"),
+            selection_limit: 1,
+            options:         vec![
+                ContestOption {
+                    label: format!("Candidate {c}.1"),
+                },
+                ContestOption {
+                    label: format!("Candidate {c}.2"),
+                },
+            ]
+            .try_into()
+            .unwrap(),
+        })
+        .collect();
+    let ballot_styles: Vec<_> = (1..)
+        .zip(ballot_styles)
+        .map(|(s, contests)| BallotStyle {
+            label:    format!("Ballot style {s}"),
+            contests: contests
+                .iter()
+                .map(|&c| ContestIndex::from_one_based_index(c).unwrap())
+                .collect(),
+        })
+        .collect();
+    let election_manifest = ElectionManifest {
+        label:         "Test election manifest".into(),
+        contests:      contests.try_into().unwrap(),
+        ballot_styles: ballot_styles.try_into().unwrap(),
+    };
+
+    let guardian = GuardianIndex::from_one_based_index(1).unwrap();
+    let election_parameters = ElectionParameters {
+        fixed_parameters:   eg::standard_parameters::STANDARD_PARAMETERS.clone(),
+        varying_parameters: VaryingParameters {
+            n:               guardian,
+            k:               guardian,
+            date:            chrono::Utc.timestamp_opt(0, 0).unwrap(),
+            info:            "Test election with a single guardian.".into(),
+            ballot_chaining: BallotChaining::Prohibited,
+        },
+    };
+    let secret_key = GuardianSecretKey::generate(
+        &mut Csprng::new(b"test guardian"),
+        &election_parameters,
+        guardian,
+        None,
+    );
+
+    EncryptedBallotContext {
+        election_manifest,
+        election_parameters,
+        guardian_public_keys: vec![encode(&secret_key.make_public_key()).unwrap()],
+        ballot_style_index: None,
+        primary_nonce: None,
+    }
+}
+
+/// The message of the javascript error `error`.
+fn error_message(error: JsValue) -> String { String::from(js_sys::Error::from(error).message()) }
+
+/// Encrypt a ballot selecting the first option, returning the encoded ballot.
+fn encrypt(context: EncryptedBallotContext) -> Result<Vec<u8>, JsValue> {
+    let ballot = get_encrypted_ballot(
+        SingleContestSelection(vec![true, false]),
+        context,
+        "test device".into(),
+    )?;
+    Ok(ballot.to_vec())
+}
+
+#[wasm_bindgen_test]
+fn test_encrypted_ballot_with_primary_nonce() {
+    let context = EncryptedBallotContext {
+        primary_nonce: Some(vec![1; 32]),
+        ..election_context(&[&[1]])
+    };
+
+    let ballot = encrypt(context.clone()).expect("Can encrypt ballot");
+    assert_eq!(
+        encrypt(context.clone()).expect("Can encrypt ballot"),
+        ballot,
+        "The same nonce gives the same ballot"
+    );
+    assert!(verify_encrypted_ballot(&ballot, context.clone())
+        .map_err(JsValue::from)
+        .expect("Can verify ballot"));
+
+    let other_nonce = EncryptedBallotContext {
+        primary_nonce: Some(vec![2; 32]),
+        ..context.clone()
+    };
+    assert_ne!(encrypt(other_nonce).expect("Can encrypt ballot"), ballot);
+    let random_nonce = EncryptedBallotContext {
+        primary_nonce: None,
+        ..context
+    };
+    assert_ne!(encrypt(random_nonce).expect("Can encrypt ballot"), ballot);
+}
+
+#[wasm_bindgen_test]
+fn test_encrypted_ballot_invalid_primary_nonce() {
+    let context = EncryptedBallotContext {
+        primary_nonce: Some(vec![1; 31]),
+        ..election_context(&[&[1]])
+    };
+    assert!(encrypt(context).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_encrypted_ballot_ballot_styles() {
+    let context = EncryptedBallotContext {
+        primary_nonce: Some(vec![1; 32]),
+        ..election_context(&[&[1], &[2]])
+    };
+    let first_style = encrypt(context.clone()).expect("Can encrypt ballot");
+    let second_style_context = EncryptedBallotContext {
+        ballot_style_index: Some(2),
+        ..context.clone()
+    };
+    let second_style = encrypt(second_style_context.clone()).expect("Can encrypt ballot");
+    assert_ne!(first_style, second_style);
+    assert!(
+        verify_encrypted_ballot(&second_style, second_style_context.clone())
+            .map_err(JsValue::from)
+            .expect("Can verify ballot")
+    );
+
+    // The ballot only contains the contest of the second style.
+    let ballot: BallotEncrypted = decode(&second_style).expect("Can decode ballot");
+    let mut tally = BallotTallyBuilder::new(
+        &second_style_context.election_manifest,
+        &second_style_context.election_parameters,
+    );
+    tally.update(ballot);
+    let contests: Vec<_> = tally.finalize().into_keys().collect();
+    assert_eq!(contests, vec![
+        ContestIndex::from_one_based_index(2).unwrap()
+    ]);
+
+    let missing_style = EncryptedBallotContext {
+        ballot_style_index: Some(3),
+        ..context
+    };
+    let error = encrypt(missing_style).expect_err("Ballot style does not exist");
+    assert_eq!(
+        error_message(error),
+        "Ballot style 3 does not exist in the election manifest"
+    );
+}
+
+/// The context of an election with two guardians, where only the first
+/// guardian has registered a public key.
+fn missing_guardian_context() -> EncryptedBallotContext {
+    let mut context = election_context(&[&[1]]);
+    context.election_parameters.varying_parameters.n =
+        GuardianIndex::from_one_based_index(2).unwrap();
+    context
+}
+
+#[wasm_bindgen_test]
+fn test_encrypted_ballot_missing_guardian_key() {
+    let error = encrypt(missing_guardian_context()).expect_err("Guardian setup is incomplete");
+    assert_eq!(
+        error_message(error),
+        "The guardian setup is incomplete. No public keys are registered for the guardians with \
+         indices 2."
+    );
+    assert!(PreVotingData::try_from(missing_guardian_context()).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_compute_joint_public_key_missing_guardian_key() {
+    let context = missing_guardian_context();
+    let result = compute_joint_public_key(GuardianSetup {
+        election_parameters:  context.election_parameters,
+        guardian_public_keys: context.guardian_public_keys,
+    })
+    .map_err(JsValue::from)
+    .expect("Can check the guardian setup");
+    assert!(
+        matches!(&result, JointPublicKeyResult::MissingGuardians { indices } if indices == &[2]),
+        "{result:?}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_verify_encrypted_ballot() {
+    let context = EncryptedBallotContext {
+        primary_nonce: Some(vec![1; 32]),
+        ..election_context(&[&[1]])
+    };
+    let ballot = encrypt(context.clone()).expect("Can encrypt ballot");
+    assert!(verify_encrypted_ballot(&ballot, context.clone())
+        .map_err(JsValue::from)
+        .expect("Can verify ballot"));
+
+    // Changing a byte of the encrypted selections invalidates the ballot.
+    let mut tampered = ballot.clone();
+    tampered[ballot.len() / 2] ^= 1;
+    assert!(!verify_encrypted_ballot(&tampered, context.clone())
+        .map_err(JsValue::from)
+        .expect("Can verify ballot"));
+
+    // Bytes that do not decode to a ballot are not valid either.
+    assert!(
+        !verify_encrypted_ballot(&ballot[..ballot.len() - 1], context)
+            .map_err(JsValue::from)
+            .expect("Can verify ballot")
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_encrypted_ballot_over_selection() {
+    let error = get_encrypted_ballot(
+        SingleContestSelection(vec![true, true]),
+        election_context(&[&[1]]),
+        "test device".into(),
+    )
+    .map_err(JsValue::from)
+    .expect_err("Only one candidate can be selected");
+    assert_eq!(
+        error_message(error),
+        "At most 1 candidates can be selected, but 2 are selected"
+    );
+
+    // Selecting no candidates is within the limit.
+    assert!(get_encrypted_ballot(
+        SingleContestSelection(vec![false, false]),
+        election_context(&[&[1]]),
+        "test device".into(),
+    )
+    .is_ok());
+}
@@ -29,8 +29,9 @@ use eg::{
     },
 };
 use election_common::{
-    decode, encode, EncryptedTally, GuardianDecryption, GuardianDecryptionProof,
-    GuardianDecryptionProofState, HttpClient,
+    decode, decode_guardian_decryption, encode, has_decryption_share, EncodedGuardianDecryption,
+    EncryptedTally, GuardianDecryption, GuardianDecryptionProof, GuardianDecryptionProofState,
+    HttpClient,
 };
 use itertools::Itertools;
 use rand::{thread_rng, Rng};
@@ -39,6 +40,7 @@ use std::{
     collections::BTreeMap,
     path::{Path, PathBuf},
     str::FromStr,
+    sync::atomic::{AtomicBool, Ordering},
 };
 use tauri::{App, AppHandle, Manager, State, Window};
 use tokio::sync::Mutex;
@@ -56,6 +58,29 @@ const SECRET_SHARE_FILE: &str = "secret-share.json.aes";
 const KEY_SHARE_ENCRYPTION_SECRETS_FILE: &str = "key-share-secrets.json.aes";
 /// The file name of the encrypted secret share for a guardian
 const DECRYPTION_SECRET_STATES: &str = "secret-decryption_states.json.aes";
+/// The encrypted files of a guardian data directory, which are included in a
+/// [`GuardianBackup`].
+const GUARDIAN_DATA_FILES: [&str; 5] = [
+    WALLET_ACCOUNT_FILE,
+    SECRET_KEY_FILE,
+    SECRET_SHARE_FILE,
+    KEY_SHARE_ENCRYPTION_SECRETS_FILE,
+    DECRYPTION_SECRET_STATES,
+];
+/// The version of the [`GuardianBackup`] format written by
+/// [`export_guardian_backup`].
+const GUARDIAN_BACKUP_VERSION: u32 = 1;
+/// The file extension of guardian backups.
+const GUARDIAN_BACKUP_EXTENSION: &str = "backup";
+/// The directory in the application data directory which stale guardian data
+/// directories are moved to when archived.
+const ARCHIVE_DIR: &str = "archive";
+/// The suffix of the sibling directory which the files of a guardian data
+/// directory are re-encrypted into by [`change_password`].
+const ROTATING_DIR_SUFFIX: &str = ".rotating";
+/// The suffix of the sibling directory which a guardian data directory is moved
+/// to by [`change_password`] while the re-encrypted directory is swapped in.
+const PREVIOUS_DIR_SUFFIX: &str = ".previous";
 
 /// The default request timeout to use if not specified by environment variable
 /// "CCD_ELECTION_REQUEST_TIMEOUT_MS".
@@ -128,6 +153,14 @@ enum Error {
     /// When a decryption share result shared by some guardian is invalid
     #[error("{0} - manual intervention required by the election coordinator")]
     InvalidDecryptionShare(String),
+    /// Another flow is already running for the active guardian, e.g. if the
+    /// user triggered the same action twice.
+    #[error("Another action is already in progress")]
+    FlowInProgress,
+    /// The guardian backup was written by an incompatible version of the
+    /// application.
+    #[error("Unsupported backup version {0}")]
+    UnsupportedBackupVersion(u32),
 }
 
 /// Formats a [`QueryError`] for the frontend.
@@ -225,9 +258,32 @@ struct ActiveGuardian {
 }
 
 /// The type of managed state for the active guardian. This is set as the user
-/// either imports or loads an account.
+/// either imports or loads an account. The flag tracks whether a flow is
+/// currently running, see [`ActiveGuardianState::start_flow`].
 #[derive(Default)]
-struct ActiveGuardianState(Mutex<Option<ActiveGuardian>>);
+struct ActiveGuardianState(Mutex<Option<ActiveGuardian>>, AtomicBool);
+
+impl ActiveGuardianState {
+    /// Mark a flow as running until the returned guard is dropped. This
+    /// prevents flows from running concurrently, which could otherwise e.g.
+    /// register the same data in the contract twice.
+    ///
+    /// ## Errors
+    /// [`Error::FlowInProgress`] if another flow is running.
+    fn start_flow(&self) -> Result<FlowGuard<'_>, Error> {
+        self.1
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .map_err(|_| Error::FlowInProgress)?;
+        Ok(FlowGuard(&self.1))
+    }
+}
+
+/// Marks a flow as running while held, see [`ActiveGuardianState::start_flow`].
+struct FlowGuard<'a>(&'a AtomicBool);
+
+impl Drop for FlowGuard<'_> {
+    fn drop(&mut self) { self.0.store(false, Ordering::Release); }
+}
 
 /// The data registered in the election contract
 #[derive(Default)]
@@ -494,6 +550,56 @@ fn read_encrypted_file<D: serde::de::DeserializeOwned>(
     Ok(value)
 }
 
+/// Get the sibling of `guardian_dir` with `suffix` appended to its name.
+fn sibling_dir(guardian_dir: &Path, suffix: &str) -> PathBuf {
+    let mut name = guardian_dir.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    guardian_dir.with_file_name(name)
+}
+
+/// Recover `guardian_dir` from a [`change_password`] which did not complete.
+/// If the process stopped between moving `guardian_dir` away and moving the
+/// re-encrypted directory in its place, the previous directory is restored,
+/// i.e. the data stays encrypted with the current password. Any leftover
+/// sibling directories are removed.
+fn recover_password_change(guardian_dir: &Path) -> Result<(), Error> {
+    let previous_dir = sibling_dir(guardian_dir, PREVIOUS_DIR_SUFFIX);
+    if previous_dir.is_dir() {
+        if guardian_dir.exists() {
+            std::fs::remove_dir_all(&previous_dir)?;
+        } else {
+            std::fs::rename(&previous_dir, guardian_dir)?;
+        }
+    }
+    let rotating_dir = sibling_dir(guardian_dir, ROTATING_DIR_SUFFIX);
+    if rotating_dir.exists() {
+        std::fs::remove_dir_all(&rotating_dir)?;
+    }
+    Ok(())
+}
+
+/// Recover all guardian data directories in `app_data_dir` from password
+/// changes which did not complete, see [`recover_password_change`].
+fn recover_password_changes(app_data_dir: &Path) -> Result<(), Error> {
+    let mut guardian_dirs = Vec::new();
+    for entry in std::fs::read_dir(app_data_dir)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let account = name
+            .strip_suffix(ROTATING_DIR_SUFFIX)
+            .or_else(|| name.strip_suffix(PREVIOUS_DIR_SUFFIX));
+        if let Some(account) = account.filter(|a| AccountAddress::from_str(a).is_ok()) {
+            guardian_dirs.push(app_data_dir.join(account));
+        }
+    }
+    for guardian_dir in guardian_dirs {
+        recover_password_change(&guardian_dir)?;
+    }
+    Ok(())
+}
+
 /// Handle a wallet import. Creates a directory for storing data associated with
 /// the guardian account and returns the [`AccountAddress`] of the imported
 /// wallet account.
@@ -546,6 +652,7 @@ async fn import_wallet_account(
 #[tauri::command(async)]
 fn get_accounts(handle: AppHandle) -> Result<Vec<AccountAddress>, Error> {
     let app_data_dir = handle.path_resolver().app_data_dir().unwrap();
+    recover_password_changes(&app_data_dir)?;
     let entries = std::fs::read_dir(app_data_dir)?;
 
     let accounts: Vec<_> = entries
@@ -577,7 +684,9 @@ async fn load_account(
     active_guardian: State<'_, ActiveGuardianState>,
 ) -> Result<(), Error> {
     let password = Password::from(password);
-    let account_path = guardian_data_dir(&app_handle, account).join(WALLET_ACCOUNT_FILE);
+    let guardian_dir = guardian_data_dir(&app_handle, account);
+    recover_password_change(&guardian_dir)?;
+    let account_path = guardian_dir.join(WALLET_ACCOUNT_FILE);
     let guardian_data: GuardianData = read_encrypted_file(&password, &account_path)?;
 
     let mut active_guardian = active_guardian.0.lock().await;
@@ -589,6 +698,219 @@ async fn load_account(
     Ok(())
 }
 
+/// A backup of the key material of a guardian, consisting of the files of the
+/// guardian data directory. The files are kept as stored on disk, i.e.
+/// encrypted with the password of the guardian account.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct GuardianBackup {
+    /// The version of the backup format, see [`GUARDIAN_BACKUP_VERSION`].
+    version: u32,
+    /// The guardian account the backup belongs to.
+    account: AccountAddress,
+    /// The contents of the files of the guardian data directory, keyed by their
+    /// file name. Only files found in [`GUARDIAN_DATA_FILES`] are included.
+    files:   BTreeMap<String, EncryptedData>,
+}
+
+/// Export the key material of the active guardian into a backup file chosen by
+/// the user. The backup is encrypted with `password`, which can differ from
+/// the password of the guardian account. Returns the path of the backup file,
+/// or `None` if the user did not choose a file.
+///
+/// ## Errors
+/// Fails if no guardian account is active, or the guardian data could not be
+/// read or the backup not written.
+#[tauri::command]
+async fn export_guardian_backup(
+    password: String,
+    active_guardian: State<'_, ActiveGuardianState>,
+    app_handle: AppHandle,
+) -> Result<Option<PathBuf>, Error> {
+    let account = active_guardian
+        .0
+        .lock()
+        .await
+        .as_ref()
+        .context("Guardian account not available in app state")?
+        .guardian
+        .account;
+
+    let guardian_dir = guardian_data_dir(&app_handle, account);
+    let mut files = BTreeMap::new();
+    for file_name in GUARDIAN_DATA_FILES {
+        let file_path = guardian_dir.join(file_name);
+        if !file_path.exists() {
+            continue;
+        }
+        let encrypted_bytes = std::fs::read(&file_path)?;
+        let encrypted: EncryptedData =
+            serde_json::from_slice(&encrypted_bytes).map_err(|_| Error::Corrupted(file_path))?;
+        files.insert(file_name.to_string(), encrypted);
+    }
+    let backup = GuardianBackup {
+        version: GUARDIAN_BACKUP_VERSION,
+        account,
+        files,
+    };
+
+    let Some(backup_path) = tauri::api::dialog::blocking::FileDialogBuilder::new()
+        .set_title("Export guardian backup")
+        .set_file_name(&format!("{account}.{GUARDIAN_BACKUP_EXTENSION}"))
+        .add_filter("Guardian backup", &[GUARDIAN_BACKUP_EXTENSION])
+        .save_file()
+    else {
+        return Ok(None);
+    };
+    write_encrypted_file(&Password::from(password), &backup, &backup_path)?;
+    Ok(Some(backup_path))
+}
+
+/// Import a backup written by [`export_guardian_backup`] from a file chosen by
+/// the user, restoring the guardian data directory of the account it belongs
+/// to. The backup is decrypted with `password`, i.e. the password used for the
+/// export. Afterwards, the account can be loaded with its original password.
+/// Returns the restored account, or `None` if the user did not choose a file.
+///
+/// ## Errors
+/// - [`Error::DecryptionFailed`] if the backup cannot be decrypted with
+///   `password`
+/// - [`Error::UnsupportedBackupVersion`] if the backup format is not supported
+/// - [`Error::ExistingAccount`] if the account has already been imported
+#[tauri::command]
+async fn import_guardian_backup(
+    password: String,
+    app_handle: AppHandle,
+) -> Result<Option<AccountAddress>, Error> {
+    let Some(backup_path) = tauri::api::dialog::blocking::FileDialogBuilder::new()
+        .set_title("Import guardian backup")
+        .add_filter("Guardian backup", &[GUARDIAN_BACKUP_EXTENSION])
+        .pick_file()
+    else {
+        return Ok(None);
+    };
+    let backup: GuardianBackup = read_encrypted_file(&Password::from(password), &backup_path)?;
+    if backup.version != GUARDIAN_BACKUP_VERSION {
+        return Err(Error::UnsupportedBackupVersion(backup.version));
+    }
+    if let Some(file_name) = backup
+        .files
+        .keys()
+        .find(|file_name| !GUARDIAN_DATA_FILES.contains(&file_name.as_str()))
+    {
+        return Err(anyhow!("Unexpected file {file_name} in guardian backup").into());
+    }
+    if !backup.files.contains_key(WALLET_ACCOUNT_FILE) {
+        return Err(anyhow!("Guardian backup does not contain the guardian account").into());
+    }
+
+    let guardian_dir = guardian_data_dir(&app_handle, backup.account);
+    if guardian_dir.exists() {
+        return Err(Error::ExistingAccount);
+    }
+    std::fs::create_dir(&guardian_dir)?;
+    for (file_name, encrypted) in &backup.files {
+        // Serialization will not fail at this point.
+        let encrypted_bytes = serde_json::to_vec(encrypted).unwrap();
+        std::fs::write(guardian_dir.join(file_name), encrypted_bytes)?;
+    }
+
+    Ok(Some(backup.account))
+}
+
+/// Change the password of the active guardian account to `new`, re-encrypting
+/// all files of the guardian data directory.
+///
+/// See [`rotate_password`] for how the files are replaced.
+///
+/// ## Errors
+/// - [`Error::DecryptionFailed`] if `current` is not the password of the
+///   account
+/// - [`Error::FlowInProgress`] if another flow is running for the account
+#[tauri::command]
+async fn change_password(
+    current: String,
+    new: String,
+    active_guardian: State<'_, ActiveGuardianState>,
+    app_handle: AppHandle,
+) -> Result<(), Error> {
+    let _flow = active_guardian.start_flow()?;
+    let mut active_guardian = active_guardian.0.lock().await;
+    let active_guardian = active_guardian
+        .as_mut()
+        .context("Guardian account not available in app state")?;
+
+    let guardian_dir = guardian_data_dir(&app_handle, active_guardian.guardian.account);
+    let new = Password::from(new);
+    rotate_password(&guardian_dir, &Password::from(current), &new)?;
+
+    active_guardian.password = new;
+    Ok(())
+}
+
+/// Re-encrypt the files of `guardian_dir` from the `current` password to the
+/// `new` password. The current password is verified by decrypting the files
+/// with `current`.
+///
+/// The files are re-encrypted into a sibling directory, which then replaces
+/// `guardian_dir`. The data is thus never encrypted with a mix of the two
+/// passwords: if the process stops before the swap completes, the directory
+/// encrypted with `current` is restored by [`recover_password_change`]. On
+/// errors, the re-encrypted files are removed.
+fn rotate_password(guardian_dir: &Path, current: &Password, new: &Password) -> Result<(), Error> {
+    recover_password_change(guardian_dir)?;
+    if !guardian_dir.join(WALLET_ACCOUNT_FILE).is_file() {
+        return Err(anyhow!("Guardian account data not found").into());
+    }
+
+    let rotating_dir = sibling_dir(guardian_dir, ROTATING_DIR_SUFFIX);
+    std::fs::create_dir(&rotating_dir)?;
+    let rotated = (|| {
+        let mut rng = thread_rng();
+        for entry in std::fs::read_dir(guardian_dir)? {
+            let file_path = entry?.path();
+            let Some(file_name) = file_path.file_name() else {
+                continue;
+            };
+            let rotated_path = rotating_dir.join(file_name);
+            let is_data_file = file_name
+                .to_str()
+                .is_some_and(|name| GUARDIAN_DATA_FILES.contains(&name));
+            if !is_data_file {
+                // Keep any other files as they are.
+                std::fs::copy(&file_path, rotated_path)?;
+                continue;
+            }
+
+            let encrypted_bytes = std::fs::read(&file_path)?;
+            let encrypted: EncryptedData = serde_json::from_slice(&encrypted_bytes)
+                .map_err(|_| Error::Corrupted(file_path.clone()))?;
+            let decrypted_bytes =
+                decrypt(current, &encrypted).map_err(|_| Error::DecryptionFailed)?;
+            // Serialization will not fail at this point.
+            let encrypted_bytes =
+                serde_json::to_vec(&encrypt(new, &decrypted_bytes, &mut rng)).unwrap();
+            std::fs::write(rotated_path, encrypted_bytes)
+                .context("Failed to write the file to disk")?;
+        }
+        Ok::<_, Error>(())
+    })();
+    if let Err(error) = rotated {
+        let _ = std::fs::remove_dir_all(&rotating_dir);
+        return Err(error);
+    }
+
+    let previous_dir = sibling_dir(guardian_dir, PREVIOUS_DIR_SUFFIX);
+    std::fs::rename(guardian_dir, &previous_dir)?;
+    if let Err(error) = std::fs::rename(&rotating_dir, guardian_dir) {
+        recover_password_change(guardian_dir)?;
+        return Err(error.into());
+    }
+    // The password has been changed at this point. If the previous directory
+    // cannot be removed now, it is removed by the next recovery.
+    let _ = std::fs::remove_dir_all(&previous_dir);
+    Ok(())
+}
+
 /// Generate a key pair for the selected guardian, storing the secret key on
 /// disk and returning the public key. If the secret key already exists, it
 /// returns the corresponding public key.
@@ -683,6 +1005,7 @@ async fn register_guardian_key_flow(
     app_handle: AppHandle,
     window: Window,
 ) -> Result<(), Error> {
+    let _flow = active_guardian.start_flow()?;
     let cancel = handle_abort(&channel_id, &window);
     let interaction = async {
         let active_guardian = active_guardian.0.lock().await;
@@ -836,6 +1159,7 @@ async fn register_guardian_shares_flow(
     app_handle: AppHandle,
     window: Window,
 ) -> Result<(), Error> {
+    let _flow = active_guardian.start_flow()?;
     let cancel = handle_abort(&channel_id, &window);
     let interaction = async {
         let active_guardian = active_guardian.0.lock().await;
@@ -1011,6 +1335,7 @@ async fn generate_secret_share_flow(
     app_handle: AppHandle,
     window: Window,
 ) -> Result<(), Error> {
+    let _flow = active_guardian.start_flow()?;
     let cancel = handle_abort(&channel_id, &window);
     let interaction = async {
         let mut app_config = app_config.0.lock().await;
@@ -1142,6 +1467,7 @@ async fn register_decryption_shares_flow(
     app_handle: AppHandle,
     window: Window,
 ) -> Result<(), Error> {
+    let _flow = active_guardian.start_flow()?;
     let cancel = handle_abort(&channel_id, &window);
     let interaction = async {
         let mut app_config = app_config.0.lock().await;
@@ -1185,7 +1511,66 @@ async fn register_decryption_shares_flow(
                 "postDecryptionShare",
                 Amount::zero(),
                 active_guardian.guardian.account,
-                &encode(&decryption_shares).context("Failed to serialize decryption shares")?,
+                &EncodedGuardianDecryption::encode(&decryption_shares)
+                    .context("Failed to serialize decryption shares")?
+                    .0,
+            )
+            .await?;
+        let ccd_cost =
+            energy_to_ccd(contract_update.current_energy(), &mut contract.client).await?;
+
+        // Wait for response from the user through the frontend
+        wait_for_approval(&channel_id, &window, &ccd_cost).await?;
+
+        contract_update
+            .send(&active_guardian.guardian.keys)
+            .await?
+            .wait_for_finalization()
+            .await?;
+
+        Ok(())
+    };
+
+    tokio::select! {
+        biased;
+        error = cancel => Err(error),
+        res = interaction => res
+    }
+}
+
+/// This command executes the following steps:
+///
+/// - Request transaction fee estimate approval from user
+/// - Register the acknowledgement of the encrypted tally in the contract
+///
+/// ## Errors
+/// Expected errors include:
+/// - [`Error::NodeConnection`]
+/// - [`Error::NetworkError`]
+/// - [`Error::QueryFailed`]
+#[tauri::command]
+async fn acknowledge_tally_flow(
+    channel_id: String,
+    active_guardian: State<'_, ActiveGuardianState>,
+    app_config: State<'_, AppConfigState>,
+    window: Window,
+) -> Result<(), Error> {
+    let _flow = active_guardian.start_flow()?;
+    let cancel = handle_abort(&channel_id, &window);
+    let interaction = async {
+        let mut app_config = app_config.0.lock().await;
+        let active_guardian = active_guardian.0.lock().await;
+        let active_guardian = active_guardian
+            .as_ref()
+            .context("Expected guardian account to be available in app state")?;
+
+        let mut contract = app_config.connection().await?.contract;
+        let contract_update = contract
+            .dry_run_update::<(), Error>(
+                "acknowledgeTally",
+                Amount::zero(),
+                active_guardian.guardian.account,
+                &(),
             )
             .await?;
         let ccd_cost =
@@ -1241,12 +1626,14 @@ async fn generate_decryption_proofs(
         .guardians
         .iter()
         .filter(|(_, guardian_state)| !guardian_state.excluded)
-        .filter_map(|(_, guardian_state)| guardian_state.decryption_share.as_ref())
-        .map(|bytes| {
-            decode::<GuardianDecryption>(bytes).map_err(|_| {
-                Error::InvalidDecryptionShare("Invalid decryption shares were detected".into())
+        .map(|(_, guardian_state)| {
+            decode_guardian_decryption(guardian_state, encrypted_tally).map_err(|e| {
+                Error::InvalidDecryptionShare(format!(
+                    "Invalid decryption shares were detected: {e}"
+                ))
             })
         })
+        .filter_map_ok(|shares| shares)
         .try_collect()?;
 
     // Generate the decryption proof for a single contest entry. An error is
@@ -1341,6 +1728,7 @@ async fn register_decryption_proofs_flow(
     app_handle: AppHandle,
     window: Window,
 ) -> Result<(), Error> {
+    let _flow = active_guardian.start_flow()?;
     let cancel = handle_abort(&channel_id, &window);
     let interaction = async {
         let mut app_config = app_config.0.lock().await;
@@ -1418,6 +1806,8 @@ struct GuardianStateResponse {
     has_decryption_proof: bool,
     /// Whether the guardian is excluded from the tally phase
     excluded:             bool,
+    /// Whether the guardian has acknowledged the encrypted tally
+    acknowledged_tally:   bool,
 }
 
 impl From<&contract::GuardianState> for GuardianStateResponse {
@@ -1427,9 +1817,10 @@ impl From<&contract::GuardianState> for GuardianStateResponse {
             has_public_key:       value.public_key.is_some(),
             index:                value.index,
             status:               value.status.clone(),
-            has_decryption_share: value.decryption_share.is_some(),
+            has_decryption_share: has_decryption_share(value),
             has_decryption_proof: value.decryption_share_proof.is_some(),
             excluded:             value.excluded,
+            acknowledged_tally:   value.acknowledged_tally,
         }
     }
 }
@@ -1503,6 +1894,513 @@ async fn refresh_encrypted_tally(
     Ok(true)
 }
 
+/// The status of an encrypted file stored for a guardian.
+#[derive(serde::Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct LocalFileStatus {
+    /// Whether the file exists
+    present:     bool,
+    /// Whether the file could be decrypted with the password of the guardian
+    decryptable: bool,
+    /// Whether the decrypted contents are structurally valid
+    valid:       bool,
+}
+
+/// Checks whether the file at `file_path` is present, can be decrypted with
+/// `password`, and holds a value of type `D` for which `validate` holds.
+fn verify_encrypted_file<D: DeserializeOwned>(
+    password: &Password,
+    file_path: &Path,
+    validate: impl FnOnce(D) -> bool,
+) -> LocalFileStatus {
+    let Ok(encrypted_bytes) = std::fs::read(file_path) else {
+        return LocalFileStatus {
+            present: file_path.exists(),
+            ..Default::default()
+        };
+    };
+    let Some(decrypted_bytes) = serde_json::from_slice::<EncryptedData>(&encrypted_bytes)
+        .ok()
+        .and_then(|encrypted| decrypt(password, &encrypted).ok())
+    else {
+        return LocalFileStatus {
+            present: true,
+            ..Default::default()
+        };
+    };
+    let valid = serde_json::from_slice::<D>(&decrypted_bytes).map_or(false, validate);
+    LocalFileStatus {
+        present: true,
+        decryptable: true,
+        valid,
+    }
+}
+
+/// The status of each of the files stored for the active guardian.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LocalStateResponse {
+    /// The status of the guardian secret key
+    secret_key:                   LocalFileStatus,
+    /// The status of the guardian secret key share
+    secret_share:                 LocalFileStatus,
+    /// The status of the secrets used to encrypt the key shares sent to the
+    /// other guardians
+    key_share_encryption_secrets: LocalFileStatus,
+    /// The status of the secret states used to prove correct decryption
+    decryption_secret_states:     LocalFileStatus,
+}
+
+/// Reads and validates each of the encrypted files stored for the active
+/// guardian. This allows guardians to confirm that the data needed for the
+/// decryption phase is recoverable before it is needed. Files which are not
+/// present are expected if the guardian has not yet reached the corresponding
+/// step of the election.
+///
+/// ## Errors
+/// - [`Error::Internal`] If no guardian account is active
+#[tauri::command]
+async fn verify_local_state(
+    active_guardian: State<'_, ActiveGuardianState>,
+    app_handle: AppHandle,
+) -> Result<LocalStateResponse, Error> {
+    let active_guardian = active_guardian.0.lock().await;
+    let active_guardian = active_guardian
+        .as_ref()
+        .context("Expected guardian account to be available in app state")?;
+    let guardian_data_dir = guardian_data_dir(&app_handle, active_guardian.guardian.account);
+    let password = &active_guardian.password;
+    let index = active_guardian.guardian.index;
+
+    let response = LocalStateResponse {
+        secret_key:                   verify_encrypted_file(
+            password,
+            &guardian_data_dir.join(SECRET_KEY_FILE),
+            |secret_key: GuardianSecretKey| secret_key.make_public_key().i == index,
+        ),
+        secret_share:                 verify_encrypted_file(
+            password,
+            &guardian_data_dir.join(SECRET_SHARE_FILE),
+            |secret_share: GuardianSecretKeyShare| secret_share.i == index,
+        ),
+        // The secrets are only kept for dispute resolution, so we only check that
+        // they are readable.
+        key_share_encryption_secrets: verify_encrypted_file(
+            password,
+            &guardian_data_dir.join(KEY_SHARE_ENCRYPTION_SECRETS_FILE),
+            |_: Vec<serde_json::Value>| true,
+        ),
+        decryption_secret_states:     verify_encrypted_file(
+            password,
+            &guardian_data_dir.join(DECRYPTION_SECRET_STATES),
+            |_: GuardianDecryptionProofState| true,
+        ),
+    };
+    Ok(response)
+}
+
+/// Describes whether the decryption of the active guardian will be included in
+/// the decryption of the election result.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InclusionResponse {
+    /// Whether the guardian has registered a decryption share
+    has_decryption_share: bool,
+    /// Whether the guardian has registered a proof of correct decryption
+    has_decryption_proof: bool,
+    /// Whether the guardian is excluded from the tally phase
+    excluded:             bool,
+    /// The number of guardians which are not excluded and have registered a
+    /// decryption share
+    contributors:         usize,
+    /// The number of decryption shares needed to decrypt the election result
+    threshold:            usize,
+    /// Whether enough guardians have registered decryption shares to decrypt
+    /// the election result
+    quorum_met:           bool,
+    /// Whether the decryption of the guardian will be included in the
+    /// decryption of the election result
+    included:             bool,
+}
+
+/// Checks whether the decryption registered by the active guardian will be
+/// included in the decryption of the election result. This lets guardians
+/// determine whether they need to act again after the finalization phase has
+/// been reset.
+///
+/// ## Errors
+/// - [`Error::NetworkError`]
+/// - [`Error::Http`]
+/// - [`Error::Internal`] If no guardian account is active, or if the guardian
+///   is not found in the guardians state of the election contract
+#[tauri::command]
+async fn check_inclusion(
+    active_guardian: State<'_, ActiveGuardianState>,
+    app_config: State<'_, AppConfigState>,
+) -> Result<InclusionResponse, Error> {
+    let account = active_guardian
+        .0
+        .lock()
+        .await
+        .as_ref()
+        .context("Expected guardian account to be available in app state")?
+        .guardian
+        .account;
+    let mut app_config = app_config.0.lock().await;
+    let threshold = app_config
+        .election_guard()
+        .await?
+        .parameters
+        .varying_parameters
+        .k
+        .get_one_based_usize();
+    let mut contract = app_config.connection().await?.contract;
+    let guardians_state = contract
+        .view::<_, contract::GuardiansState, Error>(
+            "viewGuardiansState",
+            &(),
+            BlockIdentifier::LastFinal,
+        )
+        .await?;
+    compute_inclusion(&guardians_state, account, threshold)
+}
+
+/// Determines whether the decryption registered by the guardian `account` will
+/// be included in the decryption of the election result, given the
+/// `guardians_state` registered in the contract and the number of decryption
+/// shares needed.
+///
+/// ## Errors
+/// - [`Error::Internal`] If the guardian is not found in `guardians_state`
+fn compute_inclusion(
+    guardians_state: &contract::GuardiansState,
+    account: AccountAddress,
+    threshold: usize,
+) -> Result<InclusionResponse, Error> {
+    let guardian_state = guardians_state
+        .iter()
+        .find(|(ga, _)| *ga == account)
+        .map(|(_, gs)| gs)
+        .context("Guardian not found in the election contract")?;
+    let contributors = guardians_state
+        .iter()
+        .filter(|(_, gs)| !gs.excluded && has_decryption_share(gs))
+        .count();
+
+    let has_decryption_share = has_decryption_share(guardian_state);
+    let has_decryption_proof = guardian_state.decryption_share_proof.is_some();
+    let excluded = guardian_state.excluded;
+    let response = InclusionResponse {
+        has_decryption_share,
+        has_decryption_proof,
+        excluded,
+        contributors,
+        threshold,
+        quorum_met: contributors >= threshold,
+        included: !excluded && has_decryption_share && has_decryption_proof,
+    };
+    Ok(response)
+}
+
+/// The next step of the decryption phase the active guardian should run.
+#[derive(serde::Serialize, Debug, PartialEq)]
+enum DecryptionStep {
+    /// The guardian is excluded from the decryption phase.
+    Excluded,
+    /// The encrypted tally has not been registered in the contract yet.
+    AwaitEncryptedTally,
+    /// Run [`register_decryption_shares_flow`].
+    RegisterShares,
+    /// Run [`register_decryption_proofs_flow`].
+    RegisterProofs,
+    /// The decryption shares are registered, but the secret states needed to
+    /// prove correct decryption are not readable. The election coordinator
+    /// must reset the finalization phase for the guardian to take part.
+    MissingSecretStates,
+    /// The guardian has completed the decryption phase.
+    Done,
+}
+
+/// Describes the progress of the active guardian in the decryption phase.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DecryptionProgressResponse {
+    /// Whether the guardian has registered a decryption share
+    has_decryption_share:     bool,
+    /// Whether the guardian has registered a proof of correct decryption
+    has_decryption_proof:     bool,
+    /// The status of the secret states used to prove correct decryption
+    decryption_secret_states: LocalFileStatus,
+    /// The step the guardian should run next
+    next_step:                DecryptionStep,
+}
+
+/// Determines the next step of the decryption phase from the state registered
+/// by the guardian in the contract and the status of the locally stored secret
+/// states.
+fn next_decryption_step(
+    guardian_state: &contract::GuardianState,
+    has_encrypted_tally: bool,
+    secret_states: &LocalFileStatus,
+) -> DecryptionStep {
+    if guardian_state.excluded {
+        DecryptionStep::Excluded
+    } else if !has_encrypted_tally {
+        DecryptionStep::AwaitEncryptedTally
+    } else if !has_decryption_share(guardian_state) {
+        DecryptionStep::RegisterShares
+    } else if guardian_state.decryption_share_proof.is_some() {
+        DecryptionStep::Done
+    } else if secret_states.valid {
+        DecryptionStep::RegisterProofs
+    } else {
+        DecryptionStep::MissingSecretStates
+    }
+}
+
+/// Reports which step of the decryption phase the active guardian should run
+/// next, based on the state registered in the contract and the files stored
+/// locally. This allows resuming the decryption phase after the application
+/// has been restarted between registering the decryption shares and the
+/// proofs of correct decryption.
+///
+/// ## Errors
+/// - [`Error::NetworkError`]
+/// - [`Error::Internal`] If no guardian account is active, or if the guardian
+///   is not found in the guardians state of the election contract
+#[tauri::command]
+async fn decryption_progress(
+    active_guardian: State<'_, ActiveGuardianState>,
+    app_config: State<'_, AppConfigState>,
+    app_handle: AppHandle,
+) -> Result<DecryptionProgressResponse, Error> {
+    let (account, decryption_secret_states) = {
+        let active_guardian = active_guardian.0.lock().await;
+        let active_guardian = active_guardian
+            .as_ref()
+            .context("Expected guardian account to be available in app state")?;
+        let account = active_guardian.guardian.account;
+        let secret_states = verify_encrypted_file(
+            &active_guardian.password,
+            &guardian_data_dir(&app_handle, account).join(DECRYPTION_SECRET_STATES),
+            |_: GuardianDecryptionProofState| true,
+        );
+        (account, secret_states)
+    };
+
+    let mut contract = app_config.0.lock().await.connection().await?.contract;
+    let guardians_state = contract
+        .view::<_, contract::GuardiansState, Error>(
+            "viewGuardiansState",
+            &(),
+            BlockIdentifier::LastFinal,
+        )
+        .await?;
+    let has_encrypted_tally = contract
+        .view::<_, Option<contract::HashSha2256>, Error>(
+            "viewEncryptedTallyHash",
+            &(),
+            BlockIdentifier::LastFinal,
+        )
+        .await?
+        .is_some();
+
+    let guardian_state = guardians_state
+        .iter()
+        .find(|(ga, _)| *ga == account)
+        .map(|(_, gs)| gs)
+        .context("Guardian not found in the election contract")?;
+    let next_step = next_decryption_step(
+        guardian_state,
+        has_encrypted_tally,
+        &decryption_secret_states,
+    );
+    let response = DecryptionProgressResponse {
+        has_decryption_share: has_decryption_share(guardian_state),
+        has_decryption_proof: guardian_state.decryption_share_proof.is_some(),
+        decryption_secret_states,
+        next_step,
+    };
+    Ok(response)
+}
+
+/// The reason a guardian data directory is considered stale by
+/// [`cleanup_elections`].
+#[derive(serde::Serialize, Clone, Copy, Debug, PartialEq)]
+enum StaleReason {
+    /// The directory does not contain the guardian account data, e.g. because
+    /// importing the account was interrupted.
+    MissingAccountData,
+    /// The account is not registered as a guardian in the election contract
+    /// the application is configured for, e.g. because the data is left over
+    /// from a previous election.
+    NotAGuardian,
+}
+
+/// A guardian data directory which is no longer usable with the configured
+/// election contract.
+#[derive(serde::Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct StaleGuardianData {
+    /// The account the data directory belongs to.
+    account:          AccountAddress,
+    /// Why the directory is considered stale.
+    reason:           StaleReason,
+    /// Whether the directory contains any of the [`GUARDIAN_DATA_FILES`].
+    /// Guardian data directories are not specific to an election contract, so
+    /// this might be key material of a guardian in an election on another
+    /// contract or network. Such directories are never deleted.
+    has_key_material: bool,
+}
+
+/// The action to take on the stale guardian data directories found by
+/// [`cleanup_elections`].
+#[derive(serde::Deserialize, Clone, Copy, Debug)]
+enum CleanupAction {
+    /// Move the directories into [`ARCHIVE_DIR`] in the application data
+    /// directory.
+    Archive,
+    /// Remove the directories permanently. Directories containing key material
+    /// are archived instead, see [`StaleGuardianData::has_key_material`].
+    Delete,
+    /// Leave the directories untouched.
+    Keep,
+}
+
+/// Find the guardian data directories in `app_data_dir` which are stale, i.e.
+/// either missing the guardian account data or belonging to an account which
+/// is not in `guardians`. The directory of the `active` account is never
+/// considered stale, as it is in use.
+fn find_stale_guardian_data(
+    app_data_dir: &Path,
+    guardians: &[AccountAddress],
+    active: Option<AccountAddress>,
+) -> Result<Vec<StaleGuardianData>, Error> {
+    let mut stale = Vec::new();
+    for entry in std::fs::read_dir(app_data_dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let Some(account) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| AccountAddress::from_str(name).ok())
+        else {
+            continue;
+        };
+        if Some(account) == active {
+            continue;
+        }
+
+        let reason = if !path.join(WALLET_ACCOUNT_FILE).is_file() {
+            StaleReason::MissingAccountData
+        } else if !guardians.contains(&account) {
+            StaleReason::NotAGuardian
+        } else {
+            continue;
+        };
+        let has_key_material = GUARDIAN_DATA_FILES
+            .iter()
+            .any(|file| path.join(file).exists());
+        stale.push(StaleGuardianData {
+            account,
+            reason,
+            has_key_material,
+        });
+    }
+    stale.sort_by_key(|s| s.account.to_string());
+    Ok(stale)
+}
+
+/// This command executes the following steps:
+///
+/// - Find the guardian data directories which are stale, i.e. directories
+///   missing the guardian account data, or belonging to accounts which are not
+///   guardians of the configured election contract
+/// - Request a [`CleanupAction`] for the stale directories from the user
+/// - Archive or delete the stale directories accordingly
+///
+/// The directory of the active guardian is never touched. Directories
+/// containing key material are archived instead of deleted, as they might
+/// belong to an election on another contract or network.
+///
+/// ## Errors
+/// Expected errors include:
+/// - [`Error::NodeConnection`]
+/// - [`Error::NetworkError`]
+/// - [`Error::QueryFailed`]
+#[tauri::command]
+async fn cleanup_elections(
+    channel_id: String,
+    active_guardian: State<'_, ActiveGuardianState>,
+    app_config: State<'_, AppConfigState>,
+    app_handle: AppHandle,
+    window: Window,
+) -> Result<(), Error> {
+    let cancel = handle_abort(&channel_id, &window);
+    let interaction = async {
+        let active = active_guardian
+            .0
+            .lock()
+            .await
+            .as_ref()
+            .map(|active_guardian| active_guardian.guardian.account);
+
+        let mut contract = app_config.0.lock().await.connection().await?.contract;
+        let guardians: Vec<_> = contract
+            .view::<_, contract::GuardiansState, Error>(
+                "viewGuardiansState",
+                &(),
+                BlockIdentifier::LastFinal,
+            )
+            .await?
+            .into_iter()
+            .map(|(account, _)| account)
+            .collect();
+
+        let app_data_dir = app_handle.path_resolver().app_data_dir().unwrap();
+        let stale = find_stale_guardian_data(&app_data_dir, &guardians, active)?;
+
+        // Wait for response from the user through the frontend
+        let action: CleanupAction = send_message(&window, &channel_id, &stale)
+            .await
+            .context("Unexpected result received from the frontend, expected a cleanup action")?
+            .context("Expected a cleanup action from the frontend, but received none")?;
+
+        let (to_archive, to_delete): (Vec<_>, Vec<_>) = match action {
+            CleanupAction::Keep => return Err(Error::AbortInteraction),
+            CleanupAction::Archive => (stale, Vec::new()),
+            CleanupAction::Delete => stale.into_iter().partition(|s| s.has_key_material),
+        };
+        if !to_archive.is_empty() {
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .context("System time is before the unix epoch")?
+                .as_secs();
+            let archive_dir = app_data_dir.join(ARCHIVE_DIR).join(timestamp.to_string());
+            std::fs::create_dir_all(&archive_dir)?;
+            for StaleGuardianData { account, .. } in &to_archive {
+                std::fs::rename(
+                    app_data_dir.join(account.to_string()),
+                    archive_dir.join(account.to_string()),
+                )?;
+            }
+        }
+        for StaleGuardianData { account, .. } in &to_delete {
+            std::fs::remove_dir_all(app_data_dir.join(account.to_string()))?;
+        }
+
+        Ok(())
+    };
+
+    tokio::select! {
+        biased;
+        error = cancel => Err(error),
+        res = interaction => res
+    }
+}
+
 #[derive(serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ConnectResponse {
@@ -1584,14 +2482,265 @@ fn main() {
             get_accounts,
             import_wallet_account,
             load_account,
+            export_guardian_backup,
+            import_guardian_backup,
+            change_password,
             refresh_guardians,
             register_guardian_key_flow,
             register_guardian_shares_flow,
             generate_secret_share_flow,
             refresh_encrypted_tally,
+            acknowledge_tally_flow,
             register_decryption_shares_flow,
             register_decryption_proofs_flow,
+            verify_local_state,
+            check_inclusion,
+            decryption_progress,
+            cleanup_elections,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An empty directory for the test `name`, removed when dropped.
+    struct TestDir(PathBuf);
+
+    impl TestDir {
+        fn new(name: &str) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("guardian-test-{name}-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(&path).expect("Can create test directory");
+            Self(path)
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) { let _ = std::fs::remove_dir_all(&self.0); }
+    }
+
+    /// Create the data directory of `account` in `dir` containing `files`.
+    fn guardian_dir(dir: &Path, account: AccountAddress, files: &[&str]) {
+        let path = dir.join(account.to_string());
+        std::fs::create_dir_all(&path).expect("Can create guardian directory");
+        for file in files {
+            std::fs::write(path.join(file), b"{}").expect("Can write guardian file");
+        }
+    }
+
+    #[test]
+    fn test_find_stale_guardian_data() {
+        let dir = TestDir::new("stale");
+        let guardian = AccountAddress([1; 32]);
+        let other_election = AccountAddress([2; 32]);
+        let interrupted = AccountAddress([3; 32]);
+        let active = AccountAddress([4; 32]);
+        guardian_dir(&dir.0, guardian, &[WALLET_ACCOUNT_FILE]);
+        guardian_dir(&dir.0, other_election, &[
+            WALLET_ACCOUNT_FILE,
+            SECRET_KEY_FILE,
+        ]);
+        guardian_dir(&dir.0, interrupted, &[]);
+        guardian_dir(&dir.0, active, &[]);
+        std::fs::create_dir_all(dir.0.join(ARCHIVE_DIR)).expect("Can create archive directory");
+
+        let mut stale = find_stale_guardian_data(&dir.0, &[guardian], Some(active))
+            .expect("Can find stale data");
+        stale.sort_by_key(|s| s.account.0);
+        let stale: Vec<_> = stale
+            .into_iter()
+            .map(|s| (s.account, s.reason, s.has_key_material))
+            .collect();
+        assert_eq!(stale, vec![
+            (other_election, StaleReason::NotAGuardian, true),
+            (interrupted, StaleReason::MissingAccountData, false),
+        ]);
+    }
+
+    /// Create the data directory of `account` in `dir` containing the files
+    /// [`WALLET_ACCOUNT_FILE`] and [`SECRET_KEY_FILE`] encrypted with
+    /// `password`. Each file contains its own name.
+    fn encrypted_guardian_dir(dir: &Path, account: AccountAddress, password: &Password) -> PathBuf {
+        let path = dir.join(account.to_string());
+        std::fs::create_dir_all(&path).expect("Can create guardian directory");
+        for file in [WALLET_ACCOUNT_FILE, SECRET_KEY_FILE] {
+            write_encrypted_file(password, &file, &path.join(file)).expect("Can write file");
+        }
+        path
+    }
+
+    #[test]
+    fn test_rotate_password() {
+        let dir = TestDir::new("rotate");
+        let account = AccountAddress([1; 32]);
+        let current = Password::from("current".to_string());
+        let new = Password::from("new".to_string());
+        let path = encrypted_guardian_dir(&dir.0, account, &current);
+
+        assert!(matches!(
+            rotate_password(&path, &new, &current),
+            Err(Error::DecryptionFailed)
+        ));
+        rotate_password(&path, &current, &new).expect("Can rotate password");
+
+        for file in [WALLET_ACCOUNT_FILE, SECRET_KEY_FILE] {
+            let contents: String = read_encrypted_file(&new, &path.join(file))
+                .expect("File is encrypted with the new password");
+            assert_eq!(contents, file);
+        }
+        assert!(!sibling_dir(&path, ROTATING_DIR_SUFFIX).exists());
+        assert!(!sibling_dir(&path, PREVIOUS_DIR_SUFFIX).exists());
+    }
+
+    #[test]
+    fn test_recover_password_changes() {
+        let dir = TestDir::new("recover");
+        let password = Password::from("password".to_string());
+        // Stopped between the two renames of the swap.
+        let interrupted = AccountAddress([1; 32]);
+        let path = encrypted_guardian_dir(&dir.0, interrupted, &password);
+        std::fs::create_dir(sibling_dir(&path, ROTATING_DIR_SUFFIX)).unwrap();
+        std::fs::rename(&path, sibling_dir(&path, PREVIOUS_DIR_SUFFIX)).unwrap();
+        // Stopped before removing the previous directory.
+        let swapped = AccountAddress([2; 32]);
+        let swapped_path = encrypted_guardian_dir(&dir.0, swapped, &password);
+        std::fs::create_dir(sibling_dir(&swapped_path, PREVIOUS_DIR_SUFFIX)).unwrap();
+
+        recover_password_changes(&dir.0).expect("Can recover");
+
+        let _: String = read_encrypted_file(&password, &path.join(WALLET_ACCOUNT_FILE))
+            .expect("Previous directory is restored");
+        assert!(swapped_path.join(WALLET_ACCOUNT_FILE).is_file());
+        let mut entries: Vec<_> = std::fs::read_dir(&dir.0)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        entries.sort();
+        assert_eq!(entries, vec![interrupted.to_string(), swapped.to_string()]);
+    }
+
+    /// The state of a guardian with index `index` which has registered a
+    /// decryption share, and a proof if `proof` is set.
+    fn decrypting_guardian(index: u32, proof: bool) -> contract::GuardianState {
+        let mut state = contract::GuardianState::new(index);
+        state.decryption_share = Some(vec![0]);
+        state.decryption_share_proof = proof.then(|| vec![0]);
+        state
+    }
+
+    #[test]
+    fn test_next_decryption_step() {
+        let present = LocalFileStatus {
+            present:     true,
+            decryptable: true,
+            valid:       true,
+        };
+        let missing = LocalFileStatus::default();
+        let share_without_proof = decrypting_guardian(1, false);
+
+        assert_eq!(
+            next_decryption_step(&share_without_proof, true, &present),
+            DecryptionStep::RegisterProofs,
+            "Resume with the proofs when the shares are registered and the states are stored"
+        );
+        assert_eq!(
+            next_decryption_step(&share_without_proof, true, &missing),
+            DecryptionStep::MissingSecretStates
+        );
+        assert_eq!(
+            next_decryption_step(&decrypting_guardian(1, true), true, &present),
+            DecryptionStep::Done
+        );
+        assert_eq!(
+            next_decryption_step(&contract::GuardianState::new(1), true, &missing),
+            DecryptionStep::RegisterShares
+        );
+        assert_eq!(
+            next_decryption_step(&contract::GuardianState::new(1), false, &missing),
+            DecryptionStep::AwaitEncryptedTally
+        );
+        let mut excluded = share_without_proof;
+        excluded.excluded = true;
+        assert_eq!(
+            next_decryption_step(&excluded, true, &present),
+            DecryptionStep::Excluded
+        );
+    }
+
+    #[test]
+    fn test_verify_encrypted_file() {
+        let dir = TestDir::new("verify");
+        let password = Password::from("password".to_string());
+        let validate = |value: u32| value == 1;
+
+        let missing = verify_encrypted_file(&password, &dir.0.join("missing"), validate);
+        assert!(!missing.present && !missing.decryptable && !missing.valid);
+
+        let corrupted_path = dir.0.join("corrupted");
+        std::fs::write(&corrupted_path, b"corrupted").unwrap();
+        let corrupted = verify_encrypted_file(&password, &corrupted_path, validate);
+        assert!(corrupted.present && !corrupted.decryptable && !corrupted.valid);
+
+        let valid_path = dir.0.join("valid");
+        write_encrypted_file(&password, &1u32, &valid_path).unwrap();
+        let valid = verify_encrypted_file(&password, &valid_path, validate);
+        assert!(valid.present && valid.decryptable && valid.valid);
+
+        let wrong_password = Password::from("wrong".to_string());
+        let undecryptable = verify_encrypted_file(&wrong_password, &valid_path, validate);
+        assert!(undecryptable.present && !undecryptable.decryptable);
+
+        let invalid_path = dir.0.join("invalid");
+        write_encrypted_file(&password, &2u32, &invalid_path).unwrap();
+        let invalid = verify_encrypted_file(&password, &invalid_path, validate);
+        assert!(invalid.present && invalid.decryptable && !invalid.valid);
+    }
+
+    #[test]
+    fn test_compute_inclusion() {
+        let included = AccountAddress([1; 32]);
+        let excluded = AccountAddress([2; 32]);
+        let without_proof = AccountAddress([3; 32]);
+        let mut excluded_state = decrypting_guardian(2, true);
+        excluded_state.excluded = true;
+        let guardians_state = vec![
+            (included, decrypting_guardian(1, true)),
+            (excluded, excluded_state),
+            (without_proof, decrypting_guardian(3, false)),
+        ];
+
+        let response = compute_inclusion(&guardians_state, included, 2).unwrap();
+        assert!(response.included);
+        assert_eq!(
+            response.contributors, 2,
+            "Excluded guardians do not contribute"
+        );
+        assert!(response.quorum_met);
+
+        let response = compute_inclusion(&guardians_state, excluded, 2).unwrap();
+        assert!(response.excluded);
+        assert!(!response.included);
+
+        let response = compute_inclusion(&guardians_state, without_proof, 3).unwrap();
+        assert!(response.has_decryption_share && !response.has_decryption_proof);
+        assert!(!response.included);
+        assert!(!response.quorum_met);
+
+        assert!(compute_inclusion(&guardians_state, AccountAddress([4; 32]), 2).is_err());
+    }
+
+    #[test]
+    fn test_start_flow() {
+        let state = ActiveGuardianState::default();
+        let flow = state.start_flow().expect("Can start a flow");
+        assert!(matches!(state.start_flow(), Err(Error::FlowInProgress)));
+        drop(flow);
+        state
+            .start_flow()
+            .expect("Can start a flow once the previous one is done");
+    }
+}
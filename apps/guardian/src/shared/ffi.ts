@@ -25,6 +25,10 @@ export const enum BackendErrorType {
     QueryFailed = 'QueryFailed',
     /** Interaction with backend aborted by the user */
     AbortInteraction = 'AbortInteraction',
+    /** Another interaction with the backend is already in progress */
+    FlowInProgress = 'FlowInProgress',
+    /** The guardian backup was written by an incompatible version of the application */
+    UnsupportedBackupVersion = 'UnsupportedBackupVersion',
     /** Internal error when something unexpected happens */
     Internal = 'Internal',
 }
@@ -111,6 +115,51 @@ export function loadAccount(account: AccountAddress.Type, password: string): Pro
     return invokeWrapped<void>('load_account', { account: AccountAddress.toBase58(account), password });
 }
 
+/**
+ * Wraps `change_password` invocation, which changes the password of the active guardian account and re-encrypts all
+ * data stored for the account with the new password.
+ *
+ * @param current - The current password of the account.
+ * @param newPassword - The new password of the account.
+ *
+ * @returns `void` if the password is successfully changed
+ * @throws Error of type {@linkcode BackendError} with additional information on the `type` property:
+ * - `BackendErrorType.DecryptionFailed` If the current password is incorrect
+ * - `BackendErrorType.FlowInProgress` If another interaction is in progress for the account
+ */
+export function changePassword(current: string, newPassword: string): Promise<void> {
+    return invokeWrapped<void>('change_password', { current, new: newPassword });
+}
+
+/**
+ * Wraps `export_guardian_backup` invocation, which exports the key material of the active guardian into a backup file
+ * chosen by the user.
+ *
+ * @param password - The password to use for encrypting the backup.
+ *
+ * @returns The path of the backup file, or `null` if the user did not choose a file.
+ */
+export function exportGuardianBackup(password: string): Promise<string | null> {
+    return invokeWrapped<string | null>('export_guardian_backup', { password });
+}
+
+/**
+ * Wraps `import_guardian_backup` invocation, which restores the guardian data of the account found in a backup file
+ * chosen by the user. The account can then be loaded with {@linkcode loadAccount} using its original password.
+ *
+ * @param password - The password used for encrypting the backup.
+ *
+ * @returns The {@linkcode AccountAddress.Type} restored, or `undefined` if the user did not choose a file.
+ * @throws Error of type {@linkcode BackendError} with additional information on the `type` property:
+ * - `BackendErrorType.DecryptionFailed` If the backup could not be decrypted with the password
+ * - `BackendErrorType.UnsupportedBackupVersion` If the backup was written by an incompatible version of the application
+ * - `BackendErrorType.ExistingAccount` If the account has already been imported
+ */
+export async function importGuardianBackup(password: string): Promise<AccountAddress.Type | undefined> {
+    const account = await invokeWrapped<Base58String | null>('import_guardian_backup', { password });
+    return account === null ? undefined : AccountAddress.fromBase58(account);
+}
+
 /**
  * The election config from {@linkcode connect}, corresponding to the configuration registered in the election contract
  * upon contract initialization.
@@ -177,6 +226,8 @@ export type GuardianState = {
     hasDecryptionProof: boolean;
     /** Whether the guardian has been excluded from the tally phase */
     excluded: boolean;
+    /** Whether the guardian has acknowledged the encrypted tally registered in the contract */
+    acknowledgedTally: boolean;
 };
 
 /**
@@ -213,11 +264,129 @@ export async function refreshEncryptedTally(): Promise<boolean> {
     return invokeWrapped<boolean>('refresh_encrypted_tally');
 }
 
+/**
+ * The status of an encrypted file stored for the active guardian.
+ */
+export type LocalFileStatus = {
+    /** Whether the file exists */
+    present: boolean;
+    /** Whether the file could be decrypted with the password of the guardian */
+    decryptable: boolean;
+    /** Whether the decrypted contents are structurally valid */
+    valid: boolean;
+};
+
+/**
+ * The status of each of the files stored for the active guardian.
+ */
+export type LocalState = {
+    /** The status of the guardian secret key */
+    secretKey: LocalFileStatus;
+    /** The status of the guardian secret key share */
+    secretShare: LocalFileStatus;
+    /** The status of the secrets used to encrypt the key shares sent to the other guardians */
+    keyShareEncryptionSecrets: LocalFileStatus;
+    /** The status of the secret states used to prove correct decryption */
+    decryptionSecretStates: LocalFileStatus;
+};
+
+/**
+ * Verify that the files stored for the active guardian are present, can be decrypted, and are valid. This can be used
+ * to confirm the data needed for the decryption phase is recoverable.
+ *
+ * @returns The {@linkcode LocalState} of the active guardian.
+ * @throws Error of type {@linkcode BackendError} with additional information on the `type` property:
+ * - `BackendErrorType.Internal` if no guardian account is active
+ */
+export async function verifyLocalState(): Promise<LocalState> {
+    return invokeWrapped<LocalState>('verify_local_state');
+}
+
+/**
+ * Describes whether the decryption of the active guardian will be included in the decryption of the election result.
+ */
+export type InclusionStatus = {
+    /** Whether the guardian has registered a decryption share */
+    hasDecryptionShare: boolean;
+    /** Whether the guardian has registered a proof of correct decryption */
+    hasDecryptionProof: boolean;
+    /** Whether the guardian is excluded from the tally phase */
+    excluded: boolean;
+    /** The number of guardians which are not excluded and have registered a decryption share */
+    contributors: number;
+    /** The number of decryption shares needed to decrypt the election result */
+    threshold: number;
+    /** Whether enough guardians have registered decryption shares to decrypt the election result */
+    quorumMet: boolean;
+    /** Whether the decryption of the guardian will be included in the decryption of the election result */
+    included: boolean;
+};
+
+/**
+ * Checks whether the decryption registered by the active guardian will be included in the decryption of the election
+ * result.
+ *
+ * @returns The {@linkcode InclusionStatus} of the active guardian.
+ * @throws Error of type {@linkcode BackendError} with additional information on the `type` property:
+ * - `BackendErrorType.NetworkError` if an error happened while querying the contract for the guardian information
+ * - `BackendErrorType.Http` if the election parameters could not be fetched
+ * - `BackendErrorType.Internal` if no guardian account is active, or the guardian is not found in the contract
+ */
+export async function checkInclusion(): Promise<InclusionStatus> {
+    return invokeWrapped<InclusionStatus>('check_inclusion');
+}
+
+/**
+ * The next step of the decryption phase the active guardian should run.
+ */
+export const enum DecryptionStep {
+    /** The guardian is excluded from the decryption phase */
+    Excluded = 'Excluded',
+    /** The encrypted tally has not been registered in the contract yet */
+    AwaitEncryptedTally = 'AwaitEncryptedTally',
+    /** Run {@linkcode registerDecryptionShares} */
+    RegisterShares = 'RegisterShares',
+    /** Run {@linkcode registerDecryptionProofs} */
+    RegisterProofs = 'RegisterProofs',
+    /** The decryption shares are registered, but the secret states needed to prove correct decryption are not readable */
+    MissingSecretStates = 'MissingSecretStates',
+    /** The guardian has completed the decryption phase */
+    Done = 'Done',
+}
+
+/**
+ * Describes the progress of the active guardian in the decryption phase.
+ */
+export type DecryptionProgress = {
+    /** Whether the guardian has registered a decryption share */
+    hasDecryptionShare: boolean;
+    /** Whether the guardian has registered a proof of correct decryption */
+    hasDecryptionProof: boolean;
+    /** The status of the secret states used to prove correct decryption */
+    decryptionSecretStates: LocalFileStatus;
+    /** The step the guardian should run next */
+    nextStep: DecryptionStep;
+};
+
+/**
+ * Reports which step of the decryption phase the active guardian should run next, based on the state registered in
+ * the contract and the files stored locally. This allows resuming the decryption phase after a restart.
+ *
+ * @returns The {@linkcode DecryptionProgress} of the active guardian.
+ * @throws Error of type {@linkcode BackendError} with additional information on the `type` property:
+ * - `BackendErrorType.NetworkError` if an error happened while querying the contract
+ * - `BackendErrorType.Internal` if no guardian account is active, or the guardian is not found in the contract
+ */
+export async function decryptionProgress(): Promise<DecryptionProgress> {
+    return invokeWrapped<DecryptionProgress>('decryption_progress');
+}
+
 /**
  * Creates a generator function representing an interaction with the backend through the supplied command.
  *
  * @template P - The type of the serialized payload received from the backend
  * @template Y - The type yielded by the generator.
+ * @template R - The type of the response sent to the backend for the yielded value. Defaults to `boolean`, i.e. approval.
  *
  * @param cmd - The backend command
  * @param convert - A function for converting the payload JSON type emitted from the backend to the type yielded
@@ -240,8 +409,8 @@ export async function refreshEncryptedTally(): Promise<boolean> {
  *   // Do something with the error.
  * }
  */
-function makeInteractionFlow<P, Y>(cmd: string, convert: (payload: P) => Y) {
-    return async function* (abortSignal: AbortSignal): AsyncGenerator<Y, void, boolean> {
+function makeInteractionFlow<P, Y, R = boolean>(cmd: string, convert: (payload: P) => Y) {
+    return async function* (abortSignal: AbortSignal): AsyncGenerator<Y, void, R> {
         const invocation = invokeWrapped<void>(cmd, { channelId: cmd });
 
         let unsub: UnlistenFn | undefined;
@@ -263,8 +432,8 @@ function makeInteractionFlow<P, Y>(cmd: string, convert: (payload: P) => Y) {
             // is OK.
             const result = (await Promise.race([proposal, invocation])) as Y;
 
-            const approval = yield result;
-            void appWindow.emit(cmd, approval); // Will be rejected by backend if false
+            const response = yield result;
+            void appWindow.emit(cmd, response); // Will be rejected by backend if not accepted
 
             return await invocation;
         } finally {
@@ -365,6 +534,27 @@ export const generateSecretShare = makeInteractionFlow<ValidatedProposalJSON, Va
     (payload) => ({ ...payload, ccdCost: CcdAmount.fromMicroCcd(payload.ccdCost) }),
 );
 
+/**
+ * Creates a generator for interacting with the backend to acknowledge the encrypted tally registered in the contract.
+ * The protocol for the interaction is:
+ *
+ * 1. Await approval of transaction proposal
+ * 2. Send transaction, await finalization on chain
+ *
+ * @param abortSignal - An abort signal which will terminate the interaction
+ *
+ * @yields 1. A {@linkcode CcdAmount.Type} to either accept or reject
+ * @yields 2. `void`, which signals the transaction has been submitted and finalized
+ * @throws At any step in the interaction, {@linkcode BackendError} can be thrown, with additional information on the `type` property:
+ * - `BackendErrorType.NodeConnection`
+ * - `BackendErrorType.NetworkError`
+ * - `BackendErrorType.QueryFailed`
+ */
+export const acknowledgeTally = makeInteractionFlow<number, CcdAmount.Type>(
+    'acknowledge_tally_flow',
+    (payload) => CcdAmount.fromMicroCcd(payload),
+);
+
 /**
  * Creates a generator for interacting with the backend to generate decryption shares for all ciphertexts in the
  * encrypted tally. The protocol for the interaction is:
@@ -408,3 +598,65 @@ export const registerDecryptionProofs = makeInteractionFlow<number, CcdAmount.Ty
     'register_decryption_proofs_flow',
     (payload) => CcdAmount.fromMicroCcd(payload),
 );
+
+/**
+ * The reason a guardian data directory is considered stale.
+ */
+export const enum StaleReason {
+    /** The directory does not contain the guardian account data */
+    MissingAccountData = 'MissingAccountData',
+    /** The account is not a guardian of the configured election contract, e.g. data left over from a previous election */
+    NotAGuardian = 'NotAGuardian',
+}
+
+/**
+ * A guardian data directory which is no longer usable with the configured election contract.
+ */
+export type StaleGuardianData = {
+    /** The account the data directory belongs to */
+    account: AccountAddress.Type;
+    /** Why the directory is considered stale */
+    reason: StaleReason;
+    /**
+     * Whether the directory contains guardian key material. This might belong to an election on another contract or
+     * network, so such directories are archived instead of deleted.
+     */
+    hasKeyMaterial: boolean;
+};
+
+/**
+ * The action to take on the stale guardian data directories found by {@linkcode cleanupElections}.
+ */
+export const enum CleanupAction {
+    /** Move the directories into the `archive` folder of the application data directory */
+    Archive = 'Archive',
+    /** Remove the directories permanently. Directories containing key material are archived instead. */
+    Delete = 'Delete',
+    /** Leave the directories untouched. This aborts the interaction. */
+    Keep = 'Keep',
+}
+
+/**
+ * Creates a generator for interacting with the backend to clean up stale guardian data directories, i.e. directories
+ * which are missing the guardian account data or belong to accounts which are not guardians of the configured election
+ * contract. The directory of the active guardian is never touched. The protocol for the interaction is:
+ *
+ * 1. Find stale guardian data directories, await a {@linkcode CleanupAction}
+ * 2. Archive or delete the directories
+ *
+ * @param abortSignal - An abort signal which will terminate the interaction
+ *
+ * @yields 1. A list of {@linkcode StaleGuardianData} to decide a {@linkcode CleanupAction} for
+ * @yields 2. `void`, which signals the directories have been archived or deleted
+ * @throws At any step in the interaction, {@linkcode BackendError} can be thrown, with additional information on the `type` property:
+ * - `BackendErrorType.NodeConnection`
+ * - `BackendErrorType.NetworkError`
+ * - `BackendErrorType.QueryFailed`
+ */
+export const cleanupElections = makeInteractionFlow<
+    { account: Base58String; reason: StaleReason; hasKeyMaterial: boolean }[],
+    StaleGuardianData[],
+    CleanupAction
+>('cleanup_elections', (payload) =>
+    payload.map(({ account, ...rest }) => ({ account: AccountAddress.fromBase58(account), ...rest })),
+);
@@ -95,6 +95,15 @@ export type ElectionParameter = {
     election_end: SDK.Timestamp.Type,
     decryption_deadline: SDK.Timestamp.Type,
     delegation_string: string,
+    result_posting_deadline: { type: 'None'} | { type: 'Some', content: SDK.Timestamp.Type },
+    result_approvers: Array<SDK.AccountAddress.Type>,
+    approval_threshold: number,
+    eg_version: string,
+    display_order: Array<number>,
+    decryption_grace_seconds: number | bigint,
+    threshold: number,
+    contest_sizes: Array<number>,
+    max_description_length: { type: 'None'} | { type: 'Some', content: number },
     };
 
 /**
@@ -163,6 +172,39 @@ export function createElectionParameter(parameter: ElectionParameter): SDK.Param
     const field38 = parameter.decryption_deadline;
     const timestamp39 = SDK.Timestamp.toSchemaValue(field38);
     const field40 = parameter.delegation_string;
+    const field41 = parameter.result_posting_deadline;
+    let match42: {'None' : [] } | {'Some' : [SDK.Timestamp.SchemaValue] };
+    switch (field41.type) {
+        case 'None':
+            match42 = { None: [], };
+        break;
+        case 'Some':
+    const timestamp43 = SDK.Timestamp.toSchemaValue(field41.content);
+            match42 = { Some: [timestamp43], };
+        break;
+    }
+    const field44 = parameter.result_approvers;
+    const list45 = field44.map((item46) => {
+    const accountAddress47 = SDK.AccountAddress.toSchemaValue(item46);
+    return accountAddress47;
+    });
+    const field48 = parameter.approval_threshold;
+    const field49 = parameter.eg_version;
+    const field50 = parameter.display_order;
+    const field53 = parameter.decryption_grace_seconds;
+    const number54 = BigInt(field53);
+    const field55 = parameter.threshold;
+    const field56 = parameter.contest_sizes;
+    const field59 = parameter.max_description_length;
+    let match60: {'None' : [] } | {'Some' : [number] };
+    switch (field59.type) {
+        case 'None':
+            match60 = { None: [], };
+        break;
+        case 'Some':
+            match60 = { Some: [field59.content], };
+        break;
+    }
     const named0 = {
     admin_account: accountAddress2,
     candidates: list4,
@@ -175,8 +217,17 @@ export function createElectionParameter(parameter: ElectionParameter): SDK.Param
     election_end: timestamp37,
     decryption_deadline: timestamp39,
     delegation_string: field40,
+    result_posting_deadline: match42,
+    result_approvers: list45,
+    approval_threshold: field48,
+    eg_version: field49,
+    display_order: field50,
+    decryption_grace_seconds: number54,
+    threshold: field55,
+    contest_sizes: field56,
+    max_description_length: match60,
     };
-    const out = SDK.Parameter.fromBase64SchemaType('FAALAAAADQAAAGFkbWluX2FjY291bnQLCgAAAGNhbmRpZGF0ZXMQAhQAAgAAAAMAAAB1cmwWAgQAAABoYXNoHiAAAAAJAAAAZ3VhcmRpYW5zEAILDwAAAGVsaWdpYmxlX3ZvdGVycxQAAgAAAAoAAABwYXJhbWV0ZXJzFAACAAAACgAAAHN0YXJ0X3RpbWUNCAAAAGVuZF90aW1lDQQAAABkYXRhFAACAAAAAwAAAHVybBYCBAAAAGhhc2geIAAAABEAAABlbGVjdGlvbl9tYW5pZmVzdBQAAgAAAAMAAAB1cmwWAgQAAABoYXNoHiAAAAATAAAAZWxlY3Rpb25fcGFyYW1ldGVycxQAAgAAAAMAAAB1cmwWAgQAAABoYXNoHiAAAAAUAAAAZWxlY3Rpb25fZGVzY3JpcHRpb24WAg4AAABlbGVjdGlvbl9zdGFydA0MAAAAZWxlY3Rpb25fZW5kDRMAAABkZWNyeXB0aW9uX2RlYWRsaW5lDREAAABkZWxlZ2F0aW9uX3N0cmluZxYC', named0);
+    const out = SDK.Parameter.fromBase64SchemaType('FAAUAAAADQAAAGFkbWluX2FjY291bnQLCgAAAGNhbmRpZGF0ZXMQAhQAAgAAAAMAAAB1cmwWAgQAAABoYXNoHiAAAAAJAAAAZ3VhcmRpYW5zEAILDwAAAGVsaWdpYmxlX3ZvdGVycxQAAgAAAAoAAABwYXJhbWV0ZXJzFAACAAAACgAAAHN0YXJ0X3RpbWUNCAAAAGVuZF90aW1lDQQAAABkYXRhFAACAAAAAwAAAHVybBYCBAAAAGhhc2geIAAAABEAAABlbGVjdGlvbl9tYW5pZmVzdBQAAgAAAAMAAAB1cmwWAgQAAABoYXNoHiAAAAATAAAAZWxlY3Rpb25fcGFyYW1ldGVycxQAAgAAAAMAAAB1cmwWAgQAAABoYXNoHiAAAAAUAAAAZWxlY3Rpb25fZGVzY3JpcHRpb24WAg4AAABlbGVjdGlvbl9zdGFydA0MAAAAZWxlY3Rpb25fZW5kDRMAAABkZWNyeXB0aW9uX2RlYWRsaW5lDREAAABkZWxlZ2F0aW9uX3N0cmluZxYCFwAAAHJlc3VsdF9wb3N0aW5nX2RlYWRsaW5lFQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAADRAAAAByZXN1bHRfYXBwcm92ZXJzEAILEgAAAGFwcHJvdmFsX3RocmVzaG9sZAQKAAAAZWdfdmVyc2lvbhYCDQAAAGRpc3BsYXlfb3JkZXIQAgQYAAAAZGVjcnlwdGlvbl9ncmFjZV9zZWNvbmRzBQkAAAB0aHJlc2hvbGQEDQAAAGNvbnRlc3Rfc2l6ZXMQAgQWAAAAbWF4X2Rlc2NyaXB0aW9uX2xlbmd0aBUCAAAABAAAAE5vbmUCBAAAAFNvbWUBAQAAAAQ=', named0);
     return out
 }
 
@@ -72,6 +72,66 @@ export function checkOnChain(contractClient: ElectionContract, blockHash?: SDK.B
     return contractClient.genericContract.checkOnChain({moduleReference: moduleReference, blockHash: blockHash });
 }
 
+/** Contract event type for the 'election' contract. */
+export type Event = { type: 'VoteAccepted', content: {
+    account: SDK.AccountAddress.Type,
+    ballot_hash: SDK.HexString,
+    } } | { type: 'GuardianPublicKeyRegistered', content: {
+    index: number,
+    } } | { type: 'EncryptedTallyPosted'};
+
+/**
+ * Parse the contract events logged by the 'election' contract.
+ * @param {SDK.ContractEvent.Type} event The unparsed contract event.
+ * @returns {Event} The structured contract event.
+ */
+export function parseEvent(event: SDK.ContractEvent.Type): Event {
+    const schemaJson = <{'VoteAccepted' : {
+    account: SDK.AccountAddress.SchemaValue,
+    ballot_hash: string,
+    } } | {'GuardianPublicKeyRegistered' : {
+    index: number,
+    } } | {'EncryptedTallyPosted' : [] }>SDK.ContractEvent.parseWithSchemaTypeBase64(event, 'FQMAAAAMAAAAVm90ZUFjY2VwdGVkAAIAAAAHAAAAYWNjb3VudAsLAAAAYmFsbG90X2hhc2geIAAAABsAAABHdWFyZGlhblB1YmxpY0tleVJlZ2lzdGVyZWQAAQAAAAUAAABpbmRleAQUAAAARW5jcnlwdGVkVGFsbHlQb3N0ZWQC');
+    let match61: { type: 'VoteAccepted', content: {
+    account: SDK.AccountAddress.Type,
+    ballot_hash: SDK.HexString,
+    } } | { type: 'GuardianPublicKeyRegistered', content: {
+    index: number,
+    } } | { type: 'EncryptedTallyPosted'};
+    if ('VoteAccepted' in schemaJson) {
+       const variant62 = schemaJson.VoteAccepted;
+    const field63 = variant62.account;
+    const accountAddress64 = SDK.AccountAddress.fromSchemaValue(field63);
+    const field65 = variant62.ballot_hash;
+    const named66 = {
+    account: accountAddress64,
+    ballot_hash: field65,
+    };
+       match61 = {
+           type: 'VoteAccepted',
+           content: named66,
+       };
+    } else if ('GuardianPublicKeyRegistered' in schemaJson) {
+       const variant67 = schemaJson.GuardianPublicKeyRegistered;
+    const field68 = variant67.index;
+    const named69 = {
+    index: field68,
+    };
+       match61 = {
+           type: 'GuardianPublicKeyRegistered',
+           content: named69,
+       };
+    } else if ('EncryptedTallyPosted' in schemaJson) {
+       match61 = {
+           type: 'EncryptedTallyPosted',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match61;
+}
+
 /** Parameter type for update transaction for 'registerGuardianPublicKey' entrypoint of the 'election' contract. */
 export type RegisterGuardianPublicKeyParameter = Array<number>;
 
@@ -124,7 +184,7 @@ export function dryRunRegisterGuardianPublicKey(contractClient: ElectionContract
 }
 
 /** Error message for dry-running update transaction for 'registerGuardianPublicKey' entrypoint of the 'election' contract. */
-export type ErrorMessageRegisterGuardianPublicKey = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+export type ErrorMessageRegisterGuardianPublicKey = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
 
 /**
  * Get and parse the error message from dry-running update transaction for 'registerGuardianPublicKey' entrypoint of the 'election' contract.
@@ -139,37 +199,153 @@ export function parseErrorMessageRegisterGuardianPublicKey(invokeResult: SDK.Inv
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQYAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAI=');
-    let match43: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match73: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+    if ('ParseParams' in schemaJson) {
+       match73 = {
+           type: 'ParseParams',
+       };
+    } else if ('Unauthorized' in schemaJson) {
+       match73 = {
+           type: 'Unauthorized',
+       };
+    } else if ('Malformed' in schemaJson) {
+       match73 = {
+           type: 'Malformed',
+       };
+    } else if ('IncorrectElectionPhase' in schemaJson) {
+       match73 = {
+           type: 'IncorrectElectionPhase',
+       };
+    } else if ('DuplicateEntry' in schemaJson) {
+       match73 = {
+           type: 'DuplicateEntry',
+       };
+    } else if ('GuardianExcluded' in schemaJson) {
+       match73 = {
+           type: 'GuardianExcluded',
+       };
+    } else if ('Log' in schemaJson) {
+       match73 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match73 = {
+           type: 'ElectionLocked',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match73
+}
+
+/** Parameter type for update transaction for 'replaceGuardianPublicKey' entrypoint of the 'election' contract. */
+export type ReplaceGuardianPublicKeyParameter = Array<number>;
+
+/**
+ * Construct Parameter for update transactions for 'replaceGuardianPublicKey' entrypoint of the 'election' contract.
+ * @param {ReplaceGuardianPublicKeyParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createReplaceGuardianPublicKeyParameter(parameter: ReplaceGuardianPublicKeyParameter): SDK.Parameter.Type {
+    const out = SDK.Parameter.fromBase64SchemaType('EAIC', parameter);
+    return out;
+}
+
+/**
+ * Send an update-contract transaction to the 'replaceGuardianPublicKey' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {ReplaceGuardianPublicKeyParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendReplaceGuardianPublicKey(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ReplaceGuardianPublicKeyParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('replaceGuardianPublicKey'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createReplaceGuardianPublicKeyParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'replaceGuardianPublicKey' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {ReplaceGuardianPublicKeyParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunReplaceGuardianPublicKey(contractClient: ElectionContract, parameter: ReplaceGuardianPublicKeyParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('replaceGuardianPublicKey'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createReplaceGuardianPublicKeyParameter(parameter),
+        blockHash
+    );
+}
+
+/** Error message for dry-running update transaction for 'replaceGuardianPublicKey' entrypoint of the 'election' contract. */
+export type ErrorMessageReplaceGuardianPublicKey = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+
+/**
+ * Get and parse the error message from dry-running update transaction for 'replaceGuardianPublicKey' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not a failure.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ErrorMessageReplaceGuardianPublicKey | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ */
+export function parseErrorMessageReplaceGuardianPublicKey(invokeResult: SDK.InvokeContractResult): ErrorMessageReplaceGuardianPublicKey | undefined {
+    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match84: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
     if ('ParseParams' in schemaJson) {
-       match43 = {
+       match84 = {
            type: 'ParseParams',
        };
     } else if ('Unauthorized' in schemaJson) {
-       match43 = {
+       match84 = {
            type: 'Unauthorized',
        };
     } else if ('Malformed' in schemaJson) {
-       match43 = {
+       match84 = {
            type: 'Malformed',
        };
     } else if ('IncorrectElectionPhase' in schemaJson) {
-       match43 = {
+       match84 = {
            type: 'IncorrectElectionPhase',
        };
     } else if ('DuplicateEntry' in schemaJson) {
-       match43 = {
+       match84 = {
            type: 'DuplicateEntry',
        };
     } else if ('GuardianExcluded' in schemaJson) {
-       match43 = {
+       match84 = {
            type: 'GuardianExcluded',
        };
+    } else if ('Log' in schemaJson) {
+       match84 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match84 = {
+           type: 'ElectionLocked',
+       };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    return match43
+    return match84
 }
 
 /** Parameter type for update transaction for 'registerGuardianEncryptedShare' entrypoint of the 'election' contract. */
@@ -224,7 +400,7 @@ export function dryRunRegisterGuardianEncryptedShare(contractClient: ElectionCon
 }
 
 /** Error message for dry-running update transaction for 'registerGuardianEncryptedShare' entrypoint of the 'election' contract. */
-export type ErrorMessageRegisterGuardianEncryptedShare = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+export type ErrorMessageRegisterGuardianEncryptedShare = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
 
 /**
  * Get and parse the error message from dry-running update transaction for 'registerGuardianEncryptedShare' entrypoint of the 'election' contract.
@@ -239,37 +415,45 @@ export function parseErrorMessageRegisterGuardianEncryptedShare(invokeResult: SD
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQYAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAI=');
-    let match52: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match95: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
     if ('ParseParams' in schemaJson) {
-       match52 = {
+       match95 = {
            type: 'ParseParams',
        };
     } else if ('Unauthorized' in schemaJson) {
-       match52 = {
+       match95 = {
            type: 'Unauthorized',
        };
     } else if ('Malformed' in schemaJson) {
-       match52 = {
+       match95 = {
            type: 'Malformed',
        };
     } else if ('IncorrectElectionPhase' in schemaJson) {
-       match52 = {
+       match95 = {
            type: 'IncorrectElectionPhase',
        };
     } else if ('DuplicateEntry' in schemaJson) {
-       match52 = {
+       match95 = {
            type: 'DuplicateEntry',
        };
     } else if ('GuardianExcluded' in schemaJson) {
-       match52 = {
+       match95 = {
            type: 'GuardianExcluded',
        };
+    } else if ('Log' in schemaJson) {
+       match95 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match95 = {
+           type: 'ElectionLocked',
+       };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    return match52
+    return match95
 }
 
 /** Parameter type for update transaction for 'postDecryptionShare' entrypoint of the 'election' contract. */
@@ -324,7 +508,7 @@ export function dryRunPostDecryptionShare(contractClient: ElectionContract, para
 }
 
 /** Error message for dry-running update transaction for 'postDecryptionShare' entrypoint of the 'election' contract. */
-export type ErrorMessagePostDecryptionShare = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+export type ErrorMessagePostDecryptionShare = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
 
 /**
  * Get and parse the error message from dry-running update transaction for 'postDecryptionShare' entrypoint of the 'election' contract.
@@ -339,37 +523,162 @@ export function parseErrorMessagePostDecryptionShare(invokeResult: SDK.InvokeCon
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQYAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAI=');
-    let match61: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match106: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
     if ('ParseParams' in schemaJson) {
-       match61 = {
+       match106 = {
            type: 'ParseParams',
        };
     } else if ('Unauthorized' in schemaJson) {
-       match61 = {
+       match106 = {
            type: 'Unauthorized',
        };
     } else if ('Malformed' in schemaJson) {
-       match61 = {
+       match106 = {
            type: 'Malformed',
        };
     } else if ('IncorrectElectionPhase' in schemaJson) {
-       match61 = {
+       match106 = {
            type: 'IncorrectElectionPhase',
        };
     } else if ('DuplicateEntry' in schemaJson) {
-       match61 = {
+       match106 = {
            type: 'DuplicateEntry',
        };
     } else if ('GuardianExcluded' in schemaJson) {
-       match61 = {
+       match106 = {
+           type: 'GuardianExcluded',
+       };
+    } else if ('Log' in schemaJson) {
+       match106 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match106 = {
+           type: 'ElectionLocked',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match106
+}
+
+/** Parameter type for update transaction for 'postDecryptionShareForContest' entrypoint of the 'election' contract. */
+export type PostDecryptionShareForContestParameter = {
+    contest_index: number,
+    share: Array<number>,
+    };
+
+/**
+ * Construct Parameter for update transactions for 'postDecryptionShareForContest' entrypoint of the 'election' contract.
+ * @param {PostDecryptionShareForContestParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createPostDecryptionShareForContestParameter(parameter: PostDecryptionShareForContestParameter): SDK.Parameter.Type {
+    const field116 = parameter.contest_index;
+    const field117 = parameter.share;
+    const named115 = {
+    contest_index: field116,
+    share: field117,
+    };
+    const out = SDK.Parameter.fromBase64SchemaType('FAACAAAADQAAAGNvbnRlc3RfaW5kZXgEBQAAAHNoYXJlEAIC', named115);
+    return out;
+}
+
+/**
+ * Send an update-contract transaction to the 'postDecryptionShareForContest' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {PostDecryptionShareForContestParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendPostDecryptionShareForContest(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: PostDecryptionShareForContestParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('postDecryptionShareForContest'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createPostDecryptionShareForContestParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'postDecryptionShareForContest' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {PostDecryptionShareForContestParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunPostDecryptionShareForContest(contractClient: ElectionContract, parameter: PostDecryptionShareForContestParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('postDecryptionShareForContest'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createPostDecryptionShareForContestParameter(parameter),
+        blockHash
+    );
+}
+
+/** Error message for dry-running update transaction for 'postDecryptionShareForContest' entrypoint of the 'election' contract. */
+export type ErrorMessagePostDecryptionShareForContest = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+
+/**
+ * Get and parse the error message from dry-running update transaction for 'postDecryptionShareForContest' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not a failure.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ErrorMessagePostDecryptionShareForContest | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ */
+export function parseErrorMessagePostDecryptionShareForContest(invokeResult: SDK.InvokeContractResult): ErrorMessagePostDecryptionShareForContest | undefined {
+    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match120: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+    if ('ParseParams' in schemaJson) {
+       match120 = {
+           type: 'ParseParams',
+       };
+    } else if ('Unauthorized' in schemaJson) {
+       match120 = {
+           type: 'Unauthorized',
+       };
+    } else if ('Malformed' in schemaJson) {
+       match120 = {
+           type: 'Malformed',
+       };
+    } else if ('IncorrectElectionPhase' in schemaJson) {
+       match120 = {
+           type: 'IncorrectElectionPhase',
+       };
+    } else if ('DuplicateEntry' in schemaJson) {
+       match120 = {
+           type: 'DuplicateEntry',
+       };
+    } else if ('GuardianExcluded' in schemaJson) {
+       match120 = {
            type: 'GuardianExcluded',
        };
+    } else if ('Log' in schemaJson) {
+       match120 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match120 = {
+           type: 'ElectionLocked',
+       };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    return match61
+    return match120
 }
 
 /** Parameter type for update transaction for 'postDecryptionProofResponseShare' entrypoint of the 'election' contract. */
@@ -424,7 +733,7 @@ export function dryRunPostDecryptionProofResponseShare(contractClient: ElectionC
 }
 
 /** Error message for dry-running update transaction for 'postDecryptionProofResponseShare' entrypoint of the 'election' contract. */
-export type ErrorMessagePostDecryptionProofResponseShare = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+export type ErrorMessagePostDecryptionProofResponseShare = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
 
 /**
  * Get and parse the error message from dry-running update transaction for 'postDecryptionProofResponseShare' entrypoint of the 'election' contract.
@@ -439,37 +748,45 @@ export function parseErrorMessagePostDecryptionProofResponseShare(invokeResult:
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQYAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAI=');
-    let match70: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match131: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
     if ('ParseParams' in schemaJson) {
-       match70 = {
+       match131 = {
            type: 'ParseParams',
        };
     } else if ('Unauthorized' in schemaJson) {
-       match70 = {
+       match131 = {
            type: 'Unauthorized',
        };
     } else if ('Malformed' in schemaJson) {
-       match70 = {
+       match131 = {
            type: 'Malformed',
        };
     } else if ('IncorrectElectionPhase' in schemaJson) {
-       match70 = {
+       match131 = {
            type: 'IncorrectElectionPhase',
        };
     } else if ('DuplicateEntry' in schemaJson) {
-       match70 = {
+       match131 = {
            type: 'DuplicateEntry',
        };
     } else if ('GuardianExcluded' in schemaJson) {
-       match70 = {
+       match131 = {
            type: 'GuardianExcluded',
        };
+    } else if ('Log' in schemaJson) {
+       match131 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match131 = {
+           type: 'ElectionLocked',
+       };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    return match70
+    return match131
 }
 
 /** Parameter type for update transaction for 'registerGuardianStatus' entrypoint of the 'election' contract. */
@@ -481,27 +798,27 @@ export type RegisterGuardianStatusParameter = { type: 'KeyVerificationFailed', c
  * @returns {SDK.Parameter.Type} The smart contract parameter.
  */
 export function createRegisterGuardianStatusParameter(parameter: RegisterGuardianStatusParameter): SDK.Parameter.Type {
-    let match77: {'KeyVerificationFailed' : [Array<SDK.AccountAddress.SchemaValue>] } | {'SharesVerificationFailed' : [Array<SDK.AccountAddress.SchemaValue>] } | {'VerificationSuccessful' : [] };
+    let match140: {'KeyVerificationFailed' : [Array<SDK.AccountAddress.SchemaValue>] } | {'SharesVerificationFailed' : [Array<SDK.AccountAddress.SchemaValue>] } | {'VerificationSuccessful' : [] };
     switch (parameter.type) {
         case 'KeyVerificationFailed':
-    const list78 = parameter.content.map((item79) => {
-    const accountAddress80 = SDK.AccountAddress.toSchemaValue(item79);
-    return accountAddress80;
+    const list141 = parameter.content.map((item142) => {
+    const accountAddress143 = SDK.AccountAddress.toSchemaValue(item142);
+    return accountAddress143;
     });
-            match77 = { KeyVerificationFailed: [list78], };
+            match140 = { KeyVerificationFailed: [list141], };
         break;
         case 'SharesVerificationFailed':
-    const list81 = parameter.content.map((item82) => {
-    const accountAddress83 = SDK.AccountAddress.toSchemaValue(item82);
-    return accountAddress83;
+    const list144 = parameter.content.map((item145) => {
+    const accountAddress146 = SDK.AccountAddress.toSchemaValue(item145);
+    return accountAddress146;
     });
-            match77 = { SharesVerificationFailed: [list81], };
+            match140 = { SharesVerificationFailed: [list144], };
         break;
         case 'VerificationSuccessful':
-            match77 = { VerificationSuccessful: [], };
+            match140 = { VerificationSuccessful: [], };
         break;
     }
-    const out = SDK.Parameter.fromBase64SchemaType('FQMAAAAVAAAAS2V5VmVyaWZpY2F0aW9uRmFpbGVkAQEAAAAQAgsYAAAAU2hhcmVzVmVyaWZpY2F0aW9uRmFpbGVkAQEAAAAQAgsWAAAAVmVyaWZpY2F0aW9uU3VjY2Vzc2Z1bAI=', match77);
+    const out = SDK.Parameter.fromBase64SchemaType('FQMAAAAVAAAAS2V5VmVyaWZpY2F0aW9uRmFpbGVkAQEAAAAQAgsYAAAAU2hhcmVzVmVyaWZpY2F0aW9uRmFpbGVkAQEAAAAQAgsWAAAAVmVyaWZpY2F0aW9uU3VjY2Vzc2Z1bAI=', match140);
     return out;
 }
 
@@ -544,7 +861,7 @@ export function dryRunRegisterGuardianStatus(contractClient: ElectionContract, p
 }
 
 /** Error message for dry-running update transaction for 'registerGuardianStatus' entrypoint of the 'election' contract. */
-export type ErrorMessageRegisterGuardianStatus = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+export type ErrorMessageRegisterGuardianStatus = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
 
 /**
  * Get and parse the error message from dry-running update transaction for 'registerGuardianStatus' entrypoint of the 'election' contract.
@@ -559,37 +876,45 @@ export function parseErrorMessageRegisterGuardianStatus(invokeResult: SDK.Invoke
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQYAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAI=');
-    let match84: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match147: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
     if ('ParseParams' in schemaJson) {
-       match84 = {
+       match147 = {
            type: 'ParseParams',
        };
     } else if ('Unauthorized' in schemaJson) {
-       match84 = {
+       match147 = {
            type: 'Unauthorized',
        };
     } else if ('Malformed' in schemaJson) {
-       match84 = {
+       match147 = {
            type: 'Malformed',
        };
     } else if ('IncorrectElectionPhase' in schemaJson) {
-       match84 = {
+       match147 = {
            type: 'IncorrectElectionPhase',
        };
     } else if ('DuplicateEntry' in schemaJson) {
-       match84 = {
+       match147 = {
            type: 'DuplicateEntry',
        };
     } else if ('GuardianExcluded' in schemaJson) {
-       match84 = {
+       match147 = {
            type: 'GuardianExcluded',
        };
+    } else if ('Log' in schemaJson) {
+       match147 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match147 = {
+           type: 'ElectionLocked',
+       };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    return match84
+    return match147
 }
 
 /** Parameter type for update transaction for 'viewGuardiansState' entrypoint of the 'election' contract. */
@@ -648,9 +973,11 @@ export type ReturnValueViewGuardiansState = Array<[SDK.AccountAddress.Type, {
     public_key: { type: 'None'} | { type: 'Some', content: Array<number> },
     encrypted_share: { type: 'None'} | { type: 'Some', content: Array<number> },
     decryption_share: { type: 'None'} | { type: 'Some', content: Array<number> },
+    decryption_share_contests: Map<number, Array<number>>,
     decryption_share_proof: { type: 'None'} | { type: 'Some', content: Array<number> },
     status: { type: 'None'} | { type: 'Some', content: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'} },
     excluded: boolean,
+    acknowledged_tally: boolean,
     }]>;
 
 /**
@@ -671,1056 +998,3070 @@ export function parseReturnValueViewGuardiansState(invokeResult: SDK.InvokeContr
     public_key: {'None' : [] } | {'Some' : [Array<number>] },
     encrypted_share: {'None' : [] } | {'Some' : [Array<number>] },
     decryption_share: {'None' : [] } | {'Some' : [Array<number>] },
+    decryption_share_contests: [number, Array<number>][],
     decryption_share_proof: {'None' : [] } | {'Some' : [Array<number>] },
     status: {'None' : [] } | {'Some' : [{'KeyVerificationFailed' : [Array<SDK.AccountAddress.SchemaValue>] } | {'SharesVerificationFailed' : [Array<SDK.AccountAddress.SchemaValue>] } | {'VerificationSuccessful' : [] }] },
     excluded: boolean,
-    }]>>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'EAIPCxQABwAAAAUAAABpbmRleAQKAAAAcHVibGljX2tleRUCAAAABAAAAE5vbmUCBAAAAFNvbWUBAQAAABACAg8AAABlbmNyeXB0ZWRfc2hhcmUVAgAAAAQAAABOb25lAgQAAABTb21lAQEAAAAQAgIQAAAAZGVjcnlwdGlvbl9zaGFyZRUCAAAABAAAAE5vbmUCBAAAAFNvbWUBAQAAABACAhYAAABkZWNyeXB0aW9uX3NoYXJlX3Byb29mFQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAEAICBgAAAHN0YXR1cxUCAAAABAAAAE5vbmUCBAAAAFNvbWUBAQAAABUDAAAAFQAAAEtleVZlcmlmaWNhdGlvbkZhaWxlZAEBAAAAEAILGAAAAFNoYXJlc1ZlcmlmaWNhdGlvbkZhaWxlZAEBAAAAEAILFgAAAFZlcmlmaWNhdGlvblN1Y2Nlc3NmdWwCCAAAAGV4Y2x1ZGVkAQ==');
-    const list91 = schemaJson.map((item92) => {
-    const accountAddress94 = SDK.AccountAddress.fromSchemaValue(item92[0]);
-    const field95 = item92[1].index;
-    const field96 = item92[1].public_key;
-    let match97: { type: 'None'} | { type: 'Some', content: Array<number> };
-    if ('None' in field96) {
-       match97 = {
+    acknowledged_tally: boolean,
+    }]>>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'EAIPCxQACQAAAAUAAABpbmRleAQKAAAAcHVibGljX2tleRUCAAAABAAAAE5vbmUCBAAAAFNvbWUBAQAAABACAg8AAABlbmNyeXB0ZWRfc2hhcmUVAgAAAAQAAABOb25lAgQAAABTb21lAQEAAAAQAgIQAAAAZGVjcnlwdGlvbl9zaGFyZRUCAAAABAAAAE5vbmUCBAAAAFNvbWUBAQAAABACAhkAAABkZWNyeXB0aW9uX3NoYXJlX2NvbnRlc3RzEgIEEAICFgAAAGRlY3J5cHRpb25fc2hhcmVfcHJvb2YVAgAAAAQAAABOb25lAgQAAABTb21lAQEAAAAQAgIGAAAAc3RhdHVzFQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAFQMAAAAVAAAAS2V5VmVyaWZpY2F0aW9uRmFpbGVkAQEAAAAQAgsYAAAAU2hhcmVzVmVyaWZpY2F0aW9uRmFpbGVkAQEAAAAQAgsWAAAAVmVyaWZpY2F0aW9uU3VjY2Vzc2Z1bAIIAAAAZXhjbHVkZWQBEgAAAGFja25vd2xlZGdlZF90YWxseQE=');
+    const list156 = schemaJson.map((item157) => {
+    const accountAddress159 = SDK.AccountAddress.fromSchemaValue(item157[0]);
+    const field160 = item157[1].index;
+    const field161 = item157[1].public_key;
+    let match162: { type: 'None'} | { type: 'Some', content: Array<number> };
+    if ('None' in field161) {
+       match162 = {
            type: 'None',
        };
-    } else if ('Some' in field96) {
-       const variant99 = field96.Some;
-       match97 = {
+    } else if ('Some' in field161) {
+       const variant164 = field161.Some;
+       match162 = {
            type: 'Some',
-           content: variant99[0],
+           content: variant164[0],
        };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    const field102 = item92[1].encrypted_share;
-    let match103: { type: 'None'} | { type: 'Some', content: Array<number> };
-    if ('None' in field102) {
-       match103 = {
+    const field167 = item157[1].encrypted_share;
+    let match168: { type: 'None'} | { type: 'Some', content: Array<number> };
+    if ('None' in field167) {
+       match168 = {
            type: 'None',
        };
-    } else if ('Some' in field102) {
-       const variant105 = field102.Some;
-       match103 = {
+    } else if ('Some' in field167) {
+       const variant170 = field167.Some;
+       match168 = {
            type: 'Some',
-           content: variant105[0],
+           content: variant170[0],
        };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    const field108 = item92[1].decryption_share;
-    let match109: { type: 'None'} | { type: 'Some', content: Array<number> };
-    if ('None' in field108) {
-       match109 = {
+    const field173 = item157[1].decryption_share;
+    let match174: { type: 'None'} | { type: 'Some', content: Array<number> };
+    if ('None' in field173) {
+       match174 = {
            type: 'None',
        };
-    } else if ('Some' in field108) {
-       const variant111 = field108.Some;
-       match109 = {
+    } else if ('Some' in field173) {
+       const variant176 = field173.Some;
+       match174 = {
            type: 'Some',
-           content: variant111[0],
+           content: variant176[0],
        };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    const field114 = item92[1].decryption_share_proof;
-    let match115: { type: 'None'} | { type: 'Some', content: Array<number> };
-    if ('None' in field114) {
-       match115 = {
+    const field179 = item157[1].decryption_share_contests;
+    const map180: Map<number, Array<number>> = new Map(field179);
+    const field185 = item157[1].decryption_share_proof;
+    let match186: { type: 'None'} | { type: 'Some', content: Array<number> };
+    if ('None' in field185) {
+       match186 = {
            type: 'None',
        };
-    } else if ('Some' in field114) {
-       const variant117 = field114.Some;
-       match115 = {
+    } else if ('Some' in field185) {
+       const variant188 = field185.Some;
+       match186 = {
            type: 'Some',
-           content: variant117[0],
+           content: variant188[0],
        };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    const field120 = item92[1].status;
-    let match121: { type: 'None'} | { type: 'Some', content: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'} };
-    if ('None' in field120) {
-       match121 = {
+    const field191 = item157[1].status;
+    let match192: { type: 'None'} | { type: 'Some', content: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'} };
+    if ('None' in field191) {
+       match192 = {
            type: 'None',
        };
-    } else if ('Some' in field120) {
-       const variant123 = field120.Some;
-    let match124: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'};
-    if ('KeyVerificationFailed' in variant123[0]) {
-       const variant125 = variant123[0].KeyVerificationFailed;
-    const list126 = variant125[0].map((item127) => {
-    const accountAddress128 = SDK.AccountAddress.fromSchemaValue(item127);
-    return accountAddress128;
+    } else if ('Some' in field191) {
+       const variant194 = field191.Some;
+    let match195: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'};
+    if ('KeyVerificationFailed' in variant194[0]) {
+       const variant196 = variant194[0].KeyVerificationFailed;
+    const list197 = variant196[0].map((item198) => {
+    const accountAddress199 = SDK.AccountAddress.fromSchemaValue(item198);
+    return accountAddress199;
     });
-       match124 = {
+       match195 = {
            type: 'KeyVerificationFailed',
-           content: list126,
+           content: list197,
        };
-    } else if ('SharesVerificationFailed' in variant123[0]) {
-       const variant129 = variant123[0].SharesVerificationFailed;
-    const list130 = variant129[0].map((item131) => {
-    const accountAddress132 = SDK.AccountAddress.fromSchemaValue(item131);
-    return accountAddress132;
+    } else if ('SharesVerificationFailed' in variant194[0]) {
+       const variant200 = variant194[0].SharesVerificationFailed;
+    const list201 = variant200[0].map((item202) => {
+    const accountAddress203 = SDK.AccountAddress.fromSchemaValue(item202);
+    return accountAddress203;
     });
-       match124 = {
+       match195 = {
            type: 'SharesVerificationFailed',
-           content: list130,
+           content: list201,
        };
-    } else if ('VerificationSuccessful' in variant123[0]) {
-       match124 = {
+    } else if ('VerificationSuccessful' in variant194[0]) {
+       match195 = {
            type: 'VerificationSuccessful',
        };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-       match121 = {
+       match192 = {
            type: 'Some',
-           content: match124,
+           content: match195,
        };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    const field134 = item92[1].excluded;
-    const named135 = {
-    index: field95,
-    public_key: match97,
-    encrypted_share: match103,
-    decryption_share: match109,
-    decryption_share_proof: match115,
-    status: match121,
-    excluded: field134,
+    const field205 = item157[1].excluded;
+    const field206 = item157[1].acknowledged_tally;
+    const named207 = {
+    index: field160,
+    public_key: match162,
+    encrypted_share: match168,
+    decryption_share: match174,
+    decryption_share_contests: map180,
+    decryption_share_proof: match186,
+    status: match192,
+    excluded: field205,
+    acknowledged_tally: field206,
     };
-    const pair93: [SDK.AccountAddress.Type, {
+    const pair158: [SDK.AccountAddress.Type, {
     index: number,
     public_key: { type: 'None'} | { type: 'Some', content: Array<number> },
     encrypted_share: { type: 'None'} | { type: 'Some', content: Array<number> },
     decryption_share: { type: 'None'} | { type: 'Some', content: Array<number> },
+    decryption_share_contests: Map<number, Array<number>>,
     decryption_share_proof: { type: 'None'} | { type: 'Some', content: Array<number> },
     status: { type: 'None'} | { type: 'Some', content: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'} },
     excluded: boolean,
-    }] = [accountAddress94, named135];
-    return pair93;
+    acknowledged_tally: boolean,
+    }] = [accountAddress159, named207];
+    return pair158;
     });
-    return list91;
+    return list156;
 }
 
-/** Parameter type for update transaction for 'registerVotes' entrypoint of the 'election' contract. */
-export type RegisterVotesParameter = SDK.HexString;
+/** Parameter type for update transaction for 'viewGuardian' entrypoint of the 'election' contract. */
+export type ViewGuardianParameter = SDK.AccountAddress.Type;
 
 /**
- * Construct Parameter for update transactions for 'registerVotes' entrypoint of the 'election' contract.
- * @param {RegisterVotesParameter} parameter The structured parameter to construct from.
+ * Construct Parameter for update transactions for 'viewGuardian' entrypoint of the 'election' contract.
+ * @param {ViewGuardianParameter} parameter The structured parameter to construct from.
  * @returns {SDK.Parameter.Type} The smart contract parameter.
  */
-export function createRegisterVotesParameter(parameter: RegisterVotesParameter): SDK.Parameter.Type {
-    const out = SDK.Parameter.fromBase64SchemaType('HQI=', parameter);
+export function createViewGuardianParameter(parameter: ViewGuardianParameter): SDK.Parameter.Type {
+    const accountAddress208 = SDK.AccountAddress.toSchemaValue(parameter);
+    const out = SDK.Parameter.fromBase64SchemaType('Cw==', accountAddress208);
     return out;
 }
 
 /**
- * Send an update-contract transaction to the 'registerVotes' entrypoint of the 'election' contract.
+ * Send an update-contract transaction to the 'viewGuardian' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
- * @param {RegisterVotesParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewGuardianParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
  * @throws If the entrypoint is not successfully invoked.
  * @returns {SDK.TransactionHash.Type} Hash of the transaction.
  */
-export function sendRegisterVotes(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: RegisterVotesParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+export function sendViewGuardian(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewGuardianParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
     return contractClient.genericContract.createAndSendUpdateTransaction(
-        SDK.EntrypointName.fromStringUnchecked('registerVotes'),
+        SDK.EntrypointName.fromStringUnchecked('viewGuardian'),
         SDK.Parameter.toBuffer,
         transactionMetadata,
-        createRegisterVotesParameter(parameter),
+        createViewGuardianParameter(parameter),
         signer
     );
 }
 
 /**
- * Dry-run an update-contract transaction to the 'registerVotes' entrypoint of the 'election' contract.
+ * Dry-run an update-contract transaction to the 'viewGuardian' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
- * @param {RegisterVotesParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewGuardianParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
  * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
  * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
  */
-export function dryRunRegisterVotes(contractClient: ElectionContract, parameter: RegisterVotesParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+export function dryRunViewGuardian(contractClient: ElectionContract, parameter: ViewGuardianParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
     return contractClient.genericContract.dryRun.invokeMethod(
-        SDK.EntrypointName.fromStringUnchecked('registerVotes'),
+        SDK.EntrypointName.fromStringUnchecked('viewGuardian'),
         invokeMetadata,
         SDK.Parameter.toBuffer,
-        createRegisterVotesParameter(parameter),
+        createViewGuardianParameter(parameter),
         blockHash
     );
 }
 
-/** Error message for dry-running update transaction for 'registerVotes' entrypoint of the 'election' contract. */
-export type ErrorMessageRegisterVotes = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+/** Return value for dry-running update transaction for 'viewGuardian' entrypoint of the 'election' contract. */
+export type ReturnValueViewGuardian = { type: 'None'} | { type: 'Some', content: {
+    index: number,
+    public_key: { type: 'None'} | { type: 'Some', content: Array<number> },
+    encrypted_share: { type: 'None'} | { type: 'Some', content: Array<number> },
+    decryption_share: { type: 'None'} | { type: 'Some', content: Array<number> },
+    decryption_share_contests: Map<number, Array<number>>,
+    decryption_share_proof: { type: 'None'} | { type: 'Some', content: Array<number> },
+    status: { type: 'None'} | { type: 'Some', content: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'} },
+    excluded: boolean,
+    acknowledged_tally: boolean,
+    } };
 
 /**
- * Get and parse the error message from dry-running update transaction for 'registerVotes' entrypoint of the 'election' contract.
- * Returns undefined if the result is not a failure.
+ * Get and parse the return value from dry-running update transaction for 'viewGuardian' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not successful.
  * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
- * @returns {ErrorMessageRegisterVotes | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ * @returns {ReturnValueViewGuardian | undefined} The structured return value or undefined if result was not a success.
  */
-export function parseErrorMessageRegisterVotes(invokeResult: SDK.InvokeContractResult): ErrorMessageRegisterVotes | undefined {
-    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+export function parseReturnValueViewGuardian(invokeResult: SDK.InvokeContractResult): ReturnValueViewGuardian | undefined {
+    if (invokeResult.tag !== 'success') {
         return undefined;
     }
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQYAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAI=');
-    let match136: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
-    if ('ParseParams' in schemaJson) {
-       match136 = {
-           type: 'ParseParams',
+    const schemaJson = <{'None' : [] } | {'Some' : [{
+    index: number,
+    public_key: {'None' : [] } | {'Some' : [Array<number>] },
+    encrypted_share: {'None' : [] } | {'Some' : [Array<number>] },
+    decryption_share: {'None' : [] } | {'Some' : [Array<number>] },
+    decryption_share_contests: [number, Array<number>][],
+    decryption_share_proof: {'None' : [] } | {'Some' : [Array<number>] },
+    status: {'None' : [] } | {'Some' : [{'KeyVerificationFailed' : [Array<SDK.AccountAddress.SchemaValue>] } | {'SharesVerificationFailed' : [Array<SDK.AccountAddress.SchemaValue>] } | {'VerificationSuccessful' : [] }] },
+    excluded: boolean,
+    acknowledged_tally: boolean,
+    }] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAFAAJAAAABQAAAGluZGV4BAoAAABwdWJsaWNfa2V5FQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAEAICDwAAAGVuY3J5cHRlZF9zaGFyZRUCAAAABAAAAE5vbmUCBAAAAFNvbWUBAQAAABACAhAAAABkZWNyeXB0aW9uX3NoYXJlFQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAEAICGQAAAGRlY3J5cHRpb25fc2hhcmVfY29udGVzdHMSAgQQAgIWAAAAZGVjcnlwdGlvbl9zaGFyZV9wcm9vZhUCAAAABAAAAE5vbmUCBAAAAFNvbWUBAQAAABACAgYAAABzdGF0dXMVAgAAAAQAAABOb25lAgQAAABTb21lAQEAAAAVAwAAABUAAABLZXlWZXJpZmljYXRpb25GYWlsZWQBAQAAABACCxgAAABTaGFyZXNWZXJpZmljYXRpb25GYWlsZWQBAQAAABACCxYAAABWZXJpZmljYXRpb25TdWNjZXNzZnVsAggAAABleGNsdWRlZAESAAAAYWNrbm93bGVkZ2VkX3RhbGx5AQ==');
+    let match209: { type: 'None'} | { type: 'Some', content: {
+    index: number,
+    public_key: { type: 'None'} | { type: 'Some', content: Array<number> },
+    encrypted_share: { type: 'None'} | { type: 'Some', content: Array<number> },
+    decryption_share: { type: 'None'} | { type: 'Some', content: Array<number> },
+    decryption_share_contests: Map<number, Array<number>>,
+    decryption_share_proof: { type: 'None'} | { type: 'Some', content: Array<number> },
+    status: { type: 'None'} | { type: 'Some', content: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'} },
+    excluded: boolean,
+    acknowledged_tally: boolean,
+    } };
+    if ('None' in schemaJson) {
+       match209 = {
+           type: 'None',
        };
-    } else if ('Unauthorized' in schemaJson) {
-       match136 = {
-           type: 'Unauthorized',
+    } else if ('Some' in schemaJson) {
+       const variant211 = schemaJson.Some;
+    const field212 = variant211[0].index;
+    const field213 = variant211[0].public_key;
+    let match214: { type: 'None'} | { type: 'Some', content: Array<number> };
+    if ('None' in field213) {
+       match214 = {
+           type: 'None',
        };
-    } else if ('Malformed' in schemaJson) {
-       match136 = {
-           type: 'Malformed',
+    } else if ('Some' in field213) {
+       const variant216 = field213.Some;
+       match214 = {
+           type: 'Some',
+           content: variant216[0],
        };
-    } else if ('IncorrectElectionPhase' in schemaJson) {
-       match136 = {
-           type: 'IncorrectElectionPhase',
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    const field219 = variant211[0].encrypted_share;
+    let match220: { type: 'None'} | { type: 'Some', content: Array<number> };
+    if ('None' in field219) {
+       match220 = {
+           type: 'None',
        };
-    } else if ('DuplicateEntry' in schemaJson) {
-       match136 = {
-           type: 'DuplicateEntry',
+    } else if ('Some' in field219) {
+       const variant222 = field219.Some;
+       match220 = {
+           type: 'Some',
+           content: variant222[0],
        };
-    } else if ('GuardianExcluded' in schemaJson) {
-       match136 = {
-           type: 'GuardianExcluded',
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    const field225 = variant211[0].decryption_share;
+    let match226: { type: 'None'} | { type: 'Some', content: Array<number> };
+    if ('None' in field225) {
+       match226 = {
+           type: 'None',
+       };
+    } else if ('Some' in field225) {
+       const variant228 = field225.Some;
+       match226 = {
+           type: 'Some',
+           content: variant228[0],
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    const field231 = variant211[0].decryption_share_contests;
+    const map232: Map<number, Array<number>> = new Map(field231);
+    const field237 = variant211[0].decryption_share_proof;
+    let match238: { type: 'None'} | { type: 'Some', content: Array<number> };
+    if ('None' in field237) {
+       match238 = {
+           type: 'None',
+       };
+    } else if ('Some' in field237) {
+       const variant240 = field237.Some;
+       match238 = {
+           type: 'Some',
+           content: variant240[0],
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    const field243 = variant211[0].status;
+    let match244: { type: 'None'} | { type: 'Some', content: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'} };
+    if ('None' in field243) {
+       match244 = {
+           type: 'None',
+       };
+    } else if ('Some' in field243) {
+       const variant246 = field243.Some;
+    let match247: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'};
+    if ('KeyVerificationFailed' in variant246[0]) {
+       const variant248 = variant246[0].KeyVerificationFailed;
+    const list249 = variant248[0].map((item250) => {
+    const accountAddress251 = SDK.AccountAddress.fromSchemaValue(item250);
+    return accountAddress251;
+    });
+       match247 = {
+           type: 'KeyVerificationFailed',
+           content: list249,
+       };
+    } else if ('SharesVerificationFailed' in variant246[0]) {
+       const variant252 = variant246[0].SharesVerificationFailed;
+    const list253 = variant252[0].map((item254) => {
+    const accountAddress255 = SDK.AccountAddress.fromSchemaValue(item254);
+    return accountAddress255;
+    });
+       match247 = {
+           type: 'SharesVerificationFailed',
+           content: list253,
+       };
+    } else if ('VerificationSuccessful' in variant246[0]) {
+       match247 = {
+           type: 'VerificationSuccessful',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+       match244 = {
+           type: 'Some',
+           content: match247,
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    const field257 = variant211[0].excluded;
+    const field258 = variant211[0].acknowledged_tally;
+    const named259 = {
+    index: field212,
+    public_key: match214,
+    encrypted_share: match220,
+    decryption_share: match226,
+    decryption_share_contests: map232,
+    decryption_share_proof: match238,
+    status: match244,
+    excluded: field257,
+    acknowledged_tally: field258,
+    };
+       match209 = {
+           type: 'Some',
+           content: named259,
        };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    return match136
+    return match209;
 }
 
-/** Parameter type for update transaction for 'postEncryptedTally' entrypoint of the 'election' contract. */
-export type PostEncryptedTallyParameter = Array<number>;
+/** Parameter type for update transaction for 'viewGuardiansStateRange' entrypoint of the 'election' contract. */
+export type ViewGuardiansStateRangeParameter = [number, number];
 
 /**
- * Construct Parameter for update transactions for 'postEncryptedTally' entrypoint of the 'election' contract.
- * @param {PostEncryptedTallyParameter} parameter The structured parameter to construct from.
+ * Construct Parameter for update transactions for 'viewGuardiansStateRange' entrypoint of the 'election' contract.
+ * @param {ViewGuardiansStateRangeParameter} parameter The structured parameter to construct from.
  * @returns {SDK.Parameter.Type} The smart contract parameter.
  */
-export function createPostEncryptedTallyParameter(parameter: PostEncryptedTallyParameter): SDK.Parameter.Type {
-    const out = SDK.Parameter.fromBase64SchemaType('EAIC', parameter);
+export function createViewGuardiansStateRangeParameter(parameter: ViewGuardiansStateRangeParameter): SDK.Parameter.Type {
+    const pair260: [number, number] = [parameter[0], parameter[1]];
+    const out = SDK.Parameter.fromBase64SchemaType('DwQE', pair260);
     return out;
 }
 
 /**
- * Send an update-contract transaction to the 'postEncryptedTally' entrypoint of the 'election' contract.
+ * Send an update-contract transaction to the 'viewGuardiansStateRange' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
- * @param {PostEncryptedTallyParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewGuardiansStateRangeParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
  * @throws If the entrypoint is not successfully invoked.
  * @returns {SDK.TransactionHash.Type} Hash of the transaction.
  */
-export function sendPostEncryptedTally(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: PostEncryptedTallyParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+export function sendViewGuardiansStateRange(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewGuardiansStateRangeParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
     return contractClient.genericContract.createAndSendUpdateTransaction(
-        SDK.EntrypointName.fromStringUnchecked('postEncryptedTally'),
+        SDK.EntrypointName.fromStringUnchecked('viewGuardiansStateRange'),
         SDK.Parameter.toBuffer,
         transactionMetadata,
-        createPostEncryptedTallyParameter(parameter),
+        createViewGuardiansStateRangeParameter(parameter),
         signer
     );
 }
 
 /**
- * Dry-run an update-contract transaction to the 'postEncryptedTally' entrypoint of the 'election' contract.
+ * Dry-run an update-contract transaction to the 'viewGuardiansStateRange' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
- * @param {PostEncryptedTallyParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewGuardiansStateRangeParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
  * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
  * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
  */
-export function dryRunPostEncryptedTally(contractClient: ElectionContract, parameter: PostEncryptedTallyParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+export function dryRunViewGuardiansStateRange(contractClient: ElectionContract, parameter: ViewGuardiansStateRangeParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
     return contractClient.genericContract.dryRun.invokeMethod(
-        SDK.EntrypointName.fromStringUnchecked('postEncryptedTally'),
+        SDK.EntrypointName.fromStringUnchecked('viewGuardiansStateRange'),
         invokeMetadata,
         SDK.Parameter.toBuffer,
-        createPostEncryptedTallyParameter(parameter),
+        createViewGuardiansStateRangeParameter(parameter),
         blockHash
     );
 }
 
-/** Error message for dry-running update transaction for 'postEncryptedTally' entrypoint of the 'election' contract. */
-export type ErrorMessagePostEncryptedTally = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+/** Return value for dry-running update transaction for 'viewGuardiansStateRange' entrypoint of the 'election' contract. */
+export type ReturnValueViewGuardiansStateRange = Array<[SDK.AccountAddress.Type, {
+    index: number,
+    public_key: { type: 'None'} | { type: 'Some', content: Array<number> },
+    encrypted_share: { type: 'None'} | { type: 'Some', content: Array<number> },
+    decryption_share: { type: 'None'} | { type: 'Some', content: Array<number> },
+    decryption_share_contests: Map<number, Array<number>>,
+    decryption_share_proof: { type: 'None'} | { type: 'Some', content: Array<number> },
+    status: { type: 'None'} | { type: 'Some', content: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'} },
+    excluded: boolean,
+    acknowledged_tally: boolean,
+    }]>;
 
 /**
- * Get and parse the error message from dry-running update transaction for 'postEncryptedTally' entrypoint of the 'election' contract.
- * Returns undefined if the result is not a failure.
+ * Get and parse the return value from dry-running update transaction for 'viewGuardiansStateRange' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not successful.
  * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
- * @returns {ErrorMessagePostEncryptedTally | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ * @returns {ReturnValueViewGuardiansStateRange | undefined} The structured return value or undefined if result was not a success.
  */
-export function parseErrorMessagePostEncryptedTally(invokeResult: SDK.InvokeContractResult): ErrorMessagePostEncryptedTally | undefined {
-    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+export function parseReturnValueViewGuardiansStateRange(invokeResult: SDK.InvokeContractResult): ReturnValueViewGuardiansStateRange | undefined {
+    if (invokeResult.tag !== 'success') {
         return undefined;
     }
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQYAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAI=');
-    let match145: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
-    if ('ParseParams' in schemaJson) {
-       match145 = {
-           type: 'ParseParams',
+    const schemaJson = <Array<[SDK.AccountAddress.SchemaValue, {
+    index: number,
+    public_key: {'None' : [] } | {'Some' : [Array<number>] },
+    encrypted_share: {'None' : [] } | {'Some' : [Array<number>] },
+    decryption_share: {'None' : [] } | {'Some' : [Array<number>] },
+    decryption_share_contests: [number, Array<number>][],
+    decryption_share_proof: {'None' : [] } | {'Some' : [Array<number>] },
+    status: {'None' : [] } | {'Some' : [{'KeyVerificationFailed' : [Array<SDK.AccountAddress.SchemaValue>] } | {'SharesVerificationFailed' : [Array<SDK.AccountAddress.SchemaValue>] } | {'VerificationSuccessful' : [] }] },
+    excluded: boolean,
+    acknowledged_tally: boolean,
+    }]>>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'EAIPCxQACQAAAAUAAABpbmRleAQKAAAAcHVibGljX2tleRUCAAAABAAAAE5vbmUCBAAAAFNvbWUBAQAAABACAg8AAABlbmNyeXB0ZWRfc2hhcmUVAgAAAAQAAABOb25lAgQAAABTb21lAQEAAAAQAgIQAAAAZGVjcnlwdGlvbl9zaGFyZRUCAAAABAAAAE5vbmUCBAAAAFNvbWUBAQAAABACAhkAAABkZWNyeXB0aW9uX3NoYXJlX2NvbnRlc3RzEgIEEAICFgAAAGRlY3J5cHRpb25fc2hhcmVfcHJvb2YVAgAAAAQAAABOb25lAgQAAABTb21lAQEAAAAQAgIGAAAAc3RhdHVzFQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAFQMAAAAVAAAAS2V5VmVyaWZpY2F0aW9uRmFpbGVkAQEAAAAQAgsYAAAAU2hhcmVzVmVyaWZpY2F0aW9uRmFpbGVkAQEAAAAQAgsWAAAAVmVyaWZpY2F0aW9uU3VjY2Vzc2Z1bAIIAAAAZXhjbHVkZWQBEgAAAGFja25vd2xlZGdlZF90YWxseQE=');
+    const list261 = schemaJson.map((item262) => {
+    const accountAddress264 = SDK.AccountAddress.fromSchemaValue(item262[0]);
+    const field265 = item262[1].index;
+    const field266 = item262[1].public_key;
+    let match267: { type: 'None'} | { type: 'Some', content: Array<number> };
+    if ('None' in field266) {
+       match267 = {
+           type: 'None',
        };
-    } else if ('Unauthorized' in schemaJson) {
-       match145 = {
-           type: 'Unauthorized',
+    } else if ('Some' in field266) {
+       const variant269 = field266.Some;
+       match267 = {
+           type: 'Some',
+           content: variant269[0],
        };
-    } else if ('Malformed' in schemaJson) {
-       match145 = {
-           type: 'Malformed',
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    const field272 = item262[1].encrypted_share;
+    let match273: { type: 'None'} | { type: 'Some', content: Array<number> };
+    if ('None' in field272) {
+       match273 = {
+           type: 'None',
        };
-    } else if ('IncorrectElectionPhase' in schemaJson) {
-       match145 = {
-           type: 'IncorrectElectionPhase',
+    } else if ('Some' in field272) {
+       const variant275 = field272.Some;
+       match273 = {
+           type: 'Some',
+           content: variant275[0],
        };
-    } else if ('DuplicateEntry' in schemaJson) {
-       match145 = {
-           type: 'DuplicateEntry',
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    const field278 = item262[1].decryption_share;
+    let match279: { type: 'None'} | { type: 'Some', content: Array<number> };
+    if ('None' in field278) {
+       match279 = {
+           type: 'None',
        };
-    } else if ('GuardianExcluded' in schemaJson) {
-       match145 = {
-           type: 'GuardianExcluded',
+    } else if ('Some' in field278) {
+       const variant281 = field278.Some;
+       match279 = {
+           type: 'Some',
+           content: variant281[0],
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    const field284 = item262[1].decryption_share_contests;
+    const map285: Map<number, Array<number>> = new Map(field284);
+    const field290 = item262[1].decryption_share_proof;
+    let match291: { type: 'None'} | { type: 'Some', content: Array<number> };
+    if ('None' in field290) {
+       match291 = {
+           type: 'None',
+       };
+    } else if ('Some' in field290) {
+       const variant293 = field290.Some;
+       match291 = {
+           type: 'Some',
+           content: variant293[0],
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    const field296 = item262[1].status;
+    let match297: { type: 'None'} | { type: 'Some', content: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'} };
+    if ('None' in field296) {
+       match297 = {
+           type: 'None',
+       };
+    } else if ('Some' in field296) {
+       const variant299 = field296.Some;
+    let match300: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'};
+    if ('KeyVerificationFailed' in variant299[0]) {
+       const variant301 = variant299[0].KeyVerificationFailed;
+    const list302 = variant301[0].map((item303) => {
+    const accountAddress304 = SDK.AccountAddress.fromSchemaValue(item303);
+    return accountAddress304;
+    });
+       match300 = {
+           type: 'KeyVerificationFailed',
+           content: list302,
+       };
+    } else if ('SharesVerificationFailed' in variant299[0]) {
+       const variant305 = variant299[0].SharesVerificationFailed;
+    const list306 = variant305[0].map((item307) => {
+    const accountAddress308 = SDK.AccountAddress.fromSchemaValue(item307);
+    return accountAddress308;
+    });
+       match300 = {
+           type: 'SharesVerificationFailed',
+           content: list306,
+       };
+    } else if ('VerificationSuccessful' in variant299[0]) {
+       match300 = {
+           type: 'VerificationSuccessful',
        };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    return match145
+       match297 = {
+           type: 'Some',
+           content: match300,
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    const field310 = item262[1].excluded;
+    const field311 = item262[1].acknowledged_tally;
+    const named312 = {
+    index: field265,
+    public_key: match267,
+    encrypted_share: match273,
+    decryption_share: match279,
+    decryption_share_contests: map285,
+    decryption_share_proof: match291,
+    status: match297,
+    excluded: field310,
+    acknowledged_tally: field311,
+    };
+    const pair263: [SDK.AccountAddress.Type, {
+    index: number,
+    public_key: { type: 'None'} | { type: 'Some', content: Array<number> },
+    encrypted_share: { type: 'None'} | { type: 'Some', content: Array<number> },
+    decryption_share: { type: 'None'} | { type: 'Some', content: Array<number> },
+    decryption_share_contests: Map<number, Array<number>>,
+    decryption_share_proof: { type: 'None'} | { type: 'Some', content: Array<number> },
+    status: { type: 'None'} | { type: 'Some', content: { type: 'KeyVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'SharesVerificationFailed', content: Array<SDK.AccountAddress.Type> } | { type: 'VerificationSuccessful'} },
+    excluded: boolean,
+    acknowledged_tally: boolean,
+    }] = [accountAddress264, named312];
+    return pair263;
+    });
+    return list261;
 }
 
-/** Parameter type for update transaction for 'postElectionResult' entrypoint of the 'election' contract. */
-export type PostElectionResultParameter = Array<number | bigint>;
+/** Parameter type for update transaction for 'viewGuardianProgress' entrypoint of the 'election' contract. */
+export type ViewGuardianProgressParameter = SDK.Parameter.Type;
 
 /**
- * Construct Parameter for update transactions for 'postElectionResult' entrypoint of the 'election' contract.
- * @param {PostElectionResultParameter} parameter The structured parameter to construct from.
+ * Construct Parameter for update transactions for 'viewGuardianProgress' entrypoint of the 'election' contract.
+ * @param {ViewGuardianProgressParameter} parameter The structured parameter to construct from.
  * @returns {SDK.Parameter.Type} The smart contract parameter.
  */
-export function createPostElectionResultParameter(parameter: PostElectionResultParameter): SDK.Parameter.Type {
-    const list152 = parameter.map((item153) => {
-    const number154 = BigInt(item153);
-    return number154;
-    });
-    const out = SDK.Parameter.fromBase64SchemaType('EAIF', list152);
-    return out;
+export function createViewGuardianProgressParameter(parameter: ViewGuardianProgressParameter): SDK.Parameter.Type {
+    return parameter;
 }
 
 /**
- * Send an update-contract transaction to the 'postElectionResult' entrypoint of the 'election' contract.
+ * Send an update-contract transaction to the 'viewGuardianProgress' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
- * @param {PostElectionResultParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewGuardianProgressParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
  * @throws If the entrypoint is not successfully invoked.
  * @returns {SDK.TransactionHash.Type} Hash of the transaction.
  */
-export function sendPostElectionResult(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: PostElectionResultParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+export function sendViewGuardianProgress(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewGuardianProgressParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
     return contractClient.genericContract.createAndSendUpdateTransaction(
-        SDK.EntrypointName.fromStringUnchecked('postElectionResult'),
+        SDK.EntrypointName.fromStringUnchecked('viewGuardianProgress'),
         SDK.Parameter.toBuffer,
         transactionMetadata,
-        createPostElectionResultParameter(parameter),
+        createViewGuardianProgressParameter(parameter),
         signer
     );
 }
 
 /**
- * Dry-run an update-contract transaction to the 'postElectionResult' entrypoint of the 'election' contract.
+ * Dry-run an update-contract transaction to the 'viewGuardianProgress' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
- * @param {PostElectionResultParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewGuardianProgressParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
  * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
  * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
  */
-export function dryRunPostElectionResult(contractClient: ElectionContract, parameter: PostElectionResultParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+export function dryRunViewGuardianProgress(contractClient: ElectionContract, parameter: ViewGuardianProgressParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
     return contractClient.genericContract.dryRun.invokeMethod(
-        SDK.EntrypointName.fromStringUnchecked('postElectionResult'),
+        SDK.EntrypointName.fromStringUnchecked('viewGuardianProgress'),
         invokeMetadata,
         SDK.Parameter.toBuffer,
-        createPostElectionResultParameter(parameter),
+        createViewGuardianProgressParameter(parameter),
         blockHash
     );
 }
 
-/** Error message for dry-running update transaction for 'postElectionResult' entrypoint of the 'election' contract. */
-export type ErrorMessagePostElectionResult = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+/** Return value for dry-running update transaction for 'viewGuardianProgress' entrypoint of the 'election' contract. */
+export type ReturnValueViewGuardianProgress = {
+    total: number,
+    with_public_key: number,
+    with_encrypted_share: number,
+    with_status: number,
+    with_decryption_share: number,
+    with_decryption_proof: number,
+    excluded: number,
+    };
 
 /**
- * Get and parse the error message from dry-running update transaction for 'postElectionResult' entrypoint of the 'election' contract.
- * Returns undefined if the result is not a failure.
+ * Get and parse the return value from dry-running update transaction for 'viewGuardianProgress' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not successful.
  * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
- * @returns {ErrorMessagePostElectionResult | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ * @returns {ReturnValueViewGuardianProgress | undefined} The structured return value or undefined if result was not a success.
  */
-export function parseErrorMessagePostElectionResult(invokeResult: SDK.InvokeContractResult): ErrorMessagePostElectionResult | undefined {
-    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+export function parseReturnValueViewGuardianProgress(invokeResult: SDK.InvokeContractResult): ReturnValueViewGuardianProgress | undefined {
+    if (invokeResult.tag !== 'success') {
         return undefined;
     }
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQYAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAI=');
-    let match155: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
-    if ('ParseParams' in schemaJson) {
-       match155 = {
-           type: 'ParseParams',
-       };
-    } else if ('Unauthorized' in schemaJson) {
-       match155 = {
-           type: 'Unauthorized',
-       };
-    } else if ('Malformed' in schemaJson) {
-       match155 = {
-           type: 'Malformed',
-       };
-    } else if ('IncorrectElectionPhase' in schemaJson) {
-       match155 = {
-           type: 'IncorrectElectionPhase',
-       };
-    } else if ('DuplicateEntry' in schemaJson) {
-       match155 = {
-           type: 'DuplicateEntry',
-       };
-    } else if ('GuardianExcluded' in schemaJson) {
-       match155 = {
-           type: 'GuardianExcluded',
-       };
-    }
-     else {
-       throw new Error("Unexpected enum variant");
-    }
-    return match155
+    const schemaJson = <{
+    total: number,
+    with_public_key: number,
+    with_encrypted_share: number,
+    with_status: number,
+    with_decryption_share: number,
+    with_decryption_proof: number,
+    excluded: number,
+    }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FAAHAAAABQAAAHRvdGFsBA8AAAB3aXRoX3B1YmxpY19rZXkEFAAAAHdpdGhfZW5jcnlwdGVkX3NoYXJlBAsAAAB3aXRoX3N0YXR1cwQVAAAAd2l0aF9kZWNyeXB0aW9uX3NoYXJlBBUAAAB3aXRoX2RlY3J5cHRpb25fcHJvb2YECAAAAGV4Y2x1ZGVkBA==');
+    const field313 = schemaJson.total;
+    const field314 = schemaJson.with_public_key;
+    const field315 = schemaJson.with_encrypted_share;
+    const field316 = schemaJson.with_status;
+    const field317 = schemaJson.with_decryption_share;
+    const field318 = schemaJson.with_decryption_proof;
+    const field319 = schemaJson.excluded;
+    const named320 = {
+    total: field313,
+    with_public_key: field314,
+    with_encrypted_share: field315,
+    with_status: field316,
+    with_decryption_share: field317,
+    with_decryption_proof: field318,
+    excluded: field319,
+    };
+    return named320;
 }
 
-/** Parameter type for update transaction for 'resetFinalizationPhase' entrypoint of the 'election' contract. */
-export type ResetFinalizationPhaseParameter = [Array<SDK.AccountAddress.Type>, SDK.Timestamp.Type];
+/** Parameter type for update transaction for 'viewCanDecrypt' entrypoint of the 'election' contract. */
+export type ViewCanDecryptParameter = SDK.Parameter.Type;
 
 /**
- * Construct Parameter for update transactions for 'resetFinalizationPhase' entrypoint of the 'election' contract.
- * @param {ResetFinalizationPhaseParameter} parameter The structured parameter to construct from.
+ * Construct Parameter for update transactions for 'viewCanDecrypt' entrypoint of the 'election' contract.
+ * @param {ViewCanDecryptParameter} parameter The structured parameter to construct from.
  * @returns {SDK.Parameter.Type} The smart contract parameter.
  */
-export function createResetFinalizationPhaseParameter(parameter: ResetFinalizationPhaseParameter): SDK.Parameter.Type {
-    const list163 = parameter[0].map((item164) => {
-    const accountAddress165 = SDK.AccountAddress.toSchemaValue(item164);
-    return accountAddress165;
-    });
-    const timestamp166 = SDK.Timestamp.toSchemaValue(parameter[1]);
-    const pair162: [Array<SDK.AccountAddress.SchemaValue>, SDK.Timestamp.SchemaValue] = [list163, timestamp166];
-    const out = SDK.Parameter.fromBase64SchemaType('DxACCw0=', pair162);
-    return out;
+export function createViewCanDecryptParameter(parameter: ViewCanDecryptParameter): SDK.Parameter.Type {
+    return parameter;
 }
 
 /**
- * Send an update-contract transaction to the 'resetFinalizationPhase' entrypoint of the 'election' contract.
+ * Send an update-contract transaction to the 'viewCanDecrypt' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
- * @param {ResetFinalizationPhaseParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewCanDecryptParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
  * @throws If the entrypoint is not successfully invoked.
  * @returns {SDK.TransactionHash.Type} Hash of the transaction.
  */
-export function sendResetFinalizationPhase(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ResetFinalizationPhaseParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+export function sendViewCanDecrypt(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewCanDecryptParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
     return contractClient.genericContract.createAndSendUpdateTransaction(
-        SDK.EntrypointName.fromStringUnchecked('resetFinalizationPhase'),
+        SDK.EntrypointName.fromStringUnchecked('viewCanDecrypt'),
         SDK.Parameter.toBuffer,
         transactionMetadata,
-        createResetFinalizationPhaseParameter(parameter),
+        createViewCanDecryptParameter(parameter),
         signer
     );
 }
 
 /**
- * Dry-run an update-contract transaction to the 'resetFinalizationPhase' entrypoint of the 'election' contract.
+ * Dry-run an update-contract transaction to the 'viewCanDecrypt' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
- * @param {ResetFinalizationPhaseParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewCanDecryptParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
  * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
  * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
  */
-export function dryRunResetFinalizationPhase(contractClient: ElectionContract, parameter: ResetFinalizationPhaseParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+export function dryRunViewCanDecrypt(contractClient: ElectionContract, parameter: ViewCanDecryptParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
     return contractClient.genericContract.dryRun.invokeMethod(
-        SDK.EntrypointName.fromStringUnchecked('resetFinalizationPhase'),
+        SDK.EntrypointName.fromStringUnchecked('viewCanDecrypt'),
         invokeMetadata,
         SDK.Parameter.toBuffer,
-        createResetFinalizationPhaseParameter(parameter),
+        createViewCanDecryptParameter(parameter),
         blockHash
     );
 }
 
-/** Error message for dry-running update transaction for 'resetFinalizationPhase' entrypoint of the 'election' contract. */
-export type ErrorMessageResetFinalizationPhase = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+/** Return value for dry-running update transaction for 'viewCanDecrypt' entrypoint of the 'election' contract. */
+export type ReturnValueViewCanDecrypt = {
+    ready: boolean,
+    contributing: number,
+    threshold: number,
+    missing: Array<number>,
+    };
+
+/**
+ * Get and parse the return value from dry-running update transaction for 'viewCanDecrypt' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not successful.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ReturnValueViewCanDecrypt | undefined} The structured return value or undefined if result was not a success.
+ */
+export function parseReturnValueViewCanDecrypt(invokeResult: SDK.InvokeContractResult): ReturnValueViewCanDecrypt | undefined {
+    if (invokeResult.tag !== 'success') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{
+    ready: boolean,
+    contributing: number,
+    threshold: number,
+    missing: Array<number>,
+    }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FAAEAAAABQAAAHJlYWR5AQwAAABjb250cmlidXRpbmcECQAAAHRocmVzaG9sZAQHAAAAbWlzc2luZxACBA==');
+    const field321 = schemaJson.ready;
+    const field322 = schemaJson.contributing;
+    const field323 = schemaJson.threshold;
+    const field324 = schemaJson.missing;
+    const named327 = {
+    ready: field321,
+    contributing: field322,
+    threshold: field323,
+    missing: field324,
+    };
+    return named327;
+}
+
+/** Parameter type for update transaction for 'viewElectionPhase' entrypoint of the 'election' contract. */
+export type ViewElectionPhaseParameter = SDK.Parameter.Type;
+
+/**
+ * Construct Parameter for update transactions for 'viewElectionPhase' entrypoint of the 'election' contract.
+ * @param {ViewElectionPhaseParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createViewElectionPhaseParameter(parameter: ViewElectionPhaseParameter): SDK.Parameter.Type {
+    return parameter;
+}
+
+/**
+ * Send an update-contract transaction to the 'viewElectionPhase' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {ViewElectionPhaseParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendViewElectionPhase(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewElectionPhaseParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('viewElectionPhase'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createViewElectionPhaseParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'viewElectionPhase' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {ViewElectionPhaseParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunViewElectionPhase(contractClient: ElectionContract, parameter: ViewElectionPhaseParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('viewElectionPhase'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createViewElectionPhaseParameter(parameter),
+        blockHash
+    );
+}
+
+/** Return value for dry-running update transaction for 'viewElectionPhase' entrypoint of the 'election' contract. */
+export type ReturnValueViewElectionPhase = { type: 'Setup'} | { type: 'Voting'} | { type: 'Tally'} | { type: 'Finalization'} | { type: 'Finalized'};
+
+/**
+ * Get and parse the return value from dry-running update transaction for 'viewElectionPhase' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not successful.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ReturnValueViewElectionPhase | undefined} The structured return value or undefined if result was not a success.
+ */
+export function parseReturnValueViewElectionPhase(invokeResult: SDK.InvokeContractResult): ReturnValueViewElectionPhase | undefined {
+    if (invokeResult.tag !== 'success') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'Setup' : [] } | {'Voting' : [] } | {'Tally' : [] } | {'Finalization' : [] } | {'Finalized' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQUAAAAFAAAAU2V0dXACBgAAAFZvdGluZwIFAAAAVGFsbHkCDAAAAEZpbmFsaXphdGlvbgIJAAAARmluYWxpemVkAg==');
+    let match328: { type: 'Setup'} | { type: 'Voting'} | { type: 'Tally'} | { type: 'Finalization'} | { type: 'Finalized'};
+    if ('Setup' in schemaJson) {
+       match328 = {
+           type: 'Setup',
+       };
+    } else if ('Voting' in schemaJson) {
+       match328 = {
+           type: 'Voting',
+       };
+    } else if ('Tally' in schemaJson) {
+       match328 = {
+           type: 'Tally',
+       };
+    } else if ('Finalization' in schemaJson) {
+       match328 = {
+           type: 'Finalization',
+       };
+    } else if ('Finalized' in schemaJson) {
+       match328 = {
+           type: 'Finalized',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match328;
+}
+
+/** Parameter type for update transaction for 'registerVotes' entrypoint of the 'election' contract. */
+export type RegisterVotesParameter = SDK.HexString;
+
+/**
+ * Construct Parameter for update transactions for 'registerVotes' entrypoint of the 'election' contract.
+ * @param {RegisterVotesParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createRegisterVotesParameter(parameter: RegisterVotesParameter): SDK.Parameter.Type {
+    const out = SDK.Parameter.fromBase64SchemaType('HQI=', parameter);
+    return out;
+}
+
+/**
+ * Send an update-contract transaction to the 'registerVotes' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {RegisterVotesParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendRegisterVotes(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: RegisterVotesParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('registerVotes'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createRegisterVotesParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'registerVotes' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {RegisterVotesParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunRegisterVotes(contractClient: ElectionContract, parameter: RegisterVotesParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('registerVotes'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createRegisterVotesParameter(parameter),
+        blockHash
+    );
+}
+
+/** Error message for dry-running update transaction for 'registerVotes' entrypoint of the 'election' contract. */
+export type ErrorMessageRegisterVotes = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+
+/**
+ * Get and parse the error message from dry-running update transaction for 'registerVotes' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not a failure.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ErrorMessageRegisterVotes | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ */
+export function parseErrorMessageRegisterVotes(invokeResult: SDK.InvokeContractResult): ErrorMessageRegisterVotes | undefined {
+    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match334: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+    if ('ParseParams' in schemaJson) {
+       match334 = {
+           type: 'ParseParams',
+       };
+    } else if ('Unauthorized' in schemaJson) {
+       match334 = {
+           type: 'Unauthorized',
+       };
+    } else if ('Malformed' in schemaJson) {
+       match334 = {
+           type: 'Malformed',
+       };
+    } else if ('IncorrectElectionPhase' in schemaJson) {
+       match334 = {
+           type: 'IncorrectElectionPhase',
+       };
+    } else if ('DuplicateEntry' in schemaJson) {
+       match334 = {
+           type: 'DuplicateEntry',
+       };
+    } else if ('GuardianExcluded' in schemaJson) {
+       match334 = {
+           type: 'GuardianExcluded',
+       };
+    } else if ('Log' in schemaJson) {
+       match334 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match334 = {
+           type: 'ElectionLocked',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match334
+}
+
+/** Parameter type for update transaction for 'postEncryptedTally' entrypoint of the 'election' contract. */
+export type PostEncryptedTallyParameter = Array<number>;
+
+/**
+ * Construct Parameter for update transactions for 'postEncryptedTally' entrypoint of the 'election' contract.
+ * @param {PostEncryptedTallyParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createPostEncryptedTallyParameter(parameter: PostEncryptedTallyParameter): SDK.Parameter.Type {
+    const out = SDK.Parameter.fromBase64SchemaType('EAIC', parameter);
+    return out;
+}
+
+/**
+ * Send an update-contract transaction to the 'postEncryptedTally' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {PostEncryptedTallyParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendPostEncryptedTally(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: PostEncryptedTallyParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('postEncryptedTally'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createPostEncryptedTallyParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'postEncryptedTally' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {PostEncryptedTallyParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunPostEncryptedTally(contractClient: ElectionContract, parameter: PostEncryptedTallyParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('postEncryptedTally'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createPostEncryptedTallyParameter(parameter),
+        blockHash
+    );
+}
+
+/** Error message for dry-running update transaction for 'postEncryptedTally' entrypoint of the 'election' contract. */
+export type ErrorMessagePostEncryptedTally = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+
+/**
+ * Get and parse the error message from dry-running update transaction for 'postEncryptedTally' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not a failure.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ErrorMessagePostEncryptedTally | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ */
+export function parseErrorMessagePostEncryptedTally(invokeResult: SDK.InvokeContractResult): ErrorMessagePostEncryptedTally | undefined {
+    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match345: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+    if ('ParseParams' in schemaJson) {
+       match345 = {
+           type: 'ParseParams',
+       };
+    } else if ('Unauthorized' in schemaJson) {
+       match345 = {
+           type: 'Unauthorized',
+       };
+    } else if ('Malformed' in schemaJson) {
+       match345 = {
+           type: 'Malformed',
+       };
+    } else if ('IncorrectElectionPhase' in schemaJson) {
+       match345 = {
+           type: 'IncorrectElectionPhase',
+       };
+    } else if ('DuplicateEntry' in schemaJson) {
+       match345 = {
+           type: 'DuplicateEntry',
+       };
+    } else if ('GuardianExcluded' in schemaJson) {
+       match345 = {
+           type: 'GuardianExcluded',
+       };
+    } else if ('Log' in schemaJson) {
+       match345 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match345 = {
+           type: 'ElectionLocked',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match345
+}
+
+/** Parameter type for update transaction for 'acknowledgeTally' entrypoint of the 'election' contract. */
+export type AcknowledgeTallyParameter = SDK.Parameter.Type;
+
+/**
+ * Construct Parameter for update transactions for 'acknowledgeTally' entrypoint of the 'election' contract.
+ * @param {AcknowledgeTallyParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createAcknowledgeTallyParameter(parameter: AcknowledgeTallyParameter): SDK.Parameter.Type {
+    return parameter;
+}
+
+/**
+ * Send an update-contract transaction to the 'acknowledgeTally' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {AcknowledgeTallyParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendAcknowledgeTally(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: AcknowledgeTallyParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('acknowledgeTally'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createAcknowledgeTallyParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'acknowledgeTally' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {AcknowledgeTallyParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunAcknowledgeTally(contractClient: ElectionContract, parameter: AcknowledgeTallyParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('acknowledgeTally'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createAcknowledgeTallyParameter(parameter),
+        blockHash
+    );
+}
+
+/** Error message for dry-running update transaction for 'acknowledgeTally' entrypoint of the 'election' contract. */
+export type ErrorMessageAcknowledgeTally = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+
+/**
+ * Get and parse the error message from dry-running update transaction for 'acknowledgeTally' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not a failure.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ErrorMessageAcknowledgeTally | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ */
+export function parseErrorMessageAcknowledgeTally(invokeResult: SDK.InvokeContractResult): ErrorMessageAcknowledgeTally | undefined {
+    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match354: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+    if ('ParseParams' in schemaJson) {
+       match354 = {
+           type: 'ParseParams',
+       };
+    } else if ('Unauthorized' in schemaJson) {
+       match354 = {
+           type: 'Unauthorized',
+       };
+    } else if ('Malformed' in schemaJson) {
+       match354 = {
+           type: 'Malformed',
+       };
+    } else if ('IncorrectElectionPhase' in schemaJson) {
+       match354 = {
+           type: 'IncorrectElectionPhase',
+       };
+    } else if ('DuplicateEntry' in schemaJson) {
+       match354 = {
+           type: 'DuplicateEntry',
+       };
+    } else if ('GuardianExcluded' in schemaJson) {
+       match354 = {
+           type: 'GuardianExcluded',
+       };
+    } else if ('Log' in schemaJson) {
+       match354 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match354 = {
+           type: 'ElectionLocked',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match354
+}
+
+/** Parameter type for update transaction for 'postElectionResult' entrypoint of the 'election' contract. */
+export type PostElectionResultParameter = Array<[number, Array<number | bigint>]>;
+
+/**
+ * Construct Parameter for update transactions for 'postElectionResult' entrypoint of the 'election' contract.
+ * @param {PostElectionResultParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createPostElectionResultParameter(parameter: PostElectionResultParameter): SDK.Parameter.Type {
+    const list363 = parameter.map((item364) => {
+    const list366 = item364[1].map((item367) => {
+    const number368 = BigInt(item367);
+    return number368;
+    });
+    const pair365: [number, Array<bigint>] = [item364[0], list366];
+    return pair365;
+    });
+    const out = SDK.Parameter.fromBase64SchemaType('EAIPBBACBQ==', list363);
+    return out;
+}
+
+/**
+ * Send an update-contract transaction to the 'postElectionResult' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {PostElectionResultParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendPostElectionResult(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: PostElectionResultParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('postElectionResult'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createPostElectionResultParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'postElectionResult' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {PostElectionResultParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunPostElectionResult(contractClient: ElectionContract, parameter: PostElectionResultParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('postElectionResult'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createPostElectionResultParameter(parameter),
+        blockHash
+    );
+}
+
+/** Error message for dry-running update transaction for 'postElectionResult' entrypoint of the 'election' contract. */
+export type ErrorMessagePostElectionResult = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+
+/**
+ * Get and parse the error message from dry-running update transaction for 'postElectionResult' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not a failure.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ErrorMessagePostElectionResult | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ */
+export function parseErrorMessagePostElectionResult(invokeResult: SDK.InvokeContractResult): ErrorMessagePostElectionResult | undefined {
+    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match369: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+    if ('ParseParams' in schemaJson) {
+       match369 = {
+           type: 'ParseParams',
+       };
+    } else if ('Unauthorized' in schemaJson) {
+       match369 = {
+           type: 'Unauthorized',
+       };
+    } else if ('Malformed' in schemaJson) {
+       match369 = {
+           type: 'Malformed',
+       };
+    } else if ('IncorrectElectionPhase' in schemaJson) {
+       match369 = {
+           type: 'IncorrectElectionPhase',
+       };
+    } else if ('DuplicateEntry' in schemaJson) {
+       match369 = {
+           type: 'DuplicateEntry',
+       };
+    } else if ('GuardianExcluded' in schemaJson) {
+       match369 = {
+           type: 'GuardianExcluded',
+       };
+    } else if ('Log' in schemaJson) {
+       match369 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match369 = {
+           type: 'ElectionLocked',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match369
+}
+
+/** Parameter type for update transaction for 'approveResult' entrypoint of the 'election' contract. */
+export type ApproveResultParameter = SDK.HexString;
+
+/**
+ * Construct Parameter for update transactions for 'approveResult' entrypoint of the 'election' contract.
+ * @param {ApproveResultParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createApproveResultParameter(parameter: ApproveResultParameter): SDK.Parameter.Type {
+    const out = SDK.Parameter.fromBase64SchemaType('HiAAAAA=', parameter);
+    return out;
+}
+
+/**
+ * Send an update-contract transaction to the 'approveResult' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {ApproveResultParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendApproveResult(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ApproveResultParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('approveResult'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createApproveResultParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'approveResult' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {ApproveResultParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunApproveResult(contractClient: ElectionContract, parameter: ApproveResultParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('approveResult'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createApproveResultParameter(parameter),
+        blockHash
+    );
+}
+
+/** Error message for dry-running update transaction for 'approveResult' entrypoint of the 'election' contract. */
+export type ErrorMessageApproveResult = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+
+/**
+ * Get and parse the error message from dry-running update transaction for 'approveResult' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not a failure.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ErrorMessageApproveResult | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ */
+export function parseErrorMessageApproveResult(invokeResult: SDK.InvokeContractResult): ErrorMessageApproveResult | undefined {
+    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match378: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+    if ('ParseParams' in schemaJson) {
+       match378 = {
+           type: 'ParseParams',
+       };
+    } else if ('Unauthorized' in schemaJson) {
+       match378 = {
+           type: 'Unauthorized',
+       };
+    } else if ('Malformed' in schemaJson) {
+       match378 = {
+           type: 'Malformed',
+       };
+    } else if ('IncorrectElectionPhase' in schemaJson) {
+       match378 = {
+           type: 'IncorrectElectionPhase',
+       };
+    } else if ('DuplicateEntry' in schemaJson) {
+       match378 = {
+           type: 'DuplicateEntry',
+       };
+    } else if ('GuardianExcluded' in schemaJson) {
+       match378 = {
+           type: 'GuardianExcluded',
+       };
+    } else if ('Log' in schemaJson) {
+       match378 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match378 = {
+           type: 'ElectionLocked',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match378
+}
+
+/** Parameter type for update transaction for 'viewResultProposal' entrypoint of the 'election' contract. */
+export type ViewResultProposalParameter = SDK.Parameter.Type;
+
+/**
+ * Construct Parameter for update transactions for 'viewResultProposal' entrypoint of the 'election' contract.
+ * @param {ViewResultProposalParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createViewResultProposalParameter(parameter: ViewResultProposalParameter): SDK.Parameter.Type {
+    return parameter;
+}
+
+/**
+ * Send an update-contract transaction to the 'viewResultProposal' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {ViewResultProposalParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendViewResultProposal(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewResultProposalParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('viewResultProposal'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createViewResultProposalParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'viewResultProposal' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {ViewResultProposalParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunViewResultProposal(contractClient: ElectionContract, parameter: ViewResultProposalParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('viewResultProposal'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createViewResultProposalParameter(parameter),
+        blockHash
+    );
+}
+
+/** Return value for dry-running update transaction for 'viewResultProposal' entrypoint of the 'election' contract. */
+export type ReturnValueViewResultProposal = { type: 'None'} | { type: 'Some', content: {
+    result: Array<[number, Array<number | bigint>]>,
+    approvals: Array<SDK.AccountAddress.Type>,
+    } };
+
+/**
+ * Get and parse the return value from dry-running update transaction for 'viewResultProposal' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not successful.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ReturnValueViewResultProposal | undefined} The structured return value or undefined if result was not a success.
+ */
+export function parseReturnValueViewResultProposal(invokeResult: SDK.InvokeContractResult): ReturnValueViewResultProposal | undefined {
+    if (invokeResult.tag !== 'success') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'None' : [] } | {'Some' : [{
+    result: Array<[number, Array<bigint>]>,
+    approvals: Array<SDK.AccountAddress.SchemaValue>,
+    }] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAFAACAAAABgAAAHJlc3VsdBACDwQQAgUJAAAAYXBwcm92YWxzEAIL');
+    let match387: { type: 'None'} | { type: 'Some', content: {
+    result: Array<[number, Array<number | bigint>]>,
+    approvals: Array<SDK.AccountAddress.Type>,
+    } };
+    if ('None' in schemaJson) {
+       match387 = {
+           type: 'None',
+       };
+    } else if ('Some' in schemaJson) {
+       const variant389 = schemaJson.Some;
+    const field390 = variant389[0].result;
+    const list391 = field390.map((item392) => {
+    const pair393: [number, Array<number | bigint>] = [item392[0], item392[1]];
+    return pair393;
+    });
+    const field396 = variant389[0].approvals;
+    const list397 = field396.map((item398) => {
+    const accountAddress399 = SDK.AccountAddress.fromSchemaValue(item398);
+    return accountAddress399;
+    });
+    const named400 = {
+    result: list391,
+    approvals: list397,
+    };
+       match387 = {
+           type: 'Some',
+           content: named400,
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match387;
+}
+
+/** Parameter type for update transaction for 'resetFinalizationPhase' entrypoint of the 'election' contract. */
+export type ResetFinalizationPhaseParameter = [Array<SDK.AccountAddress.Type>, SDK.Timestamp.Type];
+
+/**
+ * Construct Parameter for update transactions for 'resetFinalizationPhase' entrypoint of the 'election' contract.
+ * @param {ResetFinalizationPhaseParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createResetFinalizationPhaseParameter(parameter: ResetFinalizationPhaseParameter): SDK.Parameter.Type {
+    const list402 = parameter[0].map((item403) => {
+    const accountAddress404 = SDK.AccountAddress.toSchemaValue(item403);
+    return accountAddress404;
+    });
+    const timestamp405 = SDK.Timestamp.toSchemaValue(parameter[1]);
+    const pair401: [Array<SDK.AccountAddress.SchemaValue>, SDK.Timestamp.SchemaValue] = [list402, timestamp405];
+    const out = SDK.Parameter.fromBase64SchemaType('DxACCw0=', pair401);
+    return out;
+}
+
+/**
+ * Send an update-contract transaction to the 'resetFinalizationPhase' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {ResetFinalizationPhaseParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendResetFinalizationPhase(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ResetFinalizationPhaseParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('resetFinalizationPhase'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createResetFinalizationPhaseParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'resetFinalizationPhase' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {ResetFinalizationPhaseParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunResetFinalizationPhase(contractClient: ElectionContract, parameter: ResetFinalizationPhaseParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('resetFinalizationPhase'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createResetFinalizationPhaseParameter(parameter),
+        blockHash
+    );
+}
+
+/** Error message for dry-running update transaction for 'resetFinalizationPhase' entrypoint of the 'election' contract. */
+export type ErrorMessageResetFinalizationPhase = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+
+/**
+ * Get and parse the error message from dry-running update transaction for 'resetFinalizationPhase' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not a failure.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ErrorMessageResetFinalizationPhase | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ */
+export function parseErrorMessageResetFinalizationPhase(invokeResult: SDK.InvokeContractResult): ErrorMessageResetFinalizationPhase | undefined {
+    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match406: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+    if ('ParseParams' in schemaJson) {
+       match406 = {
+           type: 'ParseParams',
+       };
+    } else if ('Unauthorized' in schemaJson) {
+       match406 = {
+           type: 'Unauthorized',
+       };
+    } else if ('Malformed' in schemaJson) {
+       match406 = {
+           type: 'Malformed',
+       };
+    } else if ('IncorrectElectionPhase' in schemaJson) {
+       match406 = {
+           type: 'IncorrectElectionPhase',
+       };
+    } else if ('DuplicateEntry' in schemaJson) {
+       match406 = {
+           type: 'DuplicateEntry',
+       };
+    } else if ('GuardianExcluded' in schemaJson) {
+       match406 = {
+           type: 'GuardianExcluded',
+       };
+    } else if ('Log' in schemaJson) {
+       match406 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match406 = {
+           type: 'ElectionLocked',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match406
+}
+
+/** Parameter type for update transaction for 'extendDecryptionDeadline' entrypoint of the 'election' contract. */
+export type ExtendDecryptionDeadlineParameter = SDK.Timestamp.Type;
+
+/**
+ * Construct Parameter for update transactions for 'extendDecryptionDeadline' entrypoint of the 'election' contract.
+ * @param {ExtendDecryptionDeadlineParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createExtendDecryptionDeadlineParameter(parameter: ExtendDecryptionDeadlineParameter): SDK.Parameter.Type {
+    const timestamp415 = SDK.Timestamp.toSchemaValue(parameter);
+    const out = SDK.Parameter.fromBase64SchemaType('DQ==', timestamp415);
+    return out;
+}
+
+/**
+ * Send an update-contract transaction to the 'extendDecryptionDeadline' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {ExtendDecryptionDeadlineParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendExtendDecryptionDeadline(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ExtendDecryptionDeadlineParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('extendDecryptionDeadline'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createExtendDecryptionDeadlineParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'extendDecryptionDeadline' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {ExtendDecryptionDeadlineParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunExtendDecryptionDeadline(contractClient: ElectionContract, parameter: ExtendDecryptionDeadlineParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('extendDecryptionDeadline'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createExtendDecryptionDeadlineParameter(parameter),
+        blockHash
+    );
+}
+
+/** Error message for dry-running update transaction for 'extendDecryptionDeadline' entrypoint of the 'election' contract. */
+export type ErrorMessageExtendDecryptionDeadline = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+
+/**
+ * Get and parse the error message from dry-running update transaction for 'extendDecryptionDeadline' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not a failure.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ErrorMessageExtendDecryptionDeadline | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ */
+export function parseErrorMessageExtendDecryptionDeadline(invokeResult: SDK.InvokeContractResult): ErrorMessageExtendDecryptionDeadline | undefined {
+    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match416: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+    if ('ParseParams' in schemaJson) {
+       match416 = {
+           type: 'ParseParams',
+       };
+    } else if ('Unauthorized' in schemaJson) {
+       match416 = {
+           type: 'Unauthorized',
+       };
+    } else if ('Malformed' in schemaJson) {
+       match416 = {
+           type: 'Malformed',
+       };
+    } else if ('IncorrectElectionPhase' in schemaJson) {
+       match416 = {
+           type: 'IncorrectElectionPhase',
+       };
+    } else if ('DuplicateEntry' in schemaJson) {
+       match416 = {
+           type: 'DuplicateEntry',
+       };
+    } else if ('GuardianExcluded' in schemaJson) {
+       match416 = {
+           type: 'GuardianExcluded',
+       };
+    } else if ('Log' in schemaJson) {
+       match416 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match416 = {
+           type: 'ElectionLocked',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match416
+}
+
+/** Parameter type for update transaction for 'updateCandidates' entrypoint of the 'election' contract. */
+export type UpdateCandidatesParameter = Array<{
+    url: string,
+    hash: SDK.HexString,
+    }>;
+
+/**
+ * Construct Parameter for update transactions for 'updateCandidates' entrypoint of the 'election' contract.
+ * @param {UpdateCandidatesParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createUpdateCandidatesParameter(parameter: UpdateCandidatesParameter): SDK.Parameter.Type {
+    const list425 = parameter.map((item426) => {
+    const field428 = item426.url;
+    const field429 = item426.hash;
+    const named427 = {
+    url: field428,
+    hash: field429,
+    };
+    return named427;
+    });
+    const out = SDK.Parameter.fromBase64SchemaType('EAIUAAIAAAADAAAAdXJsFgIEAAAAaGFzaB4gAAAA', list425);
+    return out;
+}
+
+/**
+ * Send an update-contract transaction to the 'updateCandidates' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {UpdateCandidatesParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendUpdateCandidates(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: UpdateCandidatesParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('updateCandidates'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createUpdateCandidatesParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'updateCandidates' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {UpdateCandidatesParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunUpdateCandidates(contractClient: ElectionContract, parameter: UpdateCandidatesParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('updateCandidates'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createUpdateCandidatesParameter(parameter),
+        blockHash
+    );
+}
+
+/** Error message for dry-running update transaction for 'updateCandidates' entrypoint of the 'election' contract. */
+export type ErrorMessageUpdateCandidates = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+
+/**
+ * Get and parse the error message from dry-running update transaction for 'updateCandidates' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not a failure.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ErrorMessageUpdateCandidates | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ */
+export function parseErrorMessageUpdateCandidates(invokeResult: SDK.InvokeContractResult): ErrorMessageUpdateCandidates | undefined {
+    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match430: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+    if ('ParseParams' in schemaJson) {
+       match430 = {
+           type: 'ParseParams',
+       };
+    } else if ('Unauthorized' in schemaJson) {
+       match430 = {
+           type: 'Unauthorized',
+       };
+    } else if ('Malformed' in schemaJson) {
+       match430 = {
+           type: 'Malformed',
+       };
+    } else if ('IncorrectElectionPhase' in schemaJson) {
+       match430 = {
+           type: 'IncorrectElectionPhase',
+       };
+    } else if ('DuplicateEntry' in schemaJson) {
+       match430 = {
+           type: 'DuplicateEntry',
+       };
+    } else if ('GuardianExcluded' in schemaJson) {
+       match430 = {
+           type: 'GuardianExcluded',
+       };
+    } else if ('Log' in schemaJson) {
+       match430 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match430 = {
+           type: 'ElectionLocked',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match430
+}
+
+/** Parameter type for update transaction for 'lockElection' entrypoint of the 'election' contract. */
+export type LockElectionParameter = SDK.Parameter.Type;
+
+/**
+ * Construct Parameter for update transactions for 'lockElection' entrypoint of the 'election' contract.
+ * @param {LockElectionParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createLockElectionParameter(parameter: LockElectionParameter): SDK.Parameter.Type {
+    return parameter;
+}
+
+/**
+ * Send an update-contract transaction to the 'lockElection' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {LockElectionParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendLockElection(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: LockElectionParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('lockElection'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createLockElectionParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'lockElection' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {LockElectionParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunLockElection(contractClient: ElectionContract, parameter: LockElectionParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('lockElection'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createLockElectionParameter(parameter),
+        blockHash
+    );
+}
+
+/** Error message for dry-running update transaction for 'lockElection' entrypoint of the 'election' contract. */
+export type ErrorMessageLockElection = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+
+/**
+ * Get and parse the error message from dry-running update transaction for 'lockElection' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not a failure.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ErrorMessageLockElection | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ */
+export function parseErrorMessageLockElection(invokeResult: SDK.InvokeContractResult): ErrorMessageLockElection | undefined {
+    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match439: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+    if ('ParseParams' in schemaJson) {
+       match439 = {
+           type: 'ParseParams',
+       };
+    } else if ('Unauthorized' in schemaJson) {
+       match439 = {
+           type: 'Unauthorized',
+       };
+    } else if ('Malformed' in schemaJson) {
+       match439 = {
+           type: 'Malformed',
+       };
+    } else if ('IncorrectElectionPhase' in schemaJson) {
+       match439 = {
+           type: 'IncorrectElectionPhase',
+       };
+    } else if ('DuplicateEntry' in schemaJson) {
+       match439 = {
+           type: 'DuplicateEntry',
+       };
+    } else if ('GuardianExcluded' in schemaJson) {
+       match439 = {
+           type: 'GuardianExcluded',
+       };
+    } else if ('Log' in schemaJson) {
+       match439 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match439 = {
+           type: 'ElectionLocked',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match439
+}
+
+/** Parameter type for update transaction for 'viewConfig' entrypoint of the 'election' contract. */
+export type ViewConfigParameter = SDK.Parameter.Type;
+
+/**
+ * Construct Parameter for update transactions for 'viewConfig' entrypoint of the 'election' contract.
+ * @param {ViewConfigParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createViewConfigParameter(parameter: ViewConfigParameter): SDK.Parameter.Type {
+    return parameter;
+}
+
+/**
+ * Send an update-contract transaction to the 'viewConfig' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {ViewConfigParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendViewConfig(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewConfigParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('viewConfig'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createViewConfigParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'viewConfig' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {ViewConfigParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunViewConfig(contractClient: ElectionContract, parameter: ViewConfigParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('viewConfig'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createViewConfigParameter(parameter),
+        blockHash
+    );
+}
+
+/** Return value for dry-running update transaction for 'viewConfig' entrypoint of the 'election' contract. */
+export type ReturnValueViewConfig = {
+    admin_account: SDK.AccountAddress.Type,
+    candidates: Array<{
+    url: string,
+    hash: SDK.HexString,
+    }>,
+    guardian_accounts: Array<SDK.AccountAddress.Type>,
+    eligible_voters: {
+    parameters: {
+    start_time: SDK.Timestamp.Type,
+    end_time: SDK.Timestamp.Type,
+    },
+    data: {
+    url: string,
+    hash: SDK.HexString,
+    },
+    },
+    election_manifest: {
+    url: string,
+    hash: SDK.HexString,
+    },
+    election_parameters: {
+    url: string,
+    hash: SDK.HexString,
+    },
+    election_description: string,
+    election_start: SDK.Timestamp.Type,
+    election_end: SDK.Timestamp.Type,
+    decryption_deadline: SDK.Timestamp.Type,
+    delegation_string: string,
+    result_posting_deadline: { type: 'None'} | { type: 'Some', content: SDK.Timestamp.Type },
+    result_approvers: Array<SDK.AccountAddress.Type>,
+    approval_threshold: number,
+    eg_version: string,
+    display_order: Array<number>,
+    decryption_grace_seconds: number | bigint,
+    threshold: number,
+    contest_sizes: Array<number>,
+    locked: boolean,
+    };
+
+/**
+ * Get and parse the return value from dry-running update transaction for 'viewConfig' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not successful.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ReturnValueViewConfig | undefined} The structured return value or undefined if result was not a success.
+ */
+export function parseReturnValueViewConfig(invokeResult: SDK.InvokeContractResult): ReturnValueViewConfig | undefined {
+    if (invokeResult.tag !== 'success') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{
+    admin_account: SDK.AccountAddress.SchemaValue,
+    candidates: Array<{
+    url: string,
+    hash: string,
+    }>,
+    guardian_accounts: Array<SDK.AccountAddress.SchemaValue>,
+    eligible_voters: {
+    parameters: {
+    start_time: SDK.Timestamp.SchemaValue,
+    end_time: SDK.Timestamp.SchemaValue,
+    },
+    data: {
+    url: string,
+    hash: string,
+    },
+    },
+    election_manifest: {
+    url: string,
+    hash: string,
+    },
+    election_parameters: {
+    url: string,
+    hash: string,
+    },
+    election_description: string,
+    election_start: SDK.Timestamp.SchemaValue,
+    election_end: SDK.Timestamp.SchemaValue,
+    decryption_deadline: SDK.Timestamp.SchemaValue,
+    delegation_string: string,
+    result_posting_deadline: {'None' : [] } | {'Some' : [SDK.Timestamp.SchemaValue] },
+    result_approvers: Array<SDK.AccountAddress.SchemaValue>,
+    approval_threshold: number,
+    eg_version: string,
+    display_order: Array<number>,
+    decryption_grace_seconds: bigint,
+    threshold: number,
+    contest_sizes: Array<number>,
+    locked: boolean,
+    }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FAAUAAAADQAAAGFkbWluX2FjY291bnQLCgAAAGNhbmRpZGF0ZXMQAhQAAgAAAAMAAAB1cmwWAgQAAABoYXNoHiAAAAARAAAAZ3VhcmRpYW5fYWNjb3VudHMQAgsPAAAAZWxpZ2libGVfdm90ZXJzFAACAAAACgAAAHBhcmFtZXRlcnMUAAIAAAAKAAAAc3RhcnRfdGltZQ0IAAAAZW5kX3RpbWUNBAAAAGRhdGEUAAIAAAADAAAAdXJsFgIEAAAAaGFzaB4gAAAAEQAAAGVsZWN0aW9uX21hbmlmZXN0FAACAAAAAwAAAHVybBYCBAAAAGhhc2geIAAAABMAAABlbGVjdGlvbl9wYXJhbWV0ZXJzFAACAAAAAwAAAHVybBYCBAAAAGhhc2geIAAAABQAAABlbGVjdGlvbl9kZXNjcmlwdGlvbhYCDgAAAGVsZWN0aW9uX3N0YXJ0DQwAAABlbGVjdGlvbl9lbmQNEwAAAGRlY3J5cHRpb25fZGVhZGxpbmUNEQAAAGRlbGVnYXRpb25fc3RyaW5nFgIXAAAAcmVzdWx0X3Bvc3RpbmdfZGVhZGxpbmUVAgAAAAQAAABOb25lAgQAAABTb21lAQEAAAANEAAAAHJlc3VsdF9hcHByb3ZlcnMQAgsSAAAAYXBwcm92YWxfdGhyZXNob2xkBAoAAABlZ192ZXJzaW9uFgINAAAAZGlzcGxheV9vcmRlchACBBgAAABkZWNyeXB0aW9uX2dyYWNlX3NlY29uZHMFCQAAAHRocmVzaG9sZAQNAAAAY29udGVzdF9zaXplcxACBAYAAABsb2NrZWQB');
+    const field448 = schemaJson.admin_account;
+    const accountAddress449 = SDK.AccountAddress.fromSchemaValue(field448);
+    const field450 = schemaJson.candidates;
+    const list451 = field450.map((item452) => {
+    const field453 = item452.url;
+    const field454 = item452.hash;
+    const named455 = {
+    url: field453,
+    hash: field454,
+    };
+    return named455;
+    });
+    const field456 = schemaJson.guardian_accounts;
+    const list457 = field456.map((item458) => {
+    const accountAddress459 = SDK.AccountAddress.fromSchemaValue(item458);
+    return accountAddress459;
+    });
+    const field460 = schemaJson.eligible_voters;
+    const field461 = field460.parameters;
+    const field462 = field461.start_time;
+    const timestamp463 = SDK.Timestamp.fromSchemaValue(field462);
+    const field464 = field461.end_time;
+    const timestamp465 = SDK.Timestamp.fromSchemaValue(field464);
+    const named466 = {
+    start_time: timestamp463,
+    end_time: timestamp465,
+    };
+    const field467 = field460.data;
+    const field468 = field467.url;
+    const field469 = field467.hash;
+    const named470 = {
+    url: field468,
+    hash: field469,
+    };
+    const named471 = {
+    parameters: named466,
+    data: named470,
+    };
+    const field472 = schemaJson.election_manifest;
+    const field473 = field472.url;
+    const field474 = field472.hash;
+    const named475 = {
+    url: field473,
+    hash: field474,
+    };
+    const field476 = schemaJson.election_parameters;
+    const field477 = field476.url;
+    const field478 = field476.hash;
+    const named479 = {
+    url: field477,
+    hash: field478,
+    };
+    const field480 = schemaJson.election_description;
+    const field481 = schemaJson.election_start;
+    const timestamp482 = SDK.Timestamp.fromSchemaValue(field481);
+    const field483 = schemaJson.election_end;
+    const timestamp484 = SDK.Timestamp.fromSchemaValue(field483);
+    const field485 = schemaJson.decryption_deadline;
+    const timestamp486 = SDK.Timestamp.fromSchemaValue(field485);
+    const field487 = schemaJson.delegation_string;
+    const field488 = schemaJson.result_posting_deadline;
+    let match489: { type: 'None'} | { type: 'Some', content: SDK.Timestamp.Type };
+    if ('None' in field488) {
+       match489 = {
+           type: 'None',
+       };
+    } else if ('Some' in field488) {
+       const variant491 = field488.Some;
+    const timestamp492 = SDK.Timestamp.fromSchemaValue(variant491[0]);
+       match489 = {
+           type: 'Some',
+           content: timestamp492,
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    const field493 = schemaJson.result_approvers;
+    const list494 = field493.map((item495) => {
+    const accountAddress496 = SDK.AccountAddress.fromSchemaValue(item495);
+    return accountAddress496;
+    });
+    const field497 = schemaJson.approval_threshold;
+    const field498 = schemaJson.eg_version;
+    const field499 = schemaJson.display_order;
+    const field502 = schemaJson.decryption_grace_seconds;
+    const field503 = schemaJson.threshold;
+    const field504 = schemaJson.contest_sizes;
+    const field507 = schemaJson.locked;
+    const named508 = {
+    admin_account: accountAddress449,
+    candidates: list451,
+    guardian_accounts: list457,
+    eligible_voters: named471,
+    election_manifest: named475,
+    election_parameters: named479,
+    election_description: field480,
+    election_start: timestamp482,
+    election_end: timestamp484,
+    decryption_deadline: timestamp486,
+    delegation_string: field487,
+    result_posting_deadline: match489,
+    result_approvers: list494,
+    approval_threshold: field497,
+    eg_version: field498,
+    display_order: field499,
+    decryption_grace_seconds: field502,
+    threshold: field503,
+    contest_sizes: field504,
+    locked: field507,
+    };
+    return named508;
+}
+
+/** Parameter type for update transaction for 'viewElectionResult' entrypoint of the 'election' contract. */
+export type ViewElectionResultParameter = SDK.Parameter.Type;
+
+/**
+ * Construct Parameter for update transactions for 'viewElectionResult' entrypoint of the 'election' contract.
+ * @param {ViewElectionResultParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createViewElectionResultParameter(parameter: ViewElectionResultParameter): SDK.Parameter.Type {
+    return parameter;
+}
+
+/**
+ * Send an update-contract transaction to the 'viewElectionResult' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {ViewElectionResultParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendViewElectionResult(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewElectionResultParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('viewElectionResult'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createViewElectionResultParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'viewElectionResult' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {ViewElectionResultParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunViewElectionResult(contractClient: ElectionContract, parameter: ViewElectionResultParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('viewElectionResult'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createViewElectionResultParameter(parameter),
+        blockHash
+    );
+}
+
+/** Return value for dry-running update transaction for 'viewElectionResult' entrypoint of the 'election' contract. */
+export type ReturnValueViewElectionResult = { type: 'None'} | { type: 'Some', content: Array<{
+    candidate: {
+    url: string,
+    hash: SDK.HexString,
+    },
+    cummulative_votes: number | bigint,
+    }> };
+
+/**
+ * Get and parse the return value from dry-running update transaction for 'viewElectionResult' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not successful.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ReturnValueViewElectionResult | undefined} The structured return value or undefined if result was not a success.
+ */
+export function parseReturnValueViewElectionResult(invokeResult: SDK.InvokeContractResult): ReturnValueViewElectionResult | undefined {
+    if (invokeResult.tag !== 'success') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'None' : [] } | {'Some' : [Array<{
+    candidate: {
+    url: string,
+    hash: string,
+    },
+    cummulative_votes: bigint,
+    }>] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAEAIUAAIAAAAJAAAAY2FuZGlkYXRlFAACAAAAAwAAAHVybBYCBAAAAGhhc2geIAAAABEAAABjdW1tdWxhdGl2ZV92b3RlcwU=');
+    let match509: { type: 'None'} | { type: 'Some', content: Array<{
+    candidate: {
+    url: string,
+    hash: SDK.HexString,
+    },
+    cummulative_votes: number | bigint,
+    }> };
+    if ('None' in schemaJson) {
+       match509 = {
+           type: 'None',
+       };
+    } else if ('Some' in schemaJson) {
+       const variant511 = schemaJson.Some;
+    const list512 = variant511[0].map((item513) => {
+    const field514 = item513.candidate;
+    const field515 = field514.url;
+    const field516 = field514.hash;
+    const named517 = {
+    url: field515,
+    hash: field516,
+    };
+    const field518 = item513.cummulative_votes;
+    const named519 = {
+    candidate: named517,
+    cummulative_votes: field518,
+    };
+    return named519;
+    });
+       match509 = {
+           type: 'Some',
+           content: list512,
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match509;
+}
+
+/** Error message for dry-running update transaction for 'viewElectionResult' entrypoint of the 'election' contract. */
+export type ErrorMessageViewElectionResult = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+
+/**
+ * Get and parse the error message from dry-running update transaction for 'viewElectionResult' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not a failure.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ErrorMessageViewElectionResult | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ */
+export function parseErrorMessageViewElectionResult(invokeResult: SDK.InvokeContractResult): ErrorMessageViewElectionResult | undefined {
+    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match520: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+    if ('ParseParams' in schemaJson) {
+       match520 = {
+           type: 'ParseParams',
+       };
+    } else if ('Unauthorized' in schemaJson) {
+       match520 = {
+           type: 'Unauthorized',
+       };
+    } else if ('Malformed' in schemaJson) {
+       match520 = {
+           type: 'Malformed',
+       };
+    } else if ('IncorrectElectionPhase' in schemaJson) {
+       match520 = {
+           type: 'IncorrectElectionPhase',
+       };
+    } else if ('DuplicateEntry' in schemaJson) {
+       match520 = {
+           type: 'DuplicateEntry',
+       };
+    } else if ('GuardianExcluded' in schemaJson) {
+       match520 = {
+           type: 'GuardianExcluded',
+       };
+    } else if ('Log' in schemaJson) {
+       match520 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match520 = {
+           type: 'ElectionLocked',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match520
+}
+
+/** Parameter type for update transaction for 'viewContestResults' entrypoint of the 'election' contract. */
+export type ViewContestResultsParameter = SDK.Parameter.Type;
+
+/**
+ * Construct Parameter for update transactions for 'viewContestResults' entrypoint of the 'election' contract.
+ * @param {ViewContestResultsParameter} parameter The structured parameter to construct from.
+ * @returns {SDK.Parameter.Type} The smart contract parameter.
+ */
+export function createViewContestResultsParameter(parameter: ViewContestResultsParameter): SDK.Parameter.Type {
+    return parameter;
+}
+
+/**
+ * Send an update-contract transaction to the 'viewContestResults' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
+ * @param {ViewContestResultsParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
+ * @throws If the entrypoint is not successfully invoked.
+ * @returns {SDK.TransactionHash.Type} Hash of the transaction.
+ */
+export function sendViewContestResults(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewContestResultsParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+    return contractClient.genericContract.createAndSendUpdateTransaction(
+        SDK.EntrypointName.fromStringUnchecked('viewContestResults'),
+        SDK.Parameter.toBuffer,
+        transactionMetadata,
+        createViewContestResultsParameter(parameter),
+        signer
+    );
+}
+
+/**
+ * Dry-run an update-contract transaction to the 'viewContestResults' entrypoint of the 'election' contract.
+ * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
+ * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
+ * @param {ViewContestResultsParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
+ * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
+ * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
+ */
+export function dryRunViewContestResults(contractClient: ElectionContract, parameter: ViewContestResultsParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+    return contractClient.genericContract.dryRun.invokeMethod(
+        SDK.EntrypointName.fromStringUnchecked('viewContestResults'),
+        invokeMetadata,
+        SDK.Parameter.toBuffer,
+        createViewContestResultsParameter(parameter),
+        blockHash
+    );
+}
+
+/** Return value for dry-running update transaction for 'viewContestResults' entrypoint of the 'election' contract. */
+export type ReturnValueViewContestResults = { type: 'None'} | { type: 'Some', content: Array<{
+    contest_id: number,
+    candidates: Array<{
+    candidate: {
+    url: string,
+    hash: SDK.HexString,
+    },
+    cummulative_votes: number | bigint,
+    }>,
+    }> };
+
+/**
+ * Get and parse the return value from dry-running update transaction for 'viewContestResults' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not successful.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ReturnValueViewContestResults | undefined} The structured return value or undefined if result was not a success.
+ */
+export function parseReturnValueViewContestResults(invokeResult: SDK.InvokeContractResult): ReturnValueViewContestResults | undefined {
+    if (invokeResult.tag !== 'success') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'None' : [] } | {'Some' : [Array<{
+    contest_id: number,
+    candidates: Array<{
+    candidate: {
+    url: string,
+    hash: string,
+    },
+    cummulative_votes: bigint,
+    }>,
+    }>] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAEAIUAAIAAAAKAAAAY29udGVzdF9pZAQKAAAAY2FuZGlkYXRlcxACFAACAAAACQAAAGNhbmRpZGF0ZRQAAgAAAAMAAAB1cmwWAgQAAABoYXNoHiAAAAARAAAAY3VtbXVsYXRpdmVfdm90ZXMF');
+    let match529: { type: 'None'} | { type: 'Some', content: Array<{
+    contest_id: number,
+    candidates: Array<{
+    candidate: {
+    url: string,
+    hash: SDK.HexString,
+    },
+    cummulative_votes: number | bigint,
+    }>,
+    }> };
+    if ('None' in schemaJson) {
+       match529 = {
+           type: 'None',
+       };
+    } else if ('Some' in schemaJson) {
+       const variant531 = schemaJson.Some;
+    const list532 = variant531[0].map((item533) => {
+    const field534 = item533.contest_id;
+    const field535 = item533.candidates;
+    const list536 = field535.map((item537) => {
+    const field538 = item537.candidate;
+    const field539 = field538.url;
+    const field540 = field538.hash;
+    const named541 = {
+    url: field539,
+    hash: field540,
+    };
+    const field542 = item537.cummulative_votes;
+    const named543 = {
+    candidate: named541,
+    cummulative_votes: field542,
+    };
+    return named543;
+    });
+    const named544 = {
+    contest_id: field534,
+    candidates: list536,
+    };
+    return named544;
+    });
+       match529 = {
+           type: 'Some',
+           content: list532,
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match529;
+}
+
+/** Error message for dry-running update transaction for 'viewContestResults' entrypoint of the 'election' contract. */
+export type ErrorMessageViewContestResults = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
 
 /**
- * Get and parse the error message from dry-running update transaction for 'resetFinalizationPhase' entrypoint of the 'election' contract.
+ * Get and parse the error message from dry-running update transaction for 'viewContestResults' entrypoint of the 'election' contract.
  * Returns undefined if the result is not a failure.
  * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
- * @returns {ErrorMessageResetFinalizationPhase | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ * @returns {ErrorMessageViewContestResults | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
  */
-export function parseErrorMessageResetFinalizationPhase(invokeResult: SDK.InvokeContractResult): ErrorMessageResetFinalizationPhase | undefined {
+export function parseErrorMessageViewContestResults(invokeResult: SDK.InvokeContractResult): ErrorMessageViewContestResults | undefined {
     if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
         return undefined;
     }
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQYAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAI=');
-    let match167: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match545: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
     if ('ParseParams' in schemaJson) {
-       match167 = {
+       match545 = {
            type: 'ParseParams',
        };
     } else if ('Unauthorized' in schemaJson) {
-       match167 = {
+       match545 = {
            type: 'Unauthorized',
        };
     } else if ('Malformed' in schemaJson) {
-       match167 = {
+       match545 = {
            type: 'Malformed',
        };
     } else if ('IncorrectElectionPhase' in schemaJson) {
-       match167 = {
+       match545 = {
            type: 'IncorrectElectionPhase',
        };
     } else if ('DuplicateEntry' in schemaJson) {
-       match167 = {
+       match545 = {
            type: 'DuplicateEntry',
        };
     } else if ('GuardianExcluded' in schemaJson) {
-       match167 = {
+       match545 = {
            type: 'GuardianExcluded',
        };
+    } else if ('Log' in schemaJson) {
+       match545 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match545 = {
+           type: 'ElectionLocked',
+       };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    return match167
+    return match545
 }
 
-/** Parameter type for update transaction for 'viewConfig' entrypoint of the 'election' contract. */
-export type ViewConfigParameter = SDK.Parameter.Type;
+/** Parameter type for update transaction for 'viewEncryptedTally' entrypoint of the 'election' contract. */
+export type ViewEncryptedTallyParameter = SDK.Parameter.Type;
 
 /**
- * Construct Parameter for update transactions for 'viewConfig' entrypoint of the 'election' contract.
- * @param {ViewConfigParameter} parameter The structured parameter to construct from.
+ * Construct Parameter for update transactions for 'viewEncryptedTally' entrypoint of the 'election' contract.
+ * @param {ViewEncryptedTallyParameter} parameter The structured parameter to construct from.
  * @returns {SDK.Parameter.Type} The smart contract parameter.
  */
-export function createViewConfigParameter(parameter: ViewConfigParameter): SDK.Parameter.Type {
+export function createViewEncryptedTallyParameter(parameter: ViewEncryptedTallyParameter): SDK.Parameter.Type {
     return parameter;
 }
 
 /**
- * Send an update-contract transaction to the 'viewConfig' entrypoint of the 'election' contract.
+ * Send an update-contract transaction to the 'viewEncryptedTally' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
- * @param {ViewConfigParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewEncryptedTallyParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
  * @throws If the entrypoint is not successfully invoked.
  * @returns {SDK.TransactionHash.Type} Hash of the transaction.
  */
-export function sendViewConfig(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewConfigParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+export function sendViewEncryptedTally(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewEncryptedTallyParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
     return contractClient.genericContract.createAndSendUpdateTransaction(
-        SDK.EntrypointName.fromStringUnchecked('viewConfig'),
+        SDK.EntrypointName.fromStringUnchecked('viewEncryptedTally'),
         SDK.Parameter.toBuffer,
         transactionMetadata,
-        createViewConfigParameter(parameter),
+        createViewEncryptedTallyParameter(parameter),
         signer
     );
 }
 
 /**
- * Dry-run an update-contract transaction to the 'viewConfig' entrypoint of the 'election' contract.
+ * Dry-run an update-contract transaction to the 'viewEncryptedTally' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
- * @param {ViewConfigParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewEncryptedTallyParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
  * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
  * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
  */
-export function dryRunViewConfig(contractClient: ElectionContract, parameter: ViewConfigParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+export function dryRunViewEncryptedTally(contractClient: ElectionContract, parameter: ViewEncryptedTallyParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
     return contractClient.genericContract.dryRun.invokeMethod(
-        SDK.EntrypointName.fromStringUnchecked('viewConfig'),
+        SDK.EntrypointName.fromStringUnchecked('viewEncryptedTally'),
         invokeMetadata,
         SDK.Parameter.toBuffer,
-        createViewConfigParameter(parameter),
+        createViewEncryptedTallyParameter(parameter),
         blockHash
     );
 }
 
-/** Return value for dry-running update transaction for 'viewConfig' entrypoint of the 'election' contract. */
-export type ReturnValueViewConfig = {
-    admin_account: SDK.AccountAddress.Type,
-    candidates: Array<{
-    url: string,
-    hash: SDK.HexString,
-    }>,
-    guardian_accounts: Array<SDK.AccountAddress.Type>,
-    eligible_voters: {
-    parameters: {
-    start_time: SDK.Timestamp.Type,
-    end_time: SDK.Timestamp.Type,
-    },
-    data: {
-    url: string,
-    hash: SDK.HexString,
-    },
-    },
-    election_manifest: {
-    url: string,
-    hash: SDK.HexString,
-    },
-    election_parameters: {
-    url: string,
-    hash: SDK.HexString,
-    },
-    election_description: string,
-    election_start: SDK.Timestamp.Type,
-    election_end: SDK.Timestamp.Type,
-    decryption_deadline: SDK.Timestamp.Type,
-    delegation_string: string,
-    };
+/** Return value for dry-running update transaction for 'viewEncryptedTally' entrypoint of the 'election' contract. */
+export type ReturnValueViewEncryptedTally = { type: 'None'} | { type: 'Some', content: Array<number> };
 
 /**
- * Get and parse the return value from dry-running update transaction for 'viewConfig' entrypoint of the 'election' contract.
+ * Get and parse the return value from dry-running update transaction for 'viewEncryptedTally' entrypoint of the 'election' contract.
  * Returns undefined if the result is not successful.
  * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
- * @returns {ReturnValueViewConfig | undefined} The structured return value or undefined if result was not a success.
+ * @returns {ReturnValueViewEncryptedTally | undefined} The structured return value or undefined if result was not a success.
  */
-export function parseReturnValueViewConfig(invokeResult: SDK.InvokeContractResult): ReturnValueViewConfig | undefined {
+export function parseReturnValueViewEncryptedTally(invokeResult: SDK.InvokeContractResult): ReturnValueViewEncryptedTally | undefined {
     if (invokeResult.tag !== 'success') {
         return undefined;
     }
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{
-    admin_account: SDK.AccountAddress.SchemaValue,
-    candidates: Array<{
-    url: string,
-    hash: string,
-    }>,
-    guardian_accounts: Array<SDK.AccountAddress.SchemaValue>,
-    eligible_voters: {
-    parameters: {
-    start_time: SDK.Timestamp.SchemaValue,
-    end_time: SDK.Timestamp.SchemaValue,
-    },
-    data: {
-    url: string,
-    hash: string,
-    },
-    },
-    election_manifest: {
-    url: string,
-    hash: string,
-    },
-    election_parameters: {
-    url: string,
-    hash: string,
-    },
-    election_description: string,
-    election_start: SDK.Timestamp.SchemaValue,
-    election_end: SDK.Timestamp.SchemaValue,
-    decryption_deadline: SDK.Timestamp.SchemaValue,
-    delegation_string: string,
-    }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FAALAAAADQAAAGFkbWluX2FjY291bnQLCgAAAGNhbmRpZGF0ZXMQAhQAAgAAAAMAAAB1cmwWAgQAAABoYXNoHiAAAAARAAAAZ3VhcmRpYW5fYWNjb3VudHMQAgsPAAAAZWxpZ2libGVfdm90ZXJzFAACAAAACgAAAHBhcmFtZXRlcnMUAAIAAAAKAAAAc3RhcnRfdGltZQ0IAAAAZW5kX3RpbWUNBAAAAGRhdGEUAAIAAAADAAAAdXJsFgIEAAAAaGFzaB4gAAAAEQAAAGVsZWN0aW9uX21hbmlmZXN0FAACAAAAAwAAAHVybBYCBAAAAGhhc2geIAAAABMAAABlbGVjdGlvbl9wYXJhbWV0ZXJzFAACAAAAAwAAAHVybBYCBAAAAGhhc2geIAAAABQAAABlbGVjdGlvbl9kZXNjcmlwdGlvbhYCDgAAAGVsZWN0aW9uX3N0YXJ0DQwAAABlbGVjdGlvbl9lbmQNEwAAAGRlY3J5cHRpb25fZGVhZGxpbmUNEQAAAGRlbGVnYXRpb25fc3RyaW5nFgI=');
-    const field174 = schemaJson.admin_account;
-    const accountAddress175 = SDK.AccountAddress.fromSchemaValue(field174);
-    const field176 = schemaJson.candidates;
-    const list177 = field176.map((item178) => {
-    const field179 = item178.url;
-    const field180 = item178.hash;
-    const named181 = {
-    url: field179,
-    hash: field180,
-    };
-    return named181;
-    });
-    const field182 = schemaJson.guardian_accounts;
-    const list183 = field182.map((item184) => {
-    const accountAddress185 = SDK.AccountAddress.fromSchemaValue(item184);
-    return accountAddress185;
-    });
-    const field186 = schemaJson.eligible_voters;
-    const field187 = field186.parameters;
-    const field188 = field187.start_time;
-    const timestamp189 = SDK.Timestamp.fromSchemaValue(field188);
-    const field190 = field187.end_time;
-    const timestamp191 = SDK.Timestamp.fromSchemaValue(field190);
-    const named192 = {
-    start_time: timestamp189,
-    end_time: timestamp191,
-    };
-    const field193 = field186.data;
-    const field194 = field193.url;
-    const field195 = field193.hash;
-    const named196 = {
-    url: field194,
-    hash: field195,
-    };
-    const named197 = {
-    parameters: named192,
-    data: named196,
-    };
-    const field198 = schemaJson.election_manifest;
-    const field199 = field198.url;
-    const field200 = field198.hash;
-    const named201 = {
-    url: field199,
-    hash: field200,
-    };
-    const field202 = schemaJson.election_parameters;
-    const field203 = field202.url;
-    const field204 = field202.hash;
-    const named205 = {
-    url: field203,
-    hash: field204,
-    };
-    const field206 = schemaJson.election_description;
-    const field207 = schemaJson.election_start;
-    const timestamp208 = SDK.Timestamp.fromSchemaValue(field207);
-    const field209 = schemaJson.election_end;
-    const timestamp210 = SDK.Timestamp.fromSchemaValue(field209);
-    const field211 = schemaJson.decryption_deadline;
-    const timestamp212 = SDK.Timestamp.fromSchemaValue(field211);
-    const field213 = schemaJson.delegation_string;
-    const named214 = {
-    admin_account: accountAddress175,
-    candidates: list177,
-    guardian_accounts: list183,
-    eligible_voters: named197,
-    election_manifest: named201,
-    election_parameters: named205,
-    election_description: field206,
-    election_start: timestamp208,
-    election_end: timestamp210,
-    decryption_deadline: timestamp212,
-    delegation_string: field213,
-    };
-    return named214;
+    const schemaJson = <{'None' : [] } | {'Some' : [Array<number>] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAEAIC');
+    let match554: { type: 'None'} | { type: 'Some', content: Array<number> };
+    if ('None' in schemaJson) {
+       match554 = {
+           type: 'None',
+       };
+    } else if ('Some' in schemaJson) {
+       const variant556 = schemaJson.Some;
+       match554 = {
+           type: 'Some',
+           content: variant556[0],
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match554;
 }
 
-/** Parameter type for update transaction for 'viewElectionResult' entrypoint of the 'election' contract. */
-export type ViewElectionResultParameter = SDK.Parameter.Type;
+/** Error message for dry-running update transaction for 'viewEncryptedTally' entrypoint of the 'election' contract. */
+export type ErrorMessageViewEncryptedTally = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
 
 /**
- * Construct Parameter for update transactions for 'viewElectionResult' entrypoint of the 'election' contract.
- * @param {ViewElectionResultParameter} parameter The structured parameter to construct from.
+ * Get and parse the error message from dry-running update transaction for 'viewEncryptedTally' entrypoint of the 'election' contract.
+ * Returns undefined if the result is not a failure.
+ * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
+ * @returns {ErrorMessageViewEncryptedTally | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ */
+export function parseErrorMessageViewEncryptedTally(invokeResult: SDK.InvokeContractResult): ErrorMessageViewEncryptedTally | undefined {
+    if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
+        return undefined;
+    }
+    if (invokeResult.returnValue === undefined) {
+        throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
+    }
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match559: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
+    if ('ParseParams' in schemaJson) {
+       match559 = {
+           type: 'ParseParams',
+       };
+    } else if ('Unauthorized' in schemaJson) {
+       match559 = {
+           type: 'Unauthorized',
+       };
+    } else if ('Malformed' in schemaJson) {
+       match559 = {
+           type: 'Malformed',
+       };
+    } else if ('IncorrectElectionPhase' in schemaJson) {
+       match559 = {
+           type: 'IncorrectElectionPhase',
+       };
+    } else if ('DuplicateEntry' in schemaJson) {
+       match559 = {
+           type: 'DuplicateEntry',
+       };
+    } else if ('GuardianExcluded' in schemaJson) {
+       match559 = {
+           type: 'GuardianExcluded',
+       };
+    } else if ('Log' in schemaJson) {
+       match559 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match559 = {
+           type: 'ElectionLocked',
+       };
+    }
+     else {
+       throw new Error("Unexpected enum variant");
+    }
+    return match559
+}
+
+/** Parameter type for update transaction for 'viewEncryptedTallyHash' entrypoint of the 'election' contract. */
+export type ViewEncryptedTallyHashParameter = SDK.Parameter.Type;
+
+/**
+ * Construct Parameter for update transactions for 'viewEncryptedTallyHash' entrypoint of the 'election' contract.
+ * @param {ViewEncryptedTallyHashParameter} parameter The structured parameter to construct from.
  * @returns {SDK.Parameter.Type} The smart contract parameter.
  */
-export function createViewElectionResultParameter(parameter: ViewElectionResultParameter): SDK.Parameter.Type {
+export function createViewEncryptedTallyHashParameter(parameter: ViewEncryptedTallyHashParameter): SDK.Parameter.Type {
     return parameter;
 }
 
 /**
- * Send an update-contract transaction to the 'viewElectionResult' entrypoint of the 'election' contract.
+ * Send an update-contract transaction to the 'viewEncryptedTallyHash' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
- * @param {ViewElectionResultParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewEncryptedTallyHashParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
  * @throws If the entrypoint is not successfully invoked.
  * @returns {SDK.TransactionHash.Type} Hash of the transaction.
  */
-export function sendViewElectionResult(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewElectionResultParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+export function sendViewEncryptedTallyHash(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewEncryptedTallyHashParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
     return contractClient.genericContract.createAndSendUpdateTransaction(
-        SDK.EntrypointName.fromStringUnchecked('viewElectionResult'),
+        SDK.EntrypointName.fromStringUnchecked('viewEncryptedTallyHash'),
         SDK.Parameter.toBuffer,
         transactionMetadata,
-        createViewElectionResultParameter(parameter),
+        createViewEncryptedTallyHashParameter(parameter),
         signer
     );
 }
 
 /**
- * Dry-run an update-contract transaction to the 'viewElectionResult' entrypoint of the 'election' contract.
+ * Dry-run an update-contract transaction to the 'viewEncryptedTallyHash' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
- * @param {ViewElectionResultParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewEncryptedTallyHashParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
  * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
  * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
  */
-export function dryRunViewElectionResult(contractClient: ElectionContract, parameter: ViewElectionResultParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+export function dryRunViewEncryptedTallyHash(contractClient: ElectionContract, parameter: ViewEncryptedTallyHashParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
     return contractClient.genericContract.dryRun.invokeMethod(
-        SDK.EntrypointName.fromStringUnchecked('viewElectionResult'),
+        SDK.EntrypointName.fromStringUnchecked('viewEncryptedTallyHash'),
         invokeMetadata,
         SDK.Parameter.toBuffer,
-        createViewElectionResultParameter(parameter),
+        createViewEncryptedTallyHashParameter(parameter),
         blockHash
     );
 }
 
-/** Return value for dry-running update transaction for 'viewElectionResult' entrypoint of the 'election' contract. */
-export type ReturnValueViewElectionResult = { type: 'None'} | { type: 'Some', content: Array<{
-    candidate: {
-    url: string,
-    hash: SDK.HexString,
-    },
-    cummulative_votes: number | bigint,
-    }> };
+/** Return value for dry-running update transaction for 'viewEncryptedTallyHash' entrypoint of the 'election' contract. */
+export type ReturnValueViewEncryptedTallyHash = { type: 'None'} | { type: 'Some', content: SDK.HexString };
 
 /**
- * Get and parse the return value from dry-running update transaction for 'viewElectionResult' entrypoint of the 'election' contract.
+ * Get and parse the return value from dry-running update transaction for 'viewEncryptedTallyHash' entrypoint of the 'election' contract.
  * Returns undefined if the result is not successful.
  * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
- * @returns {ReturnValueViewElectionResult | undefined} The structured return value or undefined if result was not a success.
+ * @returns {ReturnValueViewEncryptedTallyHash | undefined} The structured return value or undefined if result was not a success.
  */
-export function parseReturnValueViewElectionResult(invokeResult: SDK.InvokeContractResult): ReturnValueViewElectionResult | undefined {
+export function parseReturnValueViewEncryptedTallyHash(invokeResult: SDK.InvokeContractResult): ReturnValueViewEncryptedTallyHash | undefined {
     if (invokeResult.tag !== 'success') {
         return undefined;
     }
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{'None' : [] } | {'Some' : [Array<{
-    candidate: {
-    url: string,
-    hash: string,
-    },
-    cummulative_votes: bigint,
-    }>] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAEAIUAAIAAAAJAAAAY2FuZGlkYXRlFAACAAAAAwAAAHVybBYCBAAAAGhhc2geIAAAABEAAABjdW1tdWxhdGl2ZV92b3RlcwU=');
-    let match215: { type: 'None'} | { type: 'Some', content: Array<{
-    candidate: {
-    url: string,
-    hash: SDK.HexString,
-    },
-    cummulative_votes: number | bigint,
-    }> };
+    const schemaJson = <{'None' : [] } | {'Some' : [string] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAHiAAAAA=');
+    let match568: { type: 'None'} | { type: 'Some', content: SDK.HexString };
     if ('None' in schemaJson) {
-       match215 = {
+       match568 = {
            type: 'None',
        };
     } else if ('Some' in schemaJson) {
-       const variant217 = schemaJson.Some;
-    const list218 = variant217[0].map((item219) => {
-    const field220 = item219.candidate;
-    const field221 = field220.url;
-    const field222 = field220.hash;
-    const named223 = {
-    url: field221,
-    hash: field222,
-    };
-    const field224 = item219.cummulative_votes;
-    const named225 = {
-    candidate: named223,
-    cummulative_votes: field224,
-    };
-    return named225;
-    });
-       match215 = {
+       const variant570 = schemaJson.Some;
+       match568 = {
            type: 'Some',
-           content: list218,
+           content: variant570[0],
        };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    return match215;
+    return match568;
 }
 
-/** Error message for dry-running update transaction for 'viewElectionResult' entrypoint of the 'election' contract. */
-export type ErrorMessageViewElectionResult = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+/** Error message for dry-running update transaction for 'viewEncryptedTallyHash' entrypoint of the 'election' contract. */
+export type ErrorMessageViewEncryptedTallyHash = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
 
 /**
- * Get and parse the error message from dry-running update transaction for 'viewElectionResult' entrypoint of the 'election' contract.
+ * Get and parse the error message from dry-running update transaction for 'viewEncryptedTallyHash' entrypoint of the 'election' contract.
  * Returns undefined if the result is not a failure.
  * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
- * @returns {ErrorMessageViewElectionResult | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ * @returns {ErrorMessageViewEncryptedTallyHash | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
  */
-export function parseErrorMessageViewElectionResult(invokeResult: SDK.InvokeContractResult): ErrorMessageViewElectionResult | undefined {
+export function parseErrorMessageViewEncryptedTallyHash(invokeResult: SDK.InvokeContractResult): ErrorMessageViewEncryptedTallyHash | undefined {
     if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
         return undefined;
     }
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQYAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAI=');
-    let match226: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match571: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
     if ('ParseParams' in schemaJson) {
-       match226 = {
+       match571 = {
            type: 'ParseParams',
        };
     } else if ('Unauthorized' in schemaJson) {
-       match226 = {
+       match571 = {
            type: 'Unauthorized',
        };
     } else if ('Malformed' in schemaJson) {
-       match226 = {
+       match571 = {
            type: 'Malformed',
        };
     } else if ('IncorrectElectionPhase' in schemaJson) {
-       match226 = {
+       match571 = {
            type: 'IncorrectElectionPhase',
        };
     } else if ('DuplicateEntry' in schemaJson) {
-       match226 = {
+       match571 = {
            type: 'DuplicateEntry',
        };
     } else if ('GuardianExcluded' in schemaJson) {
-       match226 = {
+       match571 = {
            type: 'GuardianExcluded',
        };
+    } else if ('Log' in schemaJson) {
+       match571 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match571 = {
+           type: 'ElectionLocked',
+       };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    return match226
+    return match571
 }
 
-/** Parameter type for update transaction for 'viewEncryptedTally' entrypoint of the 'election' contract. */
-export type ViewEncryptedTallyParameter = SDK.Parameter.Type;
+/** Parameter type for update transaction for 'viewElectionResultHash' entrypoint of the 'election' contract. */
+export type ViewElectionResultHashParameter = SDK.Parameter.Type;
 
 /**
- * Construct Parameter for update transactions for 'viewEncryptedTally' entrypoint of the 'election' contract.
- * @param {ViewEncryptedTallyParameter} parameter The structured parameter to construct from.
+ * Construct Parameter for update transactions for 'viewElectionResultHash' entrypoint of the 'election' contract.
+ * @param {ViewElectionResultHashParameter} parameter The structured parameter to construct from.
  * @returns {SDK.Parameter.Type} The smart contract parameter.
  */
-export function createViewEncryptedTallyParameter(parameter: ViewEncryptedTallyParameter): SDK.Parameter.Type {
+export function createViewElectionResultHashParameter(parameter: ViewElectionResultHashParameter): SDK.Parameter.Type {
     return parameter;
 }
 
 /**
- * Send an update-contract transaction to the 'viewEncryptedTally' entrypoint of the 'election' contract.
+ * Send an update-contract transaction to the 'viewElectionResultHash' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractTransactionMetadata} transactionMetadata - Metadata related to constructing a transaction for a smart contract.
- * @param {ViewEncryptedTallyParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewElectionResultHashParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.AccountSigner} signer - The signer of the update contract transaction.
  * @throws If the entrypoint is not successfully invoked.
  * @returns {SDK.TransactionHash.Type} Hash of the transaction.
  */
-export function sendViewEncryptedTally(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewEncryptedTallyParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
+export function sendViewElectionResultHash(contractClient: ElectionContract, transactionMetadata: SDK.ContractTransactionMetadata, parameter: ViewElectionResultHashParameter, signer: SDK.AccountSigner): Promise<SDK.TransactionHash.Type> {
     return contractClient.genericContract.createAndSendUpdateTransaction(
-        SDK.EntrypointName.fromStringUnchecked('viewEncryptedTally'),
+        SDK.EntrypointName.fromStringUnchecked('viewElectionResultHash'),
         SDK.Parameter.toBuffer,
         transactionMetadata,
-        createViewEncryptedTallyParameter(parameter),
+        createViewElectionResultHashParameter(parameter),
         signer
     );
 }
 
 /**
- * Dry-run an update-contract transaction to the 'viewEncryptedTally' entrypoint of the 'election' contract.
+ * Dry-run an update-contract transaction to the 'viewElectionResultHash' entrypoint of the 'election' contract.
  * @param {ElectionContract} contractClient The client for a 'election' smart contract instance on chain.
  * @param {SDK.ContractAddress.Type | SDK.AccountAddress.Type} invokeMetadata - The address of the account or contract which is invoking this transaction.
- * @param {ViewEncryptedTallyParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
+ * @param {ViewElectionResultHashParameter} parameter - Parameter to provide the smart contract entrypoint as part of the transaction.
  * @param {SDK.BlockHash.Type} [blockHash] - Optional block hash allowing for dry-running the transaction at the end of a specific block.
  * @throws {SDK.RpcError} If failing to communicate with the concordium node or if any of the checks fails.
  * @returns {SDK.InvokeContractResult} The result of invoking the smart contract instance.
  */
-export function dryRunViewEncryptedTally(contractClient: ElectionContract, parameter: ViewEncryptedTallyParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
+export function dryRunViewElectionResultHash(contractClient: ElectionContract, parameter: ViewElectionResultHashParameter, invokeMetadata: SDK.ContractInvokeMetadata = {}, blockHash?: SDK.BlockHash.Type): Promise<SDK.InvokeContractResult> {
     return contractClient.genericContract.dryRun.invokeMethod(
-        SDK.EntrypointName.fromStringUnchecked('viewEncryptedTally'),
+        SDK.EntrypointName.fromStringUnchecked('viewElectionResultHash'),
         invokeMetadata,
         SDK.Parameter.toBuffer,
-        createViewEncryptedTallyParameter(parameter),
+        createViewElectionResultHashParameter(parameter),
         blockHash
     );
 }
 
-/** Return value for dry-running update transaction for 'viewEncryptedTally' entrypoint of the 'election' contract. */
-export type ReturnValueViewEncryptedTally = { type: 'None'} | { type: 'Some', content: Array<number> };
+/** Return value for dry-running update transaction for 'viewElectionResultHash' entrypoint of the 'election' contract. */
+export type ReturnValueViewElectionResultHash = { type: 'None'} | { type: 'Some', content: SDK.HexString };
 
 /**
- * Get and parse the return value from dry-running update transaction for 'viewEncryptedTally' entrypoint of the 'election' contract.
+ * Get and parse the return value from dry-running update transaction for 'viewElectionResultHash' entrypoint of the 'election' contract.
  * Returns undefined if the result is not successful.
  * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
- * @returns {ReturnValueViewEncryptedTally | undefined} The structured return value or undefined if result was not a success.
+ * @returns {ReturnValueViewElectionResultHash | undefined} The structured return value or undefined if result was not a success.
  */
-export function parseReturnValueViewEncryptedTally(invokeResult: SDK.InvokeContractResult): ReturnValueViewEncryptedTally | undefined {
+export function parseReturnValueViewElectionResultHash(invokeResult: SDK.InvokeContractResult): ReturnValueViewElectionResultHash | undefined {
     if (invokeResult.tag !== 'success') {
         return undefined;
     }
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{'None' : [] } | {'Some' : [Array<number>] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAEAIC');
-    let match233: { type: 'None'} | { type: 'Some', content: Array<number> };
+    const schemaJson = <{'None' : [] } | {'Some' : [string] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQIAAAAEAAAATm9uZQIEAAAAU29tZQEBAAAAHiAAAAA=');
+    let match580: { type: 'None'} | { type: 'Some', content: SDK.HexString };
     if ('None' in schemaJson) {
-       match233 = {
+       match580 = {
            type: 'None',
        };
     } else if ('Some' in schemaJson) {
-       const variant235 = schemaJson.Some;
-       match233 = {
+       const variant582 = schemaJson.Some;
+       match580 = {
            type: 'Some',
-           content: variant235[0],
+           content: variant582[0],
        };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    return match233;
+    return match580;
 }
 
-/** Error message for dry-running update transaction for 'viewEncryptedTally' entrypoint of the 'election' contract. */
-export type ErrorMessageViewEncryptedTally = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+/** Error message for dry-running update transaction for 'viewElectionResultHash' entrypoint of the 'election' contract. */
+export type ErrorMessageViewElectionResultHash = { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
 
 /**
- * Get and parse the error message from dry-running update transaction for 'viewEncryptedTally' entrypoint of the 'election' contract.
+ * Get and parse the error message from dry-running update transaction for 'viewElectionResultHash' entrypoint of the 'election' contract.
  * Returns undefined if the result is not a failure.
  * @param {SDK.InvokeContractResult} invokeResult The result from dry-running the transaction.
- * @returns {ErrorMessageViewEncryptedTally | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
+ * @returns {ErrorMessageViewElectionResultHash | undefined} The structured error message or undefined if result was not a failure or failed for other reason than contract rejectedReceive.
  */
-export function parseErrorMessageViewEncryptedTally(invokeResult: SDK.InvokeContractResult): ErrorMessageViewEncryptedTally | undefined {
+export function parseErrorMessageViewElectionResultHash(invokeResult: SDK.InvokeContractResult): ErrorMessageViewElectionResultHash | undefined {
     if (invokeResult.tag !== 'failure' || invokeResult.reason.tag !== 'RejectedReceive') {
         return undefined;
     }
     if (invokeResult.returnValue === undefined) {
         throw new Error('Unexpected missing \'returnValue\' in result of invocation. Client expected a V1 smart contract.');
     }
-    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQYAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAI=');
-    let match238: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'};
+    const schemaJson = <{'ParseParams' : [] } | {'Unauthorized' : [] } | {'Malformed' : [] } | {'IncorrectElectionPhase' : [] } | {'DuplicateEntry' : [] } | {'GuardianExcluded' : [] } | {'Log' : [] } | {'ElectionLocked' : [] }>SDK.ReturnValue.parseWithSchemaTypeBase64(invokeResult.returnValue, 'FQgAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIJAAAATWFsZm9ybWVkAhYAAABJbmNvcnJlY3RFbGVjdGlvblBoYXNlAg4AAABEdXBsaWNhdGVFbnRyeQIQAAAAR3VhcmRpYW5FeGNsdWRlZAIDAAAATG9nAg4AAABFbGVjdGlvbkxvY2tlZAI=');
+    let match583: { type: 'ParseParams'} | { type: 'Unauthorized'} | { type: 'Malformed'} | { type: 'IncorrectElectionPhase'} | { type: 'DuplicateEntry'} | { type: 'GuardianExcluded'} | { type: 'Log'} | { type: 'ElectionLocked'};
     if ('ParseParams' in schemaJson) {
-       match238 = {
+       match583 = {
            type: 'ParseParams',
        };
     } else if ('Unauthorized' in schemaJson) {
-       match238 = {
+       match583 = {
            type: 'Unauthorized',
        };
     } else if ('Malformed' in schemaJson) {
-       match238 = {
+       match583 = {
            type: 'Malformed',
        };
     } else if ('IncorrectElectionPhase' in schemaJson) {
-       match238 = {
+       match583 = {
            type: 'IncorrectElectionPhase',
        };
     } else if ('DuplicateEntry' in schemaJson) {
-       match238 = {
+       match583 = {
            type: 'DuplicateEntry',
        };
     } else if ('GuardianExcluded' in schemaJson) {
-       match238 = {
+       match583 = {
            type: 'GuardianExcluded',
        };
+    } else if ('Log' in schemaJson) {
+       match583 = {
+           type: 'Log',
+       };
+    } else if ('ElectionLocked' in schemaJson) {
+       match583 = {
+           type: 'ElectionLocked',
+       };
     }
      else {
        throw new Error("Unexpected enum variant");
     }
-    return match238
+    return match583
 }
@@ -33,7 +33,7 @@ pub struct EligibleVotersParameters {
 #[derive(Serialize, SchemaType, Clone, Debug, PartialEq)]
 #[cfg_attr(
     feature = "serde",
-    derive(serde::Serialize),
+    derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct EligibleVoters {
@@ -58,12 +58,50 @@ impl serde::Serialize for ChecksumUrl {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ChecksumUrl {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>, {
+        use serde::de::Error as _;
+
+        /// A [`ChecksumUrl`] with the hash hex encoded, as serialized.
+        #[derive(serde::Deserialize)]
+        struct HexChecksumUrl {
+            url:  String,
+            hash: String,
+        }
+
+        let HexChecksumUrl { url, hash } = HexChecksumUrl::deserialize(deserializer)?;
+        let mut bytes = [0u8; 32];
+        if hash.len() != 2 * bytes.len() || !hash.is_ascii() {
+            return Err(D::Error::custom("Expected a hex encoded SHA256 hash."));
+        }
+        for (byte, digits) in bytes.iter_mut().zip(hash.as_bytes().chunks(2)) {
+            // The hash is ASCII, so the digits are valid UTF-8.
+            let digits = core::str::from_utf8(digits).map_err(D::Error::custom)?;
+            *byte = u8::from_str_radix(digits, 16).map_err(D::Error::custom)?;
+        }
+        Ok(Self {
+            url,
+            hash: HashSha2256(bytes),
+        })
+    }
+}
+
 /// An amount of weighted votes for a candidate
 pub type CandidateWeightedVotes = u64;
 
+/// Identifies a contest of the election by its zero-based position in
+/// [`InitParameter::contest_sizes`].
+pub type ContestId = u32;
+
 /// A list of weighted votes, where the position in the list identifies the
-/// corresponding [`ChecksumUrl`] in the list of candidates
-pub type ElectionResult = Vec<CandidateWeightedVotes>;
+/// corresponding [`ChecksumUrl`] in the list of candidates of the contest
+pub type ContestResult = Vec<CandidateWeightedVotes>;
+
+/// The results of all contests of the election, ordered by [`ContestId`].
+pub type ElectionResult = Vec<(ContestId, ContestResult)>;
 
 /// Describes errors that can happen during the execution of the contract.
 #[derive(Debug, PartialEq, Eq, Reject, Serialize, SchemaType)]
@@ -81,11 +119,44 @@ pub enum Error {
     DuplicateEntry,
     /// An attempt to participate in finalization phase after being excluded.
     GuardianExcluded,
+    /// Failed to log an event.
+    #[from(LogError)]
+    Log,
+    /// An attempt to change the election after it has been locked.
+    ElectionLocked,
+}
+
+/// The events logged by the contract.
+///
+/// Registered ballots are logged as [`Event::VoteAccepted`] rather than a
+/// separate `VoteRegistered` event, as the ballot hash it carries is the
+/// receipt given to voters. Indexers filtering on registered votes should match
+/// this variant.
+#[derive(Serialize, SchemaType, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Event {
+    /// A ballot was accepted from the account.
+    VoteAccepted {
+        /// The account which registered the ballot.
+        account:     AccountAddress,
+        /// The SHA256 hash of the encoded ballot, i.e. of
+        /// [`RegisterVotesParameter::inner`]. This serves as a receipt for the
+        /// voter.
+        ballot_hash: HashSha2256,
+    },
+    /// A guardian registered its public key.
+    GuardianPublicKeyRegistered {
+        /// The index of the guardian.
+        index: u32,
+    },
+    /// The admin account registered the encrypted tally. This is logged again
+    /// whenever the tally is replaced.
+    EncryptedTallyPosted,
 }
 
 /// The different status options available for guardians.
 #[derive(Serialize, SchemaType, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GuardianStatus {
     /// Guardian could not verify public key(s) of the guardians represented by
     /// the supplied account addresses.
@@ -102,21 +173,32 @@ pub enum GuardianStatus {
 #[derive(Serialize, SchemaType, Clone, Debug, PartialEq)]
 pub struct GuardianState {
     /// Index of the guardian used for key-sharing. Not modifiable.
-    pub index:                  u32,
+    pub index:                     u32,
     /// The public key of the guardian.
-    pub public_key:             Option<Vec<u8>>,
+    pub public_key:                Option<Vec<u8>>,
     /// The encrypted share of the guardian.
-    pub encrypted_share:        Option<Vec<u8>>,
+    pub encrypted_share:           Option<Vec<u8>>,
     /// The share of the decryption together with the
     /// commitment share of a single guardian for a `DecryptionProof`.
-    pub decryption_share:       Option<Vec<u8>>,
+    pub decryption_share:          Option<Vec<u8>>,
+    /// The shares of the decryption registered for individual contests, keyed
+    /// by the zero-based index of the contest, see
+    /// [`PostDecryptionShareForContestParameter::contest_index`]. This is an
+    /// alternative to
+    /// [`GuardianState::decryption_share`] for elections with multiple
+    /// contests, allowing the shares for each contest to be registered
+    /// separately.
+    pub decryption_share_contests: collections::BTreeMap<u32, Vec<u8>>,
     /// The response share of a single guardian for a `DecryptionProof`.
-    pub decryption_share_proof: Option<Vec<u8>>,
+    pub decryption_share_proof:    Option<Vec<u8>>,
     /// The verification status of the guardian, with regards to verifying the
     /// state of other guardians is as expected.
-    pub status:                 Option<GuardianStatus>,
+    pub status:                    Option<GuardianStatus>,
     /// Whether the guardian has been excluded due to incorrect behaviour.
-    pub excluded:               bool,
+    pub excluded:                  bool,
+    /// Whether the guardian has acknowledged the encrypted tally currently
+    /// registered in the contract.
+    pub acknowledged_tally:        bool,
 }
 
 impl GuardianState {
@@ -127,10 +209,19 @@ impl GuardianState {
             encrypted_share: None,
             status: None,
             decryption_share: None,
+            decryption_share_contests: collections::BTreeMap::new(),
             decryption_share_proof: None,
             excluded: false,
+            acknowledged_tally: false,
         }
     }
+
+    /// Whether the guardian has registered decryption shares for all of the
+    /// `contest_count` contests of the election, either as a single share or
+    /// as a share for each contest.
+    pub fn has_complete_decryption_share(&self, contest_count: usize) -> bool {
+        self.decryption_share.is_some() || self.decryption_share_contests.len() == contest_count
+    }
 }
 
 #[derive(Serialize)]
@@ -143,6 +234,12 @@ pub struct RegisteredData {
     pub election_parameters:  ChecksumUrl,
     /// A description of the election, e.g. "Concordium GC election, June 2024".
     pub election_description: String,
+    /// The version of electionguard used by the tooling setting up the
+    /// election.
+    pub eg_version:           String,
+    /// The order in which candidates should be displayed, see
+    /// [`InitParameter::display_order`].
+    pub display_order:        Vec<u32>,
 }
 
 /// The internal state of the contract
@@ -151,31 +248,148 @@ pub struct RegisteredData {
 pub struct State<S: HasStateApi = StateApi> {
     /// The account used to perform administrative functions, such as publishing
     /// the final result of the election.
-    pub admin_account:       StateBox<AccountAddress, S>,
+    pub admin_account:            StateBox<AccountAddress, S>,
     /// A list of candidates - identified by their position in the list - that
     /// voters can vote for in the election.
-    pub candidates:          StateSet<ChecksumUrl, S>,
+    pub candidates:               StateSet<ChecksumUrl, S>,
     /// A unique list of guardian accounts used for the election.
-    pub guardians:           StateMap<AccountAddress, GuardianState, S>,
+    pub guardians:                StateMap<AccountAddress, GuardianState, S>,
     /// Data registered upon contract instantiation which is used by off-chain
     /// applications
-    pub registered_data:     StateBox<RegisteredData, S>,
+    pub registered_data:          StateBox<RegisteredData, S>,
     /// The start time of the election, marking the time from which votes can be
     /// registered.
-    pub election_start:      Timestamp,
+    pub election_start:           Timestamp,
     /// The end time of the election, marking the time at which votes can no
     /// longer be registered.
-    pub election_end:        Timestamp,
+    pub election_end:             Timestamp,
     /// Guardians must add their [`GuardianState::decryption_share`] before this
     /// timestamp for their shares to be included in the decrypted result.
-    pub decryption_deadline: Timestamp,
+    pub decryption_deadline:      Timestamp,
+    /// The number of seconds after `decryption_deadline` during which
+    /// decryption shares are still accepted, see [`State::decryption_cutoff`].
+    pub decryption_grace_seconds: u64,
+    /// The number of guardians needed to decrypt the election result, i.e.
+    /// the threshold `k` of the election parameters.
+    pub threshold:                u32,
+    /// If set, the encrypted tally and the election result can only be posted
+    /// before this timestamp.
+    pub result_posting_deadline:  Option<Timestamp>,
     /// The string that should be used when delegating a vote.
-    pub delegation_string:   StateBox<String, S>,
+    pub delegation_string:        StateBox<String, S>,
     /// The encrypted tally posted by the operator for convenience of guardians.
-    pub encrypted_tally:     StateBox<Option<Vec<u8>>, S>,
+    pub encrypted_tally:          StateBox<Option<Vec<u8>>, S>,
     /// The election result, which will be registered after `election_end` has
     /// passed.
-    pub election_result:     StateBox<Option<ElectionResult>, S>,
+    pub election_result:          StateBox<Option<ElectionResult>, S>,
+    /// Accounts that must approve the election result before it is stored. If
+    /// empty, the result posted by the admin account is stored directly.
+    pub result_approvers:         StateBox<Vec<AccountAddress>, S>,
+    /// The number of approvals from `result_approvers` needed for a proposed
+    /// result to be stored.
+    pub approval_threshold:       u32,
+    /// The election result proposed by the admin account, awaiting approval.
+    pub result_proposal:          StateBox<Option<ResultProposal>, S>,
+    /// The number of candidates of each contest, see
+    /// [`InitParameter::contest_sizes`]. This always contains at least one
+    /// contest.
+    pub contest_sizes:            StateBox<Vec<u32>, S>,
+    /// Whether the election has been locked, see [`lock_election`].
+    pub locked:                   bool,
+}
+
+/// An election result proposed by the admin account, together with the
+/// accounts that have approved it so far.
+#[derive(Serialize, SchemaType, Clone, Debug, PartialEq)]
+pub struct ResultProposal {
+    /// The proposed result.
+    pub result:    ElectionResult,
+    /// The approvers that have approved the proposed result.
+    pub approvals: Vec<AccountAddress>,
+}
+
+/// The maximum number of guardians of an election. Guardians are identified by
+/// a one-based index in electionguard, which must not exceed `2^31 - 1`.
+pub const MAX_GUARDIANS: u32 = (1 << 31) - 1;
+
+/// Checks that `count` guardians can each be assigned a guardian index
+/// supported by electionguard, i.e. that `count` does not exceed
+/// [`MAX_GUARDIANS`].
+pub fn check_guardian_count(count: usize) -> Result<u32, Error> {
+    let count = u32::try_from(count).map_err(|_| Error::Malformed)?;
+    ensure!(count <= MAX_GUARDIANS, Error::Malformed);
+    Ok(count)
+}
+
+/// The maximum length in bytes of the election description if
+/// [`InitParameter::max_description_length`] is not set. The description is
+/// meant to be a short title, which is displayed as such by frontends.
+pub const DEFAULT_MAX_DESCRIPTION_LENGTH: u32 = 256;
+
+/// The largest value [`InitParameter::max_description_length`] can be set to.
+/// This bounds the size the description adds to the `viewConfig` response.
+pub const MAX_DESCRIPTION_LENGTH: u32 = 1024;
+
+/// Checks that `description` is a non-empty title of at most `max_length`
+/// bytes, which does not contain control characters. If `max_length` is not
+/// given, [`DEFAULT_MAX_DESCRIPTION_LENGTH`] is used, and otherwise it must not
+/// exceed [`MAX_DESCRIPTION_LENGTH`].
+pub fn check_election_description(description: &str, max_length: Option<u32>) -> Result<(), Error> {
+    let max_length = max_length.unwrap_or(DEFAULT_MAX_DESCRIPTION_LENGTH);
+    ensure!(max_length <= MAX_DESCRIPTION_LENGTH, Error::Malformed);
+    ensure!(!description.is_empty(), Error::Malformed);
+    ensure!(description.len() <= max_length as usize, Error::Malformed);
+    ensure!(!description.chars().any(char::is_control), Error::Malformed);
+    Ok(())
+}
+
+/// The time until which decryption shares are accepted given the
+/// `decryption_deadline` and the grace period `decryption_grace_seconds`, or
+/// `None` if this overflows.
+fn decryption_cutoff(
+    decryption_deadline: Timestamp,
+    decryption_grace_seconds: u64,
+) -> Option<Timestamp> {
+    decryption_deadline.checked_add(Duration::from_seconds(decryption_grace_seconds))
+}
+
+/// How long after the decryption cutoff guardians can still register the
+/// proofs of their decryption shares. The proofs can only be computed once the
+/// shares of all guardians are known, hence they are accepted for a while
+/// after the shares are no longer accepted.
+pub const DECRYPTION_PROOF_WINDOW: Duration = Duration::from_days(1);
+
+/// Check that `contest_sizes` is either empty or partitions `candidate_count`
+/// candidates into non-empty contests. Returns the contest sizes, where an
+/// empty `contest_sizes` is a single contest of all candidates.
+fn check_contest_sizes(contest_sizes: Vec<u32>, candidate_count: usize) -> Result<Vec<u32>, Error> {
+    if contest_sizes.is_empty() {
+        let size = u32::try_from(candidate_count).map_err(|_| Error::Malformed)?;
+        return Ok(vec![size]);
+    }
+    ensure!(!contest_sizes.contains(&0), Error::Malformed);
+    let total = contest_sizes
+        .iter()
+        .try_fold(0usize, |total, &size| total.checked_add(size as usize))
+        .ok_or(Error::Malformed)?;
+    ensure!(total == candidate_count, Error::Malformed);
+    Ok(contest_sizes)
+}
+
+/// Check that `display_order` is either empty or a permutation of the indices
+/// of `candidate_count` candidates.
+fn check_display_order(display_order: &[u32], candidate_count: usize) -> Result<(), Error> {
+    if display_order.is_empty() {
+        return Ok(());
+    }
+    ensure!(display_order.len() == candidate_count, Error::Malformed);
+    let mut seen = vec![false; candidate_count];
+    for &index in display_order {
+        let seen = seen.get_mut(index as usize).ok_or(Error::Malformed)?;
+        ensure!(!*seen, Error::Malformed);
+        *seen = true;
+    }
+    Ok(())
 }
 
 impl State {
@@ -197,6 +411,15 @@ impl State {
             election_end,
             decryption_deadline,
             delegation_string,
+            result_posting_deadline,
+            result_approvers,
+            approval_threshold,
+            eg_version,
+            display_order,
+            decryption_grace_seconds,
+            threshold,
+            contest_sizes,
+            max_description_length,
         }: InitParameter,
     ) -> Result<Self, Error> {
         let now = ctx.metadata().block_time();
@@ -204,11 +427,37 @@ impl State {
         ensure!(election_start >= now, Error::Malformed);
         ensure!(election_start < election_end, Error::Malformed);
         ensure!(election_end < decryption_deadline, Error::Malformed);
-        ensure!(!election_description.is_empty(), Error::Malformed);
+        let cutoff = decryption_cutoff(decryption_deadline, decryption_grace_seconds)
+            .ok_or(Error::Malformed)?;
+        ensure!(
+            result_posting_deadline.map_or(true, |deadline| cutoff < deadline),
+            Error::Malformed
+        );
+        check_election_description(&election_description, max_description_length)?;
         ensure!(!candidates.is_empty(), Error::Malformed);
         ensure!(!guardians.is_empty(), Error::Malformed);
+        check_guardian_count(guardians.len())?;
+        ensure!(
+            threshold > 0 && threshold as usize <= guardians.len(),
+            Error::Malformed
+        );
         ensure!(!eligible_voters.data.url.is_empty(), Error::Malformed);
         ensure!(!delegation_string.is_empty(), Error::Malformed);
+        ensure!(!eg_version.is_empty(), Error::Malformed);
+        check_display_order(&display_order, candidates.len())?;
+        let contest_sizes = check_contest_sizes(contest_sizes, candidates.len())?;
+        if result_approvers.is_empty() {
+            ensure!(approval_threshold == 0, Error::Malformed);
+        } else {
+            ensure!(approval_threshold > 0, Error::Malformed);
+            ensure!(
+                approval_threshold as usize <= result_approvers.len(),
+                Error::Malformed
+            );
+        }
+        for (i, approver) in result_approvers.iter().enumerate() {
+            ensure!(!result_approvers[..i].contains(approver), Error::Malformed);
+        }
 
         let mut guardians_map = state_builder.new_map();
         for (&guardian_address, index) in guardians.iter().zip(1u32..) {
@@ -231,6 +480,8 @@ impl State {
             election_description,
             election_manifest,
             election_parameters,
+            eg_version,
+            display_order,
         };
 
         let config = Self {
@@ -241,12 +492,40 @@ impl State {
             election_start,
             election_end,
             decryption_deadline,
+            decryption_grace_seconds,
+            threshold,
+            result_posting_deadline,
             encrypted_tally: state_builder.new_box(None),
             election_result: state_builder.new_box(None),
             delegation_string: state_builder.new_box(delegation_string),
+            result_approvers: state_builder.new_box(result_approvers),
+            approval_threshold,
+            result_proposal: state_builder.new_box(None),
+            contest_sizes: state_builder.new_box(contest_sizes),
+            locked: false,
         };
         Ok(config)
     }
+
+    /// The time until which guardians can register their decryption shares,
+    /// i.e. the `decryption_deadline` extended by the grace period. The grace
+    /// period allows transactions submitted shortly before the deadline to be
+    /// accepted even if they are included in a block after it.
+    pub fn decryption_cutoff(&self) -> Timestamp {
+        // Checked at init and when the deadline is reset.
+        decryption_cutoff(self.decryption_deadline, self.decryption_grace_seconds)
+            .unwrap_or(self.decryption_deadline)
+    }
+
+    /// The time until which guardians can register the proofs of their
+    /// decryption shares, i.e. the
+    /// [`decryption_cutoff`](Self::decryption_cutoff) extended by
+    /// [`DECRYPTION_PROOF_WINDOW`].
+    pub fn decryption_proof_cutoff(&self) -> Timestamp {
+        self.decryption_cutoff()
+            .checked_add(DECRYPTION_PROOF_WINDOW)
+            .unwrap_or_else(|| Timestamp::from_timestamp_millis(u64::MAX))
+    }
 }
 
 /// Parameter supplied to [`init`].
@@ -254,67 +533,135 @@ impl State {
 pub struct InitParameter {
     /// The account used to perform administrative functions, such as publishing
     /// the final result of the election.
-    pub admin_account:        AccountAddress,
+    pub admin_account:            AccountAddress,
     /// A list of candidates that voters can vote for in the election.
-    pub candidates:           Vec<ChecksumUrl>,
+    pub candidates:               Vec<ChecksumUrl>,
     /// The list of guardians for the election.
-    pub guardians:            Vec<AccountAddress>,
+    pub guardians:                Vec<AccountAddress>,
     /// The merkle root of the list of eligible voters and their respective
     /// voting weights.
-    pub eligible_voters:      EligibleVoters,
+    pub eligible_voters:          EligibleVoters,
     /// A url to the location of the election manifest used by election guard.
-    pub election_manifest:    ChecksumUrl,
+    pub election_manifest:        ChecksumUrl,
     /// A url to the location of the election parameters used by election guard.
-    pub election_parameters:  ChecksumUrl,
+    pub election_parameters:      ChecksumUrl,
     /// A description of the election, e.g. "Concordium GC election, June 2024".
-    pub election_description: String,
+    pub election_description:     String,
     /// The start time of the election, marking the time from which votes can be
     /// registered.
-    pub election_start:       Timestamp,
+    pub election_start:           Timestamp,
     /// The end time of the election, marking the time at which votes can no
     /// longer be registered.
-    pub election_end:         Timestamp,
+    pub election_end:             Timestamp,
     /// Guardians must add their [`GuardianState::decryption_share`] before this
     /// timestamp for their shares to be included in the decrypted result.
-    pub decryption_deadline:  Timestamp,
+    pub decryption_deadline:      Timestamp,
     /// A string that should be used when delegating a vote to another account.
-    pub delegation_string:    String,
+    pub delegation_string:        String,
+    /// If set, the encrypted tally and the election result can only be posted
+    /// before this timestamp. Must be after the `decryption_deadline`.
+    pub result_posting_deadline:  Option<Timestamp>,
+    /// Accounts that must approve the election result posted by the admin
+    /// account before it is stored. If empty, the result is stored directly.
+    pub result_approvers:         Vec<AccountAddress>,
+    /// The number of approvals from `result_approvers` needed for a result to
+    /// be stored. Must be 0 if there are no approvers, and otherwise at least
+    /// 1 and at most the number of approvers.
+    pub approval_threshold:       u32,
+    /// The version of electionguard used by the tooling setting up the
+    /// election, e.g. "0.1". Tools operating on the election can use this to
+    /// detect whether they are compatible with it.
+    pub eg_version:               String,
+    /// The order in which candidates should be displayed, mapping each display
+    /// position to the index of a candidate in `candidates`. This allows
+    /// displaying candidates in a different order, e.g. alphabetically,
+    /// without changing the order of the options on the ballot. If empty,
+    /// candidates are displayed in the order of `candidates`. Otherwise it
+    /// must be a permutation of the candidate indices.
+    pub display_order:            Vec<u32>,
+    /// The number of seconds after `decryption_deadline` during which
+    /// decryption shares are still accepted. This allows shares submitted
+    /// shortly before the deadline to be registered even if network delays
+    /// cause them to be included in a block after it. If set, the
+    /// `result_posting_deadline` must be after the extended deadline.
+    pub decryption_grace_seconds: u64,
+    /// The number of guardians needed to decrypt the election result. This
+    /// must match the threshold `k` of the election parameters, and be at
+    /// least 1 and at most the number of guardians.
+    pub threshold:                u32,
+    /// The number of candidates of each contest of the election. The
+    /// candidates of a contest follow those of the previous contest in
+    /// `candidates`, such that the sizes must add up to the number of
+    /// candidates. If empty, the election has a single contest of all
+    /// candidates.
+    pub contest_sizes:            Vec<u32>,
+    /// The maximum length in bytes of `election_description`. If not set,
+    /// [`DEFAULT_MAX_DESCRIPTION_LENGTH`] is used. It must not exceed
+    /// [`MAX_DESCRIPTION_LENGTH`].
+    pub max_description_length:   Option<u32>,
 }
 
 #[derive(Serialize, SchemaType, Debug, Clone)]
 #[cfg_attr(
     feature = "serde",
-    derive(serde::Serialize),
+    derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct ElectionConfig {
     /// The account used to perform administrative functions, such as publishing
     /// the final result of the election.
-    pub admin_account:        AccountAddress,
+    pub admin_account:            AccountAddress,
     /// A list of candidates that voters can vote for in the election.
-    pub candidates:           Vec<ChecksumUrl>,
+    pub candidates:               Vec<ChecksumUrl>,
     /// The list of guardians for the election.
-    pub guardian_accounts:    Vec<AccountAddress>,
+    pub guardian_accounts:        Vec<AccountAddress>,
     /// The merkle root of the list of eligible voters and their respective
     /// voting weights.
-    pub eligible_voters:      EligibleVoters,
+    pub eligible_voters:          EligibleVoters,
     /// A url to the location of the election manifest used by election guard.
-    pub election_manifest:    ChecksumUrl,
+    pub election_manifest:        ChecksumUrl,
     /// A url to the location of the election parameters used by election guard.
-    pub election_parameters:  ChecksumUrl,
+    pub election_parameters:      ChecksumUrl,
     /// A description of the election, e.g. "Concordium GC election, June 2024".
-    pub election_description: String,
+    pub election_description:     String,
     /// The start time of the election, marking the time from which votes can be
     /// registered.
-    pub election_start:       Timestamp,
+    pub election_start:           Timestamp,
     /// The end time of the election, marking the time at which votes can no
     /// longer be registered.
-    pub election_end:         Timestamp,
+    pub election_end:             Timestamp,
     /// Guardians must add their [`GuardianState::decryption_share`] before this
     /// timestamp for their shares to be included in the decrypted result.
-    pub decryption_deadline:  Timestamp,
+    pub decryption_deadline:      Timestamp,
     /// A string that should be used when delegating a vote to another account.
-    pub delegation_string:    String,
+    pub delegation_string:        String,
+    /// If set, the encrypted tally and the election result can only be posted
+    /// before this timestamp. Must be after the `decryption_deadline`.
+    pub result_posting_deadline:  Option<Timestamp>,
+    /// Accounts that must approve the election result posted by the admin
+    /// account before it is stored. If empty, the result is stored directly.
+    pub result_approvers:         Vec<AccountAddress>,
+    /// The number of approvals from `result_approvers` needed for a result to
+    /// be stored.
+    pub approval_threshold:       u32,
+    /// The version of electionguard used by the tooling setting up the
+    /// election.
+    pub eg_version:               String,
+    /// The order in which candidates should be displayed, mapping each display
+    /// position to the index of a candidate in `candidates`. If empty,
+    /// candidates are displayed in the order of `candidates`.
+    pub display_order:            Vec<u32>,
+    /// The number of seconds after `decryption_deadline` during which
+    /// decryption shares are still accepted.
+    pub decryption_grace_seconds: u64,
+    /// The number of guardians needed to decrypt the election result.
+    pub threshold:                u32,
+    /// The number of candidates of each contest of the election, in the order
+    /// of `candidates`.
+    pub contest_sizes:            Vec<u32>,
+    /// Whether the election has been locked, i.e. the election result is final
+    /// and can no longer be changed.
+    pub locked:                   bool,
 }
 
 impl From<&State> for ElectionConfig {
@@ -335,13 +682,27 @@ impl From<&State> for ElectionConfig {
             candidates,
             guardian_accounts,
             delegation_string: value.delegation_string.clone(),
+            result_posting_deadline: value.result_posting_deadline,
+            result_approvers: value.result_approvers.get().clone(),
+            approval_threshold: value.approval_threshold,
+            eg_version: registered_data.eg_version.clone(),
+            display_order: registered_data.display_order.clone(),
+            decryption_grace_seconds: value.decryption_grace_seconds,
+            threshold: value.threshold,
+            contest_sizes: value.contest_sizes.get().clone(),
+            locked: value.locked,
         }
     }
 }
 
 /// Init function that creates a new smart contract with an initial [`State`]
 /// derived from the supplied [`InitParameter`]
-#[init(contract = "election", parameter = "InitParameter", error = "Error")]
+#[init(
+    contract = "election",
+    parameter = "InitParameter",
+    error = "Error",
+    event = "Event"
+)]
 fn init(ctx: &InitContext, state_builder: &mut StateBuilder) -> InitResult<State> {
     let parameter: InitParameter = ctx.parameter_cursor().get()?;
     let initial_state = State::new_checked(ctx, state_builder, parameter)?;
@@ -375,9 +736,14 @@ pub type RegisterGuardianPublicKeyParameter = Vec<u8>;
     name = "registerGuardianPublicKey",
     parameter = "RegisterGuardianPublicKeyParameter",
     error = "Error",
+    enable_logger,
     mutable
 )]
-fn register_guardian_public_key(ctx: &ReceiveContext, host: &mut Host<State>) -> Result<(), Error> {
+fn register_guardian_public_key(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> Result<(), Error> {
     let now = ctx.metadata().block_time();
     ensure!(
         now < host.state.election_start,
@@ -389,6 +755,48 @@ fn register_guardian_public_key(ctx: &ReceiveContext, host: &mut Host<State>) ->
 
     let parameter: RegisterGuardianPublicKeyParameter = ctx.parameter_cursor().get()?;
     guardian_state.public_key = Some(parameter);
+    logger.log(&Event::GuardianPublicKeyRegistered {
+        index: guardian_state.index,
+    })?;
+    Ok(())
+}
+
+/// Entrypoint for replacing the public key registered by the guardian
+/// corresponding to the sender address, e.g. if the key was generated for the
+/// wrong account. This is only possible before the election starts. The
+/// encrypted shares of all guardians are encrypted with the registered keys,
+/// and the statuses reported by all guardians are based on them, hence these
+/// are cleared for all guardians and must be registered again.
+#[receive(
+    contract = "election",
+    name = "replaceGuardianPublicKey",
+    parameter = "RegisterGuardianPublicKeyParameter",
+    error = "Error",
+    enable_logger,
+    mutable
+)]
+fn replace_guardian_public_key(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> Result<(), Error> {
+    let now = ctx.metadata().block_time();
+    ensure!(
+        now < host.state.election_start,
+        Error::IncorrectElectionPhase
+    );
+
+    let mut guardian_state = validate_guardian_context(ctx, host)?;
+    let parameter: RegisterGuardianPublicKeyParameter = ctx.parameter_cursor().get()?;
+    guardian_state.public_key = Some(parameter);
+    let index = guardian_state.index;
+    drop(guardian_state);
+
+    for (_, mut guardian_state) in host.state.guardians.iter_mut() {
+        guardian_state.encrypted_share = None;
+        guardian_state.status = None;
+    }
+    logger.log(&Event::GuardianPublicKeyRegistered { index })?;
     Ok(())
 }
 
@@ -437,16 +845,18 @@ fn register_guardian_encrypted_share(
     mutable
 )]
 fn post_decryption_share(ctx: &ReceiveContext, host: &mut Host<State>) -> Result<(), Error> {
+    ensure!(!host.state.locked, Error::ElectionLocked);
     let now = ctx.metadata().block_time();
     ensure!(
-        host.state.election_end < now && now < host.state.decryption_deadline,
+        host.state.election_end < now && now < host.state.decryption_cutoff(),
         Error::IncorrectElectionPhase
     );
 
     let mut guardian_state = validate_guardian_context(ctx, host)?;
     ensure!(!guardian_state.excluded, Error::GuardianExcluded);
     ensure!(
-        guardian_state.decryption_share.is_none(),
+        guardian_state.decryption_share.is_none()
+            && guardian_state.decryption_share_contests.is_empty(),
         Error::DuplicateEntry
     );
 
@@ -455,6 +865,68 @@ fn post_decryption_share(ctx: &ReceiveContext, host: &mut Host<State>) -> Result
     Ok(())
 }
 
+/// The parameter supplied to the [`post_decryption_share_for_contest`]
+/// entrypoint.
+#[derive(Serialize, SchemaType, Debug)]
+pub struct PostDecryptionShareForContestParameter {
+    /// The zero-based index of the contest the share is for, i.e. the position
+    /// of the contest in [`ElectionConfig::contest_sizes`] and the
+    /// [`ContestId`] of its result. This is the contest with one-based index
+    /// `contest_index + 1` in the election manifest.
+    pub contest_index: u32,
+    /// The share of the decryption of the contest. This is meant to be the
+    /// Msgpack serialization of the `DecryptionShareResult`s of electionguard
+    /// for the contest.
+    pub share:         Vec<u8>,
+}
+
+/// Entrypoint for registering the share of the decryption for a single
+/// contest. This allows guardians to register the shares of elections with
+/// multiple contests in separate transactions, e.g. to stay within the size
+/// limit of parameters. A guardian only contributes to the decryption once it
+/// has registered a share for every contest. Shares for contests that do not
+/// exist are rejected with [`Error::Malformed`].
+#[receive(
+    contract = "election",
+    name = "postDecryptionShareForContest",
+    parameter = "PostDecryptionShareForContestParameter",
+    error = "Error",
+    mutable
+)]
+fn post_decryption_share_for_contest(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+) -> Result<(), Error> {
+    ensure!(!host.state.locked, Error::ElectionLocked);
+    let now = ctx.metadata().block_time();
+    ensure!(
+        host.state.election_end < now && now < host.state.decryption_cutoff(),
+        Error::IncorrectElectionPhase
+    );
+    let contest_count = host.state.contest_sizes.get().len();
+
+    let mut guardian_state = validate_guardian_context(ctx, host)?;
+    ensure!(!guardian_state.excluded, Error::GuardianExcluded);
+    ensure!(
+        guardian_state.decryption_share.is_none(),
+        Error::DuplicateEntry
+    );
+
+    let PostDecryptionShareForContestParameter {
+        contest_index,
+        share,
+    } = ctx.parameter_cursor().get()?;
+    ensure!((contest_index as usize) < contest_count, Error::Malformed);
+    ensure!(
+        guardian_state
+            .decryption_share_contests
+            .insert(contest_index, share)
+            .is_none(),
+        Error::DuplicateEntry
+    );
+    Ok(())
+}
+
 /// Entrypoint for registering the proof that the decryption share is correct.
 /// The parameter is meant to be Msgpack serialization of the
 /// `DecryptionProofResponseShare` type of electionguard.
@@ -469,8 +941,12 @@ fn post_decryption_proof_response_share(
     ctx: &ReceiveContext,
     host: &mut Host<State>,
 ) -> Result<(), Error> {
+    ensure!(!host.state.locked, Error::ElectionLocked);
     let now = ctx.metadata().block_time();
-    ensure!(host.state.election_end < now, Error::IncorrectElectionPhase);
+    ensure!(
+        host.state.election_end < now && now < host.state.decryption_proof_cutoff(),
+        Error::IncorrectElectionPhase
+    );
 
     let mut guardian_state = validate_guardian_context(ctx, host)?;
     ensure!(!guardian_state.excluded, Error::GuardianExcluded);
@@ -532,6 +1008,199 @@ fn view_guardians_state(
     Ok(guardians_state)
 }
 
+/// View the state of the guardian with the account address given as parameter,
+/// or `None` if the account is not a guardian of the election.
+#[receive(
+    contract = "election",
+    name = "viewGuardian",
+    parameter = "AccountAddress",
+    return_value = "Option<GuardianState>"
+)]
+fn view_guardian(ctx: &ReceiveContext, host: &Host<State>) -> ReceiveResult<Option<GuardianState>> {
+    let address: AccountAddress = ctx.parameter_cursor().get()?;
+    let guardian_state = host
+        .state
+        .guardians
+        .get(&address)
+        .map(|state| state.clone());
+    Ok(guardian_state)
+}
+
+/// The parameter supplied to the [`view_guardians_state_range`] entrypoint,
+/// consisting of the first guardian index and the maximum number of guardians
+/// to return.
+pub type ViewGuardiansStateRangeParameter = (u32, u32);
+
+/// View the state of the guardians with an index in the window described by
+/// the parameter, i.e. at least the start index and below the start index plus
+/// the limit. This allows clients to retrieve the [`GuardiansState`] of large
+/// guardian sets in pages.
+#[receive(
+    contract = "election",
+    name = "viewGuardiansStateRange",
+    parameter = "ViewGuardiansStateRangeParameter",
+    return_value = "GuardiansState"
+)]
+fn view_guardians_state_range(
+    ctx: &ReceiveContext,
+    host: &Host<State>,
+) -> ReceiveResult<GuardiansState> {
+    let (start_index, limit): ViewGuardiansStateRangeParameter = ctx.parameter_cursor().get()?;
+    let window = start_index..start_index.saturating_add(limit);
+    let guardians_state: Vec<_> = host
+        .state
+        .guardians
+        .iter()
+        .filter(|(_, guardian_state)| window.contains(&guardian_state.index))
+        .map(|(address, guardian_state)| (*address, guardian_state.clone()))
+        .collect();
+    Ok(guardians_state)
+}
+
+/// The number of guardians having completed each step of the election. This
+/// allows clients to track the progress of the guardians without retrieving
+/// the full [`GuardiansState`].
+#[derive(Serialize, SchemaType, Debug, PartialEq, Default)]
+pub struct GuardianProgress {
+    /// The total number of guardians.
+    pub total:                 u32,
+    /// The number of guardians that have registered a public key.
+    pub with_public_key:       u32,
+    /// The number of guardians that have registered encrypted shares.
+    pub with_encrypted_share:  u32,
+    /// The number of guardians that have registered a verification status.
+    pub with_status:           u32,
+    /// The number of guardians that have registered decryption shares for
+    /// every contest, either as a single share or for each contest
+    /// individually.
+    pub with_decryption_share: u32,
+    /// The number of guardians that have registered a decryption proof
+    /// response share.
+    pub with_decryption_proof: u32,
+    /// The number of guardians that have been excluded.
+    pub excluded:              u32,
+}
+
+/// View the number of guardians having completed each step of the election.
+#[receive(
+    contract = "election",
+    name = "viewGuardianProgress",
+    return_value = "GuardianProgress"
+)]
+fn view_guardian_progress(
+    _ctx: &ReceiveContext,
+    host: &Host<State>,
+) -> ReceiveResult<GuardianProgress> {
+    let contest_count = host.state.contest_sizes.get().len();
+    let mut progress = GuardianProgress::default();
+    for (_, guardian_state) in host.state.guardians.iter() {
+        progress.total += 1;
+        progress.with_public_key += u32::from(guardian_state.public_key.is_some());
+        progress.with_encrypted_share += u32::from(guardian_state.encrypted_share.is_some());
+        progress.with_status += u32::from(guardian_state.status.is_some());
+        progress.with_decryption_share +=
+            u32::from(guardian_state.has_complete_decryption_share(contest_count));
+        progress.with_decryption_proof +=
+            u32::from(guardian_state.decryption_share_proof.is_some());
+        progress.excluded += u32::from(guardian_state.excluded);
+    }
+    Ok(progress)
+}
+
+/// Whether enough guardians have contributed to the decryption for the
+/// election result to be decrypted, as returned by [`view_can_decrypt`].
+#[derive(Serialize, SchemaType, Debug, PartialEq)]
+pub struct CanDecrypt {
+    /// Whether `contributing` has reached `threshold`.
+    pub ready:        bool,
+    /// The number of guardians that are not excluded and have registered both
+    /// decryption shares for every contest and a decryption proof response
+    /// share.
+    pub contributing: u32,
+    /// The number of guardians needed to decrypt the election result.
+    pub threshold:    u32,
+    /// The indices of the guardians that are not excluded but have not yet
+    /// registered both decryption shares for every contest and a decryption
+    /// proof response share.
+    pub missing:      Vec<u32>,
+}
+
+/// View whether enough guardians have contributed to the decryption for the
+/// election result to be decrypted. Excluded guardians never contribute, as
+/// their shares are not used.
+#[receive(
+    contract = "election",
+    name = "viewCanDecrypt",
+    return_value = "CanDecrypt"
+)]
+fn view_can_decrypt(_ctx: &ReceiveContext, host: &Host<State>) -> ReceiveResult<CanDecrypt> {
+    let contest_count = host.state.contest_sizes.get().len();
+    let mut contributing = 0;
+    let mut missing = Vec::new();
+    for (_, guardian_state) in host.state.guardians.iter() {
+        if guardian_state.excluded {
+            continue;
+        }
+        if guardian_state.has_complete_decryption_share(contest_count)
+            && guardian_state.decryption_share_proof.is_some()
+        {
+            contributing += 1;
+        } else {
+            missing.push(guardian_state.index);
+        }
+    }
+    missing.sort_unstable();
+    let threshold = host.state.threshold;
+    Ok(CanDecrypt {
+        ready: contributing >= threshold,
+        contributing,
+        threshold,
+        missing,
+    })
+}
+
+/// The phase of the election, as returned by [`view_election_phase`].
+#[derive(Serialize, SchemaType, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ElectionPhase {
+    /// The election has not started yet. Guardians perform key generation.
+    Setup,
+    /// The election is open and voters can register their votes.
+    Voting,
+    /// The election has ended and guardians can register their decryption
+    /// shares.
+    Tally,
+    /// The decryption deadline, including the grace period, has passed, and
+    /// the election result is yet to be registered.
+    Finalization,
+    /// The election result has been registered.
+    Finalized,
+}
+
+/// View the phase of the election at the time of the current block. This
+/// allows clients to rely on the same timing logic as the contract.
+#[receive(
+    contract = "election",
+    name = "viewElectionPhase",
+    return_value = "ElectionPhase"
+)]
+fn view_election_phase(ctx: &ReceiveContext, host: &Host<State>) -> ReceiveResult<ElectionPhase> {
+    let now = ctx.metadata().block_time();
+    let state = host.state();
+    let phase = if state.election_result.get().is_some() {
+        ElectionPhase::Finalized
+    } else if now < state.election_start {
+        ElectionPhase::Setup
+    } else if now <= state.election_end {
+        ElectionPhase::Voting
+    } else if now < state.decryption_cutoff() {
+        ElectionPhase::Tally
+    } else {
+        ElectionPhase::Finalization
+    };
+    Ok(phase)
+}
+
 /// The parameter supplied to the [`register_votes`] entrypoint.
 #[derive(Serialize)]
 #[repr(transparent)]
@@ -550,15 +1219,26 @@ impl schema::SchemaType for RegisterVotesParameter {
 /// Receive votes registration from voter. If a contract submits the vote, an
 /// error is returned. This function does not actually store anything. Instead
 /// the encrypted votes should be read by traversing the transactions sent to
-/// the contract.
+/// the contract. An [`Event::VoteAccepted`] is logged for each registered
+/// ballot, which allows counting ballots without parsing the parameters and
+/// gives the voter an on-chain receipt of the ballot.
 #[receive(
     contract = "election",
     name = "registerVotes",
     parameter = "RegisterVotesParameter",
-    error = "Error"
+    error = "Error",
+    enable_logger,
+    crypto_primitives
 )]
-fn register_votes(ctx: &ReceiveContext, host: &Host<State>) -> Result<(), Error> {
-    ensure!(ctx.sender().is_account(), Error::Unauthorized);
+fn register_votes(
+    ctx: &ReceiveContext,
+    host: &Host<State>,
+    logger: &mut Logger,
+    crypto_primitives: &impl HasCryptoPrimitives,
+) -> Result<(), Error> {
+    let Address::Account(account) = ctx.sender() else {
+        bail!(Error::Unauthorized);
+    };
 
     let now = ctx.metadata().block_time();
     ensure!(
@@ -566,6 +1246,26 @@ fn register_votes(ctx: &ReceiveContext, host: &Host<State>) -> Result<(), Error>
         Error::IncorrectElectionPhase
     );
 
+    let parameter: RegisterVotesParameter = ctx.parameter_cursor().get()?;
+    let ballot_hash = crypto_primitives.hash_sha2_256(&parameter.inner);
+    logger.log(&Event::VoteAccepted {
+        account,
+        ballot_hash,
+    })?;
+    Ok(())
+}
+
+/// Checks that results of the election can still be posted at `now`, i.e.
+/// that the election has ended and the (optional) result posting deadline has
+/// not passed.
+fn ensure_result_posting_window(state: &State, now: Timestamp) -> Result<(), Error> {
+    ensure!(now > state.election_end, Error::IncorrectElectionPhase);
+    ensure!(
+        state
+            .result_posting_deadline
+            .map_or(true, |deadline| now < deadline),
+        Error::IncorrectElectionPhase
+    );
     Ok(())
 }
 
@@ -579,28 +1279,68 @@ pub type PostEncryptedTallyParameter = Vec<u8>;
     name = "postEncryptedTally",
     parameter = "PostEncryptedTallyParameter",
     error = "Error",
+    enable_logger,
     mutable
 )]
-fn post_encrypted_tally(ctx: &ReceiveContext, host: &mut Host<State>) -> Result<(), Error> {
+fn post_encrypted_tally(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> Result<(), Error> {
+    ensure!(!host.state.locked, Error::ElectionLocked);
     let now = ctx.metadata().block_time();
 
     ensure!(
         ctx.sender().matches_account(host.state.admin_account.get()),
         Error::Unauthorized
     );
-    ensure!(now > host.state.election_end, Error::IncorrectElectionPhase);
+    ensure_result_posting_window(&host.state, now)?;
 
     let parameter: PostEncryptedTallyParameter = ctx.parameter_cursor().get()?;
 
     *host.state.encrypted_tally.get_mut() = Some(parameter);
+    // Any acknowledgements refer to the previously registered tally.
+    for (_, mut guardian_state) in host.state.guardians.iter_mut() {
+        guardian_state.acknowledged_tally = false;
+    }
+    logger.log(&Event::EncryptedTallyPosted)?;
+    Ok(())
+}
+
+/// Entrypoint for guardians to acknowledge the encrypted tally registered in
+/// the contract. This signals to the election coordinator that the guardian is
+/// ready to take part in decryption of the tally.
+#[receive(
+    contract = "election",
+    name = "acknowledgeTally",
+    error = "Error",
+    mutable
+)]
+fn acknowledge_tally(ctx: &ReceiveContext, host: &mut Host<State>) -> Result<(), Error> {
+    ensure!(!host.state.locked, Error::ElectionLocked);
+    let now = ctx.metadata().block_time();
+    ensure!(
+        host.state.encrypted_tally.get().is_some() && now < host.state.decryption_deadline,
+        Error::IncorrectElectionPhase
+    );
+
+    let mut guardian_state = validate_guardian_context(ctx, host)?;
+    ensure!(!guardian_state.excluded, Error::GuardianExcluded);
+    ensure!(!guardian_state.acknowledged_tally, Error::DuplicateEntry);
+
+    guardian_state.acknowledged_tally = true;
     Ok(())
 }
 
 /// The parameter supplied to the [`post_election_result`] entrypoint.
-pub type PostResultParameter = Vec<CandidateWeightedVotes>;
+pub type PostResultParameter = ElectionResult;
 
 /// Receive the election result and update the contract state with the supplied
-/// result from the parameter
+/// result from the parameter. The result must contain the result of each
+/// contest in order, with a weighted vote for each candidate of the contest. If
+/// the election has result approvers, the result is instead proposed, replacing
+/// any previous proposal, and is only stored once approved through
+/// [`approve_result`].
 #[receive(
     contract = "election",
     name = "postElectionResult",
@@ -609,21 +1349,98 @@ pub type PostResultParameter = Vec<CandidateWeightedVotes>;
     mutable
 )]
 fn post_election_result(ctx: &ReceiveContext, host: &mut Host<State>) -> Result<(), Error> {
+    ensure!(!host.state.locked, Error::ElectionLocked);
     let now = ctx.metadata().block_time();
 
     ensure!(
         ctx.sender().matches_account(host.state.admin_account.get()),
         Error::Unauthorized
     );
-    ensure!(now > host.state.election_end, Error::IncorrectElectionPhase);
+    ensure_result_posting_window(&host.state, now)?;
 
-    let candidates: Vec<_> = host.state.candidates.iter().collect();
     let parameter: PostResultParameter = ctx.parameter_cursor().get()?;
-    ensure!(parameter.len() == candidates.len(), Error::Malformed);
-    *host.state.election_result.get_mut() = Some(parameter);
+    let contest_sizes = host.state.contest_sizes.get();
+    ensure!(parameter.len() == contest_sizes.len(), Error::Malformed);
+    for ((id, (contest_id, votes)), &size) in (0..).zip(&parameter).zip(contest_sizes.iter()) {
+        ensure!(*contest_id == id, Error::Malformed);
+        ensure!(votes.len() == size as usize, Error::Malformed);
+    }
+    if host.state.result_approvers.get().is_empty() {
+        *host.state.election_result.get_mut() = Some(parameter);
+    } else {
+        *host.state.result_proposal.get_mut() = Some(ResultProposal {
+            result:    parameter,
+            approvals: Vec::new(),
+        });
+    }
+    Ok(())
+}
+
+/// The parameter supplied to the [`approve_result`] entrypoint, which is the
+/// SHA256 hash of the serialized result being approved.
+pub type ApproveResultParameter = HashSha2256;
+
+/// Approve the currently proposed election result. The hash in the parameter
+/// must match the hash of the proposed result, which ensures the approver
+/// approves the result they expect. Once the number of approvals reaches the
+/// approval threshold, the proposed result is stored as the election result.
+#[receive(
+    contract = "election",
+    name = "approveResult",
+    parameter = "ApproveResultParameter",
+    error = "Error",
+    mutable,
+    crypto_primitives
+)]
+fn approve_result(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    crypto_primitives: &impl HasCryptoPrimitives,
+) -> Result<(), Error> {
+    ensure!(!host.state.locked, Error::ElectionLocked);
+    let now = ctx.metadata().block_time();
+
+    let Address::Account(sender) = ctx.sender() else {
+        bail!(Error::Unauthorized);
+    };
+    ensure!(
+        host.state.result_approvers.get().contains(&sender),
+        Error::Unauthorized
+    );
+    ensure_result_posting_window(&host.state, now)?;
+
+    let hash: ApproveResultParameter = ctx.parameter_cursor().get()?;
+    let threshold = host.state.approval_threshold as usize;
+    let mut proposal_ref = host.state.result_proposal.get_mut();
+    let Some(proposal) = proposal_ref.as_mut() else {
+        bail!(Error::Malformed);
+    };
+    ensure!(
+        crypto_primitives.hash_sha2_256(&to_bytes(&proposal.result)) == hash,
+        Error::Malformed
+    );
+    ensure!(!proposal.approvals.contains(&sender), Error::DuplicateEntry);
+    proposal.approvals.push(sender);
+    if proposal.approvals.len() >= threshold {
+        *host.state.election_result.get_mut() = Some(proposal.result.clone());
+    }
     Ok(())
 }
 
+/// View function that returns the election result currently proposed for
+/// approval, if any.
+#[receive(
+    contract = "election",
+    name = "viewResultProposal",
+    return_value = "Option<ResultProposal>"
+)]
+fn view_result_proposal(
+    _ctx: &ReceiveContext,
+    host: &Host<State>,
+) -> ReceiveResult<Option<ResultProposal>> {
+    Ok(host.state.result_proposal.get().clone())
+}
+
 /// The parameter supplied to the [`reset_finalization_phase`] entrypoint.
 pub type ResetFinalizationParameter = (Vec<AccountAddress>, Timestamp);
 
@@ -635,6 +1452,7 @@ pub type ResetFinalizationParameter = (Vec<AccountAddress>, Timestamp);
     mutable
 )]
 fn reset_finalization_phase(ctx: &ReceiveContext, host: &mut Host<State>) -> Result<(), Error> {
+    ensure!(!host.state.locked, Error::ElectionLocked);
     let now = ctx.metadata().block_time();
 
     ensure!(
@@ -646,6 +1464,14 @@ fn reset_finalization_phase(ctx: &ReceiveContext, host: &mut Host<State>) -> Res
     let (to_exclude, deadline): ResetFinalizationParameter = ctx.parameter_cursor().get()?;
 
     ensure!(now < deadline, Error::Malformed);
+    let cutoff =
+        decryption_cutoff(deadline, host.state.decryption_grace_seconds).ok_or(Error::Malformed)?;
+    ensure!(
+        host.state
+            .result_posting_deadline
+            .map_or(true, |result_deadline| cutoff < result_deadline),
+        Error::Malformed
+    );
     host.state.decryption_deadline = deadline;
 
     for (account, mut guardian_state) in host.state.guardians.iter_mut() {
@@ -653,12 +1479,125 @@ fn reset_finalization_phase(ctx: &ReceiveContext, host: &mut Host<State>) -> Res
             guardian_state.excluded = true;
         }
         guardian_state.decryption_share = None;
+        guardian_state.decryption_share_contests.clear();
         guardian_state.decryption_share_proof = None;
     }
+    // A proposed result is based on the decryption shares that are discarded.
+    *host.state.result_proposal.get_mut() = None;
 
     Ok(())
 }
 
+/// Extend the deadline for guardians to register their decryption shares,
+/// without discarding the shares and proofs already registered. This is meant
+/// for when all guardians behave correctly, but need more time. Only the admin
+/// account can extend the deadline, and only after the election has ended.
+#[receive(
+    contract = "election",
+    name = "extendDecryptionDeadline",
+    parameter = "Timestamp",
+    error = "Error",
+    mutable
+)]
+fn extend_decryption_deadline(ctx: &ReceiveContext, host: &mut Host<State>) -> Result<(), Error> {
+    ensure!(!host.state.locked, Error::ElectionLocked);
+    let now = ctx.metadata().block_time();
+
+    ensure!(
+        ctx.sender().matches_account(host.state.admin_account.get()),
+        Error::Unauthorized
+    );
+    ensure!(now > host.state.election_end, Error::IncorrectElectionPhase);
+
+    let deadline: Timestamp = ctx.parameter_cursor().get()?;
+    ensure!(
+        now < deadline && host.state.decryption_deadline < deadline,
+        Error::Malformed
+    );
+    let cutoff =
+        decryption_cutoff(deadline, host.state.decryption_grace_seconds).ok_or(Error::Malformed)?;
+    ensure!(
+        host.state
+            .result_posting_deadline
+            .map_or(true, |result_deadline| cutoff < result_deadline),
+        Error::Malformed
+    );
+    host.state.decryption_deadline = deadline;
+    Ok(())
+}
+
+/// The parameter supplied to the [`update_candidates`] entrypoint.
+pub type UpdateCandidatesParameter = Vec<ChecksumUrl>;
+
+/// Replace the candidates of the election, e.g. to correct a candidate
+/// registered with a wrong URL. Only the admin account can update the
+/// candidates, and only before the election starts. The display order must
+/// remain valid for the new candidates, and so must the contest sizes if the
+/// election has multiple contests. A single contest is resized to contain all
+/// the new candidates.
+#[receive(
+    contract = "election",
+    name = "updateCandidates",
+    parameter = "UpdateCandidatesParameter",
+    error = "Error",
+    mutable
+)]
+fn update_candidates(ctx: &ReceiveContext, host: &mut Host<State>) -> Result<(), Error> {
+    let now = ctx.metadata().block_time();
+
+    ensure!(
+        ctx.sender().matches_account(host.state.admin_account.get()),
+        Error::Unauthorized
+    );
+    ensure!(
+        now < host.state.election_start,
+        Error::IncorrectElectionPhase
+    );
+
+    let candidates: UpdateCandidatesParameter = ctx.parameter_cursor().get()?;
+    ensure!(!candidates.is_empty(), Error::Malformed);
+    check_display_order(
+        &host.state.registered_data.get().display_order,
+        candidates.len(),
+    )?;
+    let contest_sizes = match host.state.contest_sizes.get().as_slice() {
+        [_] => Vec::new(),
+        sizes => sizes.to_vec(),
+    };
+    let contest_sizes = check_contest_sizes(contest_sizes, candidates.len())?;
+
+    // The state is rolled back if a duplicate is rejected below.
+    host.state.candidates.clear();
+    for candidate in candidates {
+        ensure!(
+            host.state.candidates.insert(candidate),
+            Error::DuplicateEntry
+        );
+    }
+    *host.state.contest_sizes.get_mut() = contest_sizes;
+    Ok(())
+}
+
+/// Lock the election once the election result has been registered. After
+/// this, the election result is final, and all entrypoints of the finalization
+/// phase reject with [`Error::ElectionLocked`]. Only the admin account can lock
+/// the election.
+#[receive(contract = "election", name = "lockElection", error = "Error", mutable)]
+fn lock_election(ctx: &ReceiveContext, host: &mut Host<State>) -> Result<(), Error> {
+    ensure!(
+        ctx.sender().matches_account(host.state.admin_account.get()),
+        Error::Unauthorized
+    );
+    ensure!(!host.state.locked, Error::ElectionLocked);
+    ensure!(
+        host.state.election_result.get().is_some(),
+        Error::IncorrectElectionPhase
+    );
+
+    host.state.locked = true;
+    Ok(())
+}
+
 /// View function that returns the contract configuration
 #[receive(
     contract = "election",
@@ -671,16 +1610,19 @@ fn view_config(_ctx: &ReceiveContext, host: &Host<State>) -> ReceiveResult<Elect
 
 /// Describes the election result for a single candidate.
 #[derive(Serialize, SchemaType, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CandidateResult {
     pub candidate:         ChecksumUrl,
     pub cummulative_votes: CandidateWeightedVotes,
 }
 
-/// The type returned by the [`result`] entrypoint.
+/// The type returned by the [`view_election_result`] entrypoint.
 pub type ViewElectionResultQueryResponse = Option<Vec<CandidateResult>>;
 
-/// View function that returns the content of the state.
+/// View function that returns the election result for each candidate, in the
+/// order of the candidates. For elections with multiple contests, the results
+/// of the contests are concatenated, see [`view_contest_results`] for the
+/// results grouped by contest.
 #[receive(
     contract = "election",
     name = "viewElectionResult",
@@ -698,7 +1640,7 @@ fn view_election_result(
     let candidates: Vec<_> = host.state.candidates.iter().map(|c| c.clone()).collect();
     let response: Vec<_> = candidates
         .iter()
-        .zip(result)
+        .zip(result.iter().flat_map(|(_, votes)| votes))
         .map(|(candidate, &cummulative_votes)| CandidateResult {
             candidate: candidate.clone(),
             cummulative_votes,
@@ -708,6 +1650,52 @@ fn view_election_result(
     Ok(Some(response))
 }
 
+/// Describes the election result of a single contest.
+#[derive(Serialize, SchemaType, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ContestResultView {
+    pub contest_id: ContestId,
+    pub candidates: Vec<CandidateResult>,
+}
+
+/// The type returned by the [`view_contest_results`] entrypoint.
+pub type ViewContestResultsQueryResponse = Option<Vec<ContestResultView>>;
+
+/// View function that returns the election result for each candidate, grouped
+/// by the contests of the election.
+#[receive(
+    contract = "election",
+    name = "viewContestResults",
+    return_value = "ViewContestResultsQueryResponse",
+    error = "Error"
+)]
+fn view_contest_results(
+    _ctx: &ReceiveContext,
+    host: &Host<State>,
+) -> ReceiveResult<ViewContestResultsQueryResponse> {
+    let Some(result) = &host.state.election_result.get() else {
+        return Ok(None);
+    };
+
+    let mut candidates = host.state.candidates.iter().map(|c| c.clone());
+    let response: Vec<_> = result
+        .iter()
+        .map(|(contest_id, votes)| ContestResultView {
+            contest_id: *contest_id,
+            candidates: votes
+                .iter()
+                .zip(candidates.by_ref())
+                .map(|(&cummulative_votes, candidate)| CandidateResult {
+                    candidate,
+                    cummulative_votes,
+                })
+                .collect(),
+        })
+        .collect();
+
+    Ok(Some(response))
+}
+
 /// View function that returns the encrypted tally.
 #[receive(
     contract = "election",
@@ -725,3 +1713,52 @@ fn view_encrypted_tally(
         Ok(None)
     }
 }
+
+/// View function that returns the SHA256 hash of the encrypted tally. This
+/// allows clients to check they have the registered tally without querying the
+/// tally itself.
+#[receive(
+    contract = "election",
+    name = "viewEncryptedTallyHash",
+    return_value = "Option<HashSha2256>",
+    error = "Error",
+    crypto_primitives
+)]
+fn view_encrypted_tally_hash(
+    _ctx: &ReceiveContext,
+    host: &Host<State>,
+    crypto_primitives: &impl HasCryptoPrimitives,
+) -> ReceiveResult<Option<HashSha2256>> {
+    let hash = host
+        .state
+        .encrypted_tally
+        .get()
+        .as_ref()
+        .map(|tally| crypto_primitives.hash_sha2_256(tally));
+    Ok(hash)
+}
+
+/// View function that returns the SHA256 hash of the serialized election
+/// result. This is the hash used for approving the result, and allows clients
+/// to cheaply detect when the result is published or changes, without querying
+/// the result itself.
+#[receive(
+    contract = "election",
+    name = "viewElectionResultHash",
+    return_value = "Option<HashSha2256>",
+    error = "Error",
+    crypto_primitives
+)]
+fn view_election_result_hash(
+    _ctx: &ReceiveContext,
+    host: &Host<State>,
+    crypto_primitives: &impl HasCryptoPrimitives,
+) -> ReceiveResult<Option<HashSha2256>> {
+    let hash = host
+        .state
+        .election_result
+        .get()
+        .as_ref()
+        .map(|result| crypto_primitives.hash_sha2_256(&to_bytes(result)));
+    Ok(hash)
+}
@@ -0,0 +1,601 @@
+//! Helpers shared by the integration tests of the election contract. The
+//! [`ElectionTestBuilder`] sets up a chain with the contract deployed and
+//! initialized, and the free functions invoke the individual entrypoints.
+#![allow(dead_code)]
+
+use concordium_governance_committee_election::*;
+use concordium_smart_contract_testing::*;
+use concordium_std::{from_bytes, schema, HashSha2256};
+
+/// A test account.
+pub const ALICE: AccountAddress = AccountAddress([0u8; 32]);
+pub const ALICE_ADDR: Address = Address::Account(ALICE);
+
+pub const BOB: AccountAddress = AccountAddress([1u8; 32]);
+pub const BOB_ADDR: Address = Address::Account(BOB);
+
+pub const CAROLINE: AccountAddress = AccountAddress([2u8; 32]);
+pub const CAROLINE_ADDR: Address = Address::Account(CAROLINE);
+
+pub const DAVE: AccountAddress = AccountAddress([3u8; 32]);
+pub const DAVE_ADDR: Address = Address::Account(DAVE);
+
+/// The initial balance of the ALICE test account.
+pub const ACC_INITIAL_BALANCE: Amount = Amount::from_ccd(10_000);
+
+/// A [`Signer`] with one set of keys, used for signing transactions.
+pub const SIGNER: Signer = Signer::with_one_key();
+
+/// Builder for a chain with an initialized election contract. The timings of
+/// the election are relative to the time the contract is initialized.
+pub struct ElectionTestBuilder {
+    guardians:         Vec<AccountAddress>,
+    candidates:        Vec<ChecksumUrl>,
+    /// The time from initialization until the election starts.
+    start_after:       chrono::Duration,
+    /// The duration of the election.
+    election_duration: chrono::Duration,
+    /// The time from the election end until the decryption deadline.
+    decryption_period: chrono::Duration,
+    /// Adjustments to the [`InitParameter`] applied before initialization.
+    modifiers:         Vec<Box<dyn FnOnce(&mut InitParameter)>>,
+}
+
+impl Default for ElectionTestBuilder {
+    fn default() -> Self { Self::new() }
+}
+
+impl ElectionTestBuilder {
+    /// A builder for an election with [`BOB`], [`CAROLINE`] and [`DAVE`] as
+    /// guardians, two candidates, and [`ALICE`] as admin. The election starts
+    /// in 5 seconds and lasts a day, with a decryption deadline a day later.
+    pub fn new() -> Self {
+        Self {
+            guardians:         vec![BOB, CAROLINE, DAVE],
+            candidates:        vec![
+                ChecksumUrl {
+                    url:  "https://candidates.concordium.com/john".to_string(),
+                    hash: HashSha2256([0; 32]),
+                },
+                ChecksumUrl {
+                    url:  "https://candidates.concordium.com/peter".to_string(),
+                    hash: HashSha2256([1; 32]),
+                },
+            ],
+            start_after:       chrono::Duration::seconds(5),
+            election_duration: chrono::Duration::days(1),
+            decryption_period: chrono::Duration::days(1),
+            modifiers:         Vec::new(),
+        }
+    }
+
+    /// Set the guardians of the election. Accounts are created for each of
+    /// them.
+    pub fn guardians(mut self, guardians: Vec<AccountAddress>) -> Self {
+        self.guardians = guardians;
+        self
+    }
+
+    /// Set the candidates of the election.
+    pub fn candidates(mut self, candidates: Vec<ChecksumUrl>) -> Self {
+        self.candidates = candidates;
+        self
+    }
+
+    /// Set the time from initialization until the election starts.
+    pub fn start_after(mut self, start_after: chrono::Duration) -> Self {
+        self.start_after = start_after;
+        self
+    }
+
+    /// Set the duration of the election.
+    pub fn election_duration(mut self, election_duration: chrono::Duration) -> Self {
+        self.election_duration = election_duration;
+        self
+    }
+
+    /// Set the time from the election end until the decryption deadline.
+    pub fn decryption_period(mut self, decryption_period: chrono::Duration) -> Self {
+        self.decryption_period = decryption_period;
+        self
+    }
+
+    /// Adjust the [`InitParameter`] before initialization. This is applied
+    /// after all the other settings of the builder.
+    pub fn modify(mut self, modify: impl FnOnce(&mut InitParameter) + 'static) -> Self {
+        self.modifiers.push(Box::new(modify));
+        self
+    }
+
+    /// The [`InitParameter`] used to initialize the contract, given the time of
+    /// initialization `now`.
+    fn init_parameter(&mut self, now: chrono::DateTime<chrono::Utc>) -> InitParameter {
+        let election_start = now + self.start_after;
+        let election_end = election_start + self.election_duration;
+        let decryption_deadline = election_end + self.decryption_period;
+        let eligible_voters = EligibleVoters {
+            data:       ChecksumUrl {
+                url:  "http://some.election/voters".to_string(),
+                hash: HashSha2256([0u8; 32]),
+            },
+            parameters: EligibleVotersParameters {
+                start_time: Timestamp::from_timestamp_millis(0),
+                end_time:   Timestamp::from_timestamp_millis(0),
+            },
+        };
+        let election_manifest = ChecksumUrl {
+            url:  "http://some.election/manifest".to_string(),
+            hash: HashSha2256([1u8; 32]),
+        };
+        let election_parameters = ChecksumUrl {
+            url:  "http://some.election/parameters".to_string(),
+            hash: HashSha2256([2u8; 32]),
+        };
+
+        // Default admin account
+        let mut init_param = InitParameter {
+            admin_account: ALICE,
+            election_description: "Test election".to_string(),
+            election_start: election_start.try_into().expect("Valid datetime"),
+            election_end: election_end.try_into().expect("Valid datetime"),
+            decryption_deadline: decryption_deadline.try_into().expect("Valid datetime"),
+            candidates: self.candidates.clone(),
+            guardians: self.guardians.clone(),
+            eligible_voters,
+            election_manifest,
+            election_parameters,
+            delegation_string: "Something".into(),
+            result_posting_deadline: None,
+            result_approvers: Vec::new(),
+            approval_threshold: 0,
+            eg_version: "0.1".into(),
+            display_order: Vec::new(),
+            decryption_grace_seconds: 0,
+            threshold: self.guardians.len() as u32,
+            contest_sizes: Vec::new(),
+            max_description_length: None,
+        };
+        for modify in self.modifiers.drain(..) {
+            modify(&mut init_param);
+        }
+        init_param
+    }
+
+    /// Set up a chain with the contract module deployed, and initialize the
+    /// contract.
+    ///
+    /// ## Panics
+    /// If initialization of the contract fails.
+    pub fn build(mut self) -> ElectionTest {
+        let (mut chain, module_ref) = new_chain_and_module();
+        for guardian in &self.guardians {
+            chain.create_account(Account::new(*guardian, ACC_INITIAL_BALANCE));
+        }
+        let config = self.init_parameter(chrono::Utc::now());
+        let init = initialize(&module_ref, &config, &mut chain).expect("Init contract succeeds");
+        ElectionTest {
+            chain,
+            contract_address: init.contract_address,
+            config,
+        }
+    }
+}
+
+/// A chain with an initialized election contract, as set up by
+/// [`ElectionTestBuilder`].
+pub struct ElectionTest {
+    pub chain:            Chain,
+    pub contract_address: ContractAddress,
+    /// The parameter the contract was initialized with.
+    pub config:           InitParameter,
+}
+
+impl ElectionTest {
+    /// Shifts the block time to the election start time.
+    pub fn transition_to_open(&mut self) { transition_to_open(&mut self.chain, &self.config) }
+
+    /// Shifts the block time to after the election end time.
+    pub fn transition_to_closed(&mut self) { transition_to_closed(&mut self.chain, &self.config) }
+
+    /// Shifts the block time to after the decryption deadline.
+    pub fn transition_to_decryption_deadline_passed(&mut self) {
+        transition_to_decryption_deadline_passed(&mut self.chain, &self.config)
+    }
+
+    /// Performs contract update at the entrypoint `entrypoint` with the given
+    /// parameter.
+    pub fn update(
+        &mut self,
+        sender: Address,
+        entrypoint: &str,
+        param: &impl Serial,
+    ) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+        let payload = UpdateContractPayload {
+            amount:       Amount::zero(),
+            address:      self.contract_address,
+            receive_name: OwnedReceiveName::new_unchecked(format!("election.{entrypoint}")),
+            message:      OwnedParameter::from_serial(param).expect("Parameter within size bounds"),
+        };
+
+        self.chain
+            .contract_update(SIGNER, ALICE, sender, Energy::from(10_000), payload)
+    }
+
+    /// Performs contract update at the entrypoint `entrypoint`, expecting it to
+    /// fail, and returns the error.
+    pub fn update_err(&mut self, sender: Address, entrypoint: &str, param: &impl Serial) -> Error {
+        self.update(sender, entrypoint, param)
+            .expect_err("Update is expected to fail")
+            .parse_return_value()
+            .expect("Deserializes to error type")
+    }
+
+    /// Invokes the view entrypoint `entrypoint` and parses the return value.
+    pub fn view<T: Deserial>(&mut self, entrypoint: &str) -> T { self.view_with(entrypoint, &()) }
+
+    /// Invokes the view entrypoint `entrypoint` with the parameter `param` and
+    /// parses the return value.
+    pub fn view_with<T: Deserial>(&mut self, entrypoint: &str, param: &impl Serial) -> T {
+        let payload = UpdateContractPayload {
+            amount:       Amount::zero(),
+            address:      self.contract_address,
+            receive_name: OwnedReceiveName::new_unchecked(format!("election.{entrypoint}")),
+            message:      OwnedParameter::from_serial(param).expect("Parameter within size bounds"),
+        };
+
+        self.chain
+            .contract_invoke(ALICE, ALICE_ADDR, Energy::from(10_000), payload)
+            .expect("Can invoke entrypoint")
+            .parse_return_value()
+            .expect("Can parse value")
+    }
+}
+
+/// Shifts the block time to the election start time.
+pub fn transition_to_open(chain: &mut Chain, config: &InitParameter) {
+    let dur_until_open = chain.block_time().duration_between(config.election_start);
+    chain
+        .tick_block_time(dur_until_open)
+        .expect("Block time does not overflow");
+}
+
+/// Shifts the block time to after the election end time.
+pub fn transition_to_closed(chain: &mut Chain, config: &InitParameter) {
+    let dur_until_closed = chain
+        .block_time()
+        .duration_between(config.election_end)
+        .checked_add(Duration::from_millis(1))
+        .expect("Does not overflow");
+    chain
+        .tick_block_time(dur_until_closed)
+        .expect("Block time does not overflow");
+}
+
+/// Shifts the block time to after the decryption deadline.
+pub fn transition_to_decryption_deadline_passed(chain: &mut Chain, config: &InitParameter) {
+    let dur_until_deadline_passed = chain
+        .block_time()
+        .duration_between(config.decryption_deadline)
+        .checked_add(Duration::from_millis(1))
+        .expect("Does not overflow");
+    chain
+        .tick_block_time(dur_until_deadline_passed)
+        .expect("Block time does not overflow");
+}
+
+/// Performs contract update at `post_election_result` entrypoint.
+pub fn post_election_result_update(
+    chain: &mut Chain,
+    address: &ContractAddress,
+    sender: &Address,
+    param: &PostResultParameter,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    let payload = UpdateContractPayload {
+        amount:       Amount::zero(),
+        address:      *address,
+        receive_name: OwnedReceiveName::new_unchecked("election.postElectionResult".to_string()),
+        message:      OwnedParameter::from_serial(&param).expect("Parameter within size bounds"),
+    };
+
+    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+}
+
+/// Performs contract update at `post_encrypted_tally` entrypoint.
+pub fn post_encrypted_tally_update(
+    chain: &mut Chain,
+    address: &ContractAddress,
+    sender: &Address,
+    param: &PostEncryptedTallyParameter,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    let payload = UpdateContractPayload {
+        amount:       Amount::zero(),
+        address:      *address,
+        receive_name: OwnedReceiveName::new_unchecked("election.postEncryptedTally".to_string()),
+        message:      OwnedParameter::from_serial(&param).expect("Parameter within size bounds"),
+    };
+
+    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+}
+
+/// Performs contract update at `acknowledge_tally` entrypoint.
+pub fn acknowledge_tally_update(
+    chain: &mut Chain,
+    address: &ContractAddress,
+    sender: &Address,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    let payload = UpdateContractPayload {
+        amount:       Amount::zero(),
+        address:      *address,
+        receive_name: OwnedReceiveName::new_unchecked("election.acknowledgeTally".to_string()),
+        message:      OwnedParameter::empty(),
+    };
+
+    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+}
+
+/// Performs contract update at `register_guardian_public_key` entrypoint.
+pub fn register_guardian_public_key_update(
+    chain: &mut Chain,
+    address: &ContractAddress,
+    sender: &Address,
+    param: &RegisterGuardianPublicKeyParameter,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    let payload = UpdateContractPayload {
+        amount:       Amount::zero(),
+        address:      *address,
+        receive_name: OwnedReceiveName::new_unchecked(
+            "election.registerGuardianPublicKey".to_string(),
+        ),
+        message:      OwnedParameter::from_serial(&param).expect("Parameter within size bounds"),
+    };
+
+    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+}
+
+/// Performs contract update at `register_guardian_encrypted_share` entrypoint.
+pub fn register_guardian_encrypted_share_update(
+    chain: &mut Chain,
+    address: &ContractAddress,
+    sender: &Address,
+    param: &RegisterGuardianEncryptedShareParameter,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    let payload = UpdateContractPayload {
+        amount:       Amount::zero(),
+        address:      *address,
+        receive_name: OwnedReceiveName::new_unchecked(
+            "election.registerGuardianEncryptedShare".to_string(),
+        ),
+        message:      OwnedParameter::from_serial(&param).expect("Parameter within size bounds"),
+    };
+
+    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+}
+
+/// Performs contract update at `register_guardian_complaint` entrypoint.
+pub fn register_guardian_status_update(
+    chain: &mut Chain,
+    address: &ContractAddress,
+    sender: &Address,
+    status: GuardianStatus,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    let payload = UpdateContractPayload {
+        amount:       Amount::zero(),
+        address:      *address,
+        receive_name: OwnedReceiveName::new_unchecked(
+            "election.registerGuardianStatus".to_string(),
+        ),
+        message:      OwnedParameter::from_serial(&status).expect("Parameter within size bounds"),
+    };
+
+    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+}
+
+pub fn view_guardians_state(
+    chain: &mut Chain,
+    address: &ContractAddress,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    let payload = UpdateContractPayload {
+        amount:       Amount::zero(),
+        address:      *address,
+        receive_name: OwnedReceiveName::new_unchecked("election.viewGuardiansState".to_string()),
+        message:      OwnedParameter::empty(),
+    };
+
+    chain.contract_invoke(ALICE, ALICE_ADDR, Energy::from(10_000), payload)
+}
+
+/// Invokes `config` entrypoint
+pub fn view_election_result(
+    chain: &mut Chain,
+    address: &ContractAddress,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    let payload = UpdateContractPayload {
+        amount:       Amount::zero(),
+        address:      *address,
+        receive_name: OwnedReceiveName::new_unchecked("election.viewElectionResult".to_string()),
+        message:      OwnedParameter::empty(),
+    };
+
+    chain.contract_invoke(ALICE, ALICE_ADDR, Energy::from(10_000), payload)
+}
+
+/// Performs contract update at `register_votes` entrypoint.
+pub fn register_votes_update(
+    chain: &mut Chain,
+    address: &ContractAddress,
+    sender: &Address,
+    param: &RegisterVotesParameter,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    let payload = UpdateContractPayload {
+        amount:       Amount::zero(),
+        address:      *address,
+        receive_name: OwnedReceiveName::new_unchecked("election.registerVotes".to_string()),
+        message:      OwnedParameter::from_serial(&param).expect("Parameter within size bounds"),
+    };
+
+    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+}
+
+/// Performs contract update at `post_decryption_share` entrypoint.
+pub fn post_decryption_share_update(
+    chain: &mut Chain,
+    address: &ContractAddress,
+    sender: &Address,
+    param: &Vec<u8>,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    let payload = UpdateContractPayload {
+        amount:       Amount::zero(),
+        address:      *address,
+        receive_name: OwnedReceiveName::new_unchecked("election.postDecryptionShare".to_string()),
+        message:      OwnedParameter::from_serial(&param).expect("Parameter within size bounds"),
+    };
+
+    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+}
+
+/// Performs contract update at `post_decryption_share_for_contest` entrypoint.
+pub fn post_decryption_share_for_contest_update(
+    chain: &mut Chain,
+    address: &ContractAddress,
+    sender: &Address,
+    param: &PostDecryptionShareForContestParameter,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    let payload = UpdateContractPayload {
+        amount:       Amount::zero(),
+        address:      *address,
+        receive_name: OwnedReceiveName::new_unchecked(
+            "election.postDecryptionShareForContest".to_string(),
+        ),
+        message:      OwnedParameter::from_serial(param).expect("Parameter within size bounds"),
+    };
+
+    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+}
+
+/// Performs contract update at `post_decryption_proof_response_share`
+/// entrypoint.
+pub fn post_decryption_proof_response_share_update(
+    chain: &mut Chain,
+    address: &ContractAddress,
+    sender: &Address,
+    param: &Vec<u8>,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    let payload = UpdateContractPayload {
+        amount:       Amount::zero(),
+        address:      *address,
+        receive_name: OwnedReceiveName::new_unchecked(
+            "election.postDecryptionProofResponseShare".to_string(),
+        ),
+        message:      OwnedParameter::from_serial(&param).expect("Parameter within size bounds"),
+    };
+
+    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+}
+
+/// Invokes `config` entrypoint
+pub fn view_config(
+    chain: &mut Chain,
+    address: &ContractAddress,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    let payload = UpdateContractPayload {
+        amount:       Amount::zero(),
+        address:      *address,
+        receive_name: OwnedReceiveName::new_unchecked("election.viewConfig".to_string()),
+        message:      OwnedParameter::empty(),
+    };
+
+    chain.contract_invoke(ALICE, ALICE_ADDR, Energy::from(10_000), payload)
+}
+
+pub fn new_chain_and_contract() -> (Chain, ContractAddress) { new_chain_and_contract_with(|_| {}) }
+
+/// Initializes a new chain and contract, where the default [`InitParameter`]
+/// is adjusted by `modify` before initialization.
+pub fn new_chain_and_contract_with(
+    modify: impl FnOnce(&mut InitParameter) + 'static,
+) -> (Chain, ContractAddress) {
+    let election = ElectionTestBuilder::new().modify(modify).build();
+    (election.chain, election.contract_address)
+}
+
+pub fn new_chain_and_module() -> (Chain, ModuleReference) {
+    let now = chrono::Utc::now().try_into().unwrap();
+    // Initialize the test chain.
+    let mut chain = ChainBuilder::new()
+        .block_time(now)
+        .build()
+        .expect("Can build chain");
+    // Create the test account.
+    chain.create_account(Account::new(ALICE, ACC_INITIAL_BALANCE));
+    chain.create_account(Account::new(BOB, ACC_INITIAL_BALANCE));
+    chain.create_account(Account::new(CAROLINE, ACC_INITIAL_BALANCE));
+    chain.create_account(Account::new(DAVE, ACC_INITIAL_BALANCE));
+    // Load the module.
+    let module = module_load_v1("./concordium-out/module.wasm.v1").expect("Module exists at path");
+    // Deploy the module.
+    let deployment = chain
+        .module_deploy_v1(SIGNER, ALICE, module)
+        .expect("Deploy valid module");
+
+    (chain, deployment.module_reference)
+}
+
+/// Reads an unsigned LEB128 integer from the start of `bytes`, as used for
+/// the sizes in the binary format of wasm modules.
+fn read_leb128(bytes: &mut &[u8]) -> usize {
+    let mut value = 0;
+    for shift in (0..).step_by(7) {
+        let (&byte, rest) = bytes.split_first().expect("Unexpected end of module");
+        *bytes = rest;
+        value |= usize::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+    value
+}
+
+/// Gets the event schema of the contract from the schema embedded in the
+/// custom section of the contract module.
+pub fn event_schema() -> schema::Type {
+    let module = std::fs::read("./concordium-out/module.wasm.v1").expect("Module exists at path");
+    // Skip the version and length of the module, and the magic and version of
+    // the wasm binary.
+    let mut sections = &module[16..];
+    while !sections.is_empty() {
+        let id = sections[0];
+        sections = &sections[1..];
+        let size = read_leb128(&mut sections);
+        let (mut section, rest) = sections.split_at(size);
+        sections = rest;
+        if id != 0 {
+            continue;
+        }
+        let name_len = read_leb128(&mut section);
+        let (name, contents) = section.split_at(name_len);
+        if name == b"concordium-schema" {
+            let schema: schema::VersionedModuleSchema =
+                from_bytes(contents).expect("Schema can be parsed");
+            return schema
+                .get_event_schema("election")
+                .expect("The contract has an event schema");
+        }
+    }
+    panic!("The contract module has no embedded schema");
+}
+
+/// Helper method for initializing the contract.
+pub fn initialize(
+    module_ref: &ModuleReference,
+    init_param: &InitParameter,
+    chain: &mut Chain,
+) -> Result<ContractInitSuccess, ContractInitError> {
+    let payload = InitContractPayload {
+        amount:    Amount::zero(),
+        mod_ref:   *module_ref,
+        init_name: OwnedContractName::new_unchecked("init_election".to_string()),
+        param:     OwnedParameter::from_serial(init_param).expect("Parameter within size bounds"),
+    };
+    // Initialize the contract.
+    chain.contract_init(SIGNER, ALICE, Energy::from(10_000), payload)
+}
@@ -1,25 +1,10 @@
+mod common;
+
+use common::*;
 use concordium_governance_committee_election::*;
 use concordium_smart_contract_testing::*;
-use concordium_std::HashSha2256;
-
-/// A test account.
-const ALICE: AccountAddress = AccountAddress([0u8; 32]);
-const ALICE_ADDR: Address = Address::Account(ALICE);
-
-const BOB: AccountAddress = AccountAddress([1u8; 32]);
-const BOB_ADDR: Address = Address::Account(BOB);
-
-const CAROLINE: AccountAddress = AccountAddress([2u8; 32]);
-const CAROLINE_ADDR: Address = Address::Account(CAROLINE);
-
-const DAVE: AccountAddress = AccountAddress([3u8; 32]);
-const DAVE_ADDR: Address = Address::Account(DAVE);
-
-/// The initial balance of the ALICE test account.
-const ACC_INITIAL_BALANCE: Amount = Amount::from_ccd(10_000);
-
-/// A [`Signer`] with one set of keys, used for signing transactions.
-const SIGNER: Signer = Signer::with_one_key();
+use concordium_std::{schema::SchemaType as _, HashSha2256};
+use sha2::Digest as _;
 
 #[test]
 fn test_init_errors() {
@@ -76,6 +61,15 @@ fn test_init_errors() {
         election_manifest: election_manifest.clone(),
         election_parameters: election_parameters.clone(),
         delegation_string: "Something".into(),
+        result_posting_deadline: None,
+        result_approvers: Vec::new(),
+        approval_threshold: 0,
+        eg_version: "0.1".into(),
+        display_order: Vec::new(),
+        decryption_grace_seconds: 0,
+        threshold: 1,
+        contest_sizes: Vec::new(),
+        max_description_length: None,
     };
 
     let init_param = get_init_param();
@@ -95,6 +89,82 @@ fn test_init_errors() {
     initialize(&module_ref, &init_param, &mut chain)
         .expect_err("Election end time must be before decryption deadline");
 
+    // `decryption_deadline` is before `result_posting_deadline`.
+    let mut init_param = get_init_param();
+    init_param.result_posting_deadline = Some(election_end);
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Decryption deadline must be before result posting deadline");
+
+    // The decryption deadline extended by the grace period is before
+    // `result_posting_deadline`.
+    let mut init_param = get_init_param();
+    init_param.decryption_grace_seconds = 60;
+    init_param.result_posting_deadline =
+        decryption_deadline.checked_add(Duration::from_seconds(30));
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Grace period must end before result posting deadline");
+    init_param.decryption_grace_seconds = u64::MAX;
+    init_param.result_posting_deadline = None;
+    initialize(&module_ref, &init_param, &mut chain).expect_err("Grace period must not overflow");
+
+    // `threshold` is at least 1 and at most the number of guardians.
+    let mut init_param = get_init_param();
+    init_param.threshold = 0;
+    initialize(&module_ref, &init_param, &mut chain).expect_err("Threshold must not be 0");
+    init_param.threshold = guardians.len() as u32 + 1;
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Threshold must not exceed the number of guardians");
+
+    // `eg_version` is not empty.
+    let mut init_param = get_init_param();
+    init_param.eg_version = String::new();
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Electionguard version must not be empty");
+
+    // `display_order` is a permutation of the candidate indices.
+    let mut init_param = get_init_param();
+    init_param.display_order = vec![1, 0];
+    initialize(&module_ref, &init_param, &mut chain).expect("Display order is a permutation");
+    init_param.display_order = vec![0];
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Display order must include all candidates");
+    init_param.display_order = vec![0, 0];
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Display order must not contain duplicates");
+    init_param.display_order = vec![0, 2];
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Display order must only contain candidate indices");
+
+    // `contest_sizes` partitions the candidates into non-empty contests.
+    let mut init_param = get_init_param();
+    init_param.contest_sizes = vec![1, 1];
+    initialize(&module_ref, &init_param, &mut chain).expect("Contest sizes partition candidates");
+    init_param.contest_sizes = vec![1];
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Contest sizes must include all candidates");
+    init_param.contest_sizes = vec![2, 1];
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Contest sizes must not exceed the number of candidates");
+    init_param.contest_sizes = vec![2, 0];
+    initialize(&module_ref, &init_param, &mut chain).expect_err("Contests must not be empty");
+
+    // `approval_threshold` is inconsistent with `result_approvers`.
+    let mut init_param = get_init_param();
+    init_param.approval_threshold = 1;
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Approval threshold must be 0 without approvers");
+    let mut init_param = get_init_param();
+    init_param.result_approvers = vec![BOB, CAROLINE];
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Approval threshold must be positive with approvers");
+    init_param.approval_threshold = 3;
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Approval threshold must not exceed the number of approvers");
+    let mut init_param = get_init_param();
+    init_param.result_approvers = vec![BOB, BOB];
+    init_param.approval_threshold = 1;
+    initialize(&module_ref, &init_param, &mut chain).expect_err("Approvers must be unique");
+
     // `election_start` is in the past
     let mut init_param = get_init_param();
     let past_1d = now.checked_sub_days(chrono::Days::new(1)).unwrap();
@@ -106,6 +176,42 @@ fn test_init_errors() {
     init_param.election_description = "".to_string();
     initialize(&module_ref, &init_param, &mut chain).expect_err("Must have non-empty description");
 
+    // Too long `election_description`
+    let mut init_param = get_init_param();
+    init_param.election_description = "a".repeat(DEFAULT_MAX_DESCRIPTION_LENGTH as usize);
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect("Description of the default maximum length is accepted");
+    init_param.election_description = "a".repeat(DEFAULT_MAX_DESCRIPTION_LENGTH as usize + 1);
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Description must not exceed the default maximum length");
+
+    // Configured maximum length of `election_description`
+    let mut init_param = get_init_param();
+    init_param.max_description_length = Some(MAX_DESCRIPTION_LENGTH);
+    init_param.election_description = "a".repeat(MAX_DESCRIPTION_LENGTH as usize);
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect("Description of the configured maximum length is accepted");
+    init_param.election_description = "a".repeat(MAX_DESCRIPTION_LENGTH as usize + 1);
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Description must not exceed the configured maximum length");
+    init_param.max_description_length = Some(10);
+    init_param.election_description = "a".repeat(11);
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("A lower maximum length is enforced");
+    init_param.max_description_length = Some(MAX_DESCRIPTION_LENGTH + 1);
+    init_param.election_description = "Test election".to_string();
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Maximum length must not exceed MAX_DESCRIPTION_LENGTH");
+
+    // Control characters in `election_description`
+    let mut init_param = get_init_param();
+    init_param.election_description = "Concordium GC election\nJune 2024".to_string();
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Description must not contain control characters");
+    init_param.election_description = "Concordium GC election\u{1b}[31m".to_string();
+    initialize(&module_ref, &init_param, &mut chain)
+        .expect_err("Description must not contain escape sequences");
+
     // Empty `candidates` list
     let mut init_param = get_init_param();
     init_param.candidates = vec![];
@@ -141,6 +247,21 @@ fn test_init_errors() {
         .expect_err("Must have non-empty eligible_voters url");
 }
 
+#[test]
+fn test_guardian_count_bounds() {
+    assert_eq!(check_guardian_count(3), Ok(3));
+    assert_eq!(
+        check_guardian_count(MAX_GUARDIANS as usize),
+        Ok(MAX_GUARDIANS),
+        "The maximum number of guardians is supported"
+    );
+    assert_eq!(
+        check_guardian_count(MAX_GUARDIANS as usize + 1),
+        Err(Error::Malformed),
+        "More than the maximum number of guardians is rejected"
+    );
+}
+
 #[test]
 fn test_init_config() {
     let (mut chain, module_ref) = new_chain_and_module();
@@ -194,12 +315,23 @@ fn test_init_config() {
         election_manifest,
         election_parameters,
         delegation_string: "Something".into(),
+        result_posting_deadline: None,
+        result_approvers: Vec::new(),
+        approval_threshold: 0,
+        eg_version: "0.1".into(),
+        display_order: Vec::new(),
+        decryption_grace_seconds: 0,
+        threshold: 1,
+        contest_sizes: Vec::new(),
+        max_description_length: None,
     };
     let init = initialize(&module_ref, &init_param, &mut chain).expect("Init contract succeeds");
     let invocation =
         view_config(&mut chain, &init.contract_address).expect("Can invoke config entrypoint");
     let config: InitParameter = invocation.parse_return_value().expect("Can parse value");
     assert_eq!(config.admin_account, ALICE);
+    assert_eq!(config.eg_version, "0.1");
+    assert_eq!(config.threshold, 1);
 }
 
 #[test]
@@ -212,8 +344,17 @@ fn test_receive_guardian_public_key() {
 
     let param = vec![0, 1, 2, 5, 1, 6, 7];
     let param_other = vec![1, 2, 3, 4, 5, 1, 2, 3];
-    register_guardian_public_key_update(&mut chain, &contract_address, &BOB_ADDR, &param)
-        .expect("Key registration should succeed");
+    let result =
+        register_guardian_public_key_update(&mut chain, &contract_address, &BOB_ADDR, &param)
+            .expect("Key registration should succeed");
+    let events: Vec<Event> = result
+        .events()
+        .flat_map(|(_, events)| events)
+        .map(|event| event.parse().expect("Can parse event"))
+        .collect();
+    assert_eq!(events, vec![Event::GuardianPublicKeyRegistered {
+        index: 1,
+    }]);
 
     register_guardian_public_key_update(&mut chain, &contract_address, &DAVE_ADDR, &param_other)
         .expect("Key registration should succeed");
@@ -256,6 +397,90 @@ fn test_receive_guardian_public_key() {
     assert_eq!(guardians_state, expected_result);
 }
 
+#[test]
+fn test_replace_guardian_public_key() {
+    let mut election = ElectionTestBuilder::new().build();
+    for guardian in [BOB_ADDR, CAROLINE_ADDR, DAVE_ADDR] {
+        election
+            .update(guardian, "registerGuardianPublicKey", &vec![0u8, 1, 2])
+            .expect("Key registration should succeed");
+        election
+            .update(guardian, "registerGuardianEncryptedShare", &vec![3u8, 4])
+            .expect("Share registration should succeed");
+        election
+            .update(
+                guardian,
+                "registerGuardianStatus",
+                &GuardianStatus::VerificationSuccessful,
+            )
+            .expect("Status registration should succeed");
+    }
+
+    let error = election.update_err(ALICE_ADDR, "replaceGuardianPublicKey", &vec![5u8]);
+    assert_eq!(
+        error,
+        Error::Unauthorized,
+        "Only guardians can replace keys"
+    );
+
+    let result = election
+        .update(BOB_ADDR, "replaceGuardianPublicKey", &vec![5u8, 6, 7])
+        .expect("Key replacement should succeed");
+    let events: Vec<Event> = result
+        .events()
+        .flat_map(|(_, events)| events)
+        .map(|event| event.parse().expect("Can parse event"))
+        .collect();
+    assert_eq!(events, vec![Event::GuardianPublicKeyRegistered {
+        index: 1,
+    }]);
+
+    let mut guardians_state: GuardiansState = election.view("viewGuardiansState");
+    guardians_state.sort_by_key(|g| g.1.index);
+    let expected_result: GuardiansState = vec![
+        (BOB, GuardianState {
+            public_key: Some(vec![5, 6, 7]),
+            ..GuardianState::new(1)
+        }),
+        (CAROLINE, GuardianState {
+            public_key: Some(vec![0, 1, 2]),
+            ..GuardianState::new(2)
+        }),
+        (DAVE, GuardianState {
+            public_key: Some(vec![0, 1, 2]),
+            ..GuardianState::new(3)
+        }),
+    ];
+    assert_eq!(
+        guardians_state, expected_result,
+        "Shares and statuses derived from the replaced key are cleared"
+    );
+
+    // The guardians verify the keys again.
+    for guardian in [BOB_ADDR, CAROLINE_ADDR, DAVE_ADDR] {
+        election
+            .update(guardian, "registerGuardianEncryptedShare", &vec![8u8])
+            .expect("Share registration should succeed after key replacement");
+        election
+            .update(
+                guardian,
+                "registerGuardianStatus",
+                &GuardianStatus::VerificationSuccessful,
+            )
+            .expect("Status registration should succeed after key replacement");
+    }
+    let progress: GuardianProgress = election.view("viewGuardianProgress");
+    assert_eq!(progress.with_status, 3);
+
+    election.transition_to_open();
+    let error = election.update_err(BOB_ADDR, "replaceGuardianPublicKey", &vec![0u8, 1, 2]);
+    assert_eq!(
+        error,
+        Error::IncorrectElectionPhase,
+        "Keys cannot be replaced after the election has started"
+    );
+}
+
 #[test]
 fn test_receive_guardian_encrypted_share() {
     let (mut chain, contract_address) = new_chain_and_contract();
@@ -467,8 +692,18 @@ fn test_receive_ballot() {
     transition_to_open(&mut chain, &config);
 
     // Election window opens
-    register_votes_update(&mut chain, &contract_address, &ALICE_ADDR, &param)
+    let result = register_votes_update(&mut chain, &contract_address, &ALICE_ADDR, &param)
         .expect("Can register votes");
+    let events: Vec<Event> = result
+        .events()
+        .flat_map(|(_, events)| events)
+        .map(|event| event.parse().expect("Can parse event"))
+        .collect();
+    let ballot_hash = HashSha2256(sha2::Sha256::digest(&param.inner).into());
+    assert_eq!(events, vec![Event::VoteAccepted {
+        account: ALICE,
+        ballot_hash,
+    }]);
 
     let error: Error = register_votes_update(
         &mut chain,
@@ -597,6 +832,153 @@ fn test_receive_guardian_decryption_share() {
     assert_eq!(guardians_state, expected_result);
 }
 
+#[test]
+fn test_receive_guardian_decryption_share_for_contest() {
+    let (mut chain, contract_address) =
+        new_chain_and_contract_with(|param| param.contest_sizes = vec![1, 1]);
+    let config: InitParameter = view_config(&mut chain, &contract_address)
+        .expect("Can invoke config entrypoint")
+        .parse_return_value()
+        .expect("Can parse value");
+
+    let contest_1 = PostDecryptionShareForContestParameter {
+        contest_index: 0,
+        share:         vec![0, 1, 2, 5, 1, 6, 7],
+    };
+    let contest_2 = PostDecryptionShareForContestParameter {
+        contest_index: 1,
+        share:         vec![1, 2, 3, 4, 5, 1, 2, 3],
+    };
+
+    let error: Error = post_decryption_share_for_contest_update(
+        &mut chain,
+        &contract_address,
+        &BOB_ADDR,
+        &contest_1,
+    )
+    .expect_err("Registering decryption share should fail before election_end")
+    .parse_return_value()
+    .expect("Can deserialize error");
+    assert_eq!(
+        error,
+        Error::IncorrectElectionPhase,
+        "Unexpected error type"
+    );
+
+    transition_to_closed(&mut chain, &config);
+
+    post_decryption_share_for_contest_update(&mut chain, &contract_address, &BOB_ADDR, &contest_1)
+        .expect("Decryption share registration should succeed");
+    post_decryption_share_for_contest_update(&mut chain, &contract_address, &BOB_ADDR, &contest_2)
+        .expect("Decryption share registration for another contest should succeed");
+    post_decryption_share_for_contest_update(&mut chain, &contract_address, &DAVE_ADDR, &contest_2)
+        .expect("Decryption share registration should succeed");
+
+    let error: Error = post_decryption_share_for_contest_update(
+        &mut chain,
+        &contract_address,
+        &CAROLINE_ADDR,
+        &PostDecryptionShareForContestParameter {
+            contest_index: 2,
+            share:         vec![1, 2, 3],
+        },
+    )
+    .expect_err("Registration should fail for a contest that does not exist")
+    .parse_return_value()
+    .expect("Deserializes to error type");
+    assert_eq!(error, Error::Malformed, "Unexpected error type");
+
+    let error: Error = post_decryption_share_for_contest_update(
+        &mut chain,
+        &contract_address,
+        &BOB_ADDR,
+        &contest_1,
+    )
+    .expect_err("Registration should fail due to duplicate entry")
+    .parse_return_value()
+    .expect("Deserializes to error type");
+    assert_eq!(error, Error::DuplicateEntry, "Unexpected error type");
+
+    let error: Error = post_decryption_share_for_contest_update(
+        &mut chain,
+        &contract_address,
+        &ALICE_ADDR,
+        &contest_1,
+    )
+    .expect_err("Registration should fail due to not being in the list of guardians")
+    .parse_return_value()
+    .expect("Deserializes to error type");
+    assert_eq!(error, Error::Unauthorized, "Unexpected error type");
+
+    // Shares for all contests cannot be combined with shares for individual
+    // contests.
+    let error: Error =
+        post_decryption_share_update(&mut chain, &contract_address, &DAVE_ADDR, &contest_1.share)
+            .expect_err("Registration should fail due to existing contest shares")
+            .parse_return_value()
+            .expect("Deserializes to error type");
+    assert_eq!(error, Error::DuplicateEntry, "Unexpected error type");
+    post_decryption_share_update(
+        &mut chain,
+        &contract_address,
+        &CAROLINE_ADDR,
+        &contest_1.share,
+    )
+    .expect("Decryption share registration should succeed");
+    let error: Error = post_decryption_share_for_contest_update(
+        &mut chain,
+        &contract_address,
+        &CAROLINE_ADDR,
+        &contest_2,
+    )
+    .expect_err("Registration should fail due to existing share for all contests")
+    .parse_return_value()
+    .expect("Deserializes to error type");
+    assert_eq!(error, Error::DuplicateEntry, "Unexpected error type");
+
+    let mut guardians_state: GuardiansState = view_guardians_state(&mut chain, &contract_address)
+        .expect("Can invoke entrypoint")
+        .parse_return_value()
+        .expect("Can parse value");
+    guardians_state.sort_by_key(|x| x.1.index);
+    let expected_result: GuardiansState = vec![
+        (BOB, GuardianState {
+            decryption_share_contests: [
+                (contest_1.contest_index, contest_1.share.clone()),
+                (contest_2.contest_index, contest_2.share.clone()),
+            ]
+            .into(),
+            ..GuardianState::new(1)
+        }),
+        (CAROLINE, GuardianState {
+            decryption_share: Some(contest_1.share.clone()),
+            ..GuardianState::new(2)
+        }),
+        (DAVE, GuardianState {
+            decryption_share_contests: [(contest_2.contest_index, contest_2.share.clone())].into(),
+            ..GuardianState::new(3)
+        }),
+    ];
+    assert_eq!(guardians_state, expected_result);
+
+    transition_to_decryption_deadline_passed(&mut chain, &config);
+
+    let error: Error = post_decryption_share_for_contest_update(
+        &mut chain,
+        &contract_address,
+        &DAVE_ADDR,
+        &contest_1,
+    )
+    .expect_err("Registration should fail when deadline has passed")
+    .parse_return_value()
+    .expect("Deserializes to error type");
+    assert_eq!(
+        error,
+        Error::IncorrectElectionPhase,
+        "Unexpected error type"
+    );
+}
+
 #[test]
 fn test_receive_guardian_decryption_proof_response_share() {
     let (mut chain, contract_address) = new_chain_and_contract();
@@ -718,7 +1100,7 @@ fn test_receive_election_result() {
         .expect("Can invoke entrypoint")
         .parse_return_value()
         .expect("Can parse value");
-    let valid_param = vec![10; config.candidates.len()];
+    let valid_param = vec![(0, vec![10; config.candidates.len()])];
 
     let error: Error =
         post_election_result_update(&mut chain, &contract_address, &ALICE_ADDR, &valid_param)
@@ -734,7 +1116,7 @@ fn test_receive_election_result() {
     transition_to_closed(&mut chain, &config);
 
     // Election window closed
-    let invalid_param = vec![10; config.candidates.len() + 1];
+    let invalid_param = vec![(0, vec![10; config.candidates.len() + 1])];
     let error: Error =
         post_election_result_update(&mut chain, &contract_address, &ALICE_ADDR, &invalid_param)
             .expect_err("Cannot submit election result with too many vote counts")
@@ -742,7 +1124,7 @@ fn test_receive_election_result() {
             .expect("Deserializes to error type");
     assert_eq!(error, Error::Malformed, "Unexpected error type");
 
-    let invalid_param = vec![10; config.candidates.len() - 1];
+    let invalid_param = vec![(0, vec![10; config.candidates.len() - 1])];
     let error: Error =
         post_election_result_update(&mut chain, &contract_address, &ALICE_ADDR, &invalid_param)
             .expect_err("Cannot submit election result with insufficient vote counts")
@@ -750,6 +1132,14 @@ fn test_receive_election_result() {
             .expect("Deserializes to error type");
     assert_eq!(error, Error::Malformed, "Unexpected error type");
 
+    let invalid_param = vec![(1, vec![10; config.candidates.len()])];
+    let error: Error =
+        post_election_result_update(&mut chain, &contract_address, &ALICE_ADDR, &invalid_param)
+            .expect_err("Cannot submit election result for an unknown contest")
+            .parse_return_value()
+            .expect("Deserializes to error type");
+    assert_eq!(error, Error::Malformed, "Unexpected error type");
+
     let contract_sender = Address::Contract(ContractAddress {
         index:    0,
         subindex: 0,
@@ -787,8 +1177,8 @@ fn test_receive_election_result() {
     let expected_result: Vec<CandidateResult> = config
         .candidates
         .iter()
-        .zip(valid_param)
-        .map(|(candidate, cummulative_votes)| CandidateResult {
+        .zip(&valid_param[0].1)
+        .map(|(candidate, &cummulative_votes)| CandidateResult {
             candidate: candidate.clone(),
             cummulative_votes,
         })
@@ -796,303 +1186,1010 @@ fn test_receive_election_result() {
     assert_eq!(election_result, Some(expected_result));
 }
 
-/// Shifts the block time to the election start time.
-fn transition_to_open(chain: &mut Chain, config: &InitParameter) {
-    let dur_until_open = chain.block_time().duration_between(config.election_start);
-    chain
-        .tick_block_time(dur_until_open)
-        .expect("Block time does not overflow");
-}
-
-/// Shifts the block time to after the election end time.
-fn transition_to_closed(chain: &mut Chain, config: &InitParameter) {
-    let dur_until_closed = chain
-        .block_time()
-        .duration_between(config.election_end)
-        .checked_add(Duration::from_millis(1))
-        .expect("Does not overflow");
-    chain
-        .tick_block_time(dur_until_closed)
-        .expect("Block time does not overflow");
-}
+#[test]
+fn test_receive_result_approval() {
+    let mut election = ElectionTestBuilder::new()
+        .modify(|param| {
+            param.result_approvers = vec![BOB, CAROLINE, DAVE];
+            param.approval_threshold = 2;
+        })
+        .build();
+    let result: PostResultParameter = vec![(0, vec![10, 20])];
+    let hash = HashSha2256(sha2::Sha256::digest(concordium_std::to_bytes(&result)).into());
 
-/// Shifts the block time to after the decryption deadline.
-fn transition_to_decryption_deadline_passed(chain: &mut Chain, config: &InitParameter) {
-    let dur_until_deadline_passed = chain
-        .block_time()
-        .duration_between(config.decryption_deadline)
-        .checked_add(Duration::from_millis(1))
-        .expect("Does not overflow");
-    chain
-        .tick_block_time(dur_until_deadline_passed)
-        .expect("Block time does not overflow");
-}
+    election.transition_to_closed();
+    let error = election.update_err(BOB_ADDR, "approveResult", &hash);
+    assert_eq!(error, Error::Malformed, "Nothing proposed to approve");
 
-/// Performs contract update at `post_election_result` entrypoint.
-fn post_election_result_update(
-    chain: &mut Chain,
-    address: &ContractAddress,
-    sender: &Address,
-    param: &PostResultParameter,
-) -> Result<ContractInvokeSuccess, ContractInvokeError> {
-    let payload = UpdateContractPayload {
-        amount:       Amount::zero(),
-        address:      *address,
-        receive_name: OwnedReceiveName::new_unchecked("election.postElectionResult".to_string()),
-        message:      OwnedParameter::from_serial(&param).expect("Parameter within size bounds"),
-    };
+    election
+        .update(ALICE_ADDR, "postElectionResult", &result)
+        .expect("Can propose election result");
+    let election_result: ViewElectionResultQueryResponse = election.view("viewElectionResult");
+    assert_eq!(election_result, None, "Proposed result is not stored");
 
-    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
-}
+    let error = election.update_err(ALICE_ADDR, "approveResult", &hash);
+    assert_eq!(error, Error::Unauthorized, "Admin is not an approver");
 
-/// Performs contract update at `register_guardian_public_key` entrypoint.
-fn register_guardian_public_key_update(
-    chain: &mut Chain,
-    address: &ContractAddress,
-    sender: &Address,
-    param: &RegisterGuardianPublicKeyParameter,
-) -> Result<ContractInvokeSuccess, ContractInvokeError> {
-    let payload = UpdateContractPayload {
-        amount:       Amount::zero(),
-        address:      *address,
-        receive_name: OwnedReceiveName::new_unchecked(
-            "election.registerGuardianPublicKey".to_string(),
-        ),
-        message:      OwnedParameter::from_serial(&param).expect("Parameter within size bounds"),
-    };
+    let other_hash = HashSha2256(
+        sha2::Sha256::digest(concordium_std::to_bytes(&vec![(0u32, vec![20u64, 10])])).into(),
+    );
+    let error = election.update_err(BOB_ADDR, "approveResult", &other_hash);
+    assert_eq!(
+        error,
+        Error::Malformed,
+        "Hash must match the proposed result"
+    );
 
-    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+    election
+        .update(BOB_ADDR, "approveResult", &hash)
+        .expect("Can approve proposed result");
+    let error = election.update_err(BOB_ADDR, "approveResult", &hash);
+    assert_eq!(error, Error::DuplicateEntry, "Cannot approve twice");
+    let election_result: ViewElectionResultQueryResponse = election.view("viewElectionResult");
+    assert_eq!(election_result, None, "Result not stored below threshold");
+
+    election
+        .update(DAVE_ADDR, "approveResult", &hash)
+        .expect("Can approve proposed result");
+    let proposal: Option<ResultProposal> = election.view("viewResultProposal");
+    assert_eq!(
+        proposal,
+        Some(ResultProposal {
+            result:    result.clone(),
+            approvals: vec![BOB, DAVE],
+        })
+    );
+    let election_result: ViewElectionResultQueryResponse = election.view("viewElectionResult");
+    let votes: Option<Vec<_>> =
+        election_result.map(|r| r.into_iter().map(|c| c.cummulative_votes).collect());
+    assert_eq!(votes, Some(vec![10, 20]), "Result stored at threshold");
+
+    // A new proposal discards the approvals of the previous one.
+    let new_result: PostResultParameter = vec![(0, vec![11, 20])];
+    election
+        .update(ALICE_ADDR, "postElectionResult", &new_result)
+        .expect("Can propose new election result");
+    let proposal: Option<ResultProposal> = election.view("viewResultProposal");
+    assert_eq!(
+        proposal.map(|p| p.approvals),
+        Some(Vec::new()),
+        "Approvals are reset"
+    );
+    let error = election.update_err(CAROLINE_ADDR, "approveResult", &hash);
+    assert_eq!(
+        error,
+        Error::Malformed,
+        "Approval of a replaced proposal is rejected"
+    );
 }
 
-/// Performs contract update at `register_guardian_encrypted_share` entrypoint.
-fn register_guardian_encrypted_share_update(
-    chain: &mut Chain,
-    address: &ContractAddress,
-    sender: &Address,
-    param: &RegisterGuardianEncryptedShareParameter,
-) -> Result<ContractInvokeSuccess, ContractInvokeError> {
-    let payload = UpdateContractPayload {
-        amount:       Amount::zero(),
-        address:      *address,
-        receive_name: OwnedReceiveName::new_unchecked(
-            "election.registerGuardianEncryptedShare".to_string(),
-        ),
-        message:      OwnedParameter::from_serial(&param).expect("Parameter within size bounds"),
-    };
+#[test]
+fn test_lock_election() {
+    let mut election = ElectionTestBuilder::new().build();
+    let result: PostResultParameter = vec![(0, vec![10, 20])];
+
+    election.transition_to_closed();
+    election
+        .update(ALICE_ADDR, "postEncryptedTally", &vec![0u8, 1, 2])
+        .expect("Can post encrypted tally");
+    let error = election.update_err(ALICE_ADDR, "lockElection", &());
+    assert_eq!(
+        error,
+        Error::IncorrectElectionPhase,
+        "Cannot lock without a result"
+    );
 
-    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+    election
+        .update(ALICE_ADDR, "postElectionResult", &result)
+        .expect("Can post election result");
+    let error = election.update_err(BOB_ADDR, "lockElection", &());
+    assert_eq!(error, Error::Unauthorized, "Only admin can lock");
+    election
+        .update(ALICE_ADDR, "lockElection", &())
+        .expect("Can lock election");
+    let config: ElectionConfig = election.view("viewConfig");
+    assert!(config.locked, "Lock is exposed in the config");
+
+    let error = election.update_err(ALICE_ADDR, "lockElection", &());
+    assert_eq!(error, Error::ElectionLocked, "Cannot lock twice");
+    let error = election.update_err(ALICE_ADDR, "postElectionResult", &vec![(0u32, vec![
+        20u64, 10,
+    ])]);
+    assert_eq!(error, Error::ElectionLocked);
+    let error = election.update_err(ALICE_ADDR, "postEncryptedTally", &vec![3u8]);
+    assert_eq!(error, Error::ElectionLocked);
+    let deadline = election.config.decryption_deadline;
+    let error = election.update_err(ALICE_ADDR, "resetFinalizationPhase", &(vec![BOB], deadline));
+    assert_eq!(error, Error::ElectionLocked);
+    let error = election.update_err(ALICE_ADDR, "extendDecryptionDeadline", &deadline);
+    assert_eq!(error, Error::ElectionLocked);
+    let error = election.update_err(BOB_ADDR, "postDecryptionShare", &vec![0u8]);
+    assert_eq!(error, Error::ElectionLocked);
+    let error = election.update_err(BOB_ADDR, "postDecryptionProofResponseShare", &vec![0u8]);
+    assert_eq!(error, Error::ElectionLocked);
+    let error = election.update_err(BOB_ADDR, "acknowledgeTally", &());
+    assert_eq!(error, Error::ElectionLocked);
+
+    let election_result: ViewElectionResultQueryResponse = election.view("viewElectionResult");
+    let votes: Option<Vec<_>> =
+        election_result.map(|r| r.into_iter().map(|c| c.cummulative_votes).collect());
+    assert_eq!(votes, Some(vec![10, 20]), "Result is unchanged");
 }
 
-/// Performs contract update at `register_guardian_complaint` entrypoint.
-fn register_guardian_status_update(
-    chain: &mut Chain,
-    address: &ContractAddress,
-    sender: &Address,
-    status: GuardianStatus,
-) -> Result<ContractInvokeSuccess, ContractInvokeError> {
-    let payload = UpdateContractPayload {
-        amount:       Amount::zero(),
-        address:      *address,
-        receive_name: OwnedReceiveName::new_unchecked(
-            "election.registerGuardianStatus".to_string(),
-        ),
-        message:      OwnedParameter::from_serial(&status).expect("Parameter within size bounds"),
-    };
+#[test]
+fn test_extend_decryption_deadline() {
+    let mut election = ElectionTestBuilder::new().build();
+    let deadline = election.config.decryption_deadline;
+    let extended = deadline
+        .checked_add(Duration::from_hours(1))
+        .expect("Timestamp does not overflow");
+
+    let error = election.update_err(ALICE_ADDR, "extendDecryptionDeadline", &extended);
+    assert_eq!(
+        error,
+        Error::IncorrectElectionPhase,
+        "Cannot extend the deadline before the election has ended"
+    );
 
-    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
-}
+    election.transition_to_closed();
+    election
+        .update(BOB_ADDR, "postDecryptionShare", &vec![0u8, 1, 2])
+        .expect("Share is accepted before the deadline");
 
-fn view_guardians_state(
-    chain: &mut Chain,
-    address: &ContractAddress,
-) -> Result<ContractInvokeSuccess, ContractInvokeError> {
-    let payload = UpdateContractPayload {
-        amount:       Amount::zero(),
-        address:      *address,
-        receive_name: OwnedReceiveName::new_unchecked("election.viewGuardiansState".to_string()),
-        message:      OwnedParameter::empty(),
-    };
+    let error = election.update_err(BOB_ADDR, "extendDecryptionDeadline", &extended);
+    assert_eq!(error, Error::Unauthorized, "Only admin can extend");
+    let error = election.update_err(ALICE_ADDR, "extendDecryptionDeadline", &deadline);
+    assert_eq!(
+        error,
+        Error::Malformed,
+        "New deadline must be after the current deadline"
+    );
+    election
+        .update(ALICE_ADDR, "extendDecryptionDeadline", &extended)
+        .expect("Admin can extend the deadline");
+    let config: ElectionConfig = election.view("viewConfig");
+    assert_eq!(config.decryption_deadline, extended);
+
+    election.transition_to_decryption_deadline_passed();
+    election
+        .update(CAROLINE_ADDR, "postDecryptionShare", &vec![3u8, 4, 5])
+        .expect("Share is accepted after the original deadline");
+    let error = election.update_err(ALICE_ADDR, "extendDecryptionDeadline", &extended);
+    assert_eq!(
+        error,
+        Error::Malformed,
+        "New deadline must be after the extended deadline"
+    );
 
-    chain.contract_invoke(ALICE, ALICE_ADDR, Energy::from(10_000), payload)
+    let guardians_state: GuardiansState = election.view("viewGuardiansState");
+    let bob_state = guardians_state
+        .iter()
+        .find(|(account, _)| *account == BOB)
+        .map(|(_, state)| state)
+        .expect("Bob is a guardian");
+    assert_eq!(
+        bob_state.decryption_share,
+        Some(vec![0u8, 1, 2]),
+        "Shares are kept when extending the deadline"
+    );
 }
 
-/// Invokes `config` entrypoint
-fn view_election_result(
-    chain: &mut Chain,
-    address: &ContractAddress,
-) -> Result<ContractInvokeSuccess, ContractInvokeError> {
-    let payload = UpdateContractPayload {
-        amount:       Amount::zero(),
-        address:      *address,
-        receive_name: OwnedReceiveName::new_unchecked("election.viewElectionResult".to_string()),
-        message:      OwnedParameter::empty(),
-    };
+#[test]
+fn test_update_candidates() {
+    let mut election = ElectionTestBuilder::new().build();
+    let candidates: UpdateCandidatesParameter = ["anna", "john", "paul"]
+        .into_iter()
+        .zip(0u8..)
+        .map(|(name, i)| ChecksumUrl {
+            url:  format!("https://candidates.concordium.com/{name}"),
+            hash: HashSha2256([i; 32]),
+        })
+        .collect();
 
-    chain.contract_invoke(ALICE, ALICE_ADDR, Energy::from(10_000), payload)
-}
+    let error = election.update_err(BOB_ADDR, "updateCandidates", &candidates);
+    assert_eq!(
+        error,
+        Error::Unauthorized,
+        "Only admin can update candidates"
+    );
+    let error = election.update_err(
+        ALICE_ADDR,
+        "updateCandidates",
+        &UpdateCandidatesParameter::new(),
+    );
+    assert_eq!(error, Error::Malformed, "Candidates cannot be empty");
+    let duplicates = vec![
+        candidates[0].clone(),
+        candidates[1].clone(),
+        candidates[0].clone(),
+    ];
+    let error = election.update_err(ALICE_ADDR, "updateCandidates", &duplicates);
+    assert_eq!(
+        error,
+        Error::DuplicateEntry,
+        "Duplicate candidates are rejected"
+    );
 
-/// Performs contract update at `register_votes` entrypoint.
-fn register_votes_update(
-    chain: &mut Chain,
-    address: &ContractAddress,
-    sender: &Address,
-    param: &RegisterVotesParameter,
-) -> Result<ContractInvokeSuccess, ContractInvokeError> {
-    let payload = UpdateContractPayload {
-        amount:       Amount::zero(),
-        address:      *address,
-        receive_name: OwnedReceiveName::new_unchecked("election.registerVotes".to_string()),
-        message:      OwnedParameter::from_serial(&param).expect("Parameter within size bounds"),
-    };
+    election
+        .update(ALICE_ADDR, "updateCandidates", &candidates)
+        .expect("Admin can update candidates before the election starts");
+    let config: ElectionConfig = election.view("viewConfig");
+    assert_eq!(config.candidates, candidates, "Candidates are replaced");
+    assert_eq!(
+        config.contest_sizes,
+        vec![3],
+        "The single contest contains the new candidates"
+    );
 
-    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+    election.transition_to_open();
+    let error = election.update_err(ALICE_ADDR, "updateCandidates", &candidates);
+    assert_eq!(
+        error,
+        Error::IncorrectElectionPhase,
+        "Cannot update candidates after the election has started"
+    );
 }
 
-/// Performs contract update at `post_decryption_share` entrypoint.
-fn post_decryption_share_update(
-    chain: &mut Chain,
-    address: &ContractAddress,
-    sender: &Address,
-    param: &Vec<u8>,
-) -> Result<ContractInvokeSuccess, ContractInvokeError> {
-    let payload = UpdateContractPayload {
-        amount:       Amount::zero(),
-        address:      *address,
-        receive_name: OwnedReceiveName::new_unchecked("election.postDecryptionShare".to_string()),
-        message:      OwnedParameter::from_serial(&param).expect("Parameter within size bounds"),
-    };
-
-    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+#[test]
+fn test_decryption_grace_period() {
+    let mut election = ElectionTestBuilder::new()
+        .modify(|param| param.decryption_grace_seconds = 60)
+        .build();
+    let config: ElectionConfig = election.view("viewConfig");
+    assert_eq!(config.decryption_grace_seconds, 60);
+
+    election.transition_to_decryption_deadline_passed();
+    election
+        .update(BOB_ADDR, "postDecryptionShare", &vec![0u8, 1, 2])
+        .expect("Share is accepted within the grace period");
+    election
+        .update(
+            CAROLINE_ADDR,
+            "postDecryptionShareForContest",
+            &PostDecryptionShareForContestParameter {
+                contest_index: 0,
+                share:         vec![0u8, 1, 2],
+            },
+        )
+        .expect("Share for contest is accepted within the grace period");
+
+    election
+        .chain
+        .tick_block_time(Duration::from_seconds(60))
+        .expect("Block time does not overflow");
+    let error = election.update_err(DAVE_ADDR, "postDecryptionShare", &vec![0u8, 1, 2]);
+    assert_eq!(
+        error,
+        Error::IncorrectElectionPhase,
+        "Share is rejected after the grace period"
+    );
 }
 
-/// Performs contract update at `post_decryption_proof_response_share`
-/// entrypoint.
-fn post_decryption_proof_response_share_update(
-    chain: &mut Chain,
-    address: &ContractAddress,
-    sender: &Address,
-    param: &Vec<u8>,
-) -> Result<ContractInvokeSuccess, ContractInvokeError> {
-    let payload = UpdateContractPayload {
-        amount:       Amount::zero(),
-        address:      *address,
-        receive_name: OwnedReceiveName::new_unchecked(
-            "election.postDecryptionProofResponseShare".to_string(),
-        ),
-        message:      OwnedParameter::from_serial(&param).expect("Parameter within size bounds"),
-    };
+#[test]
+fn test_decryption_proof_window() {
+    let mut election = ElectionTestBuilder::new()
+        .modify(|param| param.decryption_grace_seconds = 60)
+        .build();
+    let share = vec![0u8, 1, 2];
+
+    election.transition_to_closed();
+    election
+        .update(BOB_ADDR, "postDecryptionProofResponseShare", &share)
+        .expect("Proof is accepted right after the election end");
+
+    // The block time is now 1ms after the decryption deadline.
+    election.transition_to_decryption_deadline_passed();
+    let until_last_accepted = Duration::from_millis(60_000 + DECRYPTION_PROOF_WINDOW.millis() - 2);
+    election
+        .chain
+        .tick_block_time(until_last_accepted)
+        .expect("Block time does not overflow");
+    let error = election.update_err(DAVE_ADDR, "postDecryptionShare", &share);
+    assert_eq!(
+        error,
+        Error::IncorrectElectionPhase,
+        "Share is rejected during the proof window"
+    );
+    election
+        .update(CAROLINE_ADDR, "postDecryptionProofResponseShare", &share)
+        .expect("Proof is accepted until the end of the proof window");
 
-    chain.contract_update(SIGNER, ALICE, *sender, Energy::from(10_000), payload)
+    election
+        .chain
+        .tick_block_time(Duration::from_millis(1))
+        .expect("Block time does not overflow");
+    let error = election.update_err(DAVE_ADDR, "postDecryptionProofResponseShare", &share);
+    assert_eq!(
+        error,
+        Error::IncorrectElectionPhase,
+        "Proof is rejected after the proof window"
+    );
 }
 
-/// Invokes `config` entrypoint
-fn view_config(
-    chain: &mut Chain,
-    address: &ContractAddress,
-) -> Result<ContractInvokeSuccess, ContractInvokeError> {
-    let payload = UpdateContractPayload {
-        amount:       Amount::zero(),
-        address:      *address,
-        receive_name: OwnedReceiveName::new_unchecked("election.viewConfig".to_string()),
-        message:      OwnedParameter::empty(),
-    };
+#[test]
+fn test_display_order() {
+    let mut election = ElectionTestBuilder::new()
+        .modify(|param| param.display_order = vec![1, 0])
+        .build();
+    let config: ElectionConfig = election.view("viewConfig");
+    assert_eq!(
+        config.display_order,
+        vec![1, 0],
+        "Display order round-trips"
+    );
 
-    chain.contract_invoke(ALICE, ALICE_ADDR, Energy::from(10_000), payload)
+    let result: PostResultParameter = vec![(0, vec![10, 20])];
+    election.transition_to_closed();
+    election
+        .update(ALICE_ADDR, "postElectionResult", &result)
+        .expect("Can post election result");
+    let election_result: ViewElectionResultQueryResponse = election.view("viewElectionResult");
+    let expected_result: Vec<_> = election
+        .config
+        .candidates
+        .iter()
+        .zip(&result[0].1)
+        .map(|(candidate, &cummulative_votes)| CandidateResult {
+            candidate: candidate.clone(),
+            cummulative_votes,
+        })
+        .collect();
+    assert_eq!(
+        election_result,
+        Some(expected_result),
+        "Result is aligned with the ballot order of candidates"
+    );
 }
 
-fn new_chain_and_contract() -> (Chain, ContractAddress) {
-    let (mut chain, module_ref) = new_chain_and_module();
+#[test]
+fn test_view_guardians_state_range() {
+    let mut election = ElectionTestBuilder::new().build();
+    election
+        .update(CAROLINE_ADDR, "registerGuardianPublicKey", &vec![0u8, 1, 2])
+        .expect("Key registration should succeed");
+    let mut guardians_state: GuardiansState = election.view("viewGuardiansState");
+    guardians_state.sort_by_key(|g| g.1.index);
 
-    let candidates = vec![
-        ChecksumUrl {
-            url:  "https://candidates.concordium.com/john".to_string(),
-            hash: HashSha2256([0; 32]),
+    let guardian: Option<GuardianState> = election.view_with("viewGuardian", &CAROLINE);
+    assert_eq!(guardian.as_ref(), Some(&guardians_state[1].1));
+    let guardian: Option<GuardianState> = election.view_with("viewGuardian", &ALICE);
+    assert_eq!(guardian, None, "Admin is not a guardian");
+
+    let mut windowed_state = Vec::new();
+    for start_index in [1u32, 3] {
+        let mut window: GuardiansState =
+            election.view_with("viewGuardiansStateRange", &(start_index, 2u32));
+        window.sort_by_key(|g| g.1.index);
+        windowed_state.append(&mut window);
+    }
+    assert_eq!(
+        windowed_state, guardians_state,
+        "Windows cover the full view"
+    );
+
+    let window: GuardiansState = election.view_with("viewGuardiansStateRange", &(2u32, 1u32));
+    assert_eq!(window, guardians_state[1..2]);
+    let window: GuardiansState = election.view_with("viewGuardiansStateRange", &(3u32, u32::MAX));
+    assert_eq!(window, guardians_state[2..], "Window end saturates");
+    let window: GuardiansState = election.view_with("viewGuardiansStateRange", &(4u32, 2u32));
+    assert_eq!(window, Vec::new(), "No guardians beyond the last index");
+}
+
+#[test]
+fn test_contest_results() {
+    let candidates: Vec<_> = ["john", "mary", "paul"]
+        .into_iter()
+        .zip(0u8..)
+        .map(|(name, i)| ChecksumUrl {
+            url:  format!("https://candidates.concordium.com/{name}"),
+            hash: HashSha2256([i; 32]),
+        })
+        .collect();
+    let mut election = ElectionTestBuilder::new()
+        .candidates(candidates.clone())
+        .modify(|param| param.contest_sizes = vec![2, 1])
+        .build();
+    let config: ElectionConfig = election.view("viewConfig");
+    assert_eq!(config.contest_sizes, vec![2, 1], "Contest sizes round-trip");
+
+    election.transition_to_closed();
+    let error = election.update_err(ALICE_ADDR, "postElectionResult", &vec![(0u32, vec![
+        10u64, 20, 30,
+    ])]);
+    assert_eq!(
+        error,
+        Error::Malformed,
+        "Must post a result for each contest"
+    );
+    let error = election.update_err(ALICE_ADDR, "postElectionResult", &vec![
+        (0u32, vec![10u64]),
+        (1, vec![20, 30]),
+    ]);
+    assert_eq!(
+        error,
+        Error::Malformed,
+        "Must post a vote for each candidate of a contest"
+    );
+    let error = election.update_err(ALICE_ADDR, "postElectionResult", &vec![
+        (1u32, vec![30u64]),
+        (0, vec![10, 20]),
+    ]);
+    assert_eq!(error, Error::Malformed, "Contests must be in order");
+
+    let result: PostResultParameter = vec![(0, vec![10, 20]), (1, vec![30])];
+    election
+        .update(ALICE_ADDR, "postElectionResult", &result)
+        .expect("Can post election result");
+    let to_candidate_result =
+        |(candidate, cummulative_votes): (&ChecksumUrl, u64)| CandidateResult {
+            candidate: candidate.clone(),
+            cummulative_votes,
+        };
+
+    let contest_results: ViewContestResultsQueryResponse = election.view("viewContestResults");
+    let expected_results = vec![
+        ContestResultView {
+            contest_id: 0,
+            candidates: candidates[..2]
+                .iter()
+                .zip([10, 20])
+                .map(to_candidate_result)
+                .collect(),
         },
-        ChecksumUrl {
-            url:  "https://candidates.concordium.com/peter".to_string(),
-            hash: HashSha2256([1; 32]),
+        ContestResultView {
+            contest_id: 1,
+            candidates: candidates[2..]
+                .iter()
+                .zip([30])
+                .map(to_candidate_result)
+                .collect(),
         },
     ];
-    let guardians = vec![BOB, CAROLINE, DAVE];
-    let election_start = chrono::Utc::now()
-        .checked_add_signed(chrono::Duration::seconds(5))
-        .unwrap();
-    let election_end = election_start
-        .checked_add_days(chrono::Days::new(1))
-        .unwrap();
-    let decryption_deadline = election_end.checked_add_days(chrono::Days::new(1)).unwrap();
-    let eligible_voters = EligibleVoters {
-        data:       ChecksumUrl {
-            url:  "http://some.election/voters".to_string(),
-            hash: HashSha2256([0u8; 32]),
-        },
-        parameters: EligibleVotersParameters {
-            start_time: Timestamp::from_timestamp_millis(0),
-            end_time:   Timestamp::from_timestamp_millis(0),
-        },
-    };
-    let election_manifest = ChecksumUrl {
-        url:  "http://some.election/manifest".to_string(),
-        hash: HashSha2256([1u8; 32]),
-    };
-    let election_parameters = ChecksumUrl {
-        url:  "http://some.election/parameters".to_string(),
-        hash: HashSha2256([2u8; 32]),
-    };
+    assert_eq!(contest_results, Some(expected_results));
 
-    // Default admin account
-    let init_param = InitParameter {
-        admin_account: ALICE,
-        election_description: "Test election".to_string(),
-        election_start: election_start.try_into().expect("Valid datetime"),
-        election_end: election_end.try_into().expect("Valid datetime"),
-        decryption_deadline: decryption_deadline.try_into().expect("Valid datetime"),
-        candidates,
-        guardians,
-        eligible_voters,
-        election_manifest,
-        election_parameters,
-        delegation_string: "Something".into(),
+    let election_result: ViewElectionResultQueryResponse = election.view("viewElectionResult");
+    let expected_result: Vec<_> = candidates
+        .iter()
+        .zip([10, 20, 30])
+        .map(to_candidate_result)
+        .collect();
+    assert_eq!(
+        election_result,
+        Some(expected_result),
+        "Contest results are concatenated"
+    );
+}
+
+#[test]
+fn test_receive_acknowledge_tally() {
+    let (mut chain, contract_address) = new_chain_and_contract();
+    let config: InitParameter = view_config(&mut chain, &contract_address)
+        .expect("Can invoke config entrypoint")
+        .parse_return_value()
+        .expect("Can parse value");
+
+    let tally = vec![0, 1, 2, 5, 1, 6, 7];
+    let tally_other = vec![1, 2, 3, 4, 5, 1, 2, 3];
+
+    transition_to_closed(&mut chain, &config);
+
+    let error: Error = acknowledge_tally_update(&mut chain, &contract_address, &BOB_ADDR)
+        .expect_err("Acknowledging the tally should fail before it is registered")
+        .parse_return_value()
+        .expect("Can deserialize error");
+    assert_eq!(
+        error,
+        Error::IncorrectElectionPhase,
+        "Unexpected error type"
+    );
+
+    post_encrypted_tally_update(&mut chain, &contract_address, &ALICE_ADDR, &tally)
+        .expect("Can post encrypted tally");
+
+    acknowledge_tally_update(&mut chain, &contract_address, &BOB_ADDR)
+        .expect("Acknowledging the tally should succeed");
+    acknowledge_tally_update(&mut chain, &contract_address, &DAVE_ADDR)
+        .expect("Acknowledging the tally should succeed");
+
+    let error: Error = acknowledge_tally_update(&mut chain, &contract_address, &ALICE_ADDR)
+        .expect_err("Acknowledging should fail due to not being in the list of guardians")
+        .parse_return_value()
+        .expect("Deserializes to error type");
+    assert_eq!(error, Error::Unauthorized, "Unexpected error type");
+
+    let contract_sender = Address::Contract(ContractAddress {
+        index:    0,
+        subindex: 0,
+    });
+    let error: Error = acknowledge_tally_update(&mut chain, &contract_address, &contract_sender)
+        .expect_err("Cannot acknowledge from contract sender")
+        .parse_return_value()
+        .expect("Deserializes to error type");
+    assert_eq!(error, Error::Unauthorized, "Unexpected error type");
+
+    let error: Error = acknowledge_tally_update(&mut chain, &contract_address, &BOB_ADDR)
+        .expect_err("Acknowledging should fail due to duplicate entry")
+        .parse_return_value()
+        .expect("Deserializes to error type");
+    assert_eq!(error, Error::DuplicateEntry, "Unexpected error type");
+
+    let mut guardians_state: GuardiansState = view_guardians_state(&mut chain, &contract_address)
+        .expect("Can invoke entrypoint")
+        .parse_return_value()
+        .expect("Can parse value");
+    guardians_state.sort_by_key(|x| x.1.index);
+    let expected_result: GuardiansState = vec![
+        (BOB, GuardianState {
+            acknowledged_tally: true,
+            ..GuardianState::new(1)
+        }),
+        (CAROLINE, GuardianState::new(2)),
+        (DAVE, GuardianState {
+            acknowledged_tally: true,
+            ..GuardianState::new(3)
+        }),
+    ];
+    assert_eq!(guardians_state, expected_result);
+
+    // Registering a new tally resets the acknowledgements.
+    post_encrypted_tally_update(&mut chain, &contract_address, &ALICE_ADDR, &tally_other)
+        .expect("Can post encrypted tally");
+    acknowledge_tally_update(&mut chain, &contract_address, &CAROLINE_ADDR)
+        .expect("Acknowledging the tally should succeed");
+
+    let mut guardians_state: GuardiansState = view_guardians_state(&mut chain, &contract_address)
+        .expect("Can invoke entrypoint")
+        .parse_return_value()
+        .expect("Can parse value");
+    guardians_state.sort_by_key(|x| x.1.index);
+    let expected_result: GuardiansState = vec![
+        (BOB, GuardianState::new(1)),
+        (CAROLINE, GuardianState {
+            acknowledged_tally: true,
+            ..GuardianState::new(2)
+        }),
+        (DAVE, GuardianState::new(3)),
+    ];
+    assert_eq!(guardians_state, expected_result);
+
+    transition_to_decryption_deadline_passed(&mut chain, &config);
+
+    let error: Error = acknowledge_tally_update(&mut chain, &contract_address, &BOB_ADDR)
+        .expect_err("Acknowledging should fail when deadline has passed")
+        .parse_return_value()
+        .expect("Deserializes to error type");
+    assert_eq!(
+        error,
+        Error::IncorrectElectionPhase,
+        "Unexpected error type"
+    );
+}
+
+#[test]
+fn test_receive_result_posting_deadline() {
+    let (mut chain, contract_address) = new_chain_and_contract_with(|init_param| {
+        let result_posting_deadline = init_param
+            .decryption_deadline
+            .checked_add(Duration::from_days(1))
+            .expect("Does not overflow");
+        init_param.result_posting_deadline = Some(result_posting_deadline);
+    });
+    let config: InitParameter = view_config(&mut chain, &contract_address)
+        .expect("Can invoke config entrypoint")
+        .parse_return_value()
+        .expect("Can parse value");
+    let result_posting_deadline = config
+        .result_posting_deadline
+        .expect("Result posting deadline is set");
+    let tally = vec![0, 1, 2, 5, 1, 6, 7];
+    let result = vec![(0, vec![10; config.candidates.len()])];
+
+    transition_to_closed(&mut chain, &config);
+
+    post_encrypted_tally_update(&mut chain, &contract_address, &ALICE_ADDR, &tally)
+        .expect("Can post encrypted tally before the deadline");
+    post_election_result_update(&mut chain, &contract_address, &ALICE_ADDR, &result)
+        .expect("Can post election result before the deadline");
+
+    let dur_until_deadline_passed = chain
+        .block_time()
+        .duration_between(result_posting_deadline)
+        .checked_add(Duration::from_millis(1))
+        .expect("Does not overflow");
+    chain
+        .tick_block_time(dur_until_deadline_passed)
+        .expect("Block time does not overflow");
+
+    let error: Error =
+        post_encrypted_tally_update(&mut chain, &contract_address, &ALICE_ADDR, &tally)
+            .expect_err("Cannot post encrypted tally after the deadline")
+            .parse_return_value()
+            .expect("Deserializes to error type");
+    assert_eq!(
+        error,
+        Error::IncorrectElectionPhase,
+        "Unexpected error type"
+    );
+    let error: Error =
+        post_election_result_update(&mut chain, &contract_address, &ALICE_ADDR, &result)
+            .expect_err("Cannot post election result after the deadline")
+            .parse_return_value()
+            .expect("Deserializes to error type");
+    assert_eq!(
+        error,
+        Error::IncorrectElectionPhase,
+        "Unexpected error type"
+    );
+}
+
+#[test]
+fn test_events_match_schema() {
+    let mut election = ElectionTestBuilder::new().build();
+    let mut logged = Vec::new();
+    let mut log_events = |result: ContractInvokeSuccess| {
+        logged.extend(
+            result
+                .events()
+                .flat_map(|(_, events)| events)
+                .map(|event| event.as_ref().to_vec()),
+        )
     };
-    let init = initialize(&module_ref, &init_param, &mut chain).expect("Init contract succeeds");
 
-    (chain, init.contract_address)
+    let key: RegisterGuardianPublicKeyParameter = vec![0, 1, 2, 5, 1, 6, 7];
+    log_events(
+        election
+            .update(BOB_ADDR, "registerGuardianPublicKey", &key)
+            .expect("Key registration should succeed"),
+    );
+    election.transition_to_open();
+    let ballot = RegisterVotesParameter::from(vec![0u8, 32u8, 55u8, 3u8]);
+    log_events(
+        election
+            .update(ALICE_ADDR, "registerVotes", &ballot)
+            .expect("Can register votes"),
+    );
+    election.transition_to_closed();
+    let tally: PostEncryptedTallyParameter = vec![0, 1, 2, 5, 1, 6, 7];
+    log_events(
+        election
+            .update(ALICE_ADDR, "postEncryptedTally", &tally)
+            .expect("Can post encrypted tally"),
+    );
+
+    let schema = event_schema();
+    assert_eq!(
+        schema,
+        Event::get_type(),
+        "The embedded schema is that of `Event`"
+    );
+    let decoded: Vec<_> = logged
+        .iter()
+        .map(|event| {
+            let json = schema
+                .to_json(&mut std::io::Cursor::new(event))
+                .expect("Event can be decoded through the schema");
+            assert_eq!(
+                &schema
+                    .serial_value(&json)
+                    .expect("Decoded event can be serialized"),
+                event,
+                "Decoding through the schema round-trips"
+            );
+            json
+        })
+        .collect();
+    assert_eq!(decoded.len(), 3, "One event is logged by each entrypoint");
+    assert_eq!(decoded[0]["GuardianPublicKeyRegistered"]["index"], 1);
+    assert_eq!(
+        decoded[1]["VoteAccepted"]["account"],
+        ALICE.to_string().as_str()
+    );
+    assert!(decoded[1]["VoteAccepted"].get("ballot_hash").is_some());
+    assert!(decoded[2].get("EncryptedTallyPosted").is_some());
+}
+
+#[test]
+fn test_view_encrypted_tally_hash() {
+    let mut election = ElectionTestBuilder::new().build();
+
+    let hash: Option<HashSha2256> = election.view("viewEncryptedTallyHash");
+    assert_eq!(hash, None, "No hash before the tally is registered");
+
+    let tally: PostEncryptedTallyParameter = vec![0, 1, 2, 5, 1, 6, 7];
+    election.transition_to_closed();
+    let result = election
+        .update(ALICE_ADDR, "postEncryptedTally", &tally)
+        .expect("Can post encrypted tally");
+    let events: Vec<Event> = result
+        .events()
+        .flat_map(|(_, events)| events)
+        .map(|event| event.parse().expect("Can parse event"))
+        .collect();
+    assert_eq!(events, vec![Event::EncryptedTallyPosted]);
+
+    let hash: Option<HashSha2256> = election.view("viewEncryptedTallyHash");
+    let expected = HashSha2256(sha2::Sha256::digest(&tally).into());
+    assert_eq!(hash, Some(expected), "Hash matches the registered tally");
+}
+
+#[test]
+fn test_view_election_result_hash() {
+    let mut election = ElectionTestBuilder::new().build();
+
+    let hash: Option<HashSha2256> = election.view("viewElectionResultHash");
+    assert_eq!(hash, None, "No hash before the result is registered");
+
+    let result: PostResultParameter = vec![(0, vec![10, 20])];
+    election.transition_to_closed();
+    election
+        .update(ALICE_ADDR, "postElectionResult", &result)
+        .expect("Can post election result");
+
+    let hash: Option<HashSha2256> = election.view("viewElectionResultHash");
+    let expected = HashSha2256(sha2::Sha256::digest(concordium_std::to_bytes(&result)).into());
+    assert_eq!(hash, Some(expected), "Hash matches the registered result");
+}
+
+#[test]
+fn test_view_guardian_progress() {
+    let mut election = ElectionTestBuilder::new().build();
+
+    let progress: GuardianProgress = election.view("viewGuardianProgress");
+    assert_eq!(progress, GuardianProgress {
+        total: 3,
+        ..Default::default()
+    });
+
+    let key: RegisterGuardianPublicKeyParameter = vec![0, 1, 2];
+    election
+        .update(BOB_ADDR, "registerGuardianPublicKey", &key)
+        .expect("Key registration should succeed");
+    election
+        .update(CAROLINE_ADDR, "registerGuardianPublicKey", &key)
+        .expect("Key registration should succeed");
+    let share: RegisterGuardianEncryptedShareParameter = vec![3, 4, 5];
+    election
+        .update(BOB_ADDR, "registerGuardianEncryptedShare", &share)
+        .expect("Share registration should succeed");
+    election
+        .update(
+            DAVE_ADDR,
+            "registerGuardianStatus",
+            &GuardianStatus::VerificationSuccessful,
+        )
+        .expect("Status registration should succeed");
+
+    let progress: GuardianProgress = election.view("viewGuardianProgress");
+    assert_eq!(progress, GuardianProgress {
+        total: 3,
+        with_public_key: 2,
+        with_encrypted_share: 1,
+        with_status: 1,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn test_view_election_phase() {
+    let mut election = ElectionTestBuilder::new()
+        .modify(|param| param.decryption_grace_seconds = 60)
+        .build();
+    let phase: ElectionPhase = election.view("viewElectionPhase");
+    assert_eq!(phase, ElectionPhase::Setup);
+
+    election.transition_to_open();
+    let phase: ElectionPhase = election.view("viewElectionPhase");
+    assert_eq!(phase, ElectionPhase::Voting);
+
+    election.transition_to_closed();
+    let phase: ElectionPhase = election.view("viewElectionPhase");
+    assert_eq!(phase, ElectionPhase::Tally);
+
+    election.transition_to_decryption_deadline_passed();
+    let phase: ElectionPhase = election.view("viewElectionPhase");
+    assert_eq!(
+        phase,
+        ElectionPhase::Tally,
+        "Shares are accepted within the grace period"
+    );
+
+    election
+        .chain
+        .tick_block_time(Duration::from_seconds(60))
+        .expect("Block time does not overflow");
+    let phase: ElectionPhase = election.view("viewElectionPhase");
+    assert_eq!(phase, ElectionPhase::Finalization);
+
+    election
+        .update(ALICE_ADDR, "postEncryptedTally", &vec![0u8, 1, 2])
+        .expect("Can post encrypted tally");
+    election
+        .update(ALICE_ADDR, "postElectionResult", &vec![(0u32, vec![
+            10u64, 20,
+        ])])
+        .expect("Can post election result");
+    let phase: ElectionPhase = election.view("viewElectionPhase");
+    assert_eq!(phase, ElectionPhase::Finalized);
 }
 
-fn new_chain_and_module() -> (Chain, ModuleReference) {
-    let now = chrono::Utc::now().try_into().unwrap();
-    // Initialize the test chain.
-    let mut chain = ChainBuilder::new()
-        .block_time(now)
-        .build()
-        .expect("Can build chain");
-    // Create the test account.
-    chain.create_account(Account::new(ALICE, ACC_INITIAL_BALANCE));
-    chain.create_account(Account::new(BOB, ACC_INITIAL_BALANCE));
-    chain.create_account(Account::new(CAROLINE, ACC_INITIAL_BALANCE));
-    chain.create_account(Account::new(DAVE, ACC_INITIAL_BALANCE));
-    // Load the module.
-    let module = module_load_v1("./concordium-out/module.wasm.v1").expect("Module exists at path");
-    // Deploy the module.
-    let deployment = chain
-        .module_deploy_v1(SIGNER, ALICE, module)
-        .expect("Deploy valid module");
-
-    (chain, deployment.module_reference)
+#[test]
+fn test_view_can_decrypt() {
+    let mut election = ElectionTestBuilder::new()
+        .modify(|param| param.threshold = 2)
+        .build();
+    election.transition_to_closed();
+
+    let can_decrypt: CanDecrypt = election.view("viewCanDecrypt");
+    assert_eq!(can_decrypt, CanDecrypt {
+        ready:        false,
+        contributing: 0,
+        threshold:    2,
+        missing:      vec![1, 2, 3],
+    });
+
+    // Below the threshold, a share without a proof does not contribute.
+    for guardian in [BOB_ADDR, CAROLINE_ADDR] {
+        election
+            .update(guardian, "postDecryptionShare", &vec![0u8, 1, 2])
+            .expect("Share registration should succeed");
+    }
+    election
+        .update(BOB_ADDR, "postDecryptionProofResponseShare", &vec![3u8])
+        .expect("Proof registration should succeed");
+    let can_decrypt: CanDecrypt = election.view("viewCanDecrypt");
+    assert_eq!(can_decrypt, CanDecrypt {
+        ready:        false,
+        contributing: 1,
+        threshold:    2,
+        missing:      vec![2, 3],
+    });
+
+    // At the threshold.
+    election
+        .update(CAROLINE_ADDR, "postDecryptionProofResponseShare", &vec![
+            3u8,
+        ])
+        .expect("Proof registration should succeed");
+    let can_decrypt: CanDecrypt = election.view("viewCanDecrypt");
+    assert_eq!(can_decrypt, CanDecrypt {
+        ready:        true,
+        contributing: 2,
+        threshold:    2,
+        missing:      vec![3],
+    });
+
+    // Above the threshold, with a share registered for a single contest.
+    election
+        .update(
+            DAVE_ADDR,
+            "postDecryptionShareForContest",
+            &PostDecryptionShareForContestParameter {
+                contest_index: 0,
+                share:         vec![0u8, 1, 2],
+            },
+        )
+        .expect("Share registration should succeed");
+    election
+        .update(DAVE_ADDR, "postDecryptionProofResponseShare", &vec![3u8])
+        .expect("Proof registration should succeed");
+    let can_decrypt: CanDecrypt = election.view("viewCanDecrypt");
+    assert_eq!(can_decrypt, CanDecrypt {
+        ready:        true,
+        contributing: 3,
+        threshold:    2,
+        missing:      vec![],
+    });
+
+    // Excluded guardians are neither contributing nor missing.
+    let deadline = election.config.decryption_deadline;
+    election
+        .update(
+            ALICE_ADDR,
+            "resetFinalizationPhase",
+            &(vec![DAVE], deadline),
+        )
+        .expect("Can reset finalization phase");
+    let can_decrypt: CanDecrypt = election.view("viewCanDecrypt");
+    assert_eq!(can_decrypt, CanDecrypt {
+        ready:        false,
+        contributing: 0,
+        threshold:    2,
+        missing:      vec![1, 2],
+    });
 }
 
-/// Helper method for initializing the contract.
-fn initialize(
-    module_ref: &ModuleReference,
-    init_param: &InitParameter,
-    chain: &mut Chain,
-) -> Result<ContractInitSuccess, ContractInitError> {
-    let payload = InitContractPayload {
-        amount:    Amount::zero(),
-        mod_ref:   *module_ref,
-        init_name: OwnedContractName::new_unchecked("init_election".to_string()),
-        param:     OwnedParameter::from_serial(init_param).expect("Parameter within size bounds"),
+#[test]
+fn test_view_guardian_progress_partial_contests() {
+    let mut election = ElectionTestBuilder::new()
+        .modify(|param| param.contest_sizes = vec![1, 1])
+        .build();
+    election.transition_to_closed();
+
+    let share_for_contest = |contest_index| PostDecryptionShareForContestParameter {
+        contest_index,
+        share: vec![0u8, 1, 2],
     };
-    // Initialize the contract.
-    chain.contract_init(SIGNER, ALICE, Energy::from(10_000), payload)
+    election
+        .update(
+            BOB_ADDR,
+            "postDecryptionShareForContest",
+            &share_for_contest(0),
+        )
+        .expect("Share registration should succeed");
+    let progress: GuardianProgress = election.view("viewGuardianProgress");
+    assert_eq!(
+        progress.with_decryption_share, 0,
+        "A share for only some contests is not counted"
+    );
+
+    election
+        .update(
+            BOB_ADDR,
+            "postDecryptionShareForContest",
+            &share_for_contest(1),
+        )
+        .expect("Share registration should succeed");
+    let progress: GuardianProgress = election.view("viewGuardianProgress");
+    assert_eq!(
+        progress.with_decryption_share, 1,
+        "Shares for every contest are counted"
+    );
+}
+
+#[test]
+fn test_view_can_decrypt_partial_contests() {
+    let mut election = ElectionTestBuilder::new()
+        .modify(|param| {
+            param.threshold = 1;
+            param.contest_sizes = vec![1, 1];
+        })
+        .build();
+    election.transition_to_closed();
+
+    election
+        .update(
+            BOB_ADDR,
+            "postDecryptionShareForContest",
+            &PostDecryptionShareForContestParameter {
+                contest_index: 0,
+                share:         vec![0u8, 1, 2],
+            },
+        )
+        .expect("Share registration should succeed");
+    election
+        .update(BOB_ADDR, "postDecryptionProofResponseShare", &vec![3u8])
+        .expect("Proof registration should succeed");
+    let can_decrypt: CanDecrypt = election.view("viewCanDecrypt");
+    assert_eq!(
+        can_decrypt,
+        CanDecrypt {
+            ready:        false,
+            contributing: 0,
+            threshold:    1,
+            missing:      vec![1, 2, 3],
+        },
+        "A share for only some contests does not contribute"
+    );
+
+    election
+        .update(
+            BOB_ADDR,
+            "postDecryptionShareForContest",
+            &PostDecryptionShareForContestParameter {
+                contest_index: 1,
+                share:         vec![4u8, 5, 6],
+            },
+        )
+        .expect("Share registration should succeed");
+    let can_decrypt: CanDecrypt = election.view("viewCanDecrypt");
+    assert_eq!(
+        can_decrypt,
+        CanDecrypt {
+            ready:        true,
+            contributing: 1,
+            threshold:    1,
+            missing:      vec![2, 3],
+        },
+        "Shares for every contest contribute"
+    );
 }
@@ -13,38 +13,56 @@ use concordium_rust_sdk::{
         self as concordium_std, AccountAddress, Amount, OwnedEntrypointName,
     },
     types::{
-        hashes::TransactionHash,
+        hashes::{BlockHash, TransactionHash},
         queries::BlockInfo,
-        smart_contracts::{OwnedContractName, WasmModule},
+        smart_contracts::{ModuleReference, OwnedContractName, WasmModule},
         AbsoluteBlockHeight, AccountAddressEq, AccountIndex, AccountTransactionEffects,
-        BlockItemSummaryDetails, ContractAddress, WalletAccount,
+        BlockItemSummary, BlockItemSummaryDetails, ContractAddress, ContractTraceElement,
+        ProtocolVersion, WalletAccount,
     },
     v2::{self as sdk, BlockIdentifier},
 };
 use concordium_std::schema::SchemaType;
 use eg::{
     ballot::BallotEncrypted,
+    ballot_style::{BallotStyle, BallotStyleIndex},
+    contest_selection::ContestSelection,
+    device::Device,
     election_manifest::{ContestIndex, ElectionManifest},
     election_parameters::ElectionParameters,
     election_record::PreVotingData,
     guardian::GuardianIndex,
     guardian_public_key::GuardianPublicKey,
-    verifiable_decryption::VerifiableDecryption,
+    guardian_secret_key::GuardianSecretKey,
+    guardian_share::{GuardianEncryptedShare, GuardianSecretKeyShare},
+    verifiable_decryption::{
+        CombinedDecryptionShare, DecryptionProof, DecryptionShare, DecryptionShareResult,
+        VerifiableDecryption,
+    },
 };
 use election_common::{
-    decode, encode, get_scaling_factor, EncryptedTally, GuardianDecryption,
-    GuardianDecryptionProof, HttpClient, WeightRow,
+    contract as election_contract, decode, decode_guardian_decryption, election_result_hash,
+    encode, fixed_parameters_version, get_scaling_factor, get_scaling_factor_with_unit,
+    has_decryption_share, joint_public_key_fingerprint, missing_guardian_keys,
+    standard_parameters_version, EncodedGuardianDecryption, EncryptedTally, GuardianDecryption,
+    GuardianDecryptionProof, GuardianDecryptionProofState, HttpClient, WeightRow,
+    ELECTIONGUARD_VERSION, MICRO_CCD_PER_CCD,
 };
 use futures::TryStreamExt;
-use indicatif::{ProgressBar, ProgressStyle};
-use rayon::iter::{IntoParallelIterator, ParallelIterator as _};
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator as _};
 use sha2::Digest as _;
 use std::{
     collections::{BTreeMap, BTreeSet},
     ffi::OsStr,
     fmt::Debug,
-    io::Write,
+    io::{IsTerminal, Write},
     str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
 };
 
 /// Command line configuration of the application.
@@ -58,9 +76,77 @@ struct Args {
         default_value = "http://localhost:20001",
         global = true
     )]
-    node_endpoint: concordium_rust_sdk::v2::Endpoint,
+    node_endpoint:        concordium_rust_sdk::v2::Endpoint,
+    #[arg(
+        long = "no-progress",
+        help = "Do not display progress bars. Progress is instead logged periodically. This is \
+                the default if stderr is not a terminal.",
+        global = true
+    )]
+    no_progress:          bool,
+    #[arg(
+        long = "connect-timeout-secs",
+        help = "The timeout in seconds for establishing a connection to the node.",
+        default_value_t = 5,
+        global = true
+    )]
+    connect_timeout_secs: u64,
+    #[arg(
+        long = "request-timeout-secs",
+        help = "The timeout in seconds for individual requests to the node. This does not apply \
+                to streaming queries, such as the ones used when indexing the chain.",
+        default_value_t = 10,
+        global = true
+    )]
+    request_timeout_secs: u64,
+    #[arg(
+        long = "max-blocks",
+        help = "Stop indexing the chain after this many blocks. This is only meant for quick \
+                testing, as results computed from the chain will be partial. NOT FOR PRODUCTION \
+                USE.",
+        global = true
+    )]
+    max_blocks:           Option<u64>,
+    #[arg(
+        long = "block-batch-size",
+        help = "The maximum number of blocks processed together when tallying. The ballots in \
+                a batch are verified in parallel, so larger batches speed up indexing of blocks \
+                with few ballots each.",
+        default_value_t = 1,
+        value_parser = clap::value_parser!(u16).range(1..),
+        global = true
+    )]
+    block_batch_size:     u16,
+    #[arg(
+        long = "parameters-version",
+        help = "The expected version of the fixed parameters of the election, as printed by \
+                `new-election`. The parameters of the election are checked against this before \
+                tallying and decrypting. Defaults to the version of the standard parameters of \
+                the electionguard library in use.",
+        global = true
+    )]
+    parameters_version:   Option<contract::HashSha2256>,
+    #[arg(
+        long = "output",
+        help = "The format of the output. With `json` a single JSON object describing the \
+                outcome is written to stdout, and informational messages are not printed. Only \
+                supported by `final-result`.",
+        value_enum,
+        default_value_t = OutputFormat::Human,
+        global = true
+    )]
+    output:               OutputFormat,
     #[command(subcommand)]
-    command:       Command,
+    command:              Command,
+}
+
+/// The format of the output of commands supporting machine-readable output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    /// Human-readable output, with informational messages on stderr.
+    Human,
+    /// A single JSON object on stdout.
+    Json,
 }
 
 /// Describes the possible locations of a candidate metadata file
@@ -95,68 +181,318 @@ struct NewElectionArgs {
         help = "Path to the file containing the Concordium account keys exported from the wallet. \
                 This will be the admin account of the election."
     )]
-    admin:                std::path::PathBuf,
+    admin: std::path::PathBuf,
     #[clap(
         long = "module",
         help = "Path of the Concordium smart contract module."
     )]
-    module:               std::path::PathBuf,
+    module: std::path::PathBuf,
     #[arg(
         long = "base-url",
         help = "Base url where the election data is accessible. This is recorded in the contract."
     )]
-    base_url:             url::Url,
+    base_url: url::Url,
     #[arg(
         long = "election-start",
         help = "The start time of the election. The format is ISO-8601, e.g. 2024-01-23T12:13:14Z."
     )]
-    election_start:       chrono::DateTime<chrono::Utc>,
+    election_start: chrono::DateTime<chrono::Utc>,
     #[arg(
         long = "election-end",
         help = "The end time of the election. The format is ISO-8601, e.g. 2024-01-23T12:13:14Z."
     )]
-    election_end:         chrono::DateTime<chrono::Utc>,
+    election_end: chrono::DateTime<chrono::Utc>,
     #[arg(
         long = "decryption-deadline",
         help = "The deadline for guardians to register decryption shares. The format is ISO-8601, \
                 e.g. 2024-01-23T12:13:14Z."
     )]
-    decryption_deadline:  chrono::DateTime<chrono::Utc>,
+    decryption_deadline: chrono::DateTime<chrono::Utc>,
+    #[arg(
+        long = "result-posting-deadline",
+        help = "The deadline for posting the encrypted tally and the election result. If not \
+                given, results can be posted at any time after the election has ended. The format \
+                is ISO-8601, e.g. 2024-01-23T12:13:14Z."
+    )]
+    result_posting_deadline: Option<chrono::DateTime<chrono::Utc>>,
+    #[arg(
+        long = "result-approver",
+        help = "Account that must approve the election result before it is stored in the \
+                contract. This can be given multiple times. If not given, the result posted by \
+                the admin account is stored directly."
+    )]
+    result_approvers: Vec<AccountAddress>,
+    #[arg(
+        long = "result-approval-threshold",
+        help = "The number of approvals needed for the election result to be stored. Defaults to \
+                the number of result approvers."
+    )]
+    approval_threshold: Option<u32>,
     #[arg(
         long = "delegation-string",
         help = "The string to identify vote delegations."
     )]
-    delegation_string:    String,
+    delegation_string: String,
     #[arg(long = "guardian", help = "The account addresses of guardians..")]
-    guardians:            Vec<AccountAddress>,
+    guardians: Vec<AccountAddress>,
     #[arg(
         long = "threshold",
         help = "Threshold for the number of guardians needed."
     )]
-    threshold:            u32,
+    threshold: u32,
     #[arg(
         long = "candidate",
         help = "The URL to candidates metadata. The order matters."
     )]
-    candidates:           Vec<CandidateLocation>,
+    candidates: Vec<CandidateLocation>,
     #[clap(long = "out", help = "Path where files produced are written to")]
-    out:                  std::path::PathBuf,
+    out: std::path::PathBuf,
     #[clap(
         long = "voters-file",
         help = "Path to the file with a list of eligible accounts with their weights."
     )]
-    voters_file:          std::path::PathBuf,
+    voters_file: std::path::PathBuf,
     #[clap(
         long = "voters-params-file",
         help = "Path to the file containing the parameters used to generate the `voters-file`."
     )]
-    voters_params_file:   std::path::PathBuf,
+    voters_params_file: std::path::PathBuf,
     #[clap(
         long = "description",
-        help = "A descriptive title of the election. This is ideally short as it is used in \
-                applications as a title."
+        help = "A descriptive title of the election. This is used in applications as a title, \
+                hence it must be at most `--max-description-length` bytes and not contain control \
+                characters."
     )]
     election_description: String,
+    #[clap(
+        long = "max-description-length",
+        help = "The maximum length in bytes of the description. This can be at most 1024. If not \
+                given, the default of the contract of 256 bytes applies."
+    )]
+    max_description_length: Option<u32>,
+    #[clap(
+        long = "display-order",
+        help = "The zero-based index of the candidate to display at the next position. If given, \
+                it must be given once for each candidate. This allows applications to display \
+                candidates in a different order than on the ballot, e.g. alphabetically. If not \
+                given, candidates are displayed in ballot order."
+    )]
+    display_order: Vec<u32>,
+    #[arg(
+        long = "decryption-grace-seconds",
+        help = "The number of seconds after the decryption deadline during which decryption \
+                shares are still accepted. This allows shares submitted shortly before the \
+                deadline to be registered despite network delays.",
+        default_value_t = 0
+    )]
+    decryption_grace_seconds: u64,
+    #[clap(
+        long = "layout",
+        help = "How the files of the election are laid out in `--out`, and correspondingly under \
+                `--base-url`.",
+        value_enum,
+        default_value_t = OutputLayout::Flat
+    )]
+    layout: OutputLayout,
+}
+
+/// How the files of an election are laid out in the output directory of
+/// `new-election`, and correspondingly under the base URL of the election.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum OutputLayout {
+    /// The election manifest and parameters are placed directly in the
+    /// output directory, and the eligible voters and candidates are expected
+    /// to be served next to them.
+    Flat,
+    /// The layout served by the voting dApp. The election manifest and
+    /// parameters are placed in `static/electionguard/`, and the eligible
+    /// voters in `static/concordium/`. Candidates read from disk and the
+    /// eligible voters are copied into the output directory.
+    Static,
+}
+
+impl OutputLayout {
+    /// The directory, relative to the output directory and the base URL,
+    /// of the election manifest and parameters.
+    fn electionguard_dir(self) -> &'static str {
+        match self {
+            OutputLayout::Flat => "",
+            OutputLayout::Static => "static/electionguard/",
+        }
+    }
+
+    /// The directory, relative to the output directory and the base URL,
+    /// of the eligible voters.
+    fn concordium_dir(self) -> &'static str {
+        match self {
+            OutputLayout::Flat => "",
+            OutputLayout::Static => "static/concordium/",
+        }
+    }
+
+    /// Whether the files supplied to `new-election` are copied into the
+    /// output directory, so that it can be served as is.
+    fn copy_inputs(self) -> bool { matches!(self, OutputLayout::Static) }
+
+    /// The path, relative to the output directory and the base URL, of the
+    /// election manifest.
+    fn manifest_path(self) -> String {
+        format!("{}election-manifest.json", self.electionguard_dir())
+    }
+
+    /// The path, relative to the output directory and the base URL, of the
+    /// election parameters.
+    fn parameters_path(self) -> String {
+        format!("{}election-parameters.json", self.electionguard_dir())
+    }
+
+    /// The path, relative to the output directory and the base URL, of the
+    /// eligible voters file named `filename`.
+    fn eligible_voters_path(self, filename: &str) -> String {
+        format!("{}{filename}", self.concordium_dir())
+    }
+
+    /// The path, relative to the output directory and the base URL, of the
+    /// candidate file named `filename` read from disk.
+    fn candidate_path(self, filename: &str) -> String { format!("candidates/{filename}") }
+}
+
+/// Write `data` to the file at `path` relative to the output directory `out`,
+/// creating any missing directories.
+fn write_output(out: &std::path::Path, path: &str, data: &[u8]) -> anyhow::Result<()> {
+    let file = out.join(path);
+    if let Some(dir) = file.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Unable to create {}.", dir.display()))?;
+    }
+    std::fs::write(&file, data).with_context(|| format!("Unable to write {}.", file.display()))
+}
+
+#[derive(Debug, clap::Parser)]
+struct ValidateElectionFilesArgs {
+    #[arg(long = "manifest", help = "Path to the election manifest.")]
+    manifest:   std::path::PathBuf,
+    #[arg(long = "parameters", help = "Path to the election parameters.")]
+    parameters: std::path::PathBuf,
+    #[arg(
+        long = "candidate",
+        help = "The URL or path to candidates metadata. The order matters."
+    )]
+    candidates: Vec<CandidateLocation>,
+    #[arg(long = "guardian", help = "The account addresses of guardians.")]
+    guardians:  Vec<AccountAddress>,
+    #[arg(
+        long = "threshold",
+        help = "Threshold for the number of guardians needed. If given, this is checked against \
+                the threshold in the election parameters."
+    )]
+    threshold:  Option<u32>,
+}
+
+#[derive(Debug, clap::Parser)]
+struct CandidatesFromCsvArgs {
+    #[arg(
+        long = "input",
+        help = "The CSV file with a row for each candidate. The columns are `name`, `imageUrl`, \
+                and `descriptionUrl`. The order of the rows is the order of the candidates in the \
+                election."
+    )]
+    input:    std::path::PathBuf,
+    #[arg(
+        long = "out",
+        help = "Directory to write the candidate metadata files into."
+    )]
+    out:      std::path::PathBuf,
+    #[arg(
+        long = "base-url",
+        help = "Base url where the election data will be accessible. This must match the \
+                `--base-url` given to `new-election`."
+    )]
+    base_url: url::Url,
+}
+
+#[derive(Debug, clap::Parser)]
+struct EstimateCostArgs {
+    #[arg(long = "guardians", help = "The number of guardians of the election.")]
+    guardians:  u32,
+    #[arg(
+        long = "candidates",
+        help = "The number of candidates of the election."
+    )]
+    candidates: u32,
+    #[arg(
+        long = "threshold",
+        help = "Threshold for the number of guardians needed. Defaults to the number of \
+                guardians, which gives an upper bound on the cost."
+    )]
+    threshold:  Option<u32>,
+    #[arg(
+        long = "ballots",
+        help = "The expected number of ballots submitted.",
+        default_value_t = 0
+    )]
+    ballots:    u64,
+    #[arg(
+        long = "module",
+        help = "Path of the Concordium smart contract module. If given, the cost of deploying it \
+                is included."
+    )]
+    module:     Option<std::path::PathBuf>,
+}
+
+/// The weight strategies that can be selected on the command line.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum WeightStrategyKind {
+    /// The time-weighted average balance.
+    #[value(alias = "mean")]
+    LinearAverage,
+    /// The time-weighted median balance. This is resistant to short-lived
+    /// balance spikes.
+    Median,
+    /// The minimum balance held.
+    Min,
+    /// The square root of the time-weighted average balance in CCD. This
+    /// reduces the influence of large holders compared to the average.
+    Quadratic,
+}
+
+impl WeightStrategyKind {
+    /// The strategy selected.
+    fn strategy(self) -> &'static dyn WeightStrategy {
+        match self {
+            WeightStrategyKind::LinearAverage => &LinearAverage,
+            WeightStrategyKind::Median => &Median,
+            WeightStrategyKind::Min => &Min,
+            WeightStrategyKind::Quadratic => &Quadratic,
+        }
+    }
+}
+
+/// How the delegation string is expected to be encoded in the memo of a
+/// delegating transfer. Wallets are supposed to CBOR encode the string, but
+/// some may put the raw UTF-8 bytes in the memo instead.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum MemoEncoding {
+    /// The memo is a CBOR encoded string.
+    Cbor,
+    /// The memo is the UTF-8 encoding of the string without CBOR framing.
+    Raw,
+    /// The memo is decoded as CBOR if possible, and otherwise as UTF-8.
+    Auto,
+}
+
+impl MemoEncoding {
+    /// Decode the string in the memo, returning `None` if the memo is not
+    /// encoded as expected.
+    fn decode(self, memo: &[u8]) -> Option<String> {
+        let cbor = || serde_cbor::from_slice::<String>(memo).ok();
+        let raw = || std::str::from_utf8(memo).ok().map(String::from);
+        match self {
+            MemoEncoding::Cbor => cbor(),
+            MemoEncoding::Raw => raw(),
+            MemoEncoding::Auto => cbor().or_else(raw),
+        }
+    }
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -180,14 +516,60 @@ enum Command {
     /// Create a new smart contract instance, together with election parameters.
     #[command(name = "new-election")]
     NewElection(Box<NewElectionArgs>),
+    /// Check that the election manifest and parameters are consistent with
+    /// each other, and with the candidates and guardians of the election. This
+    /// is meant to be used before `new-election` with files prepared
+    /// separately.
+    #[command(name = "validate-election-files")]
+    ValidateElectionFiles(#[clap(flatten)] ValidateElectionFilesArgs),
+    /// Write candidate metadata files from a CSV file with the columns
+    /// `name`, `imageUrl`, and `descriptionUrl`, and print the corresponding
+    /// `--candidate` arguments for `new-election`.
+    #[command(name = "candidates-from-csv")]
+    CandidatesFromCsv(#[clap(flatten)] CandidatesFromCsvArgs),
+    /// Estimate the cost of running an election, broken down by the
+    /// transactions needed.
+    #[command(name = "estimate-cost")]
+    EstimateCost(#[clap(flatten)] EstimateCostArgs),
+    /// Check that the guardian accounts are distinct and exist on chain. This
+    /// is also checked by `new-election` before anything is deployed, but
+    /// can be run on its own while preparing the election.
+    #[command(name = "check-guardians")]
+    CheckGuardians {
+        #[arg(long = "guardian", help = "The account addresses of guardians.")]
+        guardians: Vec<AccountAddress>,
+    },
+    /// Check that the node can be used by the other commands, i.e. that it can
+    /// be reached and is caught up with the chain, and print its version and
+    /// the protocol version of the chain.
+    #[command(name = "check-node")]
+    CheckNode {
+        #[arg(
+            long = "max-lag-secs",
+            help = "The maximum age in seconds of the last finalized block known to the node for \
+                    the node to be considered caught up.",
+            default_value_t = 300
+        )]
+        max_lag_secs: u64,
+    },
     /// For each account compute the average amount of CCD held
     /// during the period.
     #[command(name = "initial-weights")]
     InitialWeights {
         #[arg(long = "out", help = "Directory to output data into.")]
-        out:     std::path::PathBuf,
+        out:      std::path::PathBuf,
+        #[arg(
+            long = "weight-strategy",
+            alias = "weight-metric",
+            help = "The strategy used to compute the weight of an account from its balance over \
+                    the period. When verifying, this must match the strategy used to generate \
+                    the weights registered in the contract.",
+            value_enum,
+            default_value_t = WeightStrategyKind::LinearAverage
+        )]
+        strategy: WeightStrategyKind,
         #[command(subcommand)]
-        command: InitialWeights,
+        command:  InitialWeights,
     },
     /// Look for delegations of the vote during the election period.
     #[command(name = "final-weights")]
@@ -198,19 +580,145 @@ enum Command {
                     delegators for that account. This is different from the final weights file \
                     which contains only the summary."
         )]
-        out:             Option<std::path::PathBuf>,
+        out: Option<std::path::PathBuf>,
         #[arg(
             long = "contract",
             help = "Address of the election contract in the format <index, subindex>."
         )]
-        contract:        ContractAddress,
-        #[arg(long = "initial-weights", help = "The CSV file with initial weights.")]
-        initial_weights: std::path::PathBuf,
+        contract: ContractAddress,
+        #[arg(
+            long = "initial-weights",
+            help = "The CSV file with initial weights. This can be given multiple times, in which \
+                    case the weights of accounts appearing in multiple files are summed.",
+            required = true
+        )]
+        initial_weights: Vec<std::path::PathBuf>,
         #[arg(
             long = "final-weights",
             help = "Location where to write the final weights."
         )]
-        final_weights:   std::path::PathBuf,
+        final_weights: std::path::PathBuf,
+        #[arg(
+            long = "delegations-snapshot",
+            help = "File with delegations previously written by `--write-delegations-snapshot` or \
+                    `--out`. If given, the delegations are read from this file instead of the \
+                    chain, which makes the final weights reproducible."
+        )]
+        delegations_snapshot: Option<std::path::PathBuf>,
+        #[arg(
+            long = "write-delegations-snapshot",
+            help = "Location where to write the delegations used, for use with \
+                    `--delegations-snapshot`.",
+            conflicts_with = "delegations_snapshot"
+        )]
+        write_delegations_snapshot: Option<std::path::PathBuf>,
+        #[arg(
+            long = "memo-encoding",
+            help = "How the delegation string is expected to be encoded in memos. Wallets should \
+                    CBOR encode the string, but some put the raw UTF-8 bytes in the memo. With \
+                    `auto` memos are decoded as CBOR if possible, and otherwise as UTF-8.",
+            value_enum,
+            default_value_t = MemoEncoding::Cbor
+        )]
+        memo_encoding: MemoEncoding,
+    },
+    /// Write the roster of the election, i.e. the effective weight of each
+    /// account after applying the delegations made during the election
+    /// period, for public posting. This is only possible after the election
+    /// has ended, so that delegations are not revealed while voting is
+    /// ongoing.
+    #[command(name = "publish-roster")]
+    PublishRoster {
+        #[arg(
+            long = "contract",
+            help = "Address of the election contract in the format <index, subindex>."
+        )]
+        contract:        ContractAddress,
+        #[arg(
+            long = "initial-weights",
+            help = "The CSV file with initial weights. This can be given multiple times, in which \
+                    case the weights of accounts appearing in multiple files are summed.",
+            required = true
+        )]
+        initial_weights: Vec<std::path::PathBuf>,
+        #[arg(long = "out", help = "Location where to write the roster.")]
+        out:             std::path::PathBuf,
+        #[arg(
+            long = "memo-encoding",
+            help = "How the delegation string is expected to be encoded in memos. This must match \
+                    the encoding used for `final-weights`.",
+            value_enum,
+            default_value_t = MemoEncoding::Cbor
+        )]
+        memo_encoding:   MemoEncoding,
+    },
+    /// Compare two initial weights files, reporting the accounts whose weights
+    /// differ by more than the given tolerance. This is meant for checking
+    /// that upgrades of the tool do not change how the weights are computed.
+    #[command(name = "compare-weights")]
+    CompareWeights {
+        #[arg(long = "a", help = "The first initial weights file.")]
+        a:         std::path::PathBuf,
+        #[arg(long = "b", help = "The second initial weights file.")]
+        b:         std::path::PathBuf,
+        #[arg(
+            long = "tolerance",
+            help = "The largest difference in micro CCD between the weights of an account that is \
+                    accepted.",
+            default_value_t = 0
+        )]
+        tolerance: u64,
+    },
+    /// Report the accounts that submitted more than one ballot during the
+    /// election period.
+    #[command(name = "vote-changes")]
+    VoteChanges {
+        #[arg(
+            long = "contract",
+            help = "Address of the election contract in the format <index, subindex>."
+        )]
+        contract: ContractAddress,
+        #[arg(
+            long = "out",
+            help = "File to output the per-account report into. If not given the report is \
+                    written to stdout."
+        )]
+        out:      Option<std::path::PathBuf>,
+    },
+    /// Index blocks from the start of the election and report the throughput of
+    /// indexing and verifying ballots, without writing anything. This is meant
+    /// for estimating how long the tally takes with a given node and hardware.
+    #[command(name = "benchmark-indexing")]
+    BenchmarkIndexing {
+        #[arg(
+            long = "contract",
+            help = "Address of the election contract in the format <index, subindex>."
+        )]
+        contract: ContractAddress,
+        #[arg(
+            long = "blocks",
+            help = "The number of blocks to index. These must already exist, since indexing waits \
+                    for new blocks otherwise.",
+            default_value_t = 1000
+        )]
+        blocks:   u64,
+    },
+    /// Report all the election results posted to the contract, in the order
+    /// they were posted. The contract only keeps the latest result, so this
+    /// reconstructs the history from the chain.
+    #[command(name = "result-history")]
+    ResultHistory {
+        #[arg(
+            long = "contract",
+            help = "Address of the election contract in the format <index, subindex>."
+        )]
+        contract: ContractAddress,
+        #[arg(
+            long = "out",
+            help = "File to output the results into. If not given the results are written to \
+                    stdout."
+        )]
+        out:      Option<std::path::PathBuf>,
     },
     /// Tally all the votes.
     #[command(name = "tally")]
@@ -228,6 +736,162 @@ enum Command {
             help = "Location of the keys used to register election results in the contract."
         )]
         wallet_path: Option<std::path::PathBuf>,
+        #[arg(
+            long = "tally-stats",
+            help = "The statistics of the ballots included in the tally, as written by the \
+                    `--stats-out` option of the `tally` command. If given, the weight of ballots \
+                    cast is reported alongside the weight of the selections."
+        )]
+        tally_stats: Option<std::path::PathBuf>,
+        #[arg(
+            long = "notify-url",
+            help = "URL to post JSON progress updates to while decrypting the tally. Failures to \
+                    send updates are logged, but do not stop the decryption."
+        )]
+        notify_url:  Option<url::Url>,
+        #[arg(
+            long = "timing-out",
+            help = "File to write a timeline of when each guardian registered its decryption \
+                    shares and proofs into. This is reconstructed from the chain after \
+                    decrypting, and is meant for analysing slow finalizations."
+        )]
+        timing_out:  Option<std::path::PathBuf>,
+    },
+    /// Tally the votes and post the encrypted tally if not already present,
+    /// wait for the guardians to register their decryption shares and proofs,
+    /// and post the final result of the election in the contract.
+    #[command(name = "finalize-all")]
+    FinalizeAll(#[clap(flatten)] FinalizeAllArgs),
+    /// Decrypt the encrypted tally registered in the contract using decryption
+    /// shares and proofs read from files, instead of those registered by the
+    /// guardians in the contract. This is a recovery path for when the
+    /// guardians cannot register their decryption shares. The result is only
+    /// printed, and not registered in the contract.
+    #[command(name = "decrypt-from-files")]
+    DecryptFromFiles {
+        #[arg(
+            long = "contract",
+            help = "Address of the election contract in the format <index, subindex>."
+        )]
+        contract:   ContractAddress,
+        #[arg(
+            long = "shares-dir",
+            help = "Directory with a file for each guardian containing the decryption share of \
+                    the guardian, encoded as it would be registered in the contract."
+        )]
+        shares_dir: std::path::PathBuf,
+        #[arg(
+            long = "proofs-dir",
+            help = "Directory with a file for each guardian containing the proof of correct \
+                    decryption of the guardian, encoded as it would be registered in the \
+                    contract. The file must have the same name as the corresponding file in \
+                    `--shares-dir`."
+        )]
+        proofs_dir: std::path::PathBuf,
+    },
+    /// Decrypt and reveal the selections of a single ballot. This breaks the
+    /// secrecy of the ballot and is only intended for dispute resolution.
+    #[command(name = "reveal-ballot")]
+    RevealBallot(#[clap(flatten)] RevealBallotArgs),
+    /// Print a short fingerprint of the joint election public key, for
+    /// comparing the key out-of-band with other tools.
+    #[command(name = "joint-key-fingerprint")]
+    JointKeyFingerprint {
+        #[arg(
+            long = "contract",
+            help = "Address of the election contract in the format <index, subindex>."
+        )]
+        contract: ContractAddress,
+    },
+    /// Decode and check a single ballot submitted to the election contract,
+    /// without decrypting it. This is meant for diagnosing problems with
+    /// individual votes.
+    #[command(name = "inspect-ballot")]
+    InspectBallot(#[clap(flatten)] InspectBallotArgs),
+    /// Check whether the ballot of a voter is included in the tally, and report
+    /// which of the ballots submitted by the voter is counted and how it is
+    /// scaled.
+    #[command(name = "verify-inclusion")]
+    VerifyInclusion(#[clap(flatten)] VerifyInclusionArgs),
+    /// Export the complete state of the election contract to a JSON snapshot.
+    #[command(name = "snapshot")]
+    Snapshot {
+        #[arg(
+            long = "contract",
+            help = "Address of the election contract in the format <index, subindex>."
+        )]
+        contract: ContractAddress,
+        #[arg(long = "out", help = "File to write the JSON snapshot into.")]
+        out:      std::path::PathBuf,
+    },
+    /// Export the verification statuses reported by the guardians during the
+    /// setup phase to a CSV file.
+    #[command(name = "export-guardian-statuses")]
+    ExportGuardianStatuses {
+        #[arg(
+            long = "contract",
+            help = "Address of the election contract in the format <index, subindex>."
+        )]
+        contract: ContractAddress,
+        #[arg(long = "out", help = "File to write the guardian statuses into.")]
+        out:      std::path::PathBuf,
+    },
+    /// Propose an election result for approval by the result approvers of the
+    /// election.
+    #[command(name = "propose-result")]
+    ProposeResult {
+        #[arg(
+            long = "contract",
+            help = "Address of the election contract in the format <index, subindex>."
+        )]
+        contract:    ContractAddress,
+        #[arg(
+            long = "admin-keys",
+            help = "Location of the keys used to register election results in the contract."
+        )]
+        wallet_path: std::path::PathBuf,
+        #[arg(
+            long = "votes",
+            help = "The weighted votes of a candidate. This must be given once for each \
+                    candidate, in the order of the candidates in the contract.",
+            required = true
+        )]
+        votes:       Vec<contract::CandidateWeightedVotes>,
+    },
+    /// Approve the election result currently proposed in the contract.
+    #[command(name = "approve-result")]
+    ApproveResult {
+        #[arg(
+            long = "contract",
+            help = "Address of the election contract in the format <index, subindex>."
+        )]
+        contract:    ContractAddress,
+        #[arg(
+            long = "approver-keys",
+            help = "Location of the keys of the result approver."
+        )]
+        wallet_path: std::path::PathBuf,
+        #[arg(
+            long = "hash",
+            help = "The hash of the result to approve, as printed when the result was proposed. \
+                    The approval is rejected if this does not match the proposed result."
+        )]
+        hash:        contract::HashSha2256,
+    },
+    /// Lock the election, making the registered election result final. After
+    /// this, the finalization phase cannot be changed.
+    #[command(name = "lock-election")]
+    LockElection {
+        #[arg(
+            long = "contract",
+            help = "Address of the election contract in the format <index, subindex>."
+        )]
+        contract:    ContractAddress,
+        #[arg(
+            long = "admin-keys",
+            help = "Location of the keys of the admin account of the election."
+        )]
+        wallet_path: std::path::PathBuf,
     },
     /// Reset finalization phase.
     Reset {
@@ -253,33 +917,325 @@ enum Command {
         )]
         decryption_deadline: chrono::DateTime<chrono::Utc>,
     },
+    /// Extend the deadline for guardians to register their decryption shares,
+    /// keeping the shares and proofs already registered. Unlike `reset`, this
+    /// does not exclude any guardians.
+    #[command(name = "extend-deadline")]
+    ExtendDeadline {
+        #[arg(
+            long = "contract",
+            help = "Address of the election contract in the format <index, subindex>"
+        )]
+        contract:            ContractAddress,
+        #[arg(
+            long = "admin-keys",
+            help = "Location of the keys of the admin account of the election."
+        )]
+        wallet_path:         std::path::PathBuf,
+        #[arg(
+            long = "decryption-deadline",
+            help = "The new deadline for guardians to register decryption shares. This must be \
+                    after the current deadline. The format is ISO-8601, e.g. 2024-01-23T12:13:14Z."
+        )]
+        decryption_deadline: chrono::DateTime<chrono::Utc>,
+    },
+    /// Run a complete election on a local test chain and check that the
+    /// decrypted result matches the votes cast. This exercises the whole
+    /// lifecycle of an election, from deployment to the final result.
+    #[command(name = "self-test")]
+    SelfTest(#[clap(flatten)] SelfTestArgs),
 }
 
 #[derive(Debug, Parser)]
-struct TallyArgs {
+struct SelfTestArgs {
     #[arg(
-        long = "contract",
-        help = "Address of the election contract in the format <index, subindex>."
+        long = "module",
+        help = "Path of the Concordium smart contract module."
     )]
-    target_address: ContractAddress,
+    module:             std::path::PathBuf,
     #[arg(
-        long = "final-weights",
-        help = "Location of the file with final weights of accounts."
+        long = "keys",
+        help = "Directory with the keys of the accounts to use, as exported from the wallet. The \
+                first account in the order of file names is the admin account, and the following \
+                are the guardians. All of them vote in the election."
     )]
-    final_weights:  std::path::PathBuf,
+    keys:               std::path::PathBuf,
     #[arg(
-        long = "admin-keys",
-        help = "Location of the keys used to register election results in the contract."
+        long = "guardians",
+        help = "The number of guardians of the election.",
+        default_value_t = 3
     )]
-    keys:           Option<std::path::PathBuf>,
-}
-
-#[derive(Debug, clap::Parser)]
-struct InitialWeightsArgs {
+    guardians:          u32,
     #[arg(
-        long = "start",
-        help = "The start time of the collection. This is inclusive. The format is ISO-8601, e.g. \
-                2024-01-23T12:13:14Z."
+        long = "threshold",
+        help = "The number of guardians needed for decryption.",
+        default_value_t = 2
+    )]
+    threshold:          u32,
+    #[arg(
+        long = "candidates",
+        help = "The number of candidates of the election.",
+        default_value_t = 3
+    )]
+    candidates:         usize,
+    #[arg(
+        long = "setup-minutes",
+        help = "The time in minutes from creating the election until it starts, during which \
+                guardians set up their keys.",
+        default_value_t = 2
+    )]
+    setup_minutes:      i64,
+    #[arg(
+        long = "election-minutes",
+        help = "The duration of the election in minutes.",
+        default_value_t = 2
+    )]
+    election_minutes:   i64,
+    #[arg(
+        long = "decryption-minutes",
+        help = "The time in minutes from the end of the election until the decryption deadline.",
+        default_value_t = 5
+    )]
+    decryption_minutes: i64,
+    #[arg(
+        long = "base-url",
+        help = "Base url where the files written to the output directory are served. This is \
+                recorded in the contract, and the files are retrieved from it when tallying.",
+        default_value = "http://localhost:7000/"
+    )]
+    base_url:           url::Url,
+    #[arg(
+        long = "out",
+        help = "Directory to write the election files and the intermediate outputs into."
+    )]
+    out:                std::path::PathBuf,
+    #[arg(
+        long = "seed",
+        help = "Seed from which guardian keys, selections, and ballot nonces are derived. Using \
+                the same seed reproduces the same selections.",
+        default_value = "self-test"
+    )]
+    seed:               String,
+}
+
+#[derive(Debug, Parser)]
+struct TallyArgs {
+    #[arg(
+        long = "contract",
+        help = "Address of the election contract in the format <index, subindex>."
+    )]
+    target_address:        ContractAddress,
+    #[arg(
+        long = "final-weights",
+        help = "Location of the file with final weights of accounts. This can be given multiple \
+                times, in which case the weights of accounts appearing in multiple files are \
+                summed.",
+        required = true
+    )]
+    final_weights:         Vec<std::path::PathBuf>,
+    #[arg(
+        long = "admin-keys",
+        help = "Location of the keys used to register election results in the contract."
+    )]
+    keys:                  Option<std::path::PathBuf>,
+    #[arg(
+        long = "stats-out",
+        help = "File to write the statistics of the ballots included in the tally into. This can \
+                be supplied to the `final-result` command to report abstentions."
+    )]
+    stats_out:             Option<std::path::PathBuf>,
+    #[arg(
+        long = "check-eligible-voters",
+        help = "Check the accounts in the final weights against the eligible voters registered in \
+                the contract, and warn about accounts that are not eligible, either themselves or \
+                through their delegators."
+    )]
+    check_eligible_voters: bool,
+    #[arg(
+        long = "computation-log",
+        help = "File to write a log of the tally computation into. The log lists every ballot \
+                included in the tally with its scaling factor and delegators, together with the \
+                hash of the resulting encrypted tally."
+    )]
+    computation_log:       Option<std::path::PathBuf>,
+    #[arg(
+        long = "weight-unit",
+        help = "The unit of weight in micro CCD. Ballots are scaled by the number of whole units \
+                in the final weight of the account. A smaller unit takes fractional CCD into \
+                account, at the cost of larger totals in the result and a longer decryption. The \
+                same unit must be used when interpreting the result.",
+        default_value_t = MICRO_CCD_PER_CCD,
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    weight_unit:           u64,
+    #[arg(
+        long = "checkpoint",
+        help = "File to periodically save the progress of indexing the ballots into. If the file \
+                exists, indexing resumes from the saved progress instead of from the start of the \
+                election. The file must have been written for the same contract."
+    )]
+    checkpoint:            Option<std::path::PathBuf>,
+}
+
+/// A record of how the encrypted tally was computed, so that the computation
+/// can be reviewed and reproduced.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TallyComputationLog {
+    /// The ballots included in the tally, ordered by account.
+    ballots:     Vec<TallyLogEntry>,
+    /// The unit of weight in micro CCD the ballots were scaled with.
+    weight_unit: u64,
+    /// The SHA256 hash of the serialized encrypted tally.
+    tally_hash:  contract::HashSha2256,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TallyLogEntry {
+    account:          AccountAddress,
+    /// The transaction the ballot was submitted in.
+    transaction_hash: TransactionHash,
+    /// The final weight of the account.
+    amount:           Amount,
+    /// The factor the ballot was scaled by, see
+    /// [`get_scaling_factor_with_unit`].
+    scaling_factor:   u64,
+    /// The accounts that delegated their weight to `account`.
+    delegators:       Vec<String>,
+}
+
+/// Statistics of the ballots included in the encrypted tally. Since ballots
+/// are encrypted, these are needed alongside the decrypted result to
+/// determine how much of the weight cast did not result in selections.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TallyStats {
+    /// The number of ballots included in the tally.
+    ballots:          u64,
+    /// The sum of the scaling factors of the ballots included in the tally.
+    weighted_ballots: u64,
+}
+
+#[derive(Debug, Parser)]
+struct FinalizeAllArgs {
+    #[arg(
+        long = "contract",
+        help = "Address of the election contract in the format <index, subindex>."
+    )]
+    target_address:        ContractAddress,
+    #[arg(
+        long = "final-weights",
+        help = "Location of the file with final weights of accounts. This can be given multiple \
+                times, in which case the weights of accounts appearing in multiple files are \
+                summed.",
+        required = true
+    )]
+    final_weights:         Vec<std::path::PathBuf>,
+    #[arg(
+        long = "admin-keys",
+        help = "Location of the keys used to register the tally and the election result in the \
+                contract."
+    )]
+    keys:                  std::path::PathBuf,
+    #[arg(
+        long = "poll-interval",
+        help = "The number of seconds to wait between checking whether guardians have registered \
+                their decryption shares.",
+        default_value_t = 60
+    )]
+    poll_interval:         u64,
+    #[arg(
+        long = "notify-url",
+        help = "URL to post JSON progress updates to while decrypting the tally. Failures to send \
+                updates are logged, but do not stop the decryption."
+    )]
+    notify_url:            Option<url::Url>,
+    #[arg(
+        long = "check-eligible-voters",
+        help = "Check the accounts in the final weights against the eligible voters registered in \
+                the contract, and warn about accounts that are not eligible, either themselves or \
+                through their delegators."
+    )]
+    check_eligible_voters: bool,
+}
+
+#[derive(Debug, Parser)]
+struct InspectBallotArgs {
+    #[arg(
+        long = "contract",
+        help = "Address of the election contract in the format <index, subindex>."
+    )]
+    contract:    ContractAddress,
+    #[arg(
+        long = "transaction",
+        help = "The hash of the transaction which submitted the ballot to inspect."
+    )]
+    transaction: TransactionHash,
+}
+
+#[derive(Debug, Parser)]
+struct VerifyInclusionArgs {
+    #[arg(
+        long = "contract",
+        help = "Address of the election contract in the format <index, subindex>."
+    )]
+    contract:      ContractAddress,
+    #[arg(long = "account", help = "The account of the voter.")]
+    account:       AccountAddress,
+    #[arg(
+        long = "final-weights",
+        help = "The CSV file with final weights used for the tally. This can be given multiple \
+                times, in the same way as for `tally`.",
+        required = true
+    )]
+    final_weights: Vec<std::path::PathBuf>,
+}
+
+#[derive(Debug, Parser)]
+struct RevealBallotArgs {
+    #[arg(
+        long = "contract",
+        help = "Address of the election contract in the format <index, subindex>."
+    )]
+    contract:             ContractAddress,
+    #[arg(
+        long = "transaction",
+        help = "The hash of the transaction which submitted the ballot to reveal."
+    )]
+    transaction:          TransactionHash,
+    #[arg(
+        long = "guardian-decryption",
+        help = "A JSON file with a guardian's decryption shares and proofs of correct decryption \
+                of the ballot. This must be given once for each guardian taking part, and at \
+                least as many times as the decryption threshold.",
+        required = true
+    )]
+    guardian_decryptions: Vec<std::path::PathBuf>,
+    #[arg(
+        long = "i-understand-this-breaks-ballot-secrecy",
+        help = "Confirm that revealing the ballot breaks the secrecy of the vote."
+    )]
+    confirmed:            bool,
+}
+
+/// The decryption of a single ballot provided by a guardian for the purpose of
+/// revealing it. The shares and proofs are produced with respect to the
+/// ciphertexts of the ballot in the same way as they are for the encrypted
+/// tally.
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BallotDecryptionFile {
+    decryption_share: GuardianDecryption,
+    decryption_proof: GuardianDecryptionProof,
+}
+
+#[derive(Debug, clap::Parser)]
+struct InitialWeightsArgs {
+    #[arg(
+        long = "start",
+        help = "The start time of the collection. This is inclusive. The format is ISO-8601, e.g. \
+                2024-01-23T12:13:14Z."
     )]
     start: chrono::DateTime<chrono::Utc>,
     #[arg(
@@ -290,40 +1246,211 @@ struct InitialWeightsArgs {
     end:   chrono::DateTime<chrono::Utc>,
 }
 
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
-    let app: Args = Args::parse();
-    let endpoint = if app
-        .node_endpoint
+/// Configure the endpoints of the node. The first is used for individual
+/// requests, which are subject to the `request_timeout`. The second is used for
+/// streaming queries, e.g. the ones used when indexing the chain. These can
+/// run for a long time, so they are only subject to the `connect_timeout`.
+fn node_endpoints(
+    node_endpoint: sdk::Endpoint,
+    connect_timeout: Duration,
+    request_timeout: Duration,
+) -> anyhow::Result<(sdk::Endpoint, sdk::Endpoint)> {
+    let endpoint = if node_endpoint
         .uri()
         .scheme()
         .map_or(false, |x| x == &sdk::Scheme::HTTPS)
     {
-        app.node_endpoint
+        node_endpoint
             .tls_config(tonic::transport::channel::ClientTlsConfig::new())
             .context("Unable to construct TLS configuration for the Concordium API.")?
     } else {
-        app.node_endpoint
+        node_endpoint
+    }
+    .connect_timeout(connect_timeout);
+    Ok((endpoint.clone().timeout(request_timeout), endpoint))
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let app: Args = Args::parse();
+    let (endpoint, stream_endpoint) = node_endpoints(
+        app.node_endpoint,
+        Duration::from_secs(app.connect_timeout_secs),
+        Duration::from_secs(app.request_timeout_secs),
+    )?;
+    let indexing = IndexingConfig {
+        endpoint:         stream_endpoint,
+        max_blocks:       app.max_blocks,
+        block_batch_size: app.block_batch_size.into(),
+    };
+    if let Some(max_blocks) = app.max_blocks {
+        eprintln!(
+            "WARNING: Indexing at most {max_blocks} blocks. This is for testing only, results \
+             computed from the chain will be partial and must not be used in production."
+        );
     }
-    .connect_timeout(std::time::Duration::from_secs(5))
-    .timeout(std::time::Duration::from_secs(10));
+    let parameters_version = match app.parameters_version {
+        Some(version) => version,
+        None => standard_parameters_version()?,
+    };
+
+    let show_progress = !app.no_progress && std::io::stderr().is_terminal();
 
     match app.command {
-        Command::InitialWeights { out, command } => {
-            handle_initial_weights(endpoint, command, out).await
+        Command::InitialWeights {
+            out,
+            strategy,
+            command,
+        } => {
+            handle_initial_weights(
+                endpoint,
+                indexing,
+                command,
+                out,
+                strategy.strategy(),
+                show_progress,
+            )
+            .await
         }
         Command::FinalWeights {
             out,
             contract,
             initial_weights,
             final_weights,
-        } => handle_final_weights(endpoint, out, contract, initial_weights, final_weights).await,
-        Command::Tally(tally) => handle_tally(endpoint, tally).await,
+            delegations_snapshot,
+            write_delegations_snapshot,
+            memo_encoding,
+        } => {
+            handle_final_weights(
+                endpoint,
+                indexing,
+                out,
+                contract,
+                initial_weights,
+                final_weights,
+                delegations_snapshot,
+                write_delegations_snapshot,
+                memo_encoding,
+                show_progress,
+            )
+            .await
+        }
+        Command::PublishRoster {
+            contract,
+            initial_weights,
+            out,
+            memo_encoding,
+        } => {
+            handle_publish_roster(
+                endpoint,
+                indexing,
+                contract,
+                initial_weights,
+                out,
+                memo_encoding,
+                show_progress,
+            )
+            .await
+        }
+        Command::VoteChanges { contract, out } => {
+            handle_vote_changes(endpoint, indexing, contract, out, show_progress).await
+        }
+        Command::BenchmarkIndexing { contract, blocks } => {
+            handle_benchmark_indexing(endpoint, indexing, contract, blocks, show_progress).await
+        }
+        Command::ResultHistory { contract, out } => {
+            handle_result_history(endpoint, indexing, contract, out, show_progress).await
+        }
+        Command::Tally(tally) => {
+            handle_tally(endpoint, indexing, tally, parameters_version, show_progress)
+                .await
+                .map(|_| ())
+        }
         Command::FinalResult {
             contract,
             wallet_path,
-        } => handle_decrypt(endpoint, contract, wallet_path).await,
+            tally_stats,
+            notify_url,
+            timing_out,
+        } => {
+            let tally_stats = tally_stats
+                .map(|path| -> anyhow::Result<TallyStats> {
+                    let file = std::fs::File::open(&path)
+                        .with_context(|| format!("Unable to open {}.", path.display()))?;
+                    Ok(serde_json::from_reader(file)
+                        .with_context(|| format!("Unable to read {}.", path.display()))?)
+                })
+                .transpose()?;
+            let result = handle_decrypt(
+                endpoint.clone(),
+                contract,
+                wallet_path,
+                tally_stats,
+                notify_url,
+                parameters_version,
+                app.output,
+                show_progress,
+            )
+            .await;
+            // The timeline is most useful when decryption is failing, so it is written
+            // regardless of the result.
+            if let Some(timing_out) = timing_out {
+                handle_decryption_timing(endpoint, indexing, contract, &timing_out, show_progress)
+                    .await?;
+            }
+            result
+        }
+        Command::FinalizeAll(args) => {
+            handle_finalize_all(endpoint, indexing, args, parameters_version, show_progress).await
+        }
+        Command::DecryptFromFiles {
+            contract,
+            shares_dir,
+            proofs_dir,
+        } => {
+            handle_decrypt_from_files(
+                endpoint,
+                contract,
+                shares_dir,
+                proofs_dir,
+                parameters_version,
+                show_progress,
+            )
+            .await
+        }
         Command::NewElection(args) => handle_new_election(endpoint, *args).await,
+        Command::ValidateElectionFiles(args) => handle_validate_election_files(args).await,
+        Command::CandidatesFromCsv(args) => handle_candidates_from_csv(args),
+        Command::CompareWeights { a, b, tolerance } => handle_compare_weights(a, b, tolerance),
+        Command::EstimateCost(args) => handle_estimate_cost(endpoint, args).await,
+        Command::CheckGuardians { guardians } => handle_check_guardians(endpoint, guardians).await,
+        Command::CheckNode { max_lag_secs } => handle_check_node(endpoint, max_lag_secs).await,
+        Command::Snapshot { contract, out } => handle_snapshot(endpoint, contract, out).await,
+        Command::ExportGuardianStatuses { contract, out } => {
+            handle_export_guardian_statuses(endpoint, contract, out).await
+        }
+        Command::RevealBallot(args) => handle_reveal_ballot(endpoint, args).await,
+        Command::InspectBallot(args) => handle_inspect_ballot(endpoint, args).await,
+        Command::VerifyInclusion(args) => {
+            handle_verify_inclusion(endpoint, indexing, args, show_progress).await
+        }
+        Command::JointKeyFingerprint { contract } => {
+            handle_joint_key_fingerprint(endpoint, contract).await
+        }
+        Command::ProposeResult {
+            contract,
+            wallet_path,
+            votes,
+        } => handle_propose_result(endpoint, contract, wallet_path, votes).await,
+        Command::ApproveResult {
+            contract,
+            wallet_path,
+            hash,
+        } => handle_approve_result(endpoint, contract, wallet_path, hash).await,
+        Command::LockElection {
+            contract,
+            wallet_path,
+        } => handle_lock_election(endpoint, contract, wallet_path).await,
         Command::Reset {
             contract,
             wallet_path,
@@ -339,6 +1466,99 @@ async fn main() -> anyhow::Result<()> {
             )
             .await
         }
+        Command::ExtendDeadline {
+            contract,
+            wallet_path,
+            decryption_deadline,
+        } => {
+            handle_extend_deadline(
+                endpoint,
+                contract,
+                wallet_path,
+                decryption_deadline.try_into()?,
+            )
+            .await
+        }
+        Command::SelfTest(args) => handle_self_test(endpoint, indexing, args, show_progress).await,
+    }
+}
+
+/// How often progress is logged when progress bars are not displayed.
+const PROGRESS_LOG_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Progress reporting for long running commands. This is either displayed as a
+/// progress bar, or, if progress bars are disabled, periodically logged to
+/// stderr.
+struct Progress {
+    bar:         ProgressBar,
+    /// The time progress was last logged. This is [`None`] if progress is
+    /// displayed as a progress bar.
+    last_logged: Option<Mutex<Instant>>,
+}
+
+impl Progress {
+    /// Construct a new progress reporter expecting `len` steps.
+    fn new(len: u64, show_progress: bool) -> anyhow::Result<Self> {
+        if show_progress {
+            let bar = ProgressBar::new(len).with_style(ProgressStyle::with_template(
+                "{spinner} {msg} {wide_bar} {pos}/{len}",
+            )?);
+            Ok(Self {
+                bar,
+                last_logged: None,
+            })
+        } else {
+            Ok(Self {
+                bar:         ProgressBar::with_draw_target(Some(len), ProgressDrawTarget::hidden()),
+                last_logged: Some(Mutex::new(Instant::now())),
+            })
+        }
+    }
+
+    fn set_message(&self, msg: impl Into<std::borrow::Cow<'static, str>>) {
+        self.bar.set_message(msg);
+    }
+
+    fn inc(&self, delta: u64) {
+        self.bar.inc(delta);
+        let Some(last_logged) = &self.last_logged else {
+            return;
+        };
+        let mut last_logged = last_logged.lock().unwrap_or_else(|e| e.into_inner());
+        if last_logged.elapsed() >= PROGRESS_LOG_INTERVAL {
+            *last_logged = Instant::now();
+            self.log();
+        }
+    }
+
+    /// Spin the spinner automatically. This has no effect if progress bars are
+    /// not displayed.
+    fn enable_steady_tick(&self, interval: Duration) {
+        if self.last_logged.is_none() {
+            self.bar.enable_steady_tick(interval);
+        }
+    }
+
+    fn finish_and_clear(&self) {
+        self.bar.finish_and_clear();
+        if self.last_logged.is_some() {
+            self.log();
+        }
+    }
+
+    fn log(&self) {
+        eprintln!("{}", self.log_line());
+    }
+
+    /// The line logged to report progress when progress bars are not
+    /// displayed.
+    fn log_line(&self) -> String {
+        format!(
+            "Progress: {}/{} {}",
+            self.bar.position(),
+            self.bar.length().unwrap_or_default(),
+            self.bar.message()
+        )
     }
 }
 
@@ -394,55 +1614,458 @@ async fn range_setup(
     Ok((first_block, last_block))
 }
 
-#[derive(serde::Serialize, serde::Deserialize)]
-struct DelegationRow {
-    hash: TransactionHash,
-    from: AccountAddress,
-    to:   AccountAddress,
+/// Configuration of the queries used for indexing the chain.
+#[derive(Debug, Clone)]
+struct IndexingConfig {
+    /// The endpoint used for the streaming queries. This is only subject to the
+    /// connect timeout, since the queries can run for a long time.
+    endpoint:         sdk::Endpoint,
+    /// The maximum number of blocks to index, for testing.
+    max_blocks:       Option<u64>,
+    /// The maximum number of blocks to process together, see
+    /// [`IndexingConfig::recv_batch`].
+    block_batch_size: usize,
 }
 
-#[derive(serde::Serialize, serde::Deserialize)]
-struct FinalWeightRow {
-    account:    AccountAddress,
-    amount:     Amount,
-    /// ';' separated list of accounts that delegated.
-    delegators: String,
-}
+impl IndexingConfig {
+    /// Forward the blocks received on `receiver`, stopping after `max_blocks`
+    /// blocks if set. Dropping `receiver` at that point stops the indexer.
+    fn limit_blocks<T: Send + 'static>(
+        &self,
+        mut receiver: tokio::sync::mpsc::Receiver<T>,
+    ) -> tokio::sync::mpsc::Receiver<T> {
+        let Some(max_blocks) = self.max_blocks else {
+            return receiver;
+        };
+        let (sender, limited) = tokio::sync::mpsc::channel(1);
+        tokio::spawn(async move {
+            for _ in 0..max_blocks {
+                let Some(block) = receiver.recv().await else {
+                    return;
+                };
+                if sender.send(block).await.is_err() {
+                    return;
+                }
+            }
+            eprintln!(
+                "WARNING: Stopped indexing after {max_blocks} blocks as requested by \
+                 --max-blocks. The results are partial."
+            );
+        });
+        limited
+    }
 
-/// Compute the final weights given the initial weights.
-/// The time range is taken from the provided contract.
-async fn handle_final_weights(
-    endpoint: sdk::Endpoint,
-    delegations_out: Option<std::path::PathBuf>,
-    target_address: ContractAddress,
-    initial_weights: std::path::PathBuf,
-    final_weights_path: std::path::PathBuf,
-) -> anyhow::Result<()> {
-    let client = sdk::Client::new(endpoint.clone()).await?;
+    /// Receive the next batch of blocks from `receiver`. This waits for at
+    /// least one block, and includes up to `block_batch_size` blocks which are
+    /// already available, in the order they were received. Returns `None` once
+    /// `receiver` is closed.
+    async fn recv_batch<T>(&self, receiver: &mut tokio::sync::mpsc::Receiver<T>) -> Option<Vec<T>> {
+        let mut batch = vec![receiver.recv().await?];
+        while batch.len() < self.block_batch_size {
+            let Ok(block) = receiver.try_recv() else {
+                break;
+            };
+            batch.push(block);
+        }
+        Some(batch)
+    }
+}
 
-    let mut contract_client =
-        contract_client::ContractClient::<ElectionContract>::create(client, target_address).await?;
+/// The version of the format of the CSV files written by this tool. This must
+/// be bumped whenever the columns or their meaning change.
+const CSV_FORMAT_VERSION: u32 = 1;
 
-    let config = get_election_data(&mut contract_client)
-        .await
-        .context("Unable to get election data.")?;
+/// The columns of the initial weights files, see [`WeightRow`].
+const INITIAL_WEIGHTS_COLUMNS: [&str; 2] = ["account", "amount"];
+/// The columns of the final weights files, see [`FinalWeightRow`].
+const FINAL_WEIGHTS_COLUMNS: [&str; 3] = ["account", "amount", "delegators"];
+/// The columns of the roster files, see [`handle_publish_roster`].
+const ROSTER_COLUMNS: [&str; 2] = ["account", "amount"];
+/// The columns of the delegations files, see [`DelegationRow`].
+const DELEGATIONS_COLUMNS: [&str; 3] = ["hash", "from", "to"];
+/// The columns of the guardian statuses files, see [`GuardianStatusRow`].
+const GUARDIAN_STATUSES_COLUMNS: [&str; 4] = ["index", "account", "status", "accounts"];
+/// The columns of the vote changes files, see [`VoteChangesRow`].
+const VOTE_CHANGES_COLUMNS: [&str; 5] = [
+    "account",
+    "ballots",
+    "flagged",
+    "timestamps",
+    "transactions",
+];
+/// The columns of the result history files, see [`ResultHistoryRow`].
+const RESULT_HISTORY_COLUMNS: [&str; 5] = ["timestamp", "block", "transaction", "kind", "result"];
+/// The columns of the decryption timeline files, see [`DecryptionTimingRow`].
+const DECRYPTION_TIMING_COLUMNS: [&str; 6] = [
+    "timestamp",
+    "seconds_after_end",
+    "guardian",
+    "index",
+    "entrypoint",
+    "transaction",
+];
 
-    let (first_block, last_block) =
-        range_setup(&mut contract_client.client, config.start, config.end).await?;
+/// Describes the format of a CSV file written by this tool. This is written
+/// to a companion `.meta.json` file next to the CSV file, since the CSV files
+/// themselves are hashed and consumed by other tools.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CsvMetadata {
+    /// The version of the format, see [`CSV_FORMAT_VERSION`].
+    version:      u32,
+    /// The names of the columns, in order.
+    columns:      Vec<String>,
+    /// If set, computing the file was interrupted and it only covers the
+    /// blocks up to and including the block at this height. Such files are
+    /// rejected by [`check_csv_metadata`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    truncated_at: Option<u64>,
+}
 
-    let traverse_config = indexer::TraverseConfig::new_single(endpoint, first_block.block_height);
-    let (sender, mut receiver) = tokio::sync::mpsc::channel(20);
-    let cancel_handle = tokio::spawn(traverse_config.traverse(indexer::TransactionIndexer, sender));
+/// The location of the metadata of the CSV file at `path`.
+fn csv_metadata_path(path: &std::path::Path) -> std::path::PathBuf {
+    let mut meta_path = path.as_os_str().to_owned();
+    meta_path.push(".meta.json");
+    meta_path.into()
+}
 
-    let bar = ProgressBar::new(last_block.block_height.height - first_block.block_height.height)
-        .with_style(ProgressStyle::with_template(
-            "{spinner} {msg} {wide_bar} {pos}/{len}",
-        )?);
+/// Write the metadata of the CSV file at `path` with the given `columns`.
+fn write_csv_metadata(path: &std::path::Path, columns: &[&str]) -> anyhow::Result<()> {
+    write_truncated_csv_metadata(path, columns, None)
+}
 
-    let mut mapping = BTreeMap::new();
+/// Write the metadata of the CSV file at `path` with the given `columns`,
+/// marking the file as truncated after the block at height `truncated_at` if
+/// set.
+fn write_truncated_csv_metadata(
+    path: &std::path::Path,
+    columns: &[&str],
+    truncated_at: Option<AbsoluteBlockHeight>,
+) -> anyhow::Result<()> {
+    let meta_path = csv_metadata_path(path);
+    let metadata = CsvMetadata {
+        version:      CSV_FORMAT_VERSION,
+        columns:      columns.iter().map(|c| c.to_string()).collect(),
+        truncated_at: truncated_at.map(|height| height.height),
+    };
+    let file = std::fs::File::create(&meta_path)
+        .with_context(|| format!("Unable to create {}.", meta_path.display()))?;
+    serde_json::to_writer_pretty(file, &metadata)?;
+    Ok(())
+}
 
-    while let Some((block, txs)) = receiver.recv().await {
-        bar.set_message(block.block_slot_time.to_string());
+/// Check that the CSV file at `path` has the expected format, as described by
+/// its metadata. Files without metadata, e.g. written by older versions of
+/// this tool, are accepted with a warning.
+fn check_csv_metadata(path: &std::path::Path, columns: &[&str]) -> anyhow::Result<()> {
+    let meta_path = csv_metadata_path(path);
+    let file = match std::fs::File::open(&meta_path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            eprintln!(
+                "No metadata found for {}. Assuming the file has columns {}.",
+                path.display(),
+                columns.join(", ")
+            );
+            return Ok(());
+        }
+        Err(e) => {
+            return Err(e).with_context(|| format!("Unable to open {}.", meta_path.display()))
+        }
+    };
+    let metadata: CsvMetadata = serde_json::from_reader(file)
+        .with_context(|| format!("Unable to read {}.", meta_path.display()))?;
+    ensure!(
+        metadata.version == CSV_FORMAT_VERSION,
+        "The file {} has format version {}, but this tool supports version {}.",
+        path.display(),
+        metadata.version,
+        CSV_FORMAT_VERSION
+    );
+    ensure!(
+        metadata.columns.iter().eq(columns.iter()),
+        "The file {} has columns {}, but columns {} are expected.",
+        path.display(),
+        metadata.columns.join(", "),
+        columns.join(", ")
+    );
+    if let Some(height) = metadata.truncated_at {
+        anyhow::bail!(
+            "The file {} is partial, since computing it was interrupted after block {height}. It \
+             must not be used.",
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Set `flag` once the process receives a signal to interrupt or terminate.
+/// While this is running, such signals no longer stop the process, so the
+/// operation in progress can stop cleanly by checking `flag`.
+async fn set_interrupted(flag: Arc<AtomicBool>) -> anyhow::Result<()> {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix as unix_signal;
+        let mut terminate_stream = unix_signal::signal(unix_signal::SignalKind::terminate())?;
+        let mut interrupt_stream = unix_signal::signal(unix_signal::SignalKind::interrupt())?;
+        let terminate = Box::pin(terminate_stream.recv());
+        let interrupt = Box::pin(interrupt_stream.recv());
+        futures::future::select(terminate, interrupt).await;
+        flag.store(true, Ordering::Release);
+    }
+    #[cfg(windows)]
+    {
+        use tokio::signal::windows as windows_signal;
+        let mut ctrl_break_stream = windows_signal::ctrl_break()?;
+        let mut ctrl_c_stream = windows_signal::ctrl_c()?;
+        let ctrl_break = Box::pin(ctrl_break_stream.recv());
+        let ctrl_c = Box::pin(ctrl_c_stream.recv());
+        futures::future::select(ctrl_break, ctrl_c).await;
+        flag.store(true, Ordering::Release);
+    }
+    Ok(())
+}
+
+/// The progress of indexing blocks that stops cleanly when interrupted, see
+/// [`set_interrupted`].
+struct InterruptibleIndexing {
+    /// Set once the process is interrupted.
+    interrupted:    Arc<AtomicBool>,
+    /// The height and slot time of the last block processed completely.
+    last_processed: (AbsoluteBlockHeight, chrono::DateTime<chrono::Utc>),
+}
+
+impl InterruptibleIndexing {
+    /// Start indexing after the block at `height` with slot time `time`.
+    fn new(height: AbsoluteBlockHeight, time: chrono::DateTime<chrono::Utc>) -> Self {
+        Self {
+            interrupted:    Arc::new(AtomicBool::new(false)),
+            last_processed: (height, time),
+        }
+    }
+
+    /// If interrupted, the height and slot time of the last block processed
+    /// completely. Indexing must then stop before processing another block.
+    fn interrupted_at(&self) -> Option<(AbsoluteBlockHeight, chrono::DateTime<chrono::Utc>)> {
+        self.interrupted
+            .load(Ordering::Acquire)
+            .then_some(self.last_processed)
+    }
+
+    /// Record that the block at `height` with slot time `time` is processed.
+    fn processed(&mut self, height: AbsoluteBlockHeight, time: chrono::DateTime<chrono::Utc>) {
+        self.last_processed = (height, time);
+    }
+}
+
+/// Write the initial weights `data` computed until interrupted after the
+/// block at `height` with slot time `time` to `initial-weights.partial.csv` in
+/// `out`, marked as truncated. Returns the error reporting the interruption,
+/// which tells the operator how to resume, given the `end` of the period and
+/// the height of its `last_block`.
+fn write_partial_initial_weights(
+    out: &std::path::Path,
+    data: &[u8],
+    (height, time): (AbsoluteBlockHeight, chrono::DateTime<chrono::Utc>),
+    end: chrono::DateTime<chrono::Utc>,
+    last_block: AbsoluteBlockHeight,
+) -> anyhow::Result<anyhow::Error> {
+    let partial_path = out.join("initial-weights.partial.csv");
+    std::fs::write(&partial_path, data)
+        .context("Failed to write partial initial weights to file")?;
+    write_truncated_csv_metadata(&partial_path, &INITIAL_WEIGHTS_COLUMNS, Some(height))?;
+    Ok(anyhow::anyhow!(
+        "Interrupted after processing block {height} at {time}. The weights computed up to this \
+         block were written to {}, and are marked as truncated so they cannot be used in place of \
+         the complete weights. Run the command again to compute the complete weights; the period \
+         runs until {end}, i.e. block {last_block}.",
+        partial_path.display(),
+    ))
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct DelegationRow {
+    hash: TransactionHash,
+    from: AccountAddress,
+    to:   AccountAddress,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct FinalWeightRow {
+    account:    AccountAddress,
+    amount:     Amount,
+    /// ';' separated list of accounts that delegated.
+    delegators: String,
+}
+
+/// Read the initial weights from the files at `paths`. Accounts appearing more
+/// than once are logged, and all their rows are kept.
+fn read_initial_weights(paths: &[std::path::PathBuf]) -> anyhow::Result<Vec<WeightRow>> {
+    let mut rows = Vec::new();
+    let mut seen = BTreeMap::new();
+    for path in paths {
+        check_csv_metadata(path, &INITIAL_WEIGHTS_COLUMNS)?;
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Unable to open initial weights file {}.", path.display()))?;
+        let mut reader = csv::Reader::from_reader(std::io::BufReader::new(file));
+        for row in reader.deserialize() {
+            let row: WeightRow = row?;
+            if let Some(previous) = seen.insert(AccountAddressEq::from(row.account), path) {
+                eprintln!(
+                    "Account {} appears in both {} and {}. The weights will be summed.",
+                    row.account,
+                    previous.display(),
+                    path.display()
+                );
+            }
+            rows.push(row);
+        }
+    }
+    Ok(rows)
+}
+
+/// Compare the initial weights in the files `a` and `b`. Accounts missing from
+/// one of the files are treated as having weight 0 there. Fails if the weight
+/// of any account differs by more than `tolerance` micro CCD.
+fn handle_compare_weights(
+    a: std::path::PathBuf,
+    b: std::path::PathBuf,
+    tolerance: u64,
+) -> anyhow::Result<()> {
+    let read = |path: &std::path::PathBuf| -> anyhow::Result<BTreeMap<AccountAddressEq, u64>> {
+        let mut weights = BTreeMap::new();
+        for row in read_initial_weights(std::slice::from_ref(path))? {
+            *weights
+                .entry(AccountAddressEq::from(row.account))
+                .or_insert(0) += row.amount.micro_ccd();
+        }
+        Ok(weights)
+    };
+    let weights_a = read(&a)?;
+    let weights_b = read(&b)?;
+
+    let accounts: BTreeSet<_> = weights_a.keys().chain(weights_b.keys()).collect();
+    let mut differing = 0usize;
+    let mut total_drift = 0u64;
+    for account in accounts {
+        let weight_a = weights_a.get(account).copied().unwrap_or(0);
+        let weight_b = weights_b.get(account).copied().unwrap_or(0);
+        let drift = weight_a.abs_diff(weight_b);
+        total_drift += drift;
+        if drift > tolerance {
+            differing += 1;
+            let account: &AccountAddress = account.as_ref();
+            println!(
+                "{account}: {} in {}, {} in {} (difference {}).",
+                Amount::from_micro_ccd(weight_a),
+                a.display(),
+                Amount::from_micro_ccd(weight_b),
+                b.display(),
+                Amount::from_micro_ccd(drift)
+            );
+        }
+    }
+
+    let total_a: u64 = weights_a.values().sum();
+    let total_b: u64 = weights_b.values().sum();
+    println!(
+        "Compared {} and {} accounts. Total weight {} vs {}, total drift {}.",
+        weights_a.len(),
+        weights_b.len(),
+        Amount::from_micro_ccd(total_a),
+        Amount::from_micro_ccd(total_b),
+        Amount::from_micro_ccd(total_drift)
+    );
+    ensure!(
+        differing == 0,
+        "The weights of {differing} accounts differ by more than the tolerance of {}.",
+        Amount::from_micro_ccd(tolerance)
+    );
+    Ok(())
+}
+
+/// Read the final weights from the files at `paths`, merging the rows of
+/// accounts appearing more than once by summing the amounts and concatenating
+/// the delegators. Such accounts are logged.
+fn read_final_weights(
+    paths: &[std::path::PathBuf],
+) -> anyhow::Result<BTreeMap<AccountAddressEq, (Amount, String)>> {
+    let mut merged: BTreeMap<AccountAddressEq, (Amount, String)> = BTreeMap::new();
+    for path in paths {
+        check_csv_metadata(path, &FINAL_WEIGHTS_COLUMNS)?;
+        let mut reader = csv::Reader::from_path(path)
+            .with_context(|| format!("Unable to open final weights file {}.", path.display()))?;
+        for row in reader.deserialize() {
+            let FinalWeightRow {
+                account,
+                amount,
+                delegators,
+            } = row?;
+            match merged.entry(AccountAddressEq::from(account)) {
+                std::collections::btree_map::Entry::Vacant(entry) => {
+                    entry.insert((amount, delegators));
+                }
+                std::collections::btree_map::Entry::Occupied(mut entry) => {
+                    eprintln!(
+                        "Account {account} appears more than once in the final weights. The \
+                         weights will be summed."
+                    );
+                    let (total, all_delegators) = entry.get_mut();
+                    *total += amount;
+                    if !delegators.is_empty() {
+                        if !all_delegators.is_empty() {
+                            all_delegators.push(';');
+                        }
+                        all_delegators.push_str(&delegators);
+                    }
+                }
+            }
+        }
+    }
+    Ok(merged)
+}
+
+/// The delegation in effect for each delegating account, mapped to the
+/// transaction that made the delegation and the delegation target.
+type Delegations = BTreeMap<AccountAddressEq, (TransactionHash, AccountAddress)>;
+
+/// Index the chain for the delegations made during the election period, i.e.
+/// transfers with memo containing the delegation string of the election,
+/// encoded according to `memo_encoding`.
+async fn index_delegations(
+    endpoint: sdk::Endpoint,
+    indexing: IndexingConfig,
+    target_address: ContractAddress,
+    memo_encoding: MemoEncoding,
+    show_progress: bool,
+) -> anyhow::Result<Delegations> {
+    let client = sdk::Client::new(endpoint).await?;
+
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, target_address).await?;
+
+    let config = get_election_data(&mut contract_client)
+        .await
+        .context("Unable to get election data.")?;
+
+    let (first_block, last_block) =
+        range_setup(&mut contract_client.client, config.start, config.end).await?;
+
+    let traverse_config =
+        indexer::TraverseConfig::new_single(indexing.endpoint.clone(), first_block.block_height);
+    let (sender, receiver) = tokio::sync::mpsc::channel(20);
+    let cancel_handle = tokio::spawn(traverse_config.traverse(indexer::TransactionIndexer, sender));
+    let mut receiver = indexing.limit_blocks(receiver);
+
+    let bar = Progress::new(
+        last_block.block_height.height - first_block.block_height.height,
+        show_progress,
+    )?;
+
+    let mut mapping = BTreeMap::new();
+
+    while let Some((block, txs)) = receiver.recv().await {
+        bar.set_message(block.block_slot_time.to_string());
         bar.inc(1);
         if block.block_slot_time > config.end {
             drop(receiver);
@@ -452,52 +2075,89 @@ async fn handle_final_weights(
             break;
         }
         for tx in txs {
-            let BlockItemSummaryDetails::AccountTransaction(atx) = tx.details else {
-                continue; // Ignore non-account transactions
-            };
-            let AccountTransactionEffects::AccountTransferWithMemo {
-                amount: _,
-                to,
-                memo,
-            } = atx.effects
-            else {
-                continue; // Only consider transfers with memo.
-            };
-            let Ok(value) = serde_cbor::from_slice::<String>(memo.as_ref()) else {
-                continue; // invalid CBOR is ignored.
-            };
-            if value == config.delegation_string {
-                // Override any previous mapping from the same account (accounting for aliases
-                // as well)
-                mapping.insert(AccountAddressEq::from(atx.sender), (tx.hash, to));
-            }
-        }
-    }
-    {
-        let mut out_handle: csv::Writer<Box<dyn std::io::Write>> =
-            if let Some(file) = delegations_out {
-                csv::Writer::from_writer(Box::new(std::fs::File::create(file)?))
-            } else {
-                csv::Writer::from_writer(Box::new(std::io::stdout().lock()))
-            };
-        for (from, (hash, to)) in &mapping {
-            out_handle.serialize(DelegationRow {
-                hash: *hash,
-                from: *from.as_ref(),
-                to:   *to,
-            })?;
+            record_delegation(&mut mapping, tx, &config.delegation_string, memo_encoding);
         }
-        out_handle.flush()?;
     }
     bar.finish_and_clear();
+    Ok(mapping)
+}
+
+/// Record the delegation made by `tx` if it is a transfer with a memo
+/// containing the `delegation_string`, encoded as given by `memo_encoding`.
+fn record_delegation(
+    mapping: &mut Delegations,
+    tx: BlockItemSummary,
+    delegation_string: &str,
+    memo_encoding: MemoEncoding,
+) {
+    let BlockItemSummaryDetails::AccountTransaction(atx) = tx.details else {
+        return; // Ignore non-account transactions
+    };
+    let AccountTransactionEffects::AccountTransferWithMemo {
+        amount: _,
+        to,
+        memo,
+    } = atx.effects
+    else {
+        return; // Only consider transfers with memo.
+    };
+    let Some(value) = memo_encoding.decode(memo.as_ref()) else {
+        return; // Memos not encoded as expected are ignored.
+    };
+    if value == delegation_string {
+        // Override any previous mapping from the same account (accounting for aliases
+        // as well)
+        mapping.insert(AccountAddressEq::from(atx.sender), (tx.hash, to));
+    }
+}
+
+/// Read the delegations from a file previously written by `final-weights`.
+fn read_delegations(path: &std::path::Path) -> anyhow::Result<Delegations> {
+    check_csv_metadata(path, &DELEGATIONS_COLUMNS)?;
+    let mut reader = csv::Reader::from_path(path)
+        .with_context(|| format!("Unable to open delegations file {}.", path.display()))?;
+    let mut mapping = BTreeMap::new();
+    for row in reader.deserialize() {
+        let DelegationRow { hash, from, to } = row?;
+        let previous = mapping.insert(AccountAddressEq::from(from), (hash, to));
+        ensure!(
+            previous.is_none(),
+            "Account {from} appears more than once in the delegations file {}.",
+            path.display()
+        );
+    }
+    Ok(mapping)
+}
+
+/// Write the delegations to the file at `out`, or to stdout if not given.
+fn write_delegations(out: Option<&std::path::Path>, mapping: &Delegations) -> anyhow::Result<()> {
+    let mut out_handle: csv::Writer<Box<dyn std::io::Write>> = if let Some(file) = out {
+        write_csv_metadata(file, &DELEGATIONS_COLUMNS)?;
+        csv::Writer::from_writer(Box::new(std::fs::File::create(file)?))
+    } else {
+        csv::Writer::from_writer(Box::new(std::io::stdout().lock()))
+    };
+    for (from, (hash, to)) in mapping {
+        out_handle.serialize(DelegationRow {
+            hash: *hash,
+            from: *from.as_ref(),
+            to:   *to,
+        })?;
+    }
+    out_handle.flush()?;
+    Ok(())
+}
 
-    let initial_weights = std::fs::File::open(initial_weights)?;
-    let mut weights = csv::Reader::from_reader(std::io::BufReader::new(initial_weights));
-    // For each initial account
+/// Compute the final weights given the initial weights and the delegations.
+/// For each account the total weight is returned, together with the accounts
+/// that delegated to it.
+fn compute_final_weights(
+    initial_weights: Vec<WeightRow>,
+    mapping: &Delegations,
+) -> BTreeMap<AccountAddressEq, (Amount, Vec<AccountAddress>)> {
     let mut final_weights = BTreeMap::new();
-    for row in weights.deserialize() {
-        let row: WeightRow = row?;
-        if let Some((_hash, target)) = mapping.remove(row.account.as_ref()) {
+    for row in initial_weights {
+        if let Some(&(_hash, target)) = mapping.get(row.account.as_ref()) {
             let weight = final_weights
                 .entry(AccountAddressEq::from(target))
                 .or_insert((Amount::zero(), Vec::new()));
@@ -510,1048 +2170,7758 @@ async fn handle_final_weights(
             weight.0 += row.amount;
         }
     }
+    final_weights
+}
+
+/// Compute the final weights given the initial weights.
+/// The time range is taken from the provided contract. If
+/// `delegations_snapshot` is given, the delegations are read from it instead
+/// of the chain, which makes the result reproducible.
+#[allow(clippy::too_many_arguments)]
+async fn handle_final_weights(
+    endpoint: sdk::Endpoint,
+    indexing: IndexingConfig,
+    delegations_out: Option<std::path::PathBuf>,
+    target_address: ContractAddress,
+    initial_weights: Vec<std::path::PathBuf>,
+    final_weights_path: std::path::PathBuf,
+    delegations_snapshot: Option<std::path::PathBuf>,
+    write_delegations_snapshot: Option<std::path::PathBuf>,
+    memo_encoding: MemoEncoding,
+    show_progress: bool,
+) -> anyhow::Result<()> {
+    let mapping = if let Some(snapshot) = delegations_snapshot {
+        eprintln!(
+            "Using the delegations in {} instead of indexing the chain.",
+            snapshot.display()
+        );
+        read_delegations(&snapshot)?
+    } else {
+        index_delegations(
+            endpoint,
+            indexing,
+            target_address,
+            memo_encoding,
+            show_progress,
+        )
+        .await?
+    };
+    if let Some(snapshot) = write_delegations_snapshot {
+        write_delegations(Some(&snapshot), &mapping)?;
+        eprintln!("Wrote delegations snapshot to {}.", snapshot.display());
+    }
+    write_delegations(delegations_out.as_deref(), &mapping)?;
+
+    let final_weights = compute_final_weights(read_initial_weights(&initial_weights)?, &mapping);
+    write_final_weights_csv(&final_weights_path, final_weights)
+}
+
+/// Write the `final_weights` computed by [`compute_final_weights`] to the CSV
+/// file at `path`.
+fn write_final_weights_csv(
+    path: &std::path::Path,
+    final_weights: BTreeMap<AccountAddressEq, (Amount, Vec<AccountAddress>)>,
+) -> anyhow::Result<()> {
+    write_csv_metadata(path, &FINAL_WEIGHTS_COLUMNS)?;
+    let mut out_handle = csv::Writer::from_path(path)?;
+    for (addr, (amount, delegators)) in final_weights {
+        out_handle.serialize(FinalWeightRow {
+            account: AccountAddress::from(addr),
+            amount,
+            delegators: delegators
+                .into_iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(";"),
+        })?;
+    }
+    out_handle.flush()?;
+    Ok(())
+}
+
+/// Write the roster of the election to `out`, with a row for each account and
+/// its effective weight after applying the delegations made during the
+/// election period to the `initial_weights`. The weights are computed as for
+/// `final-weights`, but the delegators are left out. The SHA256 hash of the
+/// roster is printed, so that the published roster can be checked against it.
+async fn handle_publish_roster(
+    endpoint: sdk::Endpoint,
+    indexing: IndexingConfig,
+    target_address: ContractAddress,
+    initial_weights: Vec<std::path::PathBuf>,
+    out: std::path::PathBuf,
+    memo_encoding: MemoEncoding,
+    show_progress: bool,
+) -> anyhow::Result<()> {
+    let client = sdk::Client::new(endpoint.clone()).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, target_address).await?;
+    let config =
+        election_contract::view_config(&mut contract_client, BlockIdentifier::LastFinal).await?;
+    let end: chrono::DateTime<chrono::Utc> = config.election_end.try_into()?;
+    let last_final = contract_client
+        .client
+        .get_block_info(BlockIdentifier::LastFinal)
+        .await?
+        .response;
+    ensure!(
+        last_final.block_slot_time > end,
+        "The election has not ended yet. The roster can only be published after {end}, so that \
+         delegations are not revealed while voting is ongoing."
+    );
+
+    let mapping = index_delegations(
+        endpoint,
+        indexing,
+        target_address,
+        memo_encoding,
+        show_progress,
+    )
+    .await?;
+    let roster = compute_final_weights(read_initial_weights(&initial_weights)?, &mapping);
+    let hash = write_roster(&out, roster)?;
+    eprintln!(
+        "Wrote the roster to {}. Its SHA256 hash is {hash}.",
+        out.display()
+    );
+    Ok(())
+}
 
+/// Write the roster with the effective weights in `final_weights`, as
+/// computed by [`compute_final_weights`], to the CSV file at `path`, leaving
+/// out the delegators. Returns the SHA256 hash of the file.
+fn write_roster(
+    path: &std::path::Path,
+    final_weights: BTreeMap<AccountAddressEq, (Amount, Vec<AccountAddress>)>,
+) -> anyhow::Result<contract::HashSha2256> {
+    write_csv_metadata(path, &ROSTER_COLUMNS)?;
     {
-        let mut out_handle = csv::Writer::from_path(final_weights_path)?;
-        for (addr, (amount, delegators)) in final_weights {
-            out_handle.serialize(FinalWeightRow {
-                account: AccountAddress::from(addr),
+        let mut out_handle = csv::Writer::from_path(path)?;
+        for (account, (amount, _delegators)) in final_weights {
+            out_handle.serialize(WeightRow {
+                account: AccountAddress::from(account),
                 amount,
-                delegators: delegators
-                    .into_iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(";"),
             })?;
         }
         out_handle.flush()?;
     }
 
-    Ok(())
+    let data = std::fs::read(path)
+        .with_context(|| format!("Unable to read the roster from {}.", path.display()))?;
+    Ok(contract::HashSha2256(sha2::Sha256::digest(&data).into()))
 }
 
-enum ElectionContract {}
+#[derive(serde::Serialize)]
+struct VoteChangesRow {
+    account:      AccountAddress,
+    /// Number of ballots submitted by the account.
+    ballots:      usize,
+    /// Whether the account submitted more than one ballot.
+    flagged:      bool,
+    /// ';' separated list of the slot times of the blocks the ballots were
+    /// submitted in.
+    timestamps:   String,
+    /// ';' separated list of the transactions the ballots were submitted in.
+    transactions: String,
+}
 
-/// Handle decryption of the final result, and checking or publishing the result
-/// in the contract.
-async fn handle_decrypt(
+/// The ballot submissions of each account, given by the slot time of the block
+/// and the transaction of each submission, in the order they were submitted.
+type BallotSubmissions =
+    BTreeMap<AccountAddressEq, Vec<(chrono::DateTime<chrono::Utc>, TransactionHash)>>;
+
+/// Write a [`VoteChangesRow`] for each account in `submissions` to the file at
+/// `out`, or to stdout if not given.
+fn write_vote_changes(
+    out: Option<&std::path::Path>,
+    submissions: &BallotSubmissions,
+) -> anyhow::Result<()> {
+    let mut out_handle: csv::Writer<Box<dyn std::io::Write>> = if let Some(file) = out {
+        write_csv_metadata(file, &VOTE_CHANGES_COLUMNS)?;
+        csv::Writer::from_writer(Box::new(std::fs::File::create(file)?))
+    } else {
+        csv::Writer::from_writer(Box::new(std::io::stdout().lock()))
+    };
+    for (account, ballots) in submissions {
+        out_handle.serialize(VoteChangesRow {
+            account:      *account.as_ref(),
+            ballots:      ballots.len(),
+            flagged:      ballots.len() > 1,
+            timestamps:   ballots
+                .iter()
+                .map(|(time, _)| time.to_rfc3339())
+                .collect::<Vec<_>>()
+                .join(";"),
+            transactions: ballots
+                .iter()
+                .map(|(_, hash)| hash.to_string())
+                .collect::<Vec<_>>()
+                .join(";"),
+        })?;
+    }
+    out_handle.flush()?;
+    Ok(())
+}
+
+/// Index all ballots submitted during the election period and report, for each
+/// account, how many ballots it submitted and when. Only the last ballot of
+/// each account is counted in the tally, so this does not affect the result,
+/// but it can surface unusual voting behaviour.
+async fn handle_vote_changes(
     endpoint: sdk::Endpoint,
-    contract: ContractAddress,
-    wallet_path: Option<std::path::PathBuf>,
+    indexing: IndexingConfig,
+    target_address: ContractAddress,
+    out: Option<std::path::PathBuf>,
+    show_progress: bool,
 ) -> anyhow::Result<()> {
     let client = sdk::Client::new(endpoint.clone()).await?;
     let mut contract_client =
-        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
+        contract_client::ContractClient::<ElectionContract>::create(client, target_address).await?;
 
-    let mut guardians_state = contract_client
-        .view::<_, contract::GuardiansState, ViewError>(
-            "viewGuardiansState",
-            &(),
-            BlockIdentifier::LastFinal,
-        )
-        .await?;
-    let election_data = get_election_data(&mut contract_client).await?;
-    let mut decryption_shares = Vec::new();
-    let mut proof_shares = Vec::new();
+    let config =
+        election_contract::view_config(&mut contract_client, BlockIdentifier::LastFinal).await?;
+    let start = config.election_start.try_into()?;
+    let end: chrono::DateTime<chrono::Utc> = config.election_end.try_into()?;
 
-    let encrypted_tally = contract_client
-        .view::<(), Option<Vec<u8>>, ViewError>(
-            "viewEncryptedTally",
-            &(),
-            BlockIdentifier::LastFinal,
-        )
-        .await?;
-    let Some(encrypted_tally) = encrypted_tally else {
-        anyhow::bail!("Encrypted tally not yet registered.")
-    };
+    let (first_block, last_block) = range_setup(&mut contract_client.client, start, end).await?;
 
-    let Ok(tally) = decode::<EncryptedTally>(&encrypted_tally) else {
-        anyhow::bail!("Encrypted tally is not readable.")
-    };
+    let traverse_config =
+        indexer::TraverseConfig::new_single(indexing.endpoint.clone(), first_block.block_height);
+    let (sender, receiver) = tokio::sync::mpsc::channel(20);
+    let cancel_handle = tokio::spawn(traverse_config.traverse(
+        indexer::ContractUpdateIndexer {
+            target_address,
+            entrypoint: OwnedEntrypointName::new_unchecked("registerVotes".into()),
+        },
+        sender,
+    ));
+    let mut receiver = indexing.limit_blocks(receiver);
 
-    guardians_state.sort_by_key(|g| g.1.index);
+    let bar = Progress::new(
+        last_block.block_height.height - first_block.block_height.height,
+        show_progress,
+    )?;
 
-    for (guardian_address, guardian_state) in guardians_state {
-        if let (Some(share), Some(proof)) = (
-            guardian_state.decryption_share,
-            guardian_state.decryption_share_proof,
-        ) {
-            let Ok(share) = decode::<GuardianDecryption>(&share) else {
-                eprintln!("The decryption share registered by {guardian_address} is not readable.");
-                continue;
-            };
-            let Ok(proof) = decode::<GuardianDecryptionProof>(&proof) else {
-                eprintln!("The decryption proof response share registered by {guardian_address} is not readable.");
-                continue;
-            };
-            decryption_shares.push(share);
-            proof_shares.push(proof);
+    // Unlike the tally we keep all the ballots of each account, in the order they
+    // were submitted.
+    let mut submissions = BallotSubmissions::new();
+
+    while let Some((block, txs)) = receiver.recv().await {
+        bar.set_message(block.block_slot_time.to_string());
+        bar.inc(1);
+        if block.block_slot_time > end {
+            drop(receiver);
+            cancel_handle.abort();
+            drop(cancel_handle);
+            eprintln!("Done indexing.");
+            break;
+        }
+
+        for indexer::ContractUpdateInfo {
+            transaction_hash,
+            sender,
+            ..
+        } in txs
+        {
+            submissions
+                .entry(AccountAddressEq::from(sender))
+                .or_default()
+                .push((block.block_slot_time, transaction_hash));
         }
     }
-    let quorum = election_data
-        .parameters
-        .varying_parameters
-        .k
-        .get_one_based_usize();
-    anyhow::ensure!(
-        decryption_shares.len() >= quorum,
-        "Not enough shares. Require {quorum} but only have {}.",
-        decryption_shares.len()
-    );
+    bar.finish_and_clear();
 
-    eprintln!(
-        "{} decryption shares available. Starting decryption.",
-        decryption_shares.len()
-    );
+    write_vote_changes(out.as_deref(), &submissions)?;
 
-    // Progress bar for decryption.
-    let bar = ProgressBar::new(tally.values().map(|x| x.len()).sum::<usize>() as u64).with_style(
-        ProgressStyle::with_template("{spinner} {msg} {wide_bar} {pos}/{len}")?,
+    // Number of accounts for each number of submitted ballots.
+    let mut distribution = BTreeMap::<usize, usize>::new();
+    for ballots in submissions.values() {
+        *distribution.entry(ballots.len()).or_default() += 1;
+    }
+
+    let flagged = submissions.values().filter(|b| b.len() > 1).count();
+    eprintln!(
+        "{} accounts submitted ballots, {flagged} of which submitted more than one.",
+        submissions.len()
     );
-    // spin the spinner automatically since we can't tick it during decryption of an
-    // individual ciphertext
-    bar.enable_steady_tick(std::time::Duration::from_millis(100));
-
-    let mut decryption = {
-        let mut decrypted_tallies = BTreeMap::new();
-        for (contest, ciphertexts) in tally.into_iter() {
-            let mut ciphers = Vec::new();
-            for (i, ciphertext) in ciphertexts.into_iter().enumerate() {
-                bar.set_message(format!("ciphertext {i} (contest {contest})"));
-                bar.inc(1);
-                // each guardian provides a decryption share of each of the options
-                // for each of the contests.
-                let mut decryption_shares_for_option = Vec::new();
-                for guardian_shares in &decryption_shares {
-                    let Some(decryption_share) = guardian_shares.get(&contest) else {
-                        anyhow::bail!("Missing decryption share for contest {contest}");
-                    };
-                    let Some(share) = decryption_share.get(i) else {
-                        anyhow::bail!(
-                            "Missing decryption share for contest {contest} and option {i}"
-                        );
-                    };
-                    decryption_shares_for_option.push(share);
-                }
-                let mut proof_shares_for_option = Vec::new();
-                for proof_shares in &proof_shares {
-                    let Some(proof_share) = proof_shares.get(&contest) else {
-                        anyhow::bail!("Missing proof share for contest {contest}");
-                    };
-                    let Some(share) = proof_share.get(i) else {
-                        anyhow::bail!("Missing proof share for contest {contest} and option {i}");
-                    };
-                    proof_shares_for_option.push(share);
-                }
+    for (ballots, accounts) in distribution {
+        eprintln!("{accounts} account(s) submitted {ballots} ballot(s).");
+    }
 
-                let decrypted = VerifiableDecryption::compute(
-                    &election_data.manifest,
-                    &election_data.parameters,
-                    &election_data.guardian_public_keys,
-                    &ciphertext,
-                    decryption_shares_for_option,
-                    proof_shares_for_option,
-                )?;
-                ciphers.push(decrypted);
-            }
-            decrypted_tallies.insert(contest, ciphers);
-        }
-        decrypted_tallies
-    };
+    Ok(())
+}
+
+/// Index `blocks` blocks from the start of the election in the same way as
+/// the tally, i.e. parsing and verifying all ballots, and report the
+/// throughput.
+async fn handle_benchmark_indexing(
+    endpoint: sdk::Endpoint,
+    indexing: IndexingConfig,
+    target_address: ContractAddress,
+    blocks: u64,
+    show_progress: bool,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(blocks > 0, "Need at least one block to index.");
+    let client = sdk::Client::new(endpoint.clone()).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, target_address).await?;
+
+    let election_data = get_election_data(&mut contract_client).await?;
+    let verification_context = election_data.verification_context()?;
+
+    let first_block = contract_client
+        .client
+        .find_first_finalized_block_no_earlier_than(.., election_data.start)
+        .await
+        .context("Unable to find the first block of the election. Has the election started?")?;
+    eprintln!(
+        "Indexing {blocks} blocks from block {} at {}.",
+        first_block.block_hash, first_block.block_slot_time
+    );
+
+    let traverse_config =
+        indexer::TraverseConfig::new_single(indexing.endpoint.clone(), first_block.block_height);
+    let (sender, receiver) = tokio::sync::mpsc::channel(indexing.block_batch_size.max(20));
+    let cancel_handle = tokio::spawn(traverse_config.traverse(
+        indexer::ContractUpdateIndexer {
+            target_address,
+            entrypoint: OwnedEntrypointName::new_unchecked("registerVotes".into()),
+        },
+        sender,
+    ));
+    let mut receiver = indexing.limit_blocks(receiver);
+
+    let bar = Progress::new(blocks, show_progress)?;
+
+    let benchmark = IndexingBenchmark::run(
+        &indexing,
+        &mut receiver,
+        blocks,
+        &verification_context,
+        |(block, txs)| {
+            bar.set_message(block.block_slot_time.to_string());
+            bar.inc(1);
+            txs.into_iter()
+                .map(|indexer::ContractUpdateInfo { execution_tree, .. }| {
+                    execution_tree.parameter().as_ref().to_vec()
+                })
+                .collect()
+        },
+    )
+    .await;
+    drop(receiver);
+    cancel_handle.abort();
     bar.finish_and_clear();
 
-    let contest = {
-        let mut contests = election_data.manifest.contests.indices();
-        let Some(contest) = contests.next() else {
-            anyhow::bail!("Need a contest in manifest.");
-        };
-        anyhow::ensure!(
-            contests.next().is_none(),
-            "Only a single contest is supported."
-        );
-        contest
-    };
+    anyhow::ensure!(benchmark.blocks > 0, "No blocks were indexed.");
+    for line in benchmark.report() {
+        println!("{line}");
+    }
+    Ok(())
+}
+
+/// The throughput measured by `benchmark-indexing`.
+struct IndexingBenchmark {
+    /// The number of blocks indexed.
+    blocks:            u64,
+    /// The number of `registerVotes` updates in the blocks.
+    ballots:           usize,
+    /// The number of those that contain a ballot that verifies.
+    valid:             usize,
+    /// The total time spent indexing.
+    elapsed:           Duration,
+    /// The part of `elapsed` spent parsing and verifying ballots.
+    verification_time: Duration,
+}
 
-    let weights = if let Some(results) = decryption.remove(&contest) {
-        let mut weights: contract::PostResultParameter = Vec::with_capacity(results.len());
-        for value in results {
-            let weight = value.plain_text.value().to_u64_digits();
-            anyhow::ensure!(weight.len() <= 1, "Weight must fit into a u64.");
-            weights.push(weight.first().copied().unwrap_or(0));
+impl IndexingBenchmark {
+    /// Index up to `blocks` blocks received on `receiver`, and parse and verify
+    /// the ballots in them. The parameters of the `registerVotes` updates of a
+    /// block are extracted by `parameters`.
+    async fn run<T>(
+        indexing: &IndexingConfig,
+        receiver: &mut tokio::sync::mpsc::Receiver<T>,
+        blocks: u64,
+        verification_context: &PreVotingData,
+        mut parameters: impl FnMut(T) -> Vec<Vec<u8>>,
+    ) -> Self {
+        let start = std::time::Instant::now();
+        let mut indexed = 0u64;
+        let mut ballots = 0usize;
+        let mut valid = 0usize;
+        let mut verification_time = Duration::ZERO;
+        while indexed < blocks {
+            let Some(batch) = indexing.recv_batch(receiver).await else {
+                break;
+            };
+            let mut txs = Vec::new();
+            for block in batch.into_iter().take((blocks - indexed) as usize) {
+                indexed += 1;
+                txs.extend(parameters(block));
+            }
+
+            let verification_start = std::time::Instant::now();
+            let results = txs
+                .into_par_iter()
+                .map(|parameter| {
+                    concordium_std::from_bytes::<contract::RegisterVotesParameter>(&parameter)
+                        .ok()
+                        .and_then(|param| decode::<BallotEncrypted>(&param.inner).ok())
+                        .is_some_and(|ballot| ballot.verify(verification_context))
+                })
+                .collect::<Vec<_>>();
+            verification_time += verification_start.elapsed();
+            ballots += results.len();
+            valid += results.into_iter().filter(|verified| *verified).count();
         }
-        weights
-    } else if decryption.is_empty() {
-        // no contests means we had no valid votes in the election.
-        eprintln!("No valid votes in the election. All candidates get 0 votes.");
-        vec![0u64; election_data.candidates.len()] // each candidate gets 0
-                                                   // votes.
-    } else {
-        anyhow::bail!("Encryptions only exist for incorrect contests.");
-    };
+        Self {
+            blocks: indexed,
+            ballots,
+            valid,
+            elapsed: start.elapsed(),
+            verification_time,
+        }
+    }
 
-    {
-        // Format results for display.
-        let computed_results: Vec<contract::CandidateResult> = election_data
-            .candidates
-            .into_iter()
-            .zip(&weights)
-            .map(
-                |(candidate, &cummulative_votes)| contract::CandidateResult {
-                    candidate,
-                    cummulative_votes,
-                },
-            )
-            .collect();
+    fn blocks_per_second(&self) -> f64 { self.blocks as f64 / self.elapsed.as_secs_f64() }
 
-        let json_repr: String = Vec::<contract::CandidateResult>::get_type()
-            .to_json_string_pretty(&concordium_std::to_bytes(&computed_results))
-            .context("Unable to convert to String")?;
-        eprintln!("The computed election results are.");
-        println!("{json_repr}");
+    fn ballots_per_second(&self) -> f64 { self.ballots as f64 / self.elapsed.as_secs_f64() }
+
+    /// The lines of the report printed by `benchmark-indexing`.
+    fn report(&self) -> Vec<String> {
+        let mut lines = vec![
+            format!(
+                "Indexed {} blocks containing {} ballots, {} of which are valid, in {:.2}s.",
+                self.blocks,
+                self.ballots,
+                self.valid,
+                self.elapsed.as_secs_f64()
+            ),
+            format!("Blocks per second: {:.2}", self.blocks_per_second()),
+            format!("Ballots per second: {:.2}", self.ballots_per_second()),
+        ];
+        if self.ballots > 0 {
+            lines.push(format!(
+                "Time spent verifying ballots: {:.2}s, i.e. {:.2}ms per ballot.",
+                self.verification_time.as_secs_f64(),
+                self.verification_time.as_secs_f64() * 1000.0 / self.ballots as f64
+            ));
+        }
+        lines
     }
+}
 
-    let current_result = contract_client
-        .view::<_, contract::ViewElectionResultQueryResponse, ViewError>(
-            "viewElectionResult",
-            &(),
-            BlockIdentifier::LastFinal,
-        )
-        .await?;
+/// How an update of the election result changed the result, see
+/// [`ResultHistory::apply`].
+#[derive(serde::Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+enum ResultHistoryKind {
+    /// The result was posted and stored, as the election has no result
+    /// approvers.
+    Posted,
+    /// The result was proposed, and is stored once approved.
+    Proposed,
+    /// The proposed result was approved, but not by enough approvers to be
+    /// stored.
+    Approved,
+    /// The proposed result was approved by enough approvers and stored.
+    Stored,
+}
 
-    if let Some(result) = current_result {
-        let current_weights = result
-            .iter()
-            .map(|x| x.cummulative_votes)
-            .collect::<Vec<_>>();
-        if current_weights != weights {
-            let json_repr: String = Vec::<contract::CandidateResult>::get_type()
-                .to_json_string_pretty(&concordium_std::to_bytes(&result))
-                .context("Unable to convert to String")?;
-            eprintln!(
-                "The election results are already published in the contract and are\n
-                 {json_repr}."
-            );
-            let confirm = dialoguer::Confirm::new()
-                .report(true)
-                .wait_for_newline(true)
-                .with_prompt("Do you want to overwrite the published results?")
-                .interact()?;
-            anyhow::ensure!(confirm, "Aborting.");
-        } else {
-            eprintln!(
-                "The election results are already registered in the contract, and they match. \
-                 Terminating."
-            );
-            return Ok(());
+/// An update of the election result registered in the contract.
+#[derive(Debug)]
+enum ResultUpdate {
+    /// The admin account posted a result through `postElectionResult`.
+    Post(contract::ElectionResult),
+    /// A result approver approved the result with the hash through
+    /// `approveResult`.
+    Approve {
+        approver: AccountAddress,
+        hash:     contract::HashSha2256,
+    },
+}
+
+/// Tracks the result proposed in the contract, to determine when a proposed
+/// result is stored. This mirrors the `postElectionResult` and `approveResult`
+/// entrypoints of the contract.
+struct ResultHistory {
+    /// Whether posted results must be approved before they are stored.
+    requires_approval:  bool,
+    /// The number of approvals needed to store a proposed result.
+    approval_threshold: usize,
+    /// The currently proposed result and the accounts that approved it.
+    proposal:           Option<(contract::ElectionResult, Vec<AccountAddress>)>,
+}
+
+impl ResultHistory {
+    fn new(config: &contract::ElectionConfig) -> Self {
+        Self {
+            requires_approval:  !config.result_approvers.is_empty(),
+            approval_threshold: config.approval_threshold as usize,
+            proposal:           None,
         }
     }
 
-    if let Some(wallet_path) = wallet_path {
-        let wallet = WalletAccount::from_json_file(wallet_path)?;
-        let dry_run = contract_client
-            .dry_run_update::<_, ViewError>(
-                "postElectionResult",
-                Amount::zero(),
-                wallet.address,
-                &weights,
-            )
-            .await
-            .context("Failed to dry run")?;
+    /// Apply an update accepted by the contract, returning how it changed the
+    /// result together with the result concerned. Returns `None` for approvals
+    /// which do not concern the current proposal.
+    fn apply(
+        &mut self,
+        update: ResultUpdate,
+    ) -> Option<(ResultHistoryKind, contract::ElectionResult)> {
+        match update {
+            ResultUpdate::Post(result) if !self.requires_approval => {
+                Some((ResultHistoryKind::Posted, result))
+            }
+            ResultUpdate::Post(result) => {
+                self.proposal = Some((result.clone(), Vec::new()));
+                Some((ResultHistoryKind::Proposed, result))
+            }
+            ResultUpdate::Approve { approver, hash } => {
+                let (result, approvals) = self.proposal.as_mut()?;
+                if election_result_hash(result) != hash || approvals.contains(&approver) {
+                    return None;
+                }
+                approvals.push(approver);
+                // Approvals beyond the threshold store the same result again, so
+                // only the approval reaching the threshold is reported as storing it.
+                let kind = if approvals.len() == self.approval_threshold {
+                    ResultHistoryKind::Stored
+                } else {
+                    ResultHistoryKind::Approved
+                };
+                Some((kind, result.clone()))
+            }
+        }
+    }
+}
 
-        let handle = dry_run.send(&wallet).await?;
+#[derive(serde::Serialize)]
+struct ResultHistoryRow {
+    /// Slot time of the block the result was updated in.
+    timestamp:   String,
+    block:       BlockHash,
+    transaction: TransactionHash,
+    kind:        ResultHistoryKind,
+    /// ';' separated list of the weighted votes of each candidate, in the order
+    /// of the candidates in the contract.
+    result:      String,
+}
 
-        if let Err(e) = handle.wait_for_finalization().await {
-            eprintln!("Transaction failed with {e:#?}");
-        } else {
-            eprintln!("Transaction successful and finalized.",);
+impl ResultHistoryRow {
+    /// The row for an update of kind `kind` to `result`, made in transaction
+    /// `transaction` of the block `block` with slot time `block_time`.
+    fn new(
+        block_time: chrono::DateTime<chrono::Utc>,
+        block: BlockHash,
+        transaction: TransactionHash,
+        kind: ResultHistoryKind,
+        result: &contract::ElectionResult,
+    ) -> Self {
+        Self {
+            timestamp: block_time.to_rfc3339(),
+            block,
+            transaction,
+            kind,
+            result: flatten_result(result)
+                .iter()
+                .map(|votes| votes.to_string())
+                .collect::<Vec<_>>()
+                .join(";"),
         }
-    } else {
-        eprintln!(
-            "The admin keys were not provided, the results are not going to be posted to the \
-             contract.\nRun again with the `--admin-keys` option to do so. "
-        )
     }
+}
 
+/// Write the `rows` to the file at `out`, or to stdout if not given.
+fn write_result_history(
+    out: Option<&std::path::Path>,
+    rows: &[ResultHistoryRow],
+) -> anyhow::Result<()> {
+    let mut out_handle: csv::Writer<Box<dyn std::io::Write>> = if let Some(file) = out {
+        write_csv_metadata(file, &RESULT_HISTORY_COLUMNS)?;
+        csv::Writer::from_writer(Box::new(std::fs::File::create(file)?))
+    } else {
+        csv::Writer::from_writer(Box::new(std::io::stdout().lock()))
+    };
+    for row in rows {
+        out_handle.serialize(row)?;
+    }
+    out_handle.flush()?;
     Ok(())
 }
 
-async fn handle_reset(
+/// Index the chain from the end of the election and list every update of the
+/// election result, i.e. every result posted or proposed, every approval of a
+/// proposed result, and when a proposed result was stored.
+async fn handle_result_history(
     endpoint: sdk::Endpoint,
-    contract: ContractAddress,
-    wallet_path: std::path::PathBuf,
-    guardians: Vec<AccountAddress>,
-    decryption_deadline: Timestamp,
+    indexing: IndexingConfig,
+    target_address: ContractAddress,
+    out: Option<std::path::PathBuf>,
+    show_progress: bool,
 ) -> anyhow::Result<()> {
     let client = sdk::Client::new(endpoint.clone()).await?;
     let mut contract_client =
-        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
-    let wallet = WalletAccount::from_json_file(wallet_path)?;
-    let parameter = (guardians, decryption_deadline);
-    let dry_run = contract_client
-        .dry_run_update::<_, ViewError>(
-            "resetFinalizationPhase",
-            Amount::zero(),
-            wallet.address,
-            &parameter,
-        )
+        contract_client::ContractClient::<ElectionContract>::create(client, target_address).await?;
+
+    let config =
+        election_contract::view_config(&mut contract_client, BlockIdentifier::LastFinal).await?;
+    let end: chrono::DateTime<chrono::Utc> = config.election_end.try_into()?;
+
+    // Results can only be posted after the election has ended, so only the blocks
+    // from then until now are indexed.
+    let first_block = contract_client
+        .client
+        .find_first_finalized_block_no_earlier_than(.., end)
         .await
-        .context("Failed to dry run")?;
+        .context("The election has not ended yet.")?;
+    let last_block = contract_client
+        .client
+        .get_block_info(BlockIdentifier::LastFinal)
+        .await?
+        .response;
+    eprintln!(
+        "Indexing from block {} at {} until block {} at {}.",
+        first_block.block_hash,
+        first_block.block_slot_time,
+        last_block.block_hash,
+        last_block.block_slot_time
+    );
 
-    let guardians_state = contract_client
-        .view::<_, contract::GuardiansState, ViewError>(
-            "viewGuardiansState",
-            &(),
-            BlockIdentifier::LastFinal,
-        )
-        .await?;
+    let traverse_config =
+        indexer::TraverseConfig::new_single(indexing.endpoint.clone(), first_block.block_height);
+    let (sender, receiver) = tokio::sync::mpsc::channel(20);
+    let cancel_handle = tokio::spawn(traverse_config.traverse(indexer::TransactionIndexer, sender));
+    let mut receiver = indexing.limit_blocks(receiver);
 
-    let guardians_state_filtered = guardians_state
-        .iter()
-        .filter(|(addr, _)| parameter.0.contains(addr));
+    let bar = Progress::new(
+        last_block.block_height.height - first_block.block_height.height,
+        show_progress,
+    )?;
 
-    eprintln!("Guardians to be removed:");
-    for (addr, st) in guardians_state_filtered {
-        let Some(pk_bytes) = st.public_key.clone() else {
-            anyhow::bail!("Public key not found for guardian with address {}", addr);
-        };
-        let pk = decode::<GuardianPublicKey>(&pk_bytes)
-            .context("Failed to decode guardian public key.")?;
-        let pk_json = serde_json::to_string_pretty(&pk.coefficient_commitments.0[0])?;
-        eprintln!(
-            "Guardian {} with address {} and public key {}",
-            pk.i, addr, pk_json
-        );
-    }
+    let mut rows = Vec::new();
+    let mut history = ResultHistory::new(&config);
+    let mut counts = BTreeMap::<ResultHistoryKind, usize>::new();
+    while let Some((block, txs)) = receiver.recv().await {
+        bar.set_message(block.block_slot_time.to_string());
+        bar.inc(1);
 
-    let confirm = dialoguer::Confirm::new()
-        .report(true)
-        .wait_for_newline(true)
-        .with_prompt("Confirm tally decryption reset.")
-        .interact()?;
-    anyhow::ensure!(confirm, "Aborting.");
+        for tx in txs {
+            let BlockItemSummaryDetails::AccountTransaction(atx) = tx.details else {
+                continue;
+            };
+            // Rejected transactions have no effects, so only updates accepted by the
+            // contract are included.
+            let AccountTransactionEffects::ContractUpdateIssued { effects } = atx.effects else {
+                continue;
+            };
+            let Some(ContractTraceElement::Updated { data }) = effects.first() else {
+                continue;
+            };
+            if data.address != target_address {
+                continue;
+            }
+            let entrypoint = data
+                .receive_name
+                .as_receive_name()
+                .entrypoint_name()
+                .to_string();
+            let update = match entrypoint.as_str() {
+                "postElectionResult" => {
+                    concordium_std::from_bytes::<contract::PostResultParameter>(
+                        data.message.as_ref(),
+                    )
+                    .map(ResultUpdate::Post)
+                }
+                "approveResult" => concordium_std::from_bytes::<contract::ApproveResultParameter>(
+                    data.message.as_ref(),
+                )
+                .map(|hash| ResultUpdate::Approve {
+                    approver: atx.sender,
+                    hash,
+                }),
+                _ => continue,
+            };
+            let Ok(update) = update else {
+                eprintln!("Unable to parse result update from transaction {}", tx.hash);
+                continue;
+            };
+            let Some((kind, result)) = history.apply(update) else {
+                continue;
+            };
+            *counts.entry(kind).or_default() += 1;
+            rows.push(ResultHistoryRow::new(
+                block.block_slot_time,
+                block.block_hash,
+                tx.hash,
+                kind,
+                &result,
+            ));
+        }
 
-    let handle = dry_run.send(&wallet).await?;
+        if block.block_height >= last_block.block_height {
+            drop(receiver);
+            cancel_handle.abort();
+            drop(cancel_handle);
+            eprintln!("Done indexing.");
+            break;
+        }
+    }
+    bar.finish_and_clear();
+    write_result_history(out.as_deref(), &rows)?;
 
-    if let Err(e) = handle.wait_for_finalization().await {
-        eprintln!("Transaction failed with {e:#?}");
+    let count = |kind| counts.get(&kind).copied().unwrap_or_default();
+    let stored = count(ResultHistoryKind::Posted) + count(ResultHistoryKind::Stored);
+    if history.requires_approval {
+        eprintln!(
+            "An election result was proposed {} time(s), and a proposed result was stored \
+             {stored} time(s) after being approved by {} of the result approvers.",
+            count(ResultHistoryKind::Proposed),
+            history.approval_threshold
+        );
     } else {
-        eprintln!("Transaction successful and finalized.",);
+        eprintln!("The election result was posted {stored} time(s).");
+    }
+    if !counts.is_empty() {
+        eprintln!("The results are listed for the candidates in the following order.");
+        for candidate in &config.candidates {
+            eprintln!("  {}", candidate.url);
+        }
     }
 
     Ok(())
 }
 
-/// Election data retrieved from the contract and processed.
-struct ElectionData {
-    manifest:             ElectionManifest,
-    parameters:           ElectionParameters,
-    guardian_public_keys: Vec<GuardianPublicKey>,
-    candidates:           Vec<contract::ChecksumUrl>,
-    start:                chrono::DateTime<chrono::Utc>,
-    end:                  chrono::DateTime<chrono::Utc>,
-    /// String that is used to detect delegations.
-    delegation_string:    String,
+/// The entrypoints through which guardians register their decryption shares
+/// and proofs.
+const DECRYPTION_ENTRYPOINTS: [&str; 3] = [
+    "postDecryptionShare",
+    "postDecryptionShareForContest",
+    "postDecryptionProofResponseShare",
+];
+
+#[derive(serde::Serialize)]
+struct DecryptionTimingRow {
+    /// Slot time of the block the decryption data was registered in.
+    timestamp:         String,
+    /// Seconds from the end of the election until the block.
+    seconds_after_end: i64,
+    guardian:          AccountAddress,
+    index:             u32,
+    /// The entrypoint used, i.e. which kind of decryption data was registered.
+    entrypoint:        String,
+    transaction:       TransactionHash,
 }
 
-impl ElectionData {
-    pub fn verification_context(&self) -> anyhow::Result<PreVotingData> {
-        PreVotingData::compute(
-            self.manifest.clone(),
-            self.parameters.clone(),
-            &self.guardian_public_keys,
-        )
+/// Write the `rows` of a decryption timeline to the file at `out`.
+fn write_decryption_timing(
+    out: &std::path::Path,
+    rows: &[DecryptionTimingRow],
+) -> anyhow::Result<()> {
+    write_csv_metadata(out, &DECRYPTION_TIMING_COLUMNS)?;
+    let mut out_handle = csv::Writer::from_path(out)
+        .with_context(|| format!("Unable to create {}.", out.display()))?;
+    for row in rows {
+        out_handle.serialize(row)?;
     }
+    out_handle.flush()?;
+    Ok(())
 }
 
-/// Retrieve the election data from the contract and data linked from the
-/// contract.
-async fn get_election_data(
-    contract_client: &mut contract_client::ContractClient<ElectionContract>,
-) -> anyhow::Result<ElectionData> {
-    let config = contract_client
-        .view::<_, contract::ElectionConfig, contract_client::ViewError>(
-            "viewConfig",
-            &(),
-            BlockIdentifier::LastFinal,
-        )
-        .await?;
-    let guardians = contract_client
-        .view::<_, contract::GuardiansState, contract_client::ViewError>(
-            "viewGuardiansState",
-            &(),
-            BlockIdentifier::LastFinal,
-        )
-        .await?;
-
-    let start = config.election_start.try_into()?;
-    let end = config.election_end.try_into()?;
+/// Index the chain from the end of the election and write a timeline of the
+/// decryption shares and proofs registered by the guardians to `out`. The time
+/// each guardian finished registering its decryption data is summarized, which
+/// shows which guardians delayed the finalization.
+async fn handle_decryption_timing(
+    endpoint: sdk::Endpoint,
+    indexing: IndexingConfig,
+    target_address: ContractAddress,
+    out: &std::path::Path,
+    show_progress: bool,
+) -> anyhow::Result<()> {
+    let client = sdk::Client::new(endpoint).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, target_address).await?;
 
-    let client = HttpClient::try_create(5000)?;
+    let config =
+        election_contract::view_config(&mut contract_client, BlockIdentifier::LastFinal).await?;
+    let guardians_state =
+        election_contract::view_guardians_state(&mut contract_client, BlockIdentifier::LastFinal)
+            .await?;
+    let guardians: BTreeMap<AccountAddress, u32> = guardians_state
+        .into_iter()
+        .map(|(address, state)| (address, state.index))
+        .collect();
+    let end: chrono::DateTime<chrono::Utc> = config.election_end.try_into()?;
 
-    let election_manifest = client
-        .get_json_resource_checked(&config.election_manifest)
-        .await?;
-    let election_parameters = client
-        .get_json_resource_checked(&config.election_parameters)
-        .await?;
+    // Decryption data can only be registered after the election has ended.
+    let first_block = contract_client
+        .client
+        .find_first_finalized_block_no_earlier_than(.., end)
+        .await
+        .context("The election has not ended yet.")?;
+    let last_block = contract_client
+        .client
+        .get_block_info(BlockIdentifier::LastFinal)
+        .await?
+        .response;
+    eprintln!(
+        "Indexing decryption data from block {} at {} until block {} at {}.",
+        first_block.block_hash,
+        first_block.block_slot_time,
+        last_block.block_hash,
+        last_block.block_slot_time
+    );
 
-    let mut guardian_public_keys = guardians
-        .iter()
-        .map(|(ga, gs)| {
-            let bytes = gs
-                .public_key
-                .clone()
-                .with_context(|| format!("No public key found for guardian {ga}"))?;
-            let key: GuardianPublicKey = decode(&bytes)
-                .with_context(|| format!("Failed to decode public key for guardian {ga}"))?;
-            anyhow::Ok(key)
-        })
-        .collect::<Result<Vec<GuardianPublicKey>, _>>()
-        .context("Could not deserialize guardian public key")?;
+    let traverse_config =
+        indexer::TraverseConfig::new_single(indexing.endpoint.clone(), first_block.block_height);
+    let (sender, receiver) = tokio::sync::mpsc::channel(20);
+    let cancel_handle = tokio::spawn(traverse_config.traverse(indexer::TransactionIndexer, sender));
+    let mut receiver = indexing.limit_blocks(receiver);
 
-    // Sort to make sure the public keys are in the order of indices
-    // since some verification commands depend on it.
-    guardian_public_keys.sort_by_key(|g| g.i);
+    let bar = Progress::new(
+        last_block.block_height.height - first_block.block_height.height,
+        show_progress,
+    )?;
 
-    Ok(ElectionData {
-        manifest: election_manifest,
-        parameters: election_parameters,
-        candidates: config.candidates,
-        guardian_public_keys,
-        start,
-        end,
-        delegation_string: config.delegation_string,
-    })
+    let mut rows = Vec::new();
+    while let Some((block, txs)) = receiver.recv().await {
+        bar.set_message(block.block_slot_time.to_string());
+        bar.inc(1);
+
+        for tx in txs {
+            let BlockItemSummaryDetails::AccountTransaction(atx) = tx.details else {
+                continue;
+            };
+            // Rejected transactions have no effects, so only registrations accepted by the
+            // contract are included.
+            let AccountTransactionEffects::ContractUpdateIssued { effects } = atx.effects else {
+                continue;
+            };
+            let Some(ContractTraceElement::Updated { data }) = effects.first() else {
+                continue;
+            };
+            if data.address != target_address {
+                continue;
+            }
+            let entrypoint = data
+                .receive_name
+                .as_receive_name()
+                .entrypoint_name()
+                .to_string();
+            if !DECRYPTION_ENTRYPOINTS.contains(&entrypoint.as_str()) {
+                continue;
+            }
+            let Some(&index) = guardians.get(&atx.sender) else {
+                continue;
+            };
+            rows.push(DecryptionTimingRow {
+                timestamp: block.block_slot_time.to_rfc3339(),
+                seconds_after_end: (block.block_slot_time - end).num_seconds(),
+                guardian: atx.sender,
+                index,
+                entrypoint,
+                transaction: tx.hash,
+            });
+        }
+
+        if block.block_height >= last_block.block_height {
+            drop(receiver);
+            cancel_handle.abort();
+            drop(cancel_handle);
+            eprintln!("Done indexing.");
+            break;
+        }
+    }
+    bar.finish_and_clear();
+
+    write_decryption_timing(out, &rows)?;
+    eprintln!("Wrote the decryption timeline to {}.", out.display());
+
+    // The rows are in chain order, so the last row of each guardian is when it
+    // finished.
+    let mut finished = BTreeMap::new();
+    for row in &rows {
+        finished.insert(row.index, row);
+    }
+    let mut finished: Vec<_> = finished.into_values().collect();
+    finished.sort_by_key(|row| row.seconds_after_end);
+    for row in finished {
+        eprintln!(
+            "Guardian {} ({}) last registered decryption data {}s after the end of the election.",
+            row.index, row.guardian, row.seconds_after_end
+        );
+    }
+    for (address, index) in &guardians {
+        if !rows.iter().any(|row| row.index == *index) {
+            eprintln!("Guardian {index} ({address}) did not register any decryption data.");
+        }
+    }
+    Ok(())
 }
 
-/// Handle tallying of votes during the election phase.
-/// Note that this assumes access to final weights already.
-async fn handle_tally(
+enum ElectionContract {}
+
+/// Handle the full finalization of the election by composing [`handle_tally`]
+/// and [`handle_decrypt`], waiting for the guardians to register their
+/// decryption shares in between.
+async fn handle_finalize_all(
     endpoint: sdk::Endpoint,
-    TallyArgs {
+    indexing: IndexingConfig,
+    FinalizeAllArgs {
         target_address,
         final_weights,
         keys,
-    }: TallyArgs,
+        poll_interval,
+        notify_url,
+        check_eligible_voters,
+    }: FinalizeAllArgs,
+    parameters_version: contract::HashSha2256,
+    show_progress: bool,
 ) -> anyhow::Result<()> {
-    let client = sdk::Client::new(endpoint.clone()).await?;
+    eprintln!("Stage 1/3: Computing the encrypted tally.");
+    let tally_stats = handle_tally(
+        endpoint.clone(),
+        indexing,
+        TallyArgs {
+            target_address,
+            final_weights,
+            keys: Some(keys.clone()),
+            stats_out: None,
+            check_eligible_voters,
+            computation_log: None,
+            weight_unit: MICRO_CCD_PER_CCD,
+            checkpoint: None,
+        },
+        parameters_version,
+        show_progress,
+    )
+    .await
+    .context("Failed to compute or register the encrypted tally.")?;
+
+    eprintln!("Stage 2/3: Waiting for guardians to register their decryption shares.");
+    wait_for_decryption_shares(
+        endpoint.clone(),
+        target_address,
+        Duration::from_secs(poll_interval),
+    )
+    .await?;
+
+    eprintln!("Stage 3/3: Computing the election result.");
+    handle_decrypt(
+        endpoint,
+        target_address,
+        Some(keys),
+        Some(tally_stats),
+        notify_url,
+        parameters_version,
+        OutputFormat::Human,
+        show_progress,
+    )
+    .await
+    .context("Failed to compute or register the election result.")
+}
+
+/// The registrations of decryption shares and proofs by the guardians that are
+/// not excluded, as counted by [`wait_for_decryption_shares`].
+#[derive(Debug, Clone, Copy)]
+struct DecryptionRegistrations {
+    /// The number of guardians that are not excluded.
+    total:               usize,
+    /// The number of those that have registered decryption shares for every
+    /// contest.
+    with_share:          usize,
+    /// The number of those that have registered decryption shares for every
+    /// contest and a decryption proof.
+    with_proof:          usize,
+    /// The number of guardians needed to decrypt.
+    quorum:              usize,
+    /// Whether decryption shares are no longer accepted.
+    deadline_passed:     bool,
+    /// Whether decryption proofs are no longer accepted.
+    proof_window_passed: bool,
+}
+
+/// The outcome of a single check in [`wait_for_decryption_shares`].
+#[derive(Debug, PartialEq, Eq)]
+enum DecryptionWaitStage {
+    /// More shares or proofs can still be registered.
+    Waiting,
+    /// Enough guardians have registered shares and proofs to decrypt.
+    Ready,
+    /// The decryption deadline has passed without enough shares.
+    NotEnoughShares,
+    /// The window for registering proofs has passed without enough proofs.
+    NotEnoughProofs,
+}
+
+impl DecryptionRegistrations {
+    /// Decide whether to keep waiting for registrations. Before the deadline
+    /// this waits for all guardians, and afterwards for the quorum.
+    fn stage(&self) -> DecryptionWaitStage {
+        if self.with_proof == self.total && self.with_proof >= self.quorum {
+            return DecryptionWaitStage::Ready;
+        }
+        if !self.deadline_passed {
+            return DecryptionWaitStage::Waiting;
+        }
+        if self.with_share < self.quorum {
+            return DecryptionWaitStage::NotEnoughShares;
+        }
+        if self.with_proof >= self.quorum {
+            return DecryptionWaitStage::Ready;
+        }
+        if self.proof_window_passed {
+            return DecryptionWaitStage::NotEnoughProofs;
+        }
+        // Guardians can still register proofs after the deadline.
+        DecryptionWaitStage::Waiting
+    }
+}
+
+/// Wait until the guardians have registered enough decryption shares and proofs
+/// to decrypt the tally. This is the case when either all guardians that are
+/// not excluded have registered both, or the decryption deadline has passed and
+/// at least the threshold number of guardians have registered both. Fails if
+/// the decryption deadline has passed and not enough guardians have registered
+/// their decryption shares, or if the window for registering proofs has passed
+/// and not enough guardians have registered their proofs.
+async fn wait_for_decryption_shares(
+    endpoint: sdk::Endpoint,
+    target_address: ContractAddress,
+    poll_interval: Duration,
+) -> anyhow::Result<()> {
+    let client = sdk::Client::new(endpoint).await?;
     let mut contract_client =
         contract_client::ContractClient::<ElectionContract>::create(client, target_address).await?;
-
     let election_data = get_election_data(&mut contract_client).await?;
+    let quorum = election_data
+        .parameters
+        .varying_parameters
+        .k
+        .get_one_based_usize();
 
-    let verification_context: PreVotingData = election_data.verification_context()?;
+    loop {
+        let config =
+            election_contract::view_config(&mut contract_client, BlockIdentifier::LastFinal)
+                .await?;
+        let guardians_state = election_contract::view_guardians_state(
+            &mut contract_client,
+            BlockIdentifier::LastFinal,
+        )
+        .await?;
+        let now = contract_client
+            .client
+            .get_block_info(BlockIdentifier::LastFinal)
+            .await?
+            .response
+            .block_slot_time;
+        // Shares are accepted until the end of the grace period after the deadline,
+        // and proofs for a while longer.
+        let deadline: chrono::DateTime<chrono::Utc> = config.decryption_deadline.try_into()?;
+        let deadline =
+            deadline + chrono::Duration::seconds(config.decryption_grace_seconds.try_into()?);
+        let proof_deadline = deadline
+            + chrono::Duration::milliseconds(
+                contract::DECRYPTION_PROOF_WINDOW.millis().try_into()?,
+            );
 
-    let start = election_data.start;
-    let end = election_data.end;
+        let contest_count = config.contest_sizes.len();
+        let participating = guardians_state.iter().filter(|(_, g)| !g.excluded);
+        let with_share = participating
+            .clone()
+            .filter(|(_, g)| g.has_complete_decryption_share(contest_count));
+        let registrations = DecryptionRegistrations {
+            total: participating.count(),
+            with_share: with_share.clone().count(),
+            with_proof: with_share
+                .filter(|(_, g)| g.decryption_share_proof.is_some())
+                .count(),
+            quorum,
+            deadline_passed: now > deadline,
+            proof_window_passed: now > proof_deadline,
+        };
+        eprintln!(
+            "{}/{} guardians have registered decryption shares, {} of which have registered \
+             decryption proofs. {quorum} are required.",
+            registrations.with_share, registrations.total, registrations.with_proof
+        );
 
-    let (first_block, last_block) = range_setup(&mut contract_client.client, start, end).await?;
+        match registrations.stage() {
+            DecryptionWaitStage::Waiting => tokio::time::sleep(poll_interval).await,
+            DecryptionWaitStage::Ready => return Ok(()),
+            DecryptionWaitStage::NotEnoughShares => anyhow::bail!(
+                "The decryption deadline has passed and not enough guardians have registered \
+                 decryption shares. The finalization phase must be reset."
+            ),
+            DecryptionWaitStage::NotEnoughProofs => anyhow::bail!(
+                "The window for registering decryption proofs has passed and not enough guardians \
+                 have registered decryption proofs. The finalization phase must be reset."
+            ),
+        }
+    }
+}
 
-    let traverse_config = indexer::TraverseConfig::new_single(endpoint, first_block.block_height);
-    let (sender, mut receiver) = tokio::sync::mpsc::channel(20);
-    let cancel_handle = tokio::spawn(traverse_config.traverse(
-        indexer::ContractUpdateIndexer {
-            target_address,
-            entrypoint: OwnedEntrypointName::new_unchecked("registerVotes".into()),
-        },
-        sender,
-    ));
+/// A progress update of the decryption of the tally, posted to the URL given
+/// by `--notify-url`.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase", tag = "event")]
+enum DecryptionProgressEvent {
+    /// Decryption has started, using the shares of the guardians with the given
+    /// indices.
+    #[serde(rename_all = "camelCase")]
+    Started {
+        guardians_used: Vec<u32>,
+        total:          u64,
+    },
+    /// The tally of an option of a contest has been decrypted.
+    #[serde(rename_all = "camelCase")]
+    OptionDecrypted {
+        contest:   ContestIndex,
+        /// The zero-based index of the option in the contest.
+        option:    usize,
+        /// The number of options decrypted so far, out of `total`.
+        completed: u64,
+        total:     u64,
+    },
+    /// Decryption has ended, either successfully or with an error.
+    Finished { success: bool },
+}
 
-    let bar = ProgressBar::new(last_block.block_height.height - first_block.block_height.height)
-        .with_style(ProgressStyle::with_template(
-            "{spinner} {msg} {wide_bar} {pos}/{len}",
-        )?);
+/// The body posted for each [`DecryptionProgressEvent`].
+#[derive(serde::Serialize)]
+struct DecryptionProgress {
+    contract: ContractAddress,
+    #[serde(flatten)]
+    event:    DecryptionProgressEvent,
+}
 
-    let mut ballots = BTreeMap::new();
+/// Posts [`DecryptionProgressEvent`]s to a webhook in the order they are
+/// produced. This is fire-and-forget, failures to post are logged but otherwise
+/// ignored. The default value does not post anything.
+#[derive(Default)]
+struct ProgressNotifier {
+    sender: Option<tokio::sync::mpsc::UnboundedSender<DecryptionProgressEvent>>,
+    task:   Option<tokio::task::JoinHandle<()>>,
+}
 
-    while let Some((block, txs)) = receiver.recv().await {
-        bar.set_message(block.block_slot_time.to_string());
-        bar.inc(1);
-        if block.block_slot_time > end {
-            drop(receiver);
-            cancel_handle.abort();
-            drop(cancel_handle);
-            eprintln!("Done indexing.");
-            break;
-        }
+impl ProgressNotifier {
+    /// The timeout of each request posting an update.
+    const REQUEST_TIMEOUT_MS: u64 = 5000;
 
-        let results = txs
-            .into_par_iter()
-            .flat_map(
-                |indexer::ContractUpdateInfo {
-                     execution_tree,
-                     transaction_hash,
-                     sender,
-                     ..
-                 }| {
-                    let param = execution_tree.parameter();
-                    let Ok(param) = concordium_std::from_bytes::<contract::RegisterVotesParameter>(
-                        param.as_ref(),
-                    ) else {
-                        eprintln!("Unable to parse ballot from transaction {transaction_hash}");
-                        return None;
-                    };
-
-                    let Ok(ballot) = decode::<BallotEncrypted>(&param.inner) else {
-                        eprintln!("Unable to parse ballot from transaction {transaction_hash}");
-                        return None;
-                    };
-                    Some((
-                        ballot.verify(&verification_context),
-                        sender,
-                        ballot,
-                        transaction_hash,
-                    ))
-                },
-            )
-            .collect_vec_list();
+    /// Create a notifier posting updates on the decryption of the tally of
+    /// `contract` to `url`, if given.
+    fn new(contract: ContractAddress, url: Option<url::Url>) -> anyhow::Result<Self> {
+        let Some(url) = url else {
+            return Ok(Self::default());
+        };
+        let client = HttpClient::try_create(Self::REQUEST_TIMEOUT_MS)?;
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let task = tokio::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                let body = DecryptionProgress { contract, event };
+                if let Err(e) = client.post_json(url.as_str(), &body).await {
+                    eprintln!("Failed to send progress update: {e:#}");
+                }
+            }
+        });
+        Ok(Self {
+            sender: Some(sender),
+            task:   Some(task),
+        })
+    }
 
-        for (verified, sender, ballot, transaction_hash) in results.into_iter().flatten() {
-            if verified {
-                // Replace any previous ballot from the sender.
-                ballots.insert(AccountAddressEq::from(sender), (ballot, transaction_hash));
-            } else {
-                eprintln!("Vote in transaction {transaction_hash} is invalid.");
+    /// Queue the event for posting.
+    fn notify(&self, event: DecryptionProgressEvent) {
+        if let Some(sender) = &self.sender {
+            // The receiver only stops once the sender is dropped.
+            let _ = sender.send(event);
+        }
+    }
+
+    /// Wait for all queued events to be posted.
+    async fn finish(mut self) {
+        drop(self.sender.take());
+        if let Some(task) = self.task.take() {
+            if let Err(e) = task.await {
+                eprintln!("Failed to send progress updates: {e}");
             }
         }
     }
+}
 
-    let mut final_weights =
-        csv::Reader::from_path(final_weights).context("Unable to open final weights file.")?;
+/// Decrypt the `ciphertexts` using the decryption shares and proofs of
+/// correct decryption provided by the guardians. The shares and proofs are
+/// expected to be in the same order, i.e., the `i`-th decryption share and the
+/// `i`-th proof were produced by the same guardian.
+fn decrypt_ciphertexts(
+    election_data: &ElectionData,
+    ciphertexts: EncryptedTally,
+    decryption_shares: &[GuardianDecryption],
+    proof_shares: &[GuardianDecryptionProof],
+    bar: &Progress,
+    notifier: &ProgressNotifier,
+) -> anyhow::Result<BTreeMap<ContestIndex, Vec<VerifiableDecryption>>> {
+    let total = ciphertexts.values().map(|x| x.len() as u64).sum();
+    let mut completed = 0;
+    let mut decrypted_tallies = BTreeMap::new();
+    for (contest, ciphertexts) in ciphertexts.into_iter() {
+        let mut ciphers = Vec::new();
+        for (i, ciphertext) in ciphertexts.into_iter().enumerate() {
+            bar.set_message(format!("ciphertext {i} (contest {contest})"));
+            bar.inc(1);
+            // each guardian provides a decryption share of each of the options
+            // for each of the contests.
+            let mut decryption_shares_for_option = Vec::new();
+            for guardian_shares in decryption_shares {
+                let Some(decryption_share) = guardian_shares.get(&contest) else {
+                    anyhow::bail!("Missing decryption share for contest {contest}");
+                };
+                let Some(share) = decryption_share.get(i) else {
+                    anyhow::bail!("Missing decryption share for contest {contest} and option {i}");
+                };
+                decryption_shares_for_option.push(share);
+            }
+            let mut proof_shares_for_option = Vec::new();
+            for proof_shares in proof_shares {
+                let Some(proof_share) = proof_shares.get(&contest) else {
+                    anyhow::bail!("Missing proof share for contest {contest}");
+                };
+                let Some(share) = proof_share.get(i) else {
+                    anyhow::bail!("Missing proof share for contest {contest} and option {i}");
+                };
+                proof_shares_for_option.push(share);
+            }
 
-    let mut tally =
-        eg::ballot::BallotTallyBuilder::new(&election_data.manifest, &election_data.parameters);
-    for row in final_weights.deserialize() {
-        let FinalWeightRow {
-            account,
-            amount,
-            delegators,
-        } = row?;
-        if let Some((ballot, hash)) = ballots.remove(&AccountAddressEq::from(account)) {
-            let factor = get_scaling_factor(&amount);
-            eprintln!(
-                "Scaling the ballot cast by transaction {hash} by a factor {factor}. Delegators \
-                 {delegators}."
-            );
-            tally.update(ballot.scale(
-                &verification_context.parameters.fixed_parameters,
-                &util::algebra::FieldElement::from(
-                    factor,
-                    &election_data.parameters.fixed_parameters.field,
-                ),
-            ));
-        } // else the account did not vote, so nothing to do.
+            let decrypted = VerifiableDecryption::compute(
+                &election_data.manifest,
+                &election_data.parameters,
+                &election_data.guardian_public_keys,
+                &ciphertext,
+                decryption_shares_for_option,
+                proof_shares_for_option,
+            )?;
+            ciphers.push(decrypted);
+            completed += 1;
+            notifier.notify(DecryptionProgressEvent::OptionDecrypted {
+                contest,
+                option: i,
+                completed,
+                total,
+            });
+        }
+        decrypted_tallies.insert(contest, ciphers);
     }
-    let tally = tally.finalize();
-
-    let serialized_tally = encode(&tally)?;
-    let param = concordium_std::OwnedParameter::from_serial(&serialized_tally)?;
+    Ok(decrypted_tallies)
+}
 
-    let json_param =
-        contract::PostEncryptedTallyParameter::get_type().to_json_string_pretty(param.as_ref())?;
-    eprintln!("The following JSON parameter can be used to record the tally in the contract.");
-    println!("{}", json_param);
+/// Extract the weights of the candidates from the decrypted tally. The contests
+/// of the election manifest are the contests of the election result in order,
+/// i.e. the contest with one-based index `i` in the manifest has contest id
+/// `i - 1` in the result, and the number of candidates given by the contest
+/// sizes registered in the contract.
+fn decrypted_weights(
+    election_data: &ElectionData,
+    mut decryption: BTreeMap<ContestIndex, Vec<VerifiableDecryption>>,
+) -> anyhow::Result<contract::ElectionResult> {
+    let contests: Vec<_> = election_data.manifest.contests.indices().collect();
+    anyhow::ensure!(
+        contests.len() == election_data.contest_sizes.len(),
+        "The election manifest has {} contests, but the contract has {}.",
+        contests.len(),
+        election_data.contest_sizes.len()
+    );
+    if decryption.is_empty() {
+        // no contests means we had no valid votes in the election.
+        eprintln!("No valid votes in the election. All candidates get 0 votes.");
+    }
 
-    let current_tally = contract_client
-        .view::<(), Option<Vec<u8>>, ViewError>(
-            "viewEncryptedTally",
-            &(),
-            BlockIdentifier::LastFinal,
-        )
-        .await?;
+    let mut result = Vec::with_capacity(contests.len());
+    for ((contest_id, contest), &size) in (0..).zip(contests).zip(&election_data.contest_sizes) {
+        let weights = if let Some(results) = decryption.remove(&contest) {
+            anyhow::ensure!(
+                results.len() == size as usize,
+                "The tally of contest {contest_id} has {} options, but the contest has {size} \
+                 candidates.",
+                results.len()
+            );
+            let mut weights: contract::ContestResult = Vec::with_capacity(results.len());
+            for value in results {
+                let weight = value.plain_text.value().to_u64_digits();
+                anyhow::ensure!(weight.len() <= 1, "Weight must fit into a u64.");
+                weights.push(weight.first().copied().unwrap_or(0));
+            }
+            weights
+        } else {
+            // No valid votes in the contest, so each candidate gets 0 votes.
+            vec![0u64; size as usize]
+        };
+        result.push((contest_id, weights));
+    }
+    anyhow::ensure!(
+        decryption.is_empty(),
+        "Encryptions exist for contests that are not in the election manifest."
+    );
+    Ok(result)
+}
 
-    let wrong_tally = if let Some(registered_tally) = current_tally {
-        if registered_tally == serialized_tally {
+/// Report the weight of selections of each contest. For elections with a single
+/// contest, this is reported against the weight of ballots cast given by
+/// `tally_stats`, from which abstentions can be derived.
+fn report_selections(
+    election_data: &ElectionData,
+    result: &contract::ElectionResult,
+    tally_stats: Option<TallyStats>,
+) -> anyhow::Result<()> {
+    let contests: Vec<_> = election_data.manifest.contests.indices().collect();
+    let ([(_, contest_weights)], [contest]) = (result.as_slice(), contests.as_slice()) else {
+        for (contest_id, contest_weights) in result {
             eprintln!(
-                "The computed encrypted tally matches the tally already registered in the contract"
+                "The total weight of selections in contest {contest_id} is {}.",
+                contest_weights.iter().sum::<u64>()
+            );
+        }
+        if tally_stats.is_some() {
+            eprintln!(
+                "Abstentions are only reported for elections with a single contest, as ballots do \
+                 not cover every contest."
             );
-            return Ok(());
         }
+        return Ok(());
+    };
+    // Report the weight of selections against the weight of ballots cast, from
+    // which abstentions can be derived.
+    let weighted_selections: u64 = contest_weights.iter().sum();
+    eprintln!("The total weight of selections is {weighted_selections}.");
+    if let Some(TallyStats {
+        ballots,
+        weighted_ballots,
+    }) = tally_stats
+    {
         eprintln!(
-            "The encrypted tally is already registered in the contract, but it is different."
+            "{ballots} ballots with a total weight of {weighted_ballots} were included in the \
+             tally."
         );
-        if keys.is_some() {
-            let confirm = dialoguer::Confirm::new()
-                .report(true)
-                .wait_for_newline(true)
-                .with_prompt("Do you want to overwrite the published tally?")
-                .interact()?;
-            anyhow::ensure!(confirm, "Aborting.");
+        let selection_limit = election_data
+            .manifest
+            .contests
+            .get(*contest)
+            .context("Contest not found in manifest.")?
+            .selection_limit;
+        let abstentions = abstention_weight(weighted_ballots, weighted_selections, selection_limit);
+        if selection_limit == 1 {
+            eprintln!("The total weight of abstentions is {abstentions}.");
+        } else {
+            eprintln!(
+                "Ballots allow up to {selection_limit} selections, so abstentions cannot be \
+                 derived exactly. The total weight of abstentions is at most {abstentions}."
+            );
         }
-        true
-    } else {
-        false
-    };
+    }
+    Ok(())
+}
 
-    if let Some(keys) = keys {
-        let wallet = WalletAccount::from_json_file(keys)?;
-        eprintln!("Registering tally in the smart contract.");
-        let dry_run = contract_client
-            .dry_run_update_raw::<ViewError>(
-                "postEncryptedTally",
-                Amount::zero(),
-                wallet.address,
-                param,
-            )
-            .await
-            .context("Failed to dry run postEncryptedTally")?;
+/// The weight of ballots without any selections in a contest, given the weight
+/// of all ballots cast and the weight of the selections in the contest. This is
+/// exact if ballots have at most one selection, and otherwise an upper bound.
+fn abstention_weight(
+    weighted_ballots: u64,
+    weighted_selections: u64,
+    selection_limit: usize,
+) -> u64 {
+    // Each ballot has at most `selection_limit` selections, which bounds the
+    // weight of ballots with at least one selection from below.
+    let min_selecting = weighted_selections.div_ceil(selection_limit.max(1) as u64);
+    weighted_ballots.saturating_sub(min_selecting)
+}
 
-        let tx = dry_run
-            .send(&wallet)
-            .await
-            .context("Failed to send transaction to post the tally.")?;
-        eprintln!("Transaction {tx} sent. Await finalization.");
-        if let Err(reason) = tx.wait_for_finalization().await {
-            eprintln!("Transaction failed with {reason:#?}");
-        } else {
-            eprintln!("Transaction successful and finalized.");
+/// The weights of all candidates in an election result, in the order of the
+/// candidates in the contract.
+fn flatten_result(result: &contract::ElectionResult) -> Vec<u64> {
+    result
+        .iter()
+        .flat_map(|(_, votes)| votes.iter().copied())
+        .collect()
+}
+
+/// The election results computed from the decrypted tally, as pretty printed
+/// JSON.
+fn computed_results_json(
+    candidates: Vec<contract::ChecksumUrl>,
+    weights: &[u64],
+) -> anyhow::Result<String> {
+    let computed_results: Vec<contract::CandidateResult> = candidates
+        .into_iter()
+        .zip(weights)
+        .map(
+            |(candidate, &cummulative_votes)| contract::CandidateResult {
+                candidate,
+                cummulative_votes,
+            },
+        )
+        .collect();
+
+    let json_repr: String = Vec::<contract::CandidateResult>::get_type()
+        .to_json_string_pretty(&concordium_std::to_bytes(&computed_results))
+        .context("Unable to convert to String")?;
+    Ok(json_repr)
+}
+
+/// Print the election results computed from the decrypted tally.
+fn print_computed_results(
+    candidates: Vec<contract::ChecksumUrl>,
+    weights: &[u64],
+) -> anyhow::Result<()> {
+    let json_repr = computed_results_json(candidates, weights)?;
+    eprintln!("The computed election results are.");
+    println!("{json_repr}");
+    Ok(())
+}
+
+/// The decryption data registered by the guardians, split into the data used
+/// for decrypting the tally and the guardians whose data is left out.
+struct GuardianDecryptions {
+    shares:   Vec<GuardianDecryption>,
+    proofs:   Vec<GuardianDecryptionProof>,
+    /// The index and address of each guardian whose share and proof are used.
+    used:     Vec<(u32, AccountAddress)>,
+    /// Guardians that registered decryption data which is not used, and why.
+    left_out: Vec<(u32, AccountAddress, String)>,
+}
+
+impl GuardianDecryptions {
+    /// Collect the decryption shares and proofs registered by the guardians,
+    /// in the order of the guardian indices. Data which cannot be used for
+    /// decrypting `tally` is left out.
+    fn collect(mut guardians_state: contract::GuardiansState, tally: &EncryptedTally) -> Self {
+        guardians_state.sort_by_key(|g| g.1.index);
+
+        let mut decryptions = Self {
+            shares:   Vec::new(),
+            proofs:   Vec::new(),
+            used:     Vec::new(),
+            left_out: Vec::new(),
+        };
+        for (guardian_address, guardian_state) in guardians_state {
+            let index = guardian_state.index;
+            let Some(proof) = &guardian_state.decryption_share_proof else {
+                if has_decryption_share(&guardian_state) {
+                    decryptions
+                        .left_out
+                        .push((index, guardian_address, "no proof".into()));
+                }
+                continue;
+            };
+            let share = match decode_guardian_decryption(&guardian_state, tally) {
+                Ok(Some(share)) => share,
+                Ok(None) => {
+                    decryptions
+                        .left_out
+                        .push((index, guardian_address, "no share".into()));
+                    continue;
+                }
+                Err(e) => {
+                    decryptions.left_out.push((
+                        index,
+                        guardian_address,
+                        format!("unusable share: {e}"),
+                    ));
+                    continue;
+                }
+            };
+            let Ok(proof) = decode::<GuardianDecryptionProof>(proof) else {
+                decryptions
+                    .left_out
+                    .push((index, guardian_address, "unreadable proof".into()));
+                continue;
+            };
+            decryptions.shares.push(share);
+            decryptions.proofs.push(proof);
+            decryptions.used.push((index, guardian_address));
         }
-    } else if !wrong_tally {
-        eprintln!(
-            "The tally is currently not registered in the contract, and no keys were provided."
-        );
+        decryptions
+    }
+
+    /// Describe whose decryption data is used, and whose is left out and why.
+    fn log_lines(&self) -> Vec<String> {
+        let used = self.used.iter().map(|(index, address)| {
+            format!("Using the decryption share and proof of guardian {index} ({address}).")
+        });
+        let left_out = self.left_out.iter().map(|(index, address, reason)| {
+            format!("Not using the decryption data of guardian {index} ({address}): {reason}.")
+        });
+        used.chain(left_out).collect()
     }
-    Ok(())
 }
 
-/// Handle collection of initial weights.
-async fn handle_initial_weights(
+/// Handle decryption of the final result, and checking or publishing the result
+/// in the contract. With [`OutputFormat::Json`] informational messages are
+/// omitted, and the outcome is written to stdout as a single JSON object.
+async fn handle_decrypt(
     endpoint: sdk::Endpoint,
-    args: InitialWeights,
-    out: std::path::PathBuf,
+    contract: ContractAddress,
+    wallet_path: Option<std::path::PathBuf>,
+    tally_stats: Option<TallyStats>,
+    notify_url: Option<url::Url>,
+    parameters_version: contract::HashSha2256,
+    output: OutputFormat,
+    show_progress: bool,
 ) -> anyhow::Result<()> {
-    ensure!(out.is_dir(), "out argument must point to a directory");
-
-    let mut client = sdk::Client::new(endpoint.clone())
-        .await
-        .context("Unable to connect.")?;
+    let human = output == OutputFormat::Human;
+    let client = sdk::Client::new(endpoint.clone()).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
 
-    let (start, end, registered_weights_hash) = match &args {
-        InitialWeights::Generate(gen) => (gen.start, gen.end, None),
-        InitialWeights::Verify { contract } => {
-            let mut contract_client = contract_client::ContractClient::<ElectionContract>::create(
-                client.clone(),
-                *contract,
-            )
+    let guardians_state =
+        election_contract::view_guardians_state(&mut contract_client, BlockIdentifier::LastFinal)
             .await?;
-            let config = contract_client
-                .view::<_, contract::ElectionConfig, contract_client::ViewError>(
-                    "viewConfig",
-                    &(),
-                    BlockIdentifier::LastFinal,
-                )
-                .await?;
+    let election_data = get_election_data(&mut contract_client).await?;
+    election_data.check_parameters_version(&parameters_version)?;
 
-            let start = config.eligible_voters.parameters.start_time.try_into()?;
-            let end = config.eligible_voters.parameters.end_time.try_into()?;
-            let hash = config.eligible_voters.data.hash;
-            (start, end, Some(hash))
-        }
+    let encrypted_tally =
+        election_contract::view_encrypted_tally(&mut contract_client, BlockIdentifier::LastFinal)
+            .await?;
+    let Some(encrypted_tally) = encrypted_tally else {
+        anyhow::bail!("Encrypted tally not yet registered.")
     };
 
-    let (first_block, last_block) = range_setup(&mut client, start, end).await?;
-    let initial_block_ident: BlockIdentifier = first_block.block_height.into();
-    let initial_account_number = client
-        .get_account_list(initial_block_ident)
-        .await?
-        .response
-        .try_fold(0u64, |acc, _| async move { Ok(acc + 1) })
-        .await?;
-    let mut account_balances = vec![Vec::new(); initial_account_number as usize];
-    let mut account_addresses = Vec::with_capacity(initial_account_number as usize);
-    let bar = ProgressBar::new(initial_account_number).with_style(ProgressStyle::with_template(
-        "{spinner} {msg} {wide_bar} {pos}/{len}",
-    )?);
+    let Ok(tally) = decode::<EncryptedTally>(&encrypted_tally) else {
+        anyhow::bail!("Encrypted tally is not readable.")
+    };
 
-    eprintln!(
-        "Getting initial account balances in block {}.",
-        first_block.block_hash
-    );
-    for (ai, balances) in account_balances.iter_mut().enumerate() {
-        let info = client
-            .get_account_info(&AccountIndex::from(ai as u64).into(), initial_block_ident)
-            .await?;
-        account_addresses.push(info.response.account_address);
-        bar.set_message(info.response.account_address.to_string());
-        bar.inc(1);
-        balances.push((first_block.block_slot_time, info.response.account_amount));
+    let decryptions = GuardianDecryptions::collect(guardians_state, &tally);
+    if human {
+        for line in decryptions.log_lines() {
+            eprintln!("{line}");
+        }
     }
-    bar.finish_and_clear();
-    drop(bar);
-    let bar = ProgressBar::new(last_block.block_height.height - first_block.block_height.height)
-        .with_style(ProgressStyle::with_template(
-            "{spinner} {msg} {wide_bar} {pos}/{len}",
-        )?);
+    let GuardianDecryptions {
+        shares: decryption_shares,
+        proofs: proof_shares,
+        used,
+        left_out,
+    } = decryptions;
+    let guardians_used: Vec<_> = used.iter().map(|&(index, _)| index).collect();
+    if !left_out.is_empty() {
+        eprintln!(
+            "WARNING: {} guardian(s) registered decryption data that is not used. Only the shares \
+             of guardians {guardians_used:?} are used.",
+            left_out.len()
+        );
+    }
+    let quorum = election_data
+        .parameters
+        .varying_parameters
+        .k
+        .get_one_based_usize();
+    anyhow::ensure!(
+        decryption_shares.len() >= quorum,
+        "Not enough shares. Require {quorum} but only have {}.",
+        decryption_shares.len()
+    );
 
-    let traverse_config = indexer::TraverseConfig::new_single(endpoint, first_block.block_height);
-    let (sender, mut receiver) = tokio::sync::mpsc::channel(20);
-    let cancel_handle = tokio::spawn(traverse_config.traverse(indexer::BlockEventsIndexer, sender));
-    while let Some((block, normal, specials)) = receiver.recv().await {
-        if block.block_slot_time > end {
-            drop(receiver);
-            eprintln!("Done indexing");
-            break;
-        }
-        bar.set_message(block.block_slot_time.to_string());
-        bar.inc(1);
-        let mut affected = BTreeSet::new();
-        for tx in normal {
-            for addr in tx.affected_addresses() {
-                affected.insert(AccountAddressEq::from(addr));
-            }
-        }
-        for special in specials {
-            for addr in special.affected_addresses() {
-                affected.insert(AccountAddressEq::from(addr));
-            }
-        }
-        let block_ident = BlockIdentifier::from(block.block_height);
-        for acc in affected {
-            let info = client
-                .get_account_info(&AccountAddress::from(acc).into(), block_ident)
-                .await?;
-            let index = info.response.account_index.index as usize;
-            if let Some(elem) = account_balances.get_mut(index) {
-                elem.push((block.block_slot_time, info.response.account_amount));
-            } else {
-                // Newly created accounts have balance 0 at the start of the period.
-                for idx in account_balances.len()..index {
-                    account_balances.push(vec![(first_block.block_slot_time, Amount::zero())]);
-                    let idx_acc = client
-                        .get_account_info(&AccountIndex::from(idx as u64).into(), block_ident)
-                        .await?;
-                    account_addresses.push(idx_acc.response.account_address);
-                }
-                account_balances.push(vec![
-                    (first_block.block_slot_time, Amount::zero()),
-                    (block.block_slot_time, info.response.account_amount),
-                ]);
-                account_addresses.push(info.response.account_address);
-            }
-        }
+    if human {
+        eprintln!(
+            "{} decryption shares available. Starting decryption.",
+            decryption_shares.len()
+        );
     }
-    cancel_handle.abort();
-    bar.finish_and_clear();
 
-    anyhow::ensure!(
-        account_addresses.len() == account_balances.len(),
-        "Expecting addresses match account balances. This is a bug."
+    // Progress bar for decryption.
+    let bar = Progress::new(
+        tally.values().map(|x| x.len()).sum::<usize>() as u64,
+        show_progress,
+    )?;
+    // spin the spinner automatically since we can't tick it during decryption of an
+    // individual ciphertext
+    bar.enable_steady_tick(Duration::from_millis(100));
+
+    let notifier = ProgressNotifier::new(contract, notify_url)?;
+    notifier.notify(DecryptionProgressEvent::Started {
+        guardians_used,
+        total: tally.values().map(|x| x.len() as u64).sum(),
+    });
+    let decryption = decrypt_ciphertexts(
+        &election_data,
+        tally,
+        &decryption_shares,
+        &proof_shares,
+        &bar,
+        &notifier,
     );
+    bar.finish_and_clear();
+    notifier.notify(DecryptionProgressEvent::Finished {
+        success: decryption.is_ok(),
+    });
+    notifier.finish().await;
+    let result = decrypted_weights(&election_data, decryption?)?;
+    let weights = flatten_result(&result);
 
-    let mut data = vec![];
-    {
-        let mut weights = csv::Writer::from_writer(&mut data);
-        for (balances, address) in account_balances.into_iter().zip(account_addresses) {
-            let Some((&first, rest)) = balances.split_first() else {
-                anyhow::bail!("A bug, there should always be at least one reading.");
-            };
-            let mut last_time = first.0;
-            let mut weighted_sum = u128::from(first.1.micro_ccd);
-            let mut last_balance = weighted_sum;
-            for &(dt, balance) in rest {
-                weighted_sum +=
-                    (dt.signed_duration_since(last_time).num_milliseconds() as u128) * last_balance;
-                last_time = dt;
-                last_balance = u128::from(balance.micro_ccd);
-            }
-            weighted_sum += (last_block
-                .block_slot_time
-                .signed_duration_since(last_time)
-                .num_milliseconds() as u128)
-                * last_balance;
-            let amount = weighted_sum
-                / (last_block
-                    .block_slot_time
-                    .signed_duration_since(first_block.block_slot_time)
-                    .num_milliseconds() as u128);
-            let amount = Amount::from_micro_ccd(amount as u64);
-            weights.serialize(WeightRow {
-                account: address,
-                amount,
-            })?;
-        }
-        weights.flush()?;
+    if human {
+        report_selections(&election_data, &result, tally_stats)?;
     }
 
-    let mut weights_out = std::fs::File::create(out.join("initial-weights.csv"))
-        .context("Failed to create weights file")?;
-    weights_out
-        .write(&data)
-        .context("Failed to write initial weights to file")?;
+    let computed_results = computed_results_json(election_data.candidates, &weights)?;
+    if human {
+        eprintln!("The computed election results are.");
+        println!("{computed_results}");
+    }
 
-    let mut params_out = std::fs::File::create(out.join("initial-weights-params.json"))
-        .context("Failed to create weight params file")?;
-    let params = contract::EligibleVotersParameters {
-        start_time: Timestamp::from_timestamp_millis(start.timestamp_millis() as u64),
-        end_time:   Timestamp::from_timestamp_millis(end.timestamp_millis() as u64),
-    };
-    params_out
-        .write(&serde_json::to_vec(&params).expect("Serialization of params will not fail"))
-        .context("Failed to write weight parameters to file")?;
+    let status = post_computed_result(&mut contract_client, wallet_path, result, human).await?;
 
-    // Only runs if the `verify` subcommand is run.
-    if let Some(registered_weights_hash) = registered_weights_hash {
-        let hash = contract::HashSha2256(sha2::Sha256::digest(&data).into());
-        ensure!(
-            hash == registered_weights_hash,
-            "The checksum registered in the contract does not match the computed hash: registered \
-             {}, computed {}",
-            &registered_weights_hash,
-            &hash
-        );
-        println!("Succesfully verified the weights registered in the contract.");
+    if !human {
+        let candidates: serde_json::Value = serde_json::from_str(&computed_results)
+            .context("Unable to parse the computed election results.")?;
+        let output = serde_json::json!({
+            "contract": contract,
+            "candidates": candidates,
+            "posted": status == ResultStatus::Stored,
+            "status": status,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
     }
 
     Ok(())
 }
 
-#[derive(Debug, serde::Deserialize)]
-/// Metadata of a candidate. We only need the name in this tool so we only model
-/// that, and not the image and other parameters used by the frontend.
-struct CandidateMetadata {
-    name: String,
+/// The status of a computed election result in the contract.
+#[derive(serde::Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+enum ResultStatus {
+    /// The result is neither stored nor proposed.
+    NotPosted,
+    /// The result is proposed, and is stored once approved.
+    Proposed,
+    /// The result is stored in the contract.
+    Stored,
 }
 
-/// Create a new election instance.
-async fn handle_new_election(endpoint: sdk::Endpoint, app: NewElectionArgs) -> anyhow::Result<()> {
-    let mut client = sdk::Client::new(endpoint).await?;
-
-    let wallet = WalletAccount::from_json_file(app.admin)?;
+/// Check the computed `result` against the result registered in the contract,
+/// and post it if the result is not registered, or the user confirms
+/// overwriting it. If the election requires the result to be approved, a
+/// pending proposal is only replaced if the user confirms it, as this discards
+/// its approvals. If not `human`, no one can confirm, so overwriting fails
+/// instead. Returns the status of the computed result in the contract
+/// afterwards.
+async fn post_computed_result(
+    contract_client: &mut contract_client::ContractClient<ElectionContract>,
+    wallet_path: Option<std::path::PathBuf>,
+    result: contract::ElectionResult,
+    human: bool,
+) -> anyhow::Result<ResultStatus> {
+    let current_result =
+        election_contract::view_election_result(contract_client, BlockIdentifier::LastFinal)
+            .await?;
+    let current_weights = current_result.as_ref().map(|current_result| {
+        current_result
+            .iter()
+            .map(|x| x.cummulative_votes)
+            .collect::<Vec<_>>()
+    });
 
-    let wasm_module = WasmModule::from_file(&app.module).context("Unable to read module.")?;
-    let module_ref = wasm_module.get_module_ref();
-    let existing_module = client
-        .get_module_source(&module_ref, BlockIdentifier::LastFinal)
-        .await;
-    match existing_module {
-        Ok(_) => {
-            eprintln!("Module {module_ref} already exists.");
-        }
-        Err(e) if e.is_not_found() => {
-            let nonce = client
-                .get_next_account_sequence_number(&wallet.address)
-                .await?;
-            let tx = transactions::send::deploy_module(
-                &wallet,
-                wallet.address,
-                nonce.nonce,
-                TransactionTime::hours_after(1),
-                wasm_module,
+    if let Some(current_result) = current_result {
+        if current_weights.as_ref() != Some(&flatten_result(&result)) {
+            let json_repr: String = Vec::<contract::CandidateResult>::get_type()
+                .to_json_string_pretty(&concordium_std::to_bytes(&current_result))
+                .context("Unable to convert to String")?;
+            anyhow::ensure!(
+                human,
+                "The election results are already published in the contract and differ from the \
+                 computed results. They can only be overwritten with human output, after \
+                 confirming it."
             );
-            let hash = client.send_account_transaction(tx).await?;
-            let (block_hash, result) = client
-                .wait_until_finalized(&hash)
-                .await
-                .context("Module deployment failed.")?;
-            anyhow::ensure!(result.is_success(), "Transaction failed {result:#?}");
-            eprintln!("Module {module_ref} deployed in block {block_hash}");
+            eprintln!(
+                "The election results are already published in the contract and are\n
+                 {json_repr}."
+            );
+            let confirm = dialoguer::Confirm::new()
+                .report(true)
+                .wait_for_newline(true)
+                .with_prompt("Do you want to overwrite the published results?")
+                .interact()?;
+            anyhow::ensure!(confirm, "Aborting.");
+        } else {
+            if human {
+                eprintln!(
+                    "The election results are already registered in the contract, and they match. \
+                     Terminating."
+                );
+            }
+            return Ok(ResultStatus::Stored);
         }
-        Err(err) => anyhow::bail!("Could not inspect module status: {err}"),
     }
 
-    let url = &app.base_url;
-    let make_url = move |path: String| -> anyhow::Result<String> {
-        let url = url.clone();
-        let url = url.join(&path).context("Failed to construct URL")?;
-        Ok(url.to_string())
+    let proposal =
+        election_contract::view_result_proposal(contract_client, BlockIdentifier::LastFinal)
+            .await?;
+    if let Some(proposal) = pending_proposal(proposal, current_weights.as_deref()) {
+        let config =
+            election_contract::view_config(contract_client, BlockIdentifier::LastFinal).await?;
+        if proposal.result == result {
+            if human {
+                eprintln!(
+                    "The election results are already proposed in the contract, and they match. \
+                     The result has been approved by {} of the {} approvals needed, and can be \
+                     approved with `approve-result --hash {}`.",
+                    proposal.approvals.len(),
+                    config.approval_threshold,
+                    election_result_hash(&result)
+                );
+            }
+            return Ok(ResultStatus::Proposed);
+        }
+        anyhow::ensure!(
+            human,
+            "Different election results are proposed in the contract. They can only be replaced \
+             with human output, after confirming it."
+        );
+        eprintln!(
+            "Different election results are proposed in the contract, which have been approved by \
+             {} of the {} approvals needed. Posting the computed results replaces the proposal \
+             and discards its approvals.",
+            proposal.approvals.len(),
+            config.approval_threshold
+        );
+        let confirm = dialoguer::Confirm::new()
+            .report(true)
+            .wait_for_newline(true)
+            .with_prompt("Do you want to replace the proposed results?")
+            .interact()?;
+        anyhow::ensure!(confirm, "Aborting.");
+    }
+
+    if let Some(wallet_path) = wallet_path {
+        let wallet = WalletAccount::from_json_file(wallet_path)?;
+        let dry_run = contract_client
+            .dry_run_update::<_, ViewError>(
+                "postElectionResult",
+                Amount::zero(),
+                wallet.address,
+                &result,
+            )
+            .await
+            .context("Failed to dry run")?;
+
+        let handle = dry_run.send(&wallet).await?;
+
+        if let Err(e) = handle.wait_for_finalization().await {
+            eprintln!("Transaction failed with {e:#?}");
+            Ok(ResultStatus::NotPosted)
+        } else {
+            if human {
+                eprintln!("Transaction successful and finalized.",);
+            }
+            report_result_proposal(contract_client, &result, human).await
+        }
+    } else {
+        if human {
+            eprintln!(
+                "The admin keys were not provided, the results are not going to be posted to the \
+                 contract.\nRun again with the `--admin-keys` option to do so. "
+            )
+        }
+        Ok(ResultStatus::NotPosted)
+    }
+}
+
+/// Decrypt the encrypted tally registered in the contract with the decryption
+/// shares and proofs found in `shares_dir` and `proofs_dir`. Each file in
+/// `shares_dir` is paired with the file of the same name in `proofs_dir`.
+async fn handle_decrypt_from_files(
+    endpoint: sdk::Endpoint,
+    contract: ContractAddress,
+    shares_dir: std::path::PathBuf,
+    proofs_dir: std::path::PathBuf,
+    parameters_version: contract::HashSha2256,
+    show_progress: bool,
+) -> anyhow::Result<()> {
+    let client = sdk::Client::new(endpoint.clone()).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
+
+    let election_data = get_election_data(&mut contract_client).await?;
+    election_data.check_parameters_version(&parameters_version)?;
+
+    let encrypted_tally =
+        election_contract::view_encrypted_tally(&mut contract_client, BlockIdentifier::LastFinal)
+            .await?;
+    let Some(encrypted_tally) = encrypted_tally else {
+        anyhow::bail!("Encrypted tally not yet registered.")
+    };
+    let tally =
+        decode::<EncryptedTally>(&encrypted_tally).context("Encrypted tally is not readable.")?;
+
+    let weights = decrypt_from_files(
+        &election_data,
+        tally,
+        &shares_dir,
+        &proofs_dir,
+        show_progress,
+    )?;
+    print_computed_results(election_data.candidates, &weights)?;
+    eprintln!(
+        "The result is not registered in the contract. It can be registered with \
+         `propose-result`, giving `--votes` for each candidate in order."
+    );
+    Ok(())
+}
+
+/// Decrypt `tally` with the decryption shares found in `shares_dir` and the
+/// proofs found in `proofs_dir`, returning the weights of the candidates.
+fn decrypt_from_files(
+    election_data: &ElectionData,
+    tally: EncryptedTally,
+    shares_dir: &std::path::Path,
+    proofs_dir: &std::path::Path,
+    show_progress: bool,
+) -> anyhow::Result<Vec<u64>> {
+    let mut share_paths = std::fs::read_dir(shares_dir)
+        .with_context(|| format!("Unable to read directory {}.", shares_dir.display()))?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    share_paths.retain(|path| path.is_file());
+    share_paths.sort();
+
+    let mut decryption_shares = Vec::new();
+    let mut proof_shares = Vec::new();
+    for share_path in share_paths {
+        let file_name = share_path
+            .file_name()
+            .context("Decryption share file has no name.")?;
+        let proof_path = proofs_dir.join(file_name);
+        let share_bytes = std::fs::read(&share_path)
+            .with_context(|| format!("Unable to read {}.", share_path.display()))?;
+        let share = EncodedGuardianDecryption(share_bytes)
+            .decode(&tally)
+            .with_context(|| {
+                format!(
+                    "The decryption share in {} is not usable.",
+                    share_path.display()
+                )
+            })?;
+        let proof_bytes = std::fs::read(&proof_path).with_context(|| {
+            format!(
+                "Unable to read the proof of correct decryption {} matching {}.",
+                proof_path.display(),
+                share_path.display()
+            )
+        })?;
+        let proof = decode::<GuardianDecryptionProof>(&proof_bytes).with_context(|| {
+            format!(
+                "The proof of correct decryption in {} is not readable.",
+                proof_path.display()
+            )
+        })?;
+        eprintln!(
+            "Using the decryption share in {} and proof in {}.",
+            share_path.display(),
+            proof_path.display()
+        );
+        decryption_shares.push(share);
+        proof_shares.push(proof);
+    }
+
+    let quorum = election_data
+        .parameters
+        .varying_parameters
+        .k
+        .get_one_based_usize();
+    anyhow::ensure!(
+        decryption_shares.len() >= quorum,
+        "Not enough shares. Require {quorum} but only have {}.",
+        decryption_shares.len()
+    );
+
+    let bar = Progress::new(
+        tally.values().map(|x| x.len()).sum::<usize>() as u64,
+        show_progress,
+    )?;
+    bar.enable_steady_tick(Duration::from_millis(100));
+    let decryption = decrypt_ciphertexts(
+        election_data,
+        tally,
+        &decryption_shares,
+        &proof_shares,
+        &bar,
+        &ProgressNotifier::default(),
+    );
+    bar.finish_and_clear();
+
+    Ok(flatten_result(&decrypted_weights(
+        election_data,
+        decryption?,
+    )?))
+}
+
+/// Get the status of the result just posted by the admin account, which is only
+/// proposed if the election requires the result to be approved. If `human`,
+/// report how a proposed result can be approved.
+async fn report_result_proposal(
+    contract_client: &mut contract_client::ContractClient<ElectionContract>,
+    result: &contract::ElectionResult,
+    human: bool,
+) -> anyhow::Result<ResultStatus> {
+    let config =
+        election_contract::view_config(contract_client, BlockIdentifier::LastFinal).await?;
+    if config.result_approvers.is_empty() {
+        return Ok(ResultStatus::Stored);
+    }
+    if human {
+        eprintln!(
+            "The result is proposed, and is stored once approved by {} of the result approvers \
+             with `approve-result --hash {}`.",
+            config.approval_threshold,
+            election_result_hash(result)
+        );
+    }
+    Ok(ResultStatus::Proposed)
+}
+
+/// The `proposal` registered in the contract if it is pending approval, i.e. it
+/// has not been stored as the result with the `stored` weights yet. A proposal
+/// which has been stored remains viewable in the contract.
+fn pending_proposal(
+    proposal: Option<contract::ResultProposal>,
+    stored: Option<&[u64]>,
+) -> Option<contract::ResultProposal> {
+    proposal.filter(|proposal| Some(flatten_result(&proposal.result).as_slice()) != stored)
+}
+
+/// Split the weighted votes of all candidates, given in the order of the
+/// candidates in the contract, into the results of the contests of the
+/// election.
+fn contest_results(
+    contest_sizes: &[u32],
+    votes: &[contract::CandidateWeightedVotes],
+) -> anyhow::Result<contract::ElectionResult> {
+    let candidates: usize = contest_sizes.iter().map(|&size| size as usize).sum();
+    ensure!(
+        votes.len() == candidates,
+        "Votes given for {} candidates, but the election has {candidates}.",
+        votes.len()
+    );
+    let mut remaining = votes;
+    let mut result = Vec::with_capacity(contest_sizes.len());
+    for (contest_id, &size) in (0..).zip(contest_sizes) {
+        let (contest, rest) = remaining.split_at(size as usize);
+        result.push((contest_id, contest.to_vec()));
+        remaining = rest;
+    }
+    Ok(result)
+}
+
+async fn handle_propose_result(
+    endpoint: sdk::Endpoint,
+    contract: ContractAddress,
+    wallet_path: std::path::PathBuf,
+    votes: Vec<contract::CandidateWeightedVotes>,
+) -> anyhow::Result<()> {
+    let client = sdk::Client::new(endpoint).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
+    let config =
+        election_contract::view_config(&mut contract_client, BlockIdentifier::LastFinal).await?;
+    if config.result_approvers.is_empty() {
+        eprintln!(
+            "WARNING: The election has no result approvers, so the result is stored directly."
+        );
+    }
+    let result = contest_results(&config.contest_sizes, &votes)?;
+    for (candidate, votes) in config.candidates.iter().zip(&votes) {
+        eprintln!("  {}: {votes}", candidate.url);
+    }
+
+    let wallet = WalletAccount::from_json_file(wallet_path)?;
+    let dry_run = contract_client
+        .dry_run_update::<_, ViewError>(
+            "postElectionResult",
+            Amount::zero(),
+            wallet.address,
+            &result,
+        )
+        .await
+        .context("Failed to dry run")?;
+    let handle = dry_run.send(&wallet).await?;
+    if let Err(e) = handle.wait_for_finalization().await {
+        eprintln!("Transaction failed with {e:#?}");
+    } else {
+        eprintln!("Transaction successful and finalized.",);
+        report_result_proposal(&mut contract_client, &result, true).await?;
+    }
+
+    Ok(())
+}
+
+async fn handle_approve_result(
+    endpoint: sdk::Endpoint,
+    contract: ContractAddress,
+    wallet_path: std::path::PathBuf,
+    hash: contract::HashSha2256,
+) -> anyhow::Result<()> {
+    let client = sdk::Client::new(endpoint).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
+    let config =
+        election_contract::view_config(&mut contract_client, BlockIdentifier::LastFinal).await?;
+    let Some(proposal) =
+        election_contract::view_result_proposal(&mut contract_client, BlockIdentifier::LastFinal)
+            .await?
+    else {
+        anyhow::bail!("No election result is proposed in the contract.");
+    };
+    eprintln!("The proposed election result is.");
+    let proposed_votes = proposal.result.iter().flat_map(|(_, votes)| votes);
+    for (candidate, votes) in config.candidates.iter().zip(proposed_votes) {
+        eprintln!("  {}: {votes}", candidate.url);
+    }
+    eprintln!(
+        "It has been approved by {} of the {} approvals needed.",
+        proposal.approvals.len(),
+        config.approval_threshold
+    );
+    let proposed_hash = election_result_hash(&proposal.result);
+    ensure!(
+        proposed_hash == hash,
+        "The hash of the proposed result is {proposed_hash}, which does not match the given hash \
+         {hash}."
+    );
+
+    let wallet = WalletAccount::from_json_file(wallet_path)?;
+    let dry_run = contract_client
+        .dry_run_update::<_, ViewError>("approveResult", Amount::zero(), wallet.address, &hash)
+        .await
+        .context("Failed to dry run")?;
+    let handle = dry_run.send(&wallet).await?;
+    if let Err(e) = handle.wait_for_finalization().await {
+        eprintln!("Transaction failed with {e:#?}");
+    } else {
+        eprintln!("Transaction successful and finalized.",);
+    }
+
+    Ok(())
+}
+
+/// Lock the election after printing the registered election result, which
+/// becomes final.
+async fn handle_lock_election(
+    endpoint: sdk::Endpoint,
+    contract: ContractAddress,
+    wallet_path: std::path::PathBuf,
+) -> anyhow::Result<()> {
+    let client = sdk::Client::new(endpoint).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
+    let config =
+        election_contract::view_config(&mut contract_client, BlockIdentifier::LastFinal).await?;
+    ensure!(!config.locked, "The election is already locked.");
+    let Some(result) =
+        election_contract::view_election_result(&mut contract_client, BlockIdentifier::LastFinal)
+            .await?
+    else {
+        anyhow::bail!("No election result is registered in the contract.");
     };
+    eprintln!("The election result that will be final is.");
+    for candidate_result in &result {
+        eprintln!(
+            "  {}: {}",
+            candidate_result.candidate.url, candidate_result.cummulative_votes
+        );
+    }
+
+    let wallet = WalletAccount::from_json_file(wallet_path)?;
+    let dry_run = contract_client
+        .dry_run_update::<_, ViewError>("lockElection", Amount::zero(), wallet.address, &())
+        .await
+        .context("Failed to dry run")?;
+    let handle = dry_run.send(&wallet).await?;
+    if let Err(e) = handle.wait_for_finalization().await {
+        eprintln!("Transaction failed with {e:#?}");
+    } else {
+        eprintln!("Transaction successful and finalized. The election is locked.");
+    }
+
+    Ok(())
+}
+
+/// Extend the decryption deadline of the election to `decryption_deadline`,
+/// keeping the decryption shares and proofs already registered.
+async fn handle_extend_deadline(
+    endpoint: sdk::Endpoint,
+    contract: ContractAddress,
+    wallet_path: std::path::PathBuf,
+    decryption_deadline: Timestamp,
+) -> anyhow::Result<()> {
+    let client = sdk::Client::new(endpoint).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
+    let config =
+        election_contract::view_config(&mut contract_client, BlockIdentifier::LastFinal).await?;
+    ensure!(
+        config.decryption_deadline < decryption_deadline,
+        "The new deadline {decryption_deadline} must be after the current deadline {}.",
+        config.decryption_deadline
+    );
+
+    let wallet = WalletAccount::from_json_file(wallet_path)?;
+    let dry_run = contract_client
+        .dry_run_update::<_, ViewError>(
+            "extendDecryptionDeadline",
+            Amount::zero(),
+            wallet.address,
+            &decryption_deadline,
+        )
+        .await
+        .context("Failed to dry run")?;
+    eprintln!(
+        "The decryption deadline will be extended from {} to {decryption_deadline}. Registered \
+         decryption shares and proofs are kept.",
+        config.decryption_deadline
+    );
+    let handle = dry_run.send(&wallet).await?;
+    if let Err(e) = handle.wait_for_finalization().await {
+        eprintln!("Transaction failed with {e:#?}");
+    } else {
+        eprintln!("Transaction successful and finalized.");
+    }
+
+    Ok(())
+}
+
+/// Base64 encode the given binary data for inclusion in a [`ContractSnapshot`].
+fn snapshot_blob(data: &Option<Vec<u8>>) -> Option<String> {
+    use base64::Engine as _;
+    data.as_ref()
+        .map(|data| base64::engine::general_purpose::STANDARD.encode(data))
+}
+
+/// The state of a single guardian as recorded in a [`ContractSnapshot`]. The
+/// binary blobs are base64 encoded.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GuardianSnapshot {
+    account:                AccountAddress,
+    index:                  u32,
+    public_key:             Option<String>,
+    encrypted_share:        Option<String>,
+    decryption_share:       Option<String>,
+    decryption_share_proof: Option<String>,
+    status:                 Option<contract::GuardianStatus>,
+    excluded:               bool,
+    acknowledged_tally:     bool,
+}
+
+/// The complete on-chain state of the election contract at a given block.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ContractSnapshot {
+    contract:        ContractAddress,
+    /// The block the state was queried in.
+    block_hash:      BlockHash,
+    config:          contract::ElectionConfig,
+    guardians:       Vec<GuardianSnapshot>,
+    /// The base64 encoded encrypted tally, if registered.
+    encrypted_tally: Option<String>,
+    election_result: contract::ViewElectionResultQueryResponse,
+}
+
+impl ContractSnapshot {
+    /// Construct the snapshot of the state of `contract` queried in the block
+    /// with hash `block_hash`.
+    fn new(
+        contract: ContractAddress,
+        block_hash: BlockHash,
+        config: contract::ElectionConfig,
+        mut guardians_state: contract::GuardiansState,
+        encrypted_tally: Option<Vec<u8>>,
+        election_result: contract::ViewElectionResultQueryResponse,
+    ) -> Self {
+        guardians_state.sort_by_key(|g| g.1.index);
+        let guardians = guardians_state
+            .into_iter()
+            .map(|(account, state)| GuardianSnapshot {
+                account,
+                index: state.index,
+                public_key: snapshot_blob(&state.public_key),
+                encrypted_share: snapshot_blob(&state.encrypted_share),
+                decryption_share: snapshot_blob(&state.decryption_share),
+                decryption_share_proof: snapshot_blob(&state.decryption_share_proof),
+                status: state.status,
+                excluded: state.excluded,
+                acknowledged_tally: state.acknowledged_tally,
+            })
+            .collect();
+        Self {
+            contract,
+            block_hash,
+            config,
+            guardians,
+            encrypted_tally: snapshot_blob(&encrypted_tally),
+            election_result,
+        }
+    }
+}
+
+/// The oldest protocol version of the chain supported by the commands indexing
+/// the chain. Earlier protocol versions report block information differently.
+const MIN_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::P6;
+
+/// Check that the chain has a protocol version supported by the commands
+/// indexing the chain, see [`MIN_PROTOCOL_VERSION`].
+fn check_protocol_version(protocol_version: ProtocolVersion) -> anyhow::Result<()> {
+    ensure!(
+        protocol_version >= MIN_PROTOCOL_VERSION,
+        "The chain has protocol version {protocol_version:?}, but at least version \
+         {MIN_PROTOCOL_VERSION:?} is required. Use a node of a chain with a newer protocol \
+         version."
+    );
+    Ok(())
+}
+
+/// Print the version of the node and the state of the chain as seen by the
+/// node. Fails if the protocol version of the chain is not supported, or if
+/// the last finalized block is older than `max_lag_secs`, since results
+/// computed from the chain would then be incomplete.
+async fn handle_check_node(endpoint: sdk::Endpoint, max_lag_secs: u64) -> anyhow::Result<()> {
+    let mut client = sdk::Client::new(endpoint)
+        .await
+        .context("Unable to connect to the node.")?;
+    let node_info = client
+        .get_node_info()
+        .await
+        .context("Unable to query the node information.")?;
+    let consensus_info = client
+        .get_consensus_info()
+        .await
+        .context("Unable to query the consensus information.")?;
+    eprintln!("Node version: {}", node_info.version);
+    eprintln!("Protocol version: {:?}", consensus_info.protocol_version);
+    eprintln!("Genesis block: {}", consensus_info.genesis_block);
+    eprintln!(
+        "Last finalized block: {} at height {}",
+        consensus_info.last_finalized_block, consensus_info.last_finalized_block_height
+    );
+    check_protocol_version(consensus_info.protocol_version)?;
+
+    let last_finalized = client
+        .get_block_info(BlockIdentifier::LastFinal)
+        .await?
+        .response;
+    let lag = chrono::Utc::now().signed_duration_since(last_finalized.block_slot_time);
+    eprintln!(
+        "The last finalized block is from {}, {} seconds ago.",
+        last_finalized.block_slot_time,
+        lag.num_seconds()
+    );
+    ensure!(
+        lag.num_seconds() <= max_lag_secs as i64,
+        "The node is not caught up. The last finalized block is more than {max_lag_secs} seconds \
+         old."
+    );
+    eprintln!("The node is ready for use.");
+    Ok(())
+}
+
+/// Query all the view entrypoints of the election contract in the last
+/// finalized block and write the result as a single JSON document to `out`.
+async fn handle_snapshot(
+    endpoint: sdk::Endpoint,
+    contract: ContractAddress,
+    out: std::path::PathBuf,
+) -> anyhow::Result<()> {
+    let mut client = sdk::Client::new(endpoint).await?;
+    // Query all the state in the same block so that the snapshot is consistent.
+    let block_hash = client.get_consensus_info().await?.last_finalized_block;
+    let block = BlockIdentifier::Given(block_hash);
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
+
+    let config = election_contract::view_config(&mut contract_client, block).await?;
+    let guardians_state =
+        election_contract::view_guardians_state(&mut contract_client, block).await?;
+    let encrypted_tally =
+        election_contract::view_encrypted_tally(&mut contract_client, block).await?;
+    let election_result =
+        election_contract::view_election_result(&mut contract_client, block).await?;
+
+    let snapshot = ContractSnapshot::new(
+        contract,
+        block_hash,
+        config,
+        guardians_state,
+        encrypted_tally,
+        election_result,
+    );
+
+    let file = std::fs::File::create(&out)
+        .with_context(|| format!("Unable to create {}.", out.display()))?;
+    serde_json::to_writer_pretty(file, &snapshot)?;
+    eprintln!(
+        "Wrote snapshot of the contract state in block {block_hash} to {}.",
+        out.display()
+    );
+
+    Ok(())
+}
+
+/// A row in the CSV file written by `export-guardian-statuses`.
+#[derive(serde::Serialize)]
+struct GuardianStatusRow {
+    index:    u32,
+    account:  AccountAddress,
+    /// The status reported by the guardian, or `missing` if the guardian has
+    /// not reported a status.
+    status:   &'static str,
+    /// The space separated list of accounts the guardian could not verify the
+    /// data of. Empty unless verification failed.
+    accounts: String,
+}
+
+impl GuardianStatusRow {
+    fn new(account: AccountAddress, state: &contract::GuardianState) -> Self {
+        let (status, accounts) = match &state.status {
+            None => ("missing", &[][..]),
+            Some(contract::GuardianStatus::KeyVerificationFailed(accounts)) => {
+                ("keyVerificationFailed", &accounts[..])
+            }
+            Some(contract::GuardianStatus::SharesVerificationFailed(accounts)) => {
+                ("sharesVerificationFailed", &accounts[..])
+            }
+            Some(contract::GuardianStatus::VerificationSuccessful) => {
+                ("verificationSuccessful", &[][..])
+            }
+        };
+        Self {
+            index: state.index,
+            account,
+            status,
+            accounts: accounts
+                .iter()
+                .map(|a| a.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+/// Write the verification status reported by each of the guardians to the CSV
+/// file `out`, and return the number of guardians reporting failed
+/// verification.
+fn write_guardian_statuses(
+    out: &std::path::Path,
+    guardians_state: &[(AccountAddress, contract::GuardianState)],
+) -> anyhow::Result<usize> {
+    write_csv_metadata(out, &GUARDIAN_STATUSES_COLUMNS)?;
+    let mut writer = csv::Writer::from_path(out)
+        .with_context(|| format!("Unable to create {}.", out.display()))?;
+    let mut failed = 0;
+    for (account, state) in guardians_state {
+        if matches!(
+            state.status,
+            Some(
+                contract::GuardianStatus::KeyVerificationFailed(_)
+                    | contract::GuardianStatus::SharesVerificationFailed(_)
+            )
+        ) {
+            failed += 1;
+        }
+        writer.serialize(GuardianStatusRow::new(*account, state))?;
+    }
+    writer.flush()?;
+    Ok(failed)
+}
+
+/// Write the verification status reported by each guardian during the setup
+/// phase to the CSV file `out`, ordered by guardian index.
+async fn handle_export_guardian_statuses(
+    endpoint: sdk::Endpoint,
+    contract: ContractAddress,
+    out: std::path::PathBuf,
+) -> anyhow::Result<()> {
+    let client = sdk::Client::new(endpoint).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
+    let mut guardians_state =
+        election_contract::view_guardians_state(&mut contract_client, BlockIdentifier::LastFinal)
+            .await?;
+    guardians_state.sort_by_key(|g| g.1.index);
+    let failed = write_guardian_statuses(&out, &guardians_state)?;
+
+    eprintln!(
+        "Wrote the statuses of {} guardians, {failed} of which reported failed verification, to \
+         {}.",
+        guardians_state.len(),
+        out.display()
+    );
+    Ok(())
+}
+
+/// Print the fingerprint of the joint election public key computed from the
+/// public keys registered by the guardians.
+async fn handle_joint_key_fingerprint(
+    endpoint: sdk::Endpoint,
+    contract: ContractAddress,
+) -> anyhow::Result<()> {
+    let client = sdk::Client::new(endpoint).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
+    let election_data = get_election_data(&mut contract_client).await?;
+    let verification_context = election_data.verification_context()?;
+
+    eprintln!(
+        "Fingerprint of the joint election public key computed from the keys of {} guardians.",
+        election_data.guardian_public_keys.len()
+    );
+    println!(
+        "{}",
+        joint_public_key_fingerprint(&verification_context.public_key)?
+    );
+    Ok(())
+}
+
+/// Get the summary and the parameter of a finalized transaction which
+/// submitted a ballot to the election contract.
+async fn get_ballot_transaction(
+    contract_client: &mut contract_client::ContractClient<ElectionContract>,
+    transaction: TransactionHash,
+) -> anyhow::Result<(BlockItemSummary, contract::RegisterVotesParameter)> {
+    let status = contract_client
+        .client
+        .get_block_item_status(&transaction)
+        .await
+        .context("Unable to query transaction status.")?;
+    let Some((_, summary)) = status.is_finalized() else {
+        anyhow::bail!("Transaction {transaction} is not finalized.");
+    };
+    let param = ballot_parameter(summary, contract_client.address)?;
+    Ok((summary.clone(), param))
+}
+
+/// Get the parameter of the ballot submitted to the election contract at
+/// `contract` in the transaction with the given `summary`.
+fn ballot_parameter(
+    summary: &BlockItemSummary,
+    contract: ContractAddress,
+) -> anyhow::Result<contract::RegisterVotesParameter> {
+    let transaction = summary.hash;
+    let BlockItemSummaryDetails::AccountTransaction(atx) = &summary.details else {
+        anyhow::bail!("Transaction {transaction} is not an account transaction.");
+    };
+    let effects = match &atx.effects {
+        AccountTransactionEffects::ContractUpdateIssued { effects } => effects,
+        AccountTransactionEffects::None { reject_reason, .. } => {
+            anyhow::bail!("Transaction {transaction} was rejected: {reject_reason:?}")
+        }
+        _ => anyhow::bail!("Transaction {transaction} is not a contract update."),
+    };
+    let Some(ContractTraceElement::Updated { data }) = effects.first() else {
+        anyhow::bail!("Transaction {transaction} did not update a contract.");
+    };
+    anyhow::ensure!(
+        data.address == contract
+            && data.receive_name.as_receive_name().get_chain_name() == "election.registerVotes",
+        "Transaction {transaction} did not submit a ballot to the election contract."
+    );
+    concordium_std::from_bytes::<contract::RegisterVotesParameter>(data.message.as_ref())
+        .context("Unable to parse ballot from transaction.")
+}
+
+/// The structure of the selections of `ballot`, i.e. the number of encrypted
+/// selections of each contest of the ballot together with the number of
+/// options of the contest in the manifest.
+fn ballot_structure(
+    election_data: &ElectionData,
+    ballot: BallotEncrypted,
+) -> Vec<(ContestIndex, usize, usize)> {
+    // A tally of only the given ballot consists of exactly the ciphertexts of
+    // the ballot, which gives the selection structure without decrypting it.
+    let mut tally =
+        eg::ballot::BallotTallyBuilder::new(&election_data.manifest, &election_data.parameters);
+    tally.update(ballot);
+    tally
+        .finalize()
+        .into_iter()
+        .map(|(contest, selections)| {
+            let expected = election_data
+                .manifest
+                .contests
+                .get(contest)
+                .map_or(0, |c| c.options.len());
+            (contest, selections.len(), expected)
+        })
+        .collect()
+}
+
+async fn handle_inspect_ballot(
+    endpoint: sdk::Endpoint,
+    InspectBallotArgs {
+        contract,
+        transaction,
+    }: InspectBallotArgs,
+) -> anyhow::Result<()> {
+    let client = sdk::Client::new(endpoint).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
+    let election_data = get_election_data(&mut contract_client).await?;
+    let verification_context = election_data.verification_context()?;
+
+    let (summary, param) = get_ballot_transaction(&mut contract_client, transaction).await?;
+    if let Some(sender) = summary.sender_account() {
+        println!("Sender: {sender}");
+    }
+    println!("Energy cost: {}", summary.energy_cost);
+    println!("Serialized ballot size: {} bytes", param.inner.len());
+
+    let ballot = match decode::<BallotEncrypted>(&param.inner) {
+        Ok(ballot) => ballot,
+        Err(e) => {
+            println!("The ballot could not be decoded: {e:#}");
+            return Ok(());
+        }
+    };
+    println!(
+        "Verifies against the election parameters: {}",
+        ballot.verify(&verification_context)
+    );
+
+    for (contest, selections, expected) in ballot_structure(&election_data, ballot) {
+        println!(
+            "Contest {contest}: {selections} encrypted selections ({expected} options in the \
+             manifest)."
+        );
+    }
+
+    Ok(())
+}
+
+/// Find the ballot counted in the tally among the ballots `submitted` by an
+/// account, given in the order they were submitted together with whether they
+/// verify. As in the tally, this is the last ballot that verifies. Returns its
+/// transaction together with the number of earlier valid ballots it
+/// supersedes, or `None` if no ballot verifies.
+fn counted_ballot(
+    submitted: &[(chrono::DateTime<chrono::Utc>, TransactionHash, bool)],
+) -> Option<(TransactionHash, usize)> {
+    let position = submitted.iter().rposition(|(_, _, verified)| *verified)?;
+    let superseded = submitted[..position]
+        .iter()
+        .filter(|(_, _, verified)| *verified)
+        .count();
+    Some((submitted[position].1, superseded))
+}
+
+/// Index the ballots submitted by `account` during the election period, and
+/// report which of them is counted in the tally. As in the tally, this is the
+/// last ballot that verifies, and it is scaled according to the final weight of
+/// the account.
+async fn handle_verify_inclusion(
+    endpoint: sdk::Endpoint,
+    indexing: IndexingConfig,
+    VerifyInclusionArgs {
+        contract,
+        account,
+        final_weights,
+    }: VerifyInclusionArgs,
+    show_progress: bool,
+) -> anyhow::Result<()> {
+    let client = sdk::Client::new(endpoint).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
+    let election_data = get_election_data(&mut contract_client).await?;
+    let verification_context = election_data.verification_context()?;
+    let end = election_data.end;
+
+    let (first_block, last_block) =
+        range_setup(&mut contract_client.client, election_data.start, end).await?;
+
+    let traverse_config =
+        indexer::TraverseConfig::new_single(indexing.endpoint.clone(), first_block.block_height);
+    let (sender, receiver) = tokio::sync::mpsc::channel(20);
+    let cancel_handle = tokio::spawn(traverse_config.traverse(
+        indexer::ContractUpdateIndexer {
+            target_address: contract,
+            entrypoint:     OwnedEntrypointName::new_unchecked("registerVotes".into()),
+        },
+        sender,
+    ));
+    let mut receiver = indexing.limit_blocks(receiver);
+
+    let bar = Progress::new(
+        last_block.block_height.height - first_block.block_height.height,
+        show_progress,
+    )?;
+
+    let voter = AccountAddressEq::from(account);
+    // The ballots submitted by the voter, in the order they were submitted,
+    // together with whether they verify.
+    let mut submitted = Vec::new();
+    while let Some((block, txs)) = receiver.recv().await {
+        bar.set_message(block.block_slot_time.to_string());
+        bar.inc(1);
+        if block.block_slot_time > end {
+            drop(receiver);
+            cancel_handle.abort();
+            drop(cancel_handle);
+            break;
+        }
+
+        for indexer::ContractUpdateInfo {
+            execution_tree,
+            transaction_hash,
+            sender,
+            ..
+        } in txs
+        {
+            if AccountAddressEq::from(sender) != voter {
+                continue;
+            }
+            let verified = concordium_std::from_bytes::<contract::RegisterVotesParameter>(
+                execution_tree.parameter().as_ref(),
+            )
+            .ok()
+            .and_then(|param| decode::<BallotEncrypted>(&param.inner).ok())
+            .is_some_and(|ballot| ballot.verify(&verification_context));
+            submitted.push((block.block_slot_time, transaction_hash, verified));
+        }
+    }
+    bar.finish_and_clear();
+
+    if submitted.is_empty() {
+        println!("Account {account} did not submit any ballots during the election period.");
+        return Ok(());
+    }
+    for (time, hash, verified) in &submitted {
+        let status = if *verified { "valid" } else { "invalid" };
+        println!("Ballot submitted at {time} in transaction {hash} is {status}.");
+    }
+
+    let Some((counted, superseded)) = counted_ballot(&submitted) else {
+        println!("None of the ballots of account {account} are valid, so none are counted.");
+        return Ok(());
+    };
+    if superseded > 0 {
+        println!("{superseded} earlier valid ballot(s) are superseded by the latest one.");
+    }
+
+    let final_weights = read_final_weights(&final_weights)?;
+    let Some((amount, delegators)) = final_weights.get(&voter) else {
+        println!(
+            "The ballot in transaction {counted} is not counted, since account {account} does not \
+             appear in the final weights, e.g. because it delegated its vote."
+        );
+        return Ok(());
+    };
+    let factor = get_scaling_factor(amount);
+    println!(
+        "The ballot in transaction {counted} is counted in the tally with weight {amount}, i.e. \
+         scaled by a factor {factor}."
+    );
+    if delegators.is_empty() {
+        println!("No accounts delegated their vote to account {account}.");
+    } else {
+        println!("The weight includes the delegated votes of the accounts:");
+        for delegator in delegators.split(';').filter(|d| !d.is_empty()) {
+            println!("  {delegator}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Decrypt and print the selections of the ballot submitted in the given
+/// transaction, using the decryption shares provided by the guardians.
+async fn handle_reveal_ballot(
+    endpoint: sdk::Endpoint,
+    RevealBallotArgs {
+        contract,
+        transaction,
+        guardian_decryptions,
+        confirmed,
+    }: RevealBallotArgs,
+) -> anyhow::Result<()> {
+    eprintln!(
+        "WARNING: Revealing a ballot breaks the secrecy of the vote. This must only be done for \
+         dispute resolution, with the cooperation of the guardians."
+    );
+    anyhow::ensure!(
+        confirmed,
+        "Refusing to reveal the ballot without the --i-understand-this-breaks-ballot-secrecy flag."
+    );
+
+    let client = sdk::Client::new(endpoint).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
+    let election_data = get_election_data(&mut contract_client).await?;
+    let verification_context = election_data.verification_context()?;
+
+    let (_, param) = get_ballot_transaction(&mut contract_client, transaction).await?;
+    let ballot = decode::<BallotEncrypted>(&param.inner)
+        .context("Unable to parse ballot from transaction.")?;
+    if !ballot.verify(&verification_context) {
+        eprintln!("WARNING: The ballot submitted in transaction {transaction} is invalid.");
+    }
+
+    let mut decryptions = Vec::with_capacity(guardian_decryptions.len());
+    for path in guardian_decryptions {
+        let file = std::fs::File::open(&path)
+            .with_context(|| format!("Unable to open {}.", path.display()))?;
+        let decryption: BallotDecryptionFile =
+            serde_json::from_reader(file).with_context(|| {
+                format!(
+                    "Unable to read guardian decryption from {}.",
+                    path.display()
+                )
+            })?;
+        decryptions.push(decryption);
+    }
+    let decryption = reveal_ballot(&election_data, ballot, decryptions)?;
+
+    eprintln!("The selections of the ballot submitted in transaction {transaction} are.");
+    for (contest, selections) in decryption {
+        println!("Contest {contest}:");
+        for (candidate, selection) in election_data.candidates.iter().zip(selections) {
+            println!("  {}: {}", candidate.url, selection.plain_text.value());
+        }
+    }
+
+    Ok(())
+}
+
+/// Decrypt the selections of the `ballot` using the `decryptions` of it
+/// provided by the guardians.
+fn reveal_ballot(
+    election_data: &ElectionData,
+    ballot: BallotEncrypted,
+    decryptions: Vec<BallotDecryptionFile>,
+) -> anyhow::Result<BTreeMap<ContestIndex, Vec<VerifiableDecryption>>> {
+    // A tally of only the given ballot consists of exactly the ciphertexts of
+    // the ballot.
+    let mut tally =
+        eg::ballot::BallotTallyBuilder::new(&election_data.manifest, &election_data.parameters);
+    tally.update(ballot);
+    let ciphertexts = tally.finalize();
+
+    let (decryption_shares, proof_shares): (Vec<_>, Vec<_>) = decryptions
+        .into_iter()
+        .map(|decryption| (decryption.decryption_share, decryption.decryption_proof))
+        .unzip();
+    let quorum = election_data
+        .parameters
+        .varying_parameters
+        .k
+        .get_one_based_usize();
+    anyhow::ensure!(
+        decryption_shares.len() >= quorum,
+        "Not enough shares. Require {quorum} but only have {}.",
+        decryption_shares.len()
+    );
+
+    let bar = Progress::new(
+        ciphertexts.values().map(|x| x.len()).sum::<usize>() as u64,
+        false,
+    )?;
+    let decryption = decrypt_ciphertexts(
+        election_data,
+        ciphertexts,
+        &decryption_shares,
+        &proof_shares,
+        &bar,
+        &ProgressNotifier::default(),
+    )?;
+    bar.finish_and_clear();
+    Ok(decryption)
+}
+
+async fn handle_reset(
+    endpoint: sdk::Endpoint,
+    contract: ContractAddress,
+    wallet_path: std::path::PathBuf,
+    guardians: Vec<AccountAddress>,
+    decryption_deadline: Timestamp,
+) -> anyhow::Result<()> {
+    let client = sdk::Client::new(endpoint.clone()).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, contract).await?;
+    let wallet = WalletAccount::from_json_file(wallet_path)?;
+    let parameter = (guardians, decryption_deadline);
+    let dry_run = contract_client
+        .dry_run_update::<_, ViewError>(
+            "resetFinalizationPhase",
+            Amount::zero(),
+            wallet.address,
+            &parameter,
+        )
+        .await
+        .context("Failed to dry run")?;
+
+    let guardians_state =
+        election_contract::view_guardians_state(&mut contract_client, BlockIdentifier::LastFinal)
+            .await?;
+
+    let guardians_state_filtered = guardians_state
+        .iter()
+        .filter(|(addr, _)| parameter.0.contains(addr));
+
+    eprintln!("Guardians to be removed:");
+    for (addr, st) in guardians_state_filtered {
+        let Some(pk_bytes) = st.public_key.clone() else {
+            anyhow::bail!("Public key not found for guardian with address {}", addr);
+        };
+        let pk = decode::<GuardianPublicKey>(&pk_bytes)
+            .context("Failed to decode guardian public key.")?;
+        let pk_json = serde_json::to_string_pretty(&pk.coefficient_commitments.0[0])?;
+        eprintln!(
+            "Guardian {} with address {} and public key {}",
+            pk.i, addr, pk_json
+        );
+    }
+
+    let confirm = dialoguer::Confirm::new()
+        .report(true)
+        .wait_for_newline(true)
+        .with_prompt("Confirm tally decryption reset.")
+        .interact()?;
+    anyhow::ensure!(confirm, "Aborting.");
+
+    let handle = dry_run.send(&wallet).await?;
+
+    if let Err(e) = handle.wait_for_finalization().await {
+        eprintln!("Transaction failed with {e:#?}");
+    } else {
+        eprintln!("Transaction successful and finalized.",);
+    }
+
+    Ok(())
+}
+
+/// Election data retrieved from the contract and processed.
+struct ElectionData {
+    manifest:             ElectionManifest,
+    parameters:           ElectionParameters,
+    guardian_public_keys: Vec<GuardianPublicKey>,
+    candidates:           Vec<contract::ChecksumUrl>,
+    /// The number of candidates of each contest, see
+    /// [`contract::ElectionConfig::contest_sizes`].
+    contest_sizes:        Vec<u32>,
+    start:                chrono::DateTime<chrono::Utc>,
+    end:                  chrono::DateTime<chrono::Utc>,
+    /// String that is used to detect delegations.
+    delegation_string:    String,
+}
+
+impl ElectionData {
+    pub fn verification_context(&self) -> anyhow::Result<PreVotingData> {
+        let missing = missing_guardian_keys(&self.parameters, &self.guardian_public_keys);
+        anyhow::ensure!(
+            missing.is_empty(),
+            "The guardian setup is incomplete. No public keys are registered for the guardians \
+             with indices {}.",
+            missing
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        PreVotingData::compute(
+            self.manifest.clone(),
+            self.parameters.clone(),
+            &self.guardian_public_keys,
+        )
+    }
+
+    /// Check that the fixed parameters of the election have the expected
+    /// version. This guards against tallying or decrypting with cryptographic
+    /// parameters that differ from the ones the election was expected to use.
+    pub fn check_parameters_version(&self, expected: &contract::HashSha2256) -> anyhow::Result<()> {
+        let version = fixed_parameters_version(&self.parameters.fixed_parameters)?;
+        anyhow::ensure!(
+            &version == expected,
+            "The fixed parameters of the election have version {version}, but version {expected} \
+             is expected. Use --parameters-version to override the expected version."
+        );
+        Ok(())
+    }
+}
+
+/// Retrieve the election data from the contract and data linked from the
+/// contract.
+async fn get_election_data(
+    contract_client: &mut contract_client::ContractClient<ElectionContract>,
+) -> anyhow::Result<ElectionData> {
+    let config =
+        election_contract::view_config(contract_client, BlockIdentifier::LastFinal).await?;
+    let guardians =
+        election_contract::view_guardians_state(contract_client, BlockIdentifier::LastFinal)
+            .await?;
+
+    if config.eg_version != ELECTIONGUARD_VERSION {
+        eprintln!(
+            "WARNING: The election was set up with electionguard version {}, but this tool uses \
+             version {ELECTIONGUARD_VERSION}.",
+            config.eg_version
+        );
+    }
+
+    let start = config.election_start.try_into()?;
+    let end = config.election_end.try_into()?;
+
+    let client = HttpClient::try_create(5000)?;
+
+    let election_manifest = client
+        .get_json_resource_checked(&config.election_manifest)
+        .await?;
+    let election_parameters: ElectionParameters = client
+        .get_json_resource_checked(&config.election_parameters)
+        .await?;
+    // The threshold recorded in the contract is authoritative, so the election
+    // parameters must agree with it.
+    let k = &election_parameters.varying_parameters.k;
+    ensure!(
+        GuardianIndex::from_one_based_index(config.threshold)
+            .ok()
+            .as_ref()
+            == Some(k),
+        "The election parameters have threshold {k}, but the contract has threshold {}.",
+        config.threshold
+    );
+
+    // Decoding the keys is CPU intensive, so it is done in parallel.
+    let mut guardian_public_keys = guardians
+        .par_iter()
+        .map(|(ga, gs)| {
+            let bytes = gs
+                .public_key
+                .clone()
+                .with_context(|| format!("No public key found for guardian {ga}"))?;
+            let key: GuardianPublicKey = decode(&bytes)
+                .with_context(|| format!("Failed to decode public key for guardian {ga}"))?;
+            anyhow::Ok(key)
+        })
+        .collect::<Result<Vec<GuardianPublicKey>, _>>()
+        .context("Could not deserialize guardian public key")?;
+
+    // Sort to make sure the public keys are in the order of indices
+    // since some verification commands depend on it.
+    guardian_public_keys.sort_by_key(|g| g.i);
+
+    Ok(ElectionData {
+        manifest: election_manifest,
+        parameters: election_parameters,
+        candidates: config.candidates,
+        contest_sizes: config.contest_sizes,
+        guardian_public_keys,
+        start,
+        end,
+        delegation_string: config.delegation_string,
+    })
+}
+
+/// Check that the accounts in the final weights derive from the eligible voters
+/// registered in the contract. Each ineligible account and delegator is
+/// reported, and the number of them is returned.
+async fn check_final_weights_eligibility(
+    contract_client: &mut contract_client::ContractClient<ElectionContract>,
+    final_weights: &BTreeMap<AccountAddressEq, (Amount, String)>,
+) -> anyhow::Result<usize> {
+    let config =
+        election_contract::view_config(contract_client, BlockIdentifier::LastFinal).await?;
+    let data = HttpClient::try_create(5000)?
+        .get_resource_checked(&config.eligible_voters.data)
+        .await
+        .context("Unable to get the eligible voters registered in the contract.")?;
+    let problems = ineligible_final_weights(&data, final_weights)?;
+    for problem in &problems {
+        eprintln!("{problem}");
+    }
+    Ok(problems.len())
+}
+
+/// Describe the accounts in the final weights which do not derive from the
+/// `eligible_voters`, given as the contents of an initial weights file. An
+/// account is eligible if it is one of the eligible voters, or if it only
+/// received weight through delegations from eligible voters.
+fn ineligible_final_weights(
+    eligible_voters: &[u8],
+    final_weights: &BTreeMap<AccountAddressEq, (Amount, String)>,
+) -> anyhow::Result<Vec<String>> {
+    let mut eligible = BTreeSet::new();
+    for row in csv::Reader::from_reader(eligible_voters).deserialize() {
+        let row: WeightRow = row.context("Unable to parse the eligible voters.")?;
+        eligible.insert(AccountAddressEq::from(row.account));
+    }
+
+    let mut problems = Vec::new();
+    for (account_eq, (_, delegators)) in final_weights {
+        let account: &AccountAddress = account_eq.as_ref();
+        let delegators = delegators
+            .split(';')
+            .filter(|d| !d.is_empty())
+            .map(AccountAddress::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("Invalid delegators of account {account}."))?;
+        if delegators.is_empty() && !eligible.contains(account_eq) {
+            problems.push(format!(
+                "Account {account} in the final weights is not an eligible voter."
+            ));
+        }
+        for delegator in delegators {
+            if !eligible.contains(&AccountAddressEq::from(delegator)) {
+                problems.push(format!(
+                    "Account {delegator} delegating to {account} is not an eligible voter."
+                ));
+            }
+        }
+    }
+    Ok(problems)
+}
+
+/// Verify the ballots submitted by the `registerVotes` transactions `txs`,
+/// given by their sender, hash and parameter, in parallel. The verified ballots
+/// are recorded in `ballots`, replacing any previous ballot from the same
+/// account, so the transactions must be given in the order they were submitted.
+fn record_ballots(
+    ballots: &mut BTreeMap<AccountAddressEq, (BallotEncrypted, TransactionHash)>,
+    txs: Vec<(AccountAddress, TransactionHash, Vec<u8>)>,
+    verification_context: &PreVotingData,
+) {
+    let results = txs
+        .into_par_iter()
+        .flat_map(|(sender, transaction_hash, param)| {
+            let Ok(param) = concordium_std::from_bytes::<contract::RegisterVotesParameter>(&param)
+            else {
+                eprintln!("Unable to parse ballot from transaction {transaction_hash}");
+                return None;
+            };
+
+            let Ok(ballot) = decode::<BallotEncrypted>(&param.inner) else {
+                eprintln!("Unable to parse ballot from transaction {transaction_hash}");
+                return None;
+            };
+            Some((
+                ballot.verify(verification_context),
+                sender,
+                ballot,
+                transaction_hash,
+            ))
+        })
+        .collect_vec_list();
+
+    for (verified, sender, ballot, transaction_hash) in results.into_iter().flatten() {
+        if verified {
+            // Replace any previous ballot from the sender.
+            ballots.insert(AccountAddressEq::from(sender), (ballot, transaction_hash));
+        } else {
+            eprintln!("Vote in transaction {transaction_hash} is invalid.");
+        }
+    }
+}
+
+/// Compute the encrypted tally of the `ballots`, each scaled by the final
+/// weight of the account that cast it. Returns the tally together with
+/// statistics of the included ballots and a log entry for each of them.
+fn scale_ballots(
+    election_data: &ElectionData,
+    mut ballots: BTreeMap<AccountAddressEq, (BallotEncrypted, TransactionHash)>,
+    final_weights: BTreeMap<AccountAddressEq, (Amount, String)>,
+    weight_unit: u64,
+) -> (EncryptedTally, TallyStats, Vec<TallyLogEntry>) {
+    let mut tally =
+        eg::ballot::BallotTallyBuilder::new(&election_data.manifest, &election_data.parameters);
+    let mut tally_stats = TallyStats {
+        ballots:          0,
+        weighted_ballots: 0,
+    };
+    let mut log_entries = Vec::new();
+    for (account, (amount, delegators)) in final_weights {
+        if let Some((ballot, hash)) = ballots.remove(&account) {
+            let factor = get_scaling_factor_with_unit(&amount, weight_unit);
+            tally_stats.ballots += 1;
+            tally_stats.weighted_ballots += factor;
+            eprintln!(
+                "Scaling the ballot cast by transaction {hash} by a factor {factor}. Delegators \
+                 {delegators}."
+            );
+            log_entries.push(TallyLogEntry {
+                account: AccountAddress::from(account),
+                transaction_hash: hash,
+                amount,
+                scaling_factor: factor,
+                delegators: delegators
+                    .split(';')
+                    .filter(|d| !d.is_empty())
+                    .map(String::from)
+                    .collect(),
+            });
+            tally.update(ballot.scale(
+                &election_data.parameters.fixed_parameters,
+                &util::algebra::FieldElement::from(
+                    factor,
+                    &election_data.parameters.fixed_parameters.field,
+                ),
+            ));
+        } // else the account did not vote, so nothing to do.
+    }
+    (tally.finalize(), tally_stats, log_entries)
+}
+
+/// The number of blocks indexed by `tally` between writing checkpoints.
+const TALLY_CHECKPOINT_INTERVAL: u64 = 1000;
+
+/// The progress of indexing the ballots for the tally, saved so that indexing
+/// can be resumed after a failure.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TallyCheckpoint {
+    /// The election contract the ballots are submitted to.
+    contract:    ContractAddress,
+    /// The height of the last block whose ballots are included.
+    last_height: AbsoluteBlockHeight,
+    /// The last valid ballot of each account, and the transaction it was
+    /// submitted in.
+    ballots:     Vec<(AccountAddress, BallotEncrypted, TransactionHash)>,
+}
+
+impl TallyCheckpoint {
+    /// Read the checkpoint at `path`, or return `None` if no checkpoint is
+    /// saved there. Fails if the checkpoint is for a different contract than
+    /// `contract`.
+    fn read(path: &std::path::Path, contract: ContractAddress) -> anyhow::Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let bytes = std::fs::read(path)
+            .with_context(|| format!("Unable to read checkpoint {}.", path.display()))?;
+        let checkpoint: Self = decode(&bytes)
+            .with_context(|| format!("Unable to parse checkpoint {}.", path.display()))?;
+        ensure!(
+            checkpoint.contract == contract,
+            "The checkpoint {} is for contract {}, not {contract}.",
+            path.display(),
+            checkpoint.contract
+        );
+        Ok(Some(checkpoint))
+    }
+
+    /// Write the checkpoint to `path`. The checkpoint is written to a
+    /// temporary file first, so that an interrupted write does not corrupt a
+    /// previous checkpoint.
+    fn write(
+        path: &std::path::Path,
+        contract: ContractAddress,
+        last_height: AbsoluteBlockHeight,
+        ballots: &BTreeMap<AccountAddressEq, (BallotEncrypted, TransactionHash)>,
+    ) -> anyhow::Result<()> {
+        let checkpoint = Self {
+            contract,
+            last_height,
+            ballots: ballots
+                .iter()
+                .map(|(account, (ballot, hash))| {
+                    (AccountAddress::from(*account), ballot.clone(), *hash)
+                })
+                .collect(),
+        };
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, encode(&checkpoint)?)
+            .with_context(|| format!("Unable to write checkpoint {}.", tmp.display()))?;
+        std::fs::rename(&tmp, path)
+            .with_context(|| format!("Unable to write checkpoint {}.", path.display()))?;
+        Ok(())
+    }
+}
+
+/// Handle tallying of votes during the election phase.
+/// Note that this assumes access to final weights already.
+async fn handle_tally(
+    endpoint: sdk::Endpoint,
+    indexing: IndexingConfig,
+    TallyArgs {
+        target_address,
+        final_weights,
+        keys,
+        stats_out,
+        check_eligible_voters,
+        computation_log,
+        weight_unit,
+        checkpoint,
+    }: TallyArgs,
+    parameters_version: contract::HashSha2256,
+    show_progress: bool,
+) -> anyhow::Result<TallyStats> {
+    let client = sdk::Client::new(endpoint.clone()).await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client, target_address).await?;
+
+    let election_data = get_election_data(&mut contract_client).await?;
+    election_data.check_parameters_version(&parameters_version)?;
+
+    let verification_context: PreVotingData = election_data.verification_context()?;
+
+    let start = election_data.start;
+    let end = election_data.end;
+
+    let (first_block, last_block) = range_setup(&mut contract_client.client, start, end).await?;
+
+    let mut ballots = BTreeMap::new();
+    // The height of the last block whose ballots are included in `ballots`.
+    let mut last_height = first_block
+        .block_height
+        .height
+        .checked_sub(1)
+        .map(AbsoluteBlockHeight::from);
+    if let Some(path) = &checkpoint {
+        if let Some(saved) = TallyCheckpoint::read(path, target_address)? {
+            ensure!(
+                saved.last_height >= first_block.block_height
+                    && saved.last_height <= last_block.block_height,
+                "The checkpoint {} is at height {}, which is outside the election period.",
+                path.display(),
+                saved.last_height
+            );
+            eprintln!(
+                "Resuming from the checkpoint at height {} with {} ballots.",
+                saved.last_height,
+                saved.ballots.len()
+            );
+            ballots.extend(
+                saved.ballots.into_iter().map(|(account, ballot, hash)| {
+                    (AccountAddressEq::from(account), (ballot, hash))
+                }),
+            );
+            last_height = Some(saved.last_height);
+        }
+    }
+    let start_height = last_height.map_or(first_block.block_height, |height| {
+        AbsoluteBlockHeight::from(height.height + 1)
+    });
+
+    let traverse_config =
+        indexer::TraverseConfig::new_single(indexing.endpoint.clone(), start_height);
+    // Buffer enough blocks to fill a batch.
+    let (sender, receiver) = tokio::sync::mpsc::channel(indexing.block_batch_size.max(20));
+    let cancel_handle = tokio::spawn(traverse_config.traverse(
+        indexer::ContractUpdateIndexer {
+            target_address,
+            entrypoint: OwnedEntrypointName::new_unchecked("registerVotes".into()),
+        },
+        sender,
+    ));
+    let mut receiver = indexing.limit_blocks(receiver);
+
+    let bar = Progress::new(
+        last_block
+            .block_height
+            .height
+            .saturating_sub(start_height.height),
+        show_progress,
+    )?;
+
+    // The number of blocks indexed since the last checkpoint was written.
+    let mut since_checkpoint = 0;
+    while let Some(blocks) = indexing.recv_batch(&mut receiver).await {
+        // The transactions of the blocks in the batch before the end of the
+        // election, in the order they were submitted.
+        let mut txs = Vec::new();
+        let mut done = false;
+        for (block, block_txs) in blocks {
+            bar.set_message(block.block_slot_time.to_string());
+            bar.inc(1);
+            if block.block_slot_time > end {
+                done = true;
+                break;
+            }
+            last_height = Some(block.block_height);
+            since_checkpoint += 1;
+            txs.extend(block_txs);
+        }
+
+        let txs = txs
+            .into_iter()
+            .map(|info| {
+                (
+                    info.sender,
+                    info.transaction_hash,
+                    info.execution_tree.parameter().as_ref().to_vec(),
+                )
+            })
+            .collect();
+        record_ballots(&mut ballots, txs, &verification_context);
+
+        if let (Some(path), Some(height)) = (&checkpoint, last_height) {
+            if done || since_checkpoint >= TALLY_CHECKPOINT_INTERVAL {
+                TallyCheckpoint::write(path, target_address, height, &ballots)?;
+                since_checkpoint = 0;
+            }
+        }
+
+        if done {
+            drop(receiver);
+            cancel_handle.abort();
+            drop(cancel_handle);
+            eprintln!("Done indexing.");
+            break;
+        }
+    }
+
+    let final_weights = read_final_weights(&final_weights)?;
+    if check_eligible_voters {
+        let problems =
+            check_final_weights_eligibility(&mut contract_client, &final_weights).await?;
+        if problems == 0 {
+            eprintln!("All accounts in the final weights derive from the eligible voters.");
+        } else {
+            eprintln!(
+                "WARNING: Found {problems} account(s) in the final weights that are not eligible. \
+                 Check that the correct final weights are used."
+            );
+        }
+    }
+
+    let (tally, tally_stats, log_entries) =
+        scale_ballots(&election_data, ballots, final_weights, weight_unit);
+
+    eprintln!(
+        "Included {} ballots with a total weight of {} units of {weight_unit} micro CCD in the \
+         tally.",
+        tally_stats.ballots, tally_stats.weighted_ballots
+    );
+    if let Some(stats_out) = stats_out {
+        let file = std::fs::File::create(&stats_out)
+            .with_context(|| format!("Unable to create {}.", stats_out.display()))?;
+        serde_json::to_writer_pretty(file, &tally_stats)?;
+    }
+
+    let serialized_tally = encode(&tally)?;
+    if let Some(computation_log) = computation_log {
+        let log = TallyComputationLog {
+            ballots: log_entries,
+            weight_unit,
+            tally_hash: contract::HashSha2256(sha2::Sha256::digest(&serialized_tally).into()),
+        };
+        let file = std::fs::File::create(&computation_log)
+            .with_context(|| format!("Unable to create {}.", computation_log.display()))?;
+        serde_json::to_writer_pretty(file, &log)?;
+        eprintln!(
+            "Wrote the log of the tally computation to {}.",
+            computation_log.display()
+        );
+    }
+    let param = concordium_std::OwnedParameter::from_serial(&serialized_tally)?;
+
+    let json_param =
+        contract::PostEncryptedTallyParameter::get_type().to_json_string_pretty(param.as_ref())?;
+    eprintln!("The following JSON parameter can be used to record the tally in the contract.");
+    println!("{}", json_param);
+
+    let current_tally =
+        election_contract::view_encrypted_tally(&mut contract_client, BlockIdentifier::LastFinal)
+            .await?;
+
+    let wrong_tally = if let Some(registered_tally) = current_tally {
+        if registered_tally == serialized_tally {
+            eprintln!(
+                "The computed encrypted tally matches the tally already registered in the contract"
+            );
+            return Ok(tally_stats);
+        }
+        eprintln!(
+            "The encrypted tally is already registered in the contract, but it is different."
+        );
+        if keys.is_some() {
+            let confirm = dialoguer::Confirm::new()
+                .report(true)
+                .wait_for_newline(true)
+                .with_prompt("Do you want to overwrite the published tally?")
+                .interact()?;
+            anyhow::ensure!(confirm, "Aborting.");
+        }
+        true
+    } else {
+        false
+    };
+
+    if let Some(keys) = keys {
+        let wallet = WalletAccount::from_json_file(keys)?;
+        eprintln!("Registering tally in the smart contract.");
+        let dry_run = contract_client
+            .dry_run_update_raw::<ViewError>(
+                "postEncryptedTally",
+                Amount::zero(),
+                wallet.address,
+                param,
+            )
+            .await
+            .context("Failed to dry run postEncryptedTally")?;
+
+        let tx = dry_run
+            .send(&wallet)
+            .await
+            .context("Failed to send transaction to post the tally.")?;
+        eprintln!("Transaction {tx} sent. Await finalization.");
+        if let Err(reason) = tx.wait_for_finalization().await {
+            eprintln!("Transaction failed with {reason:#?}");
+        } else {
+            eprintln!("Transaction successful and finalized.");
+        }
+    } else if !wrong_tally {
+        eprintln!(
+            "The tally is currently not registered in the contract, and no keys were provided."
+        );
+    }
+    Ok(tally_stats)
+}
+
+/// A way of computing the weight of an account from the `balances` it held
+/// during the period. Each reading holds from its timestamp until the
+/// timestamp of the next reading, and the last reading holds until `end`. The
+/// readings are expected to be ordered by time.
+trait WeightStrategy {
+    fn weight(
+        &self,
+        balances: &[(chrono::DateTime<chrono::Utc>, Amount)],
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Amount>;
+}
+
+/// The time-weighted average balance.
+struct LinearAverage;
+
+/// The square root of the time-weighted average balance in CCD.
+struct Quadratic;
+
+/// The time-weighted median balance.
+struct Median;
+
+/// The minimum balance held.
+struct Min;
+
+/// The time-weighted average of the `balances` in micro CCD.
+fn time_weighted_average(
+    balances: &[(chrono::DateTime<chrono::Utc>, Amount)],
+    end: chrono::DateTime<chrono::Utc>,
+) -> anyhow::Result<u128> {
+    let Some((&first, rest)) = balances.split_first() else {
+        anyhow::bail!("A bug, there should always be at least one reading.");
+    };
+    let mut last_time = first.0;
+    let mut weighted_sum = u128::from(first.1.micro_ccd);
+    let mut last_balance = weighted_sum;
+    for &(dt, balance) in rest {
+        weighted_sum +=
+            (dt.signed_duration_since(last_time).num_milliseconds() as u128) * last_balance;
+        last_time = dt;
+        last_balance = u128::from(balance.micro_ccd);
+    }
+    weighted_sum +=
+        (end.signed_duration_since(last_time).num_milliseconds() as u128) * last_balance;
+    Ok(weighted_sum / (end.signed_duration_since(first.0).num_milliseconds() as u128))
+}
+
+impl WeightStrategy for LinearAverage {
+    fn weight(
+        &self,
+        balances: &[(chrono::DateTime<chrono::Utc>, Amount)],
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Amount> {
+        let average = time_weighted_average(balances, end)?;
+        Ok(Amount::from_micro_ccd(average as u64))
+    }
+}
+
+impl WeightStrategy for Quadratic {
+    fn weight(
+        &self,
+        balances: &[(chrono::DateTime<chrono::Utc>, Amount)],
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Amount> {
+        // An average of m micro CCD is m / 10^6 CCD, whose root sqrt(m / 10^6) CCD is
+        // sqrt(m * 10^6) micro CCD.
+        let average = time_weighted_average(balances, end)?;
+        let root = integer_sqrt(average * u128::from(MICRO_CCD_PER_CCD));
+        Ok(Amount::from_micro_ccd(root as u64))
+    }
+}
+
+impl WeightStrategy for Median {
+    fn weight(
+        &self,
+        balances: &[(chrono::DateTime<chrono::Utc>, Amount)],
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Amount> {
+        let Some(&(_, first)) = balances.first() else {
+            anyhow::bail!("A bug, there should always be at least one reading.");
+        };
+        // The duration each balance was held for.
+        let mut held: Vec<(Amount, u64)> = balances
+            .iter()
+            .zip(balances.iter().skip(1).map(|(dt, _)| *dt).chain([end]))
+            .map(|(&(start, balance), end)| {
+                let duration = end.signed_duration_since(start).num_milliseconds().max(0);
+                (balance, duration as u64)
+            })
+            .collect();
+        held.sort_by_key(|(balance, _)| *balance);
+        let total: u64 = held.iter().map(|(_, duration)| duration).sum();
+        let mut cumulative = 0;
+        let mut median = first;
+        for (balance, duration) in held {
+            cumulative += duration;
+            median = balance;
+            if 2 * cumulative >= total {
+                break;
+            }
+        }
+        Ok(median)
+    }
+}
+
+impl WeightStrategy for Min {
+    fn weight(
+        &self,
+        balances: &[(chrono::DateTime<chrono::Utc>, Amount)],
+        _end: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Amount> {
+        balances
+            .iter()
+            .map(|(_, balance)| *balance)
+            .min()
+            .context("A bug, there should always be at least one reading.")
+    }
+}
+
+/// The largest integer whose square does not exceed `n`.
+fn integer_sqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method, starting from an upper bound of the root.
+    let mut x = 1u128 << (128 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/// Handle collection of initial weights.
+async fn handle_initial_weights(
+    endpoint: sdk::Endpoint,
+    indexing: IndexingConfig,
+    args: InitialWeights,
+    out: std::path::PathBuf,
+    strategy: &dyn WeightStrategy,
+    show_progress: bool,
+) -> anyhow::Result<()> {
+    ensure!(out.is_dir(), "out argument must point to a directory");
+
+    let mut client = sdk::Client::new(endpoint.clone())
+        .await
+        .context("Unable to connect.")?;
+    check_protocol_version(client.get_consensus_info().await?.protocol_version)?;
+
+    let (start, end, registered_weights_hash) = match &args {
+        InitialWeights::Generate(gen) => (gen.start, gen.end, None),
+        InitialWeights::Verify { contract } => {
+            let mut contract_client = contract_client::ContractClient::<ElectionContract>::create(
+                client.clone(),
+                *contract,
+            )
+            .await?;
+            let config =
+                election_contract::view_config(&mut contract_client, BlockIdentifier::LastFinal)
+                    .await?;
+
+            let start = config.eligible_voters.parameters.start_time.try_into()?;
+            let end = config.eligible_voters.parameters.end_time.try_into()?;
+            let hash = config.eligible_voters.data.hash;
+            (start, end, Some(hash))
+        }
+    };
+
+    let (first_block, last_block) = range_setup(&mut client, start, end).await?;
+    let initial_block_ident: BlockIdentifier = first_block.block_height.into();
+    let initial_account_number = sdk::Client::new(indexing.endpoint.clone())
+        .await
+        .context("Unable to connect.")?
+        .get_account_list(initial_block_ident)
+        .await?
+        .response
+        .try_fold(0u64, |acc, _| async move { Ok(acc + 1) })
+        .await?;
+    let mut account_balances = vec![Vec::new(); initial_account_number as usize];
+    let mut account_addresses = Vec::with_capacity(initial_account_number as usize);
+    let bar = Progress::new(initial_account_number, show_progress)?;
+
+    eprintln!(
+        "Getting initial account balances in block {}.",
+        first_block.block_hash
+    );
+    for (ai, balances) in account_balances.iter_mut().enumerate() {
+        let info = client
+            .get_account_info(&AccountIndex::from(ai as u64).into(), initial_block_ident)
+            .await?;
+        account_addresses.push(info.response.account_address);
+        bar.set_message(info.response.account_address.to_string());
+        bar.inc(1);
+        balances.push((first_block.block_slot_time, info.response.account_amount));
+    }
+    bar.finish_and_clear();
+    drop(bar);
+    let bar = Progress::new(
+        last_block.block_height.height - first_block.block_height.height,
+        show_progress,
+    )?;
+
+    let traverse_config =
+        indexer::TraverseConfig::new_single(indexing.endpoint.clone(), first_block.block_height);
+    let (sender, receiver) = tokio::sync::mpsc::channel(20);
+    let cancel_handle = tokio::spawn(traverse_config.traverse(indexer::BlockEventsIndexer, sender));
+    let mut receiver = indexing.limit_blocks(receiver);
+    // Stop after the current block on interrupt, so that the weights of the
+    // blocks processed so far can be written.
+    let mut progress =
+        InterruptibleIndexing::new(first_block.block_height, first_block.block_slot_time);
+    let interrupt_handle = tokio::spawn(set_interrupted(progress.interrupted.clone()));
+    let mut interrupted_at = None;
+    while let Some((block, normal, specials)) = receiver.recv().await {
+        if block.block_slot_time > end {
+            drop(receiver);
+            eprintln!("Done indexing");
+            break;
+        }
+        if let Some(last_processed) = progress.interrupted_at() {
+            drop(receiver);
+            interrupted_at = Some(last_processed);
+            break;
+        }
+        bar.set_message(block.block_slot_time.to_string());
+        bar.inc(1);
+        let mut affected = BTreeSet::new();
+        for tx in normal {
+            for addr in tx.affected_addresses() {
+                affected.insert(AccountAddressEq::from(addr));
+            }
+        }
+        for special in specials {
+            for addr in special.affected_addresses() {
+                affected.insert(AccountAddressEq::from(addr));
+            }
+        }
+        let block_ident = BlockIdentifier::from(block.block_height);
+        for acc in affected {
+            let info = client
+                .get_account_info(&AccountAddress::from(acc).into(), block_ident)
+                .await?;
+            let index = info.response.account_index.index as usize;
+            if let Some(elem) = account_balances.get_mut(index) {
+                elem.push((block.block_slot_time, info.response.account_amount));
+            } else {
+                // Newly created accounts have balance 0 at the start of the period.
+                for idx in account_balances.len()..index {
+                    account_balances.push(vec![(first_block.block_slot_time, Amount::zero())]);
+                    let idx_acc = client
+                        .get_account_info(&AccountIndex::from(idx as u64).into(), block_ident)
+                        .await?;
+                    account_addresses.push(idx_acc.response.account_address);
+                }
+                account_balances.push(vec![
+                    (first_block.block_slot_time, Amount::zero()),
+                    (block.block_slot_time, info.response.account_amount),
+                ]);
+                account_addresses.push(info.response.account_address);
+            }
+        }
+        progress.processed(block.block_height, block.block_slot_time);
+    }
+    cancel_handle.abort();
+    interrupt_handle.abort();
+    bar.finish_and_clear();
+
+    anyhow::ensure!(
+        account_addresses.len() == account_balances.len(),
+        "Expecting addresses match account balances. This is a bug."
+    );
+
+    // If interrupted, the weights are computed as if the period ended with the
+    // last processed block.
+    let weights_end = interrupted_at.map_or(last_block.block_slot_time, |(_, time)| time);
+    let mut data = vec![];
+    {
+        let mut weights = csv::Writer::from_writer(&mut data);
+        for (balances, address) in account_balances.into_iter().zip(account_addresses) {
+            let amount = strategy.weight(&balances, weights_end)?;
+            weights.serialize(WeightRow {
+                account: address,
+                amount,
+            })?;
+        }
+        weights.flush()?;
+    }
+
+    if let Some(interrupted_at) = interrupted_at {
+        return Err(write_partial_initial_weights(
+            &out,
+            &data,
+            interrupted_at,
+            end,
+            last_block.block_height,
+        )?);
+    }
+
+    let weights_path = out.join("initial-weights.csv");
+    let mut weights_out =
+        std::fs::File::create(&weights_path).context("Failed to create weights file")?;
+    weights_out
+        .write(&data)
+        .context("Failed to write initial weights to file")?;
+    write_csv_metadata(&weights_path, &INITIAL_WEIGHTS_COLUMNS)?;
+
+    let mut params_out = std::fs::File::create(out.join("initial-weights-params.json"))
+        .context("Failed to create weight params file")?;
+    let params = contract::EligibleVotersParameters {
+        start_time: Timestamp::from_timestamp_millis(start.timestamp_millis() as u64),
+        end_time:   Timestamp::from_timestamp_millis(end.timestamp_millis() as u64),
+    };
+    params_out
+        .write(&serde_json::to_vec(&params).expect("Serialization of params will not fail"))
+        .context("Failed to write weight parameters to file")?;
+
+    // Only runs if the `verify` subcommand is run.
+    if let Some(registered_weights_hash) = registered_weights_hash {
+        let hash = contract::HashSha2256(sha2::Sha256::digest(&data).into());
+        ensure!(
+            hash == registered_weights_hash,
+            "The checksum registered in the contract does not match the computed hash: registered \
+             {}, computed {}",
+            &registered_weights_hash,
+            &hash
+        );
+        println!("Succesfully verified the weights registered in the contract.");
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, serde::Deserialize)]
+/// Metadata of a candidate. We only need the name in this tool so we only model
+/// that, and not the image and other parameters used by the frontend.
+struct CandidateMetadata {
+    name: String,
+}
+
+/// The full metadata of a candidate, as used by the frontend. This is also the
+/// format of the rows of the CSV file read by `candidates-from-csv`.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CandidateRow {
+    name:            String,
+    image_url:       String,
+    description_url: String,
+}
+
+/// Write a metadata file for each candidate in the input CSV file, and print
+/// the `--candidate` arguments for `new-election` referring to the files.
+fn handle_candidates_from_csv(
+    CandidatesFromCsvArgs {
+        input,
+        out,
+        base_url,
+    }: CandidatesFromCsvArgs,
+) -> anyhow::Result<()> {
+    let mut reader = csv::Reader::from_path(&input)
+        .with_context(|| format!("Unable to open {}.", input.display()))?;
+    let candidates = reader
+        .deserialize::<CandidateRow>()
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("Unable to read candidates from {}.", input.display()))?;
+    ensure!(
+        !candidates.is_empty(),
+        "No candidates found in {}.",
+        input.display()
+    );
+
+    let mut names = BTreeSet::new();
+    for candidate in &candidates {
+        ensure!(
+            !candidate.name.trim().is_empty(),
+            "Candidates must have a non-empty name."
+        );
+        ensure!(
+            names.insert(candidate.name.as_str()),
+            "Candidate {} appears more than once.",
+            candidate.name
+        );
+        for link in [&candidate.image_url, &candidate.description_url] {
+            url::Url::parse(link)
+                .with_context(|| format!("Invalid URL {link} for candidate {}.", candidate.name))?;
+        }
+    }
+
+    std::fs::create_dir_all(&out)
+        .with_context(|| format!("Unable to create directory {}.", out.display()))?;
+    for (i, candidate) in candidates.iter().enumerate() {
+        let file_name = format!("candidate{}.json", i + 1);
+        let path = out.join(&file_name);
+        let data = serde_json::to_vec_pretty(candidate)?;
+        std::fs::write(&path, &data)
+            .with_context(|| format!("Unable to write {}.", path.display()))?;
+
+        // This is the URL and checksum `new-election` records in the contract for
+        // a candidate file given by its path.
+        let url = base_url
+            .join(&format!("candidates/{file_name}"))
+            .context("Failed to construct URL")?;
+        let hash = contract::HashSha2256(sha2::Sha256::digest(&data).into());
+        eprintln!(
+            "Candidate {} is served at {url} with checksum {hash}.",
+            candidate.name
+        );
+        println!("--candidate '{}' \\", path.display());
+    }
+    eprintln!(
+        "Wrote {} candidate files to {}. These must be served at {} for the election.",
+        candidates.len(),
+        out.display(),
+        base_url
+            .join("candidates/")
+            .context("Failed to construct URL")?
+    );
+    Ok(())
+}
+
+/// Read the metadata of a candidate from its location.
+async fn read_candidate_data(candidate: &CandidateLocation) -> anyhow::Result<Vec<u8>> {
+    match candidate {
+        CandidateLocation::Remote(url) => {
+            let r = reqwest::get(url.clone())
+                .await
+                .context("Unable to get data for candidate.")?;
+
+            anyhow::ensure!(r.status().is_success(), "Unable to get data for candidate.");
+            Ok(r.bytes().await?.into())
+        }
+        CandidateLocation::Disk(path) => {
+            std::fs::read(path).context("Unable to read candidate file.")
+        }
+    }
+}
+
+/// Check that the delegation string can be used in memos of transfers, in the
+/// same way as it is detected by the `final-weights` command. The string is
+/// CBOR encoded in memos, so the encoding must fit within the maximum memo size
+/// and decode to the same string.
+fn validate_delegation_string(delegation_string: &str) -> anyhow::Result<()> {
+    ensure!(
+        !delegation_string.is_empty(),
+        "The delegation string must not be empty."
+    );
+    ensure!(
+        !delegation_string.chars().any(char::is_control),
+        "The delegation string must not contain control characters."
+    );
+    let encoded = serde_cbor::to_vec(delegation_string)
+        .context("Unable to CBOR encode the delegation string.")?;
+    let decoded = serde_cbor::from_slice::<String>(&encoded)
+        .context("Unable to decode the CBOR encoded delegation string.")?;
+    ensure!(
+        decoded == delegation_string,
+        "The delegation string does not round-trip through CBOR encoding."
+    );
+    let encoded_len = encoded.len();
+    ensure!(
+        transactions::Memo::try_from(encoded).is_ok(),
+        "The CBOR encoded delegation string is {encoded_len} bytes, which exceeds the maximum \
+         memo size of {} bytes.",
+        transactions::MAX_MEMO_SIZE
+    );
+    Ok(())
+}
+
+/// Create a new election instance.
+async fn handle_new_election(endpoint: sdk::Endpoint, app: NewElectionArgs) -> anyhow::Result<()> {
+    validate_delegation_string(&app.delegation_string)?;
+    let max_description_length = app
+        .max_description_length
+        .unwrap_or(contract::DEFAULT_MAX_DESCRIPTION_LENGTH);
+    ensure!(
+        max_description_length <= contract::MAX_DESCRIPTION_LENGTH,
+        "The maximum length of the description can be at most {} bytes.",
+        contract::MAX_DESCRIPTION_LENGTH
+    );
+    ensure!(
+        contract::check_election_description(&app.election_description, app.max_description_length)
+            .is_ok(),
+        "The description must be a non-empty title of at most {max_description_length} bytes \
+         without control characters."
+    );
+
+    let mut client = sdk::Client::new(endpoint).await?;
+
+    let wallet = WalletAccount::from_json_file(app.admin)?;
+    check_accounts_exist(&mut client, wallet.address, &app.guardians).await?;
+
+    let wasm_module = WasmModule::from_file(&app.module).context("Unable to read module.")?;
+    let module_ref = ensure_module_deployed(&mut client, &wallet, wasm_module).await?;
+
+    let url = &app.base_url;
+    let make_url = move |path: String| -> anyhow::Result<String> {
+        let url = url.clone();
+        let url = url.join(&path).context("Failed to construct URL")?;
+        Ok(url.to_string())
+    };
+
+    let layout = app.layout;
+    std::fs::create_dir_all(&app.out)
+        .with_context(|| format!("Unable to create {}.", app.out.display()))?;
+
+    anyhow::ensure!(
+        !app.guardians.is_empty(),
+        "The set of guardians must have at least one address."
+    );
+
+    anyhow::ensure!(
+        !app.candidates.is_empty(),
+        "There must be at least one candidate."
+    );
+
+    // Construct the manifest and candidates.
+    let (options, candidates) = {
+        let mut candidates = Vec::with_capacity(app.candidates.len());
+        let mut options = Vec::with_capacity(app.candidates.len());
+        for candidate in app.candidates {
+            let data = read_candidate_data(&candidate).await?;
+            let candidate_url = match candidate {
+                CandidateLocation::Remote(url) => url.to_string(),
+                CandidateLocation::Disk(path) => {
+                    let candidate_file = path
+                        .file_name()
+                        .and_then(OsStr::to_str)
+                        .with_context(|| format!("Invalid filename for path {:?}", &path))?;
+                    let candidate_path = layout.candidate_path(candidate_file);
+                    if layout.copy_inputs() {
+                        write_output(&app.out, &candidate_path, &data)?;
+                    }
+                    make_url(candidate_path)?
+                }
+            };
+
+            let hash = contract::HashSha2256(sha2::Sha256::digest(&data).into());
+            candidates.push(contract::ChecksumUrl {
+                url: candidate_url,
+                hash,
+            });
+            let candidate_meta = serde_json::from_slice::<CandidateMetadata>(&data)
+                .context("Unable to parse guardian's information")?;
+            options.push(eg::election_manifest::ContestOption {
+                label: candidate_meta.name,
+            });
+        }
+        (options, candidates)
+    };
+
+    let manifest_hash = {
+        let contest = eg::election_manifest::Contest {
+            label:           "Governance committee member".into(),
+            selection_limit: options.len(),
+            options:         options.try_into()?,
+        };
+
+        let manifest = eg::election_manifest::ElectionManifest {
+            label:         "Governance commitee election 2024 election manifest".into(),
+            contests:      [contest].try_into()?,
+            ballot_styles: [eg::ballot_style::BallotStyle {
+                label:    "Governance committee vote".into(),
+                contests: [ContestIndex::from_one_based_index_const(1).unwrap()].into(),
+            }]
+            .try_into()?,
+        };
+        let manifest_json = serde_json::to_vec_pretty(&manifest)?;
+        let digest: [u8; 32] = sha2::Sha256::digest(&manifest_json).into();
+        write_output(&app.out, &layout.manifest_path(), &manifest_json)?;
+        contract::HashSha2256(digest)
+    };
+
+    let parameters_hash = {
+        let n = GuardianIndex::from_one_based_index(app.guardians.len().try_into()?)
+            .context("Need at least one guardian.")?;
+
+        let k = GuardianIndex::from_one_based_index_const(app.threshold)
+            .context("Threshold must be at least 1.")?;
+        anyhow::ensure!(
+            k <= n,
+            "Threshold must be less than total number of guardians."
+        );
+
+        let parameters = eg::election_parameters::ElectionParameters {
+            fixed_parameters:   eg::standard_parameters::STANDARD_PARAMETERS.clone(),
+            varying_parameters: eg::varying_parameters::VaryingParameters {
+                n,
+                k,
+                date: chrono::Utc::now(),
+                info: format!(
+                    "Governance committee election from {} to {} with {k} out of {n} threshold.",
+                    app.election_start, app.election_end
+                ),
+                ballot_chaining: eg::varying_parameters::BallotChaining::Prohibited,
+            },
+        };
+        let parameters_json = serde_json::to_vec_pretty(&parameters)?;
+        let digest: [u8; 32] = sha2::Sha256::digest(&parameters_json).into();
+        write_output(&app.out, &layout.parameters_path(), &parameters_json)?;
+        eprintln!(
+            "The fixed parameters of the election have version {}. Supply this with \
+             --parameters-version when tallying and decrypting to pin the parameters.",
+            fixed_parameters_version(&parameters.fixed_parameters)?
+        );
+        contract::HashSha2256(digest)
+    };
+
+    let eligible_voters_filename = app
+        .voters_file
+        .file_name()
+        .context("voters-file must be a path to a file")?
+        .to_str()
+        .context("voters-file path is not valid unicode")?
+        .to_string();
+
+    let eligible_voters_hash = {
+        let data = std::fs::read(&app.voters_file).context("Unable to read voters file.")?;
+        if layout.copy_inputs() {
+            write_output(
+                &app.out,
+                &layout.eligible_voters_path(&eligible_voters_filename),
+                &data,
+            )?;
+        }
+        contract::HashSha2256(sha2::Sha256::digest(data).into())
+    };
+
+    let voters_params_file = std::fs::File::open(app.voters_params_file)
+        .context("Failed to open `voters-params-file`.")?;
+    let voters_params: contract::EligibleVotersParameters =
+        serde_json::from_reader(&voters_params_file)
+            .context("Failed to deserialize voters params")?;
+
+    let init_param = contract::InitParameter {
+        admin_account: wallet.address,
+        candidates,
+        guardians: app.guardians,
+        eligible_voters: contract::EligibleVoters {
+            parameters: voters_params,
+            data:       contract::ChecksumUrl {
+                url:  make_url(layout.eligible_voters_path(&eligible_voters_filename))?,
+                hash: eligible_voters_hash,
+            },
+        },
+        election_manifest: contract::ChecksumUrl {
+            url:  make_url(layout.manifest_path())?,
+            hash: manifest_hash,
+        },
+        election_parameters: contract::ChecksumUrl {
+            url:  make_url(layout.parameters_path())?,
+            hash: parameters_hash,
+        },
+        election_description: app.election_description,
+        election_start: app.election_start.try_into()?,
+        election_end: app.election_end.try_into()?,
+        decryption_deadline: app.decryption_deadline.try_into()?,
+        delegation_string: app.delegation_string,
+        result_posting_deadline: app
+            .result_posting_deadline
+            .map(TryInto::try_into)
+            .transpose()?,
+        approval_threshold: app
+            .approval_threshold
+            .unwrap_or(app.result_approvers.len().try_into()?),
+        result_approvers: app.result_approvers,
+        eg_version: ELECTIONGUARD_VERSION.into(),
+        display_order: app.display_order,
+        decryption_grace_seconds: app.decryption_grace_seconds,
+        threshold: app.threshold,
+        contest_sizes: Vec::new(),
+        max_description_length: app.max_description_length,
+    };
+
+    let param = concordium_std::OwnedParameter::from_serial(&init_param)?; // Example
+    let param_json = contract::InitParameter::get_type()
+        .to_json_string_pretty(&concordium_std::to_bytes(&init_param))?;
+    eprintln!("JSON parameter that will be used to initialize the contract.");
+    println!("{}", param_json);
+
+    let confirm = dialoguer::Confirm::new()
+        .report(true)
+        .wait_for_newline(true)
+        .with_prompt("Do you want to initialize the contract?")
+        .interact()?;
+    anyhow::ensure!(confirm, "Aborting.");
+
+    init_election_contract(&mut client, &wallet, module_ref, param).await?;
+    Ok(())
+}
+
+/// Check that the guardian accounts `guardians` are distinct and exist on
+/// chain.
+async fn handle_check_guardians(
+    endpoint: sdk::Endpoint,
+    guardians: Vec<AccountAddress>,
+) -> anyhow::Result<()> {
+    let mut client = sdk::Client::new(endpoint)
+        .await
+        .context("Unable to connect to the node.")?;
+    check_guardian_accounts(&mut client, &guardians).await?;
+    eprintln!(
+        "All {} guardian accounts are distinct and exist.",
+        guardians.len()
+    );
+    Ok(())
+}
+
+/// Check that the admin account exists and holds CCD to pay for setting up the
+/// election, and that the guardian accounts are valid, see
+/// [`check_guardian_accounts`]. This catches keys or accounts from a different
+/// network than the one of the node early, before any transactions are sent.
+async fn check_accounts_exist(
+    client: &mut sdk::Client,
+    admin: AccountAddress,
+    guardians: &[AccountAddress],
+) -> anyhow::Result<()> {
+    check_admin_balance(admin, account_balance(client, admin).await?)?;
+    check_guardian_accounts(client, guardians).await
+}
+
+/// The balance of `account` in the last finalized block, or `None` if the
+/// account does not exist.
+async fn account_balance(
+    client: &mut sdk::Client,
+    account: AccountAddress,
+) -> anyhow::Result<Option<Amount>> {
+    match client
+        .get_account_info(&account.into(), BlockIdentifier::LastFinal)
+        .await
+    {
+        Ok(info) => Ok(Some(info.response.account_amount)),
+        Err(e) if e.is_not_found() => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("Unable to query the account {account}.")),
+    }
+}
+
+/// Check that the admin account `admin` exists and holds CCD, given its
+/// `balance` as returned by [`account_balance`].
+fn check_admin_balance(admin: AccountAddress, balance: Option<Amount>) -> anyhow::Result<()> {
+    let Some(balance) = balance else {
+        anyhow::bail!(
+            "The admin account {admin} does not exist. Check that the node is on the intended \
+             network."
+        );
+    };
+    eprintln!("The admin account {admin} exists with a balance of {balance}.");
+    ensure!(
+        balance > Amount::zero(),
+        "The admin account {admin} has no CCD to pay for setting up the election."
+    );
+    Ok(())
+}
+
+/// Check that no guardian account appears more than once in `guardians`,
+/// including as an alias of another, and that all of them exist on chain. All
+/// problems found are reported together, so they can be fixed at once.
+async fn check_guardian_accounts(
+    client: &mut sdk::Client,
+    guardians: &[AccountAddress],
+) -> anyhow::Result<()> {
+    let mut existing = BTreeSet::new();
+    for guardian in guardians
+        .iter()
+        .map(|guardian| AccountAddressEq::from(*guardian))
+        .collect::<BTreeSet<_>>()
+    {
+        if account_balance(client, guardian.into()).await?.is_some() {
+            existing.insert(guardian);
+        }
+    }
+    let problems = guardian_account_problems(guardians, &existing);
+    if !problems.is_empty() {
+        for problem in &problems {
+            eprintln!("{problem}");
+        }
+        anyhow::bail!(
+            "Found {} problem(s) with the guardian accounts.",
+            problems.len()
+        );
+    }
+    Ok(())
+}
+
+/// The problems with the guardian accounts `guardians`, given the accounts
+/// that exist on chain in `existing`: accounts listed more than once, either
+/// directly or as aliases, and accounts that do not exist.
+fn guardian_account_problems(
+    guardians: &[AccountAddress],
+    existing: &BTreeSet<AccountAddressEq>,
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mut seen = BTreeMap::new();
+    for guardian in guardians {
+        let account = AccountAddressEq::from(*guardian);
+        if let Some(previous) = seen.insert(account, guardian) {
+            if previous == guardian {
+                problems.push(format!(
+                    "The guardian account {guardian} is listed more than once."
+                ));
+            } else {
+                problems.push(format!(
+                    "The guardian accounts {previous} and {guardian} are aliases of the same \
+                     account."
+                ));
+            }
+        } else if !existing.contains(&account) {
+            problems.push(format!("The guardian account {guardian} does not exist."));
+        }
+    }
+    problems
+}
+
+/// Deploy the module unless it already exists on chain. Returns the reference
+/// of the module.
+async fn ensure_module_deployed(
+    client: &mut sdk::Client,
+    wallet: &WalletAccount,
+    wasm_module: WasmModule,
+) -> anyhow::Result<ModuleReference> {
+    let module_ref = wasm_module.get_module_ref();
+    let existing_module = client
+        .get_module_source(&module_ref, BlockIdentifier::LastFinal)
+        .await;
+    match existing_module {
+        Ok(_) => {
+            eprintln!("Module {module_ref} already exists.");
+        }
+        Err(e) if e.is_not_found() => {
+            let nonce = client
+                .get_next_account_sequence_number(&wallet.address)
+                .await?;
+            let tx = transactions::send::deploy_module(
+                wallet,
+                wallet.address,
+                nonce.nonce,
+                TransactionTime::hours_after(1),
+                wasm_module,
+            );
+            let hash = client.send_account_transaction(tx).await?;
+            let (block_hash, result) = client
+                .wait_until_finalized(&hash)
+                .await
+                .context("Module deployment failed.")?;
+            anyhow::ensure!(result.is_success(), "Transaction failed {result:#?}");
+            eprintln!("Module {module_ref} deployed in block {block_hash}");
+        }
+        Err(err) => anyhow::bail!("Could not inspect module status: {err}"),
+    }
+    Ok(module_ref)
+}
+
+/// Initialize a new election contract instance from the module `module_ref`
+/// with the serialized [`contract::InitParameter`] `param`. Returns the
+/// address of the new instance.
+async fn init_election_contract(
+    client: &mut sdk::Client,
+    wallet: &WalletAccount,
+    module_ref: ModuleReference,
+    param: concordium_std::OwnedParameter,
+) -> anyhow::Result<ContractAddress> {
+    let payload = transactions::InitContractPayload {
+        init_name: OwnedContractName::new("init_election".into())?,
+        amount: Amount::from_micro_ccd(0),
+        mod_ref: module_ref,
+        param,
+    };
+
+    let nonce = client
+        .get_next_account_sequence_number(&wallet.address)
+        .await?;
+
+    let at = transactions::send::init_contract(
+        wallet,
+        wallet.address,
+        nonce.nonce,
+        TransactionTime::hours_after(1),
+        payload,
+        20_000.into(),
+    );
+    let tx_hash = client.send_account_transaction(at).await?;
+    eprintln!("Submitted transaction with hash {tx_hash}.");
+    let (_, result) = client
+        .wait_until_finalized(&tx_hash)
+        .await
+        .context("Failed to initialize contract instance.")?;
+    let result = result
+        .contract_init()
+        .context("Unexpected response from transaction.")?;
+
+    eprintln!(
+        "Deployed new contract instance with address {} using transaction hash {}.",
+        result.address, tx_hash
+    );
+    Ok(result.address)
+}
+
+/// Cross-check the election manifest and parameters with each other and with
+/// the candidates and guardians of the election. Returns a description of each
+/// inconsistency found.
+fn check_election_files(
+    manifest: &ElectionManifest,
+    parameters: &ElectionParameters,
+    candidate_names: &[String],
+    guardians: &[AccountAddress],
+    threshold: Option<u32>,
+) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    let unique_guardians: BTreeSet<_> = guardians.iter().collect();
+    if unique_guardians.len() != guardians.len() {
+        problems.push("The same guardian account is given more than once.".to_string());
+    }
+
+    let n = &parameters.varying_parameters.n;
+    let k = &parameters.varying_parameters.k;
+    match u32::try_from(guardians.len())
+        .ok()
+        .and_then(|len| GuardianIndex::from_one_based_index(len).ok())
+    {
+        Some(expected_n) if &expected_n != n => problems.push(format!(
+            "The parameters are for {n} guardians, but {} guardians are given.",
+            guardians.len()
+        )),
+        Some(_) => (),
+        None => problems.push("At least one guardian must be given.".to_string()),
+    }
+    if k > n {
+        problems.push(format!(
+            "The threshold {k} of the parameters exceeds the number of guardians {n}."
+        ));
+    }
+    if let Some(threshold) = threshold {
+        if GuardianIndex::from_one_based_index(threshold).ok().as_ref() != Some(k) {
+            problems.push(format!(
+                "The parameters have threshold {k}, but threshold {threshold} is given."
+            ));
+        }
+    }
+    let is_standard = match (
+        fixed_parameters_version(&parameters.fixed_parameters),
+        standard_parameters_version(),
+    ) {
+        (Ok(fixed), Ok(standard)) => fixed == standard,
+        _ => false,
+    };
+    if !is_standard {
+        problems.push("The fixed parameters are not the standard parameters.".to_string());
+    }
+
+    let contests: Vec<_> = manifest.contests.indices().collect();
+    if contests.len() != 1 {
+        problems.push(format!(
+            "The manifest has {} contests, but exactly one is supported.",
+            contests.len()
+        ));
+    }
+    for contest_index in contests {
+        let Some(contest) = manifest.contests.get(contest_index) else {
+            continue;
+        };
+        let labels: Vec<_> = contest
+            .options
+            .indices()
+            .filter_map(|i| contest.options.get(i))
+            .map(|option| option.label.as_str())
+            .collect();
+        if labels.len() != candidate_names.len() {
+            problems.push(format!(
+                "Contest \"{}\" has {} options, but {} candidates are given.",
+                contest.label,
+                labels.len(),
+                candidate_names.len()
+            ));
+        } else if labels.iter().zip(candidate_names).any(|(l, c)| l != c) {
+            problems.push(format!(
+                "The options of contest \"{}\" do not match the names of the candidates in order.",
+                contest.label
+            ));
+        }
+    }
+    for style_index in manifest.ballot_styles.indices() {
+        let Some(style) = manifest.ballot_styles.get(style_index) else {
+            continue;
+        };
+        if !style
+            .contests
+            .iter()
+            .all(|c| manifest.contests.get(*c).is_some())
+        {
+            problems.push(format!(
+                "Ballot style \"{}\" refers to contests not in the manifest.",
+                style.label
+            ));
+        }
+    }
+
+    problems
+}
+
+/// Load the election manifest, parameters and candidates, and report any
+/// inconsistencies between them and the given guardians.
+async fn handle_validate_election_files(
+    ValidateElectionFilesArgs {
+        manifest,
+        parameters,
+        candidates,
+        guardians,
+        threshold,
+    }: ValidateElectionFilesArgs,
+) -> anyhow::Result<()> {
+    let manifest: ElectionManifest = serde_json::from_slice(
+        &std::fs::read(&manifest).context("Unable to read election manifest.")?,
+    )
+    .context("Unable to parse election manifest.")?;
+    let parameters: ElectionParameters = serde_json::from_slice(
+        &std::fs::read(&parameters).context("Unable to read election parameters.")?,
+    )
+    .context("Unable to parse election parameters.")?;
+    let mut candidate_names = Vec::with_capacity(candidates.len());
+    for candidate in &candidates {
+        let data = read_candidate_data(candidate).await?;
+        let candidate_meta = serde_json::from_slice::<CandidateMetadata>(&data)
+            .with_context(|| format!("Unable to parse candidate metadata from {candidate:?}."))?;
+        candidate_names.push(candidate_meta.name);
+    }
+
+    let problems = check_election_files(
+        &manifest,
+        &parameters,
+        &candidate_names,
+        &guardians,
+        threshold,
+    );
+    for problem in &problems {
+        eprintln!("{problem}");
+    }
+    anyhow::ensure!(
+        problems.is_empty(),
+        "Found {} inconsistencies in the election files.",
+        problems.len()
+    );
+    eprintln!("The election files are consistent.");
+    Ok(())
+}
+
+/// The size in bytes of a serialized element of the group used by the standard
+/// election parameters.
+const GROUP_ELEMENT_SIZE: u64 = 512;
+/// The size in bytes of a serialized element of the field used by the standard
+/// election parameters.
+const FIELD_ELEMENT_SIZE: u64 = 32;
+/// The energy charged for the header and signature of a transaction.
+const TRANSACTION_BASE_ENERGY: u64 = 300;
+/// The energy charged per byte of contract update parameters. This covers the
+/// size of the transaction as well as executing the update and storing the
+/// parameter in the contract state.
+const UPDATE_ENERGY_PER_BYTE: u64 = 2;
+/// The energy needed to initialize the contract, as used by `new-election`.
+const INIT_ENERGY: u64 = 20_000;
+
+/// The estimated cost of a kind of transaction needed for an election.
+struct CostEstimate {
+    /// A description of the transaction.
+    operation:   &'static str,
+    /// Who is paying for the transaction.
+    paid_by:     &'static str,
+    /// The number of transactions of this kind.
+    count:       u64,
+    /// The estimated energy of a single transaction.
+    energy_each: u64,
+}
+
+impl CostEstimate {
+    /// A contract update with a parameter of `size` bytes.
+    fn update(operation: &'static str, paid_by: &'static str, count: u64, size: u64) -> Self {
+        Self {
+            operation,
+            paid_by,
+            count,
+            energy_each: TRANSACTION_BASE_ENERGY + UPDATE_ENERGY_PER_BYTE * size,
+        }
+    }
+
+    fn total_energy(&self) -> u64 { self.count * self.energy_each }
+}
+
+/// Model the transactions needed for an election with `n` guardians, threshold
+/// `k`, `m` candidates and the given number of ballots. The sizes of the data
+/// registered are approximated from the sizes of the cryptographic objects
+/// under the standard election parameters, so the result is an estimate.
+fn model_election_cost(
+    n: u64,
+    k: u64,
+    m: u64,
+    ballots: u64,
+    module_size: Option<u64>,
+) -> Vec<CostEstimate> {
+    let mut estimates = Vec::new();
+    if let Some(size) = module_size {
+        // Deployment is charged per byte of the transaction, and additionally
+        // per 10 bytes of the module.
+        estimates.push(CostEstimate {
+            operation:   "Deploy module",
+            paid_by:     "admin",
+            count:       1,
+            energy_each: TRANSACTION_BASE_ENERGY + size + size / 10,
+        });
+    }
+    estimates.push(CostEstimate {
+        operation:   "Initialize contract",
+        paid_by:     "admin",
+        count:       1,
+        energy_each: INIT_ENERGY,
+    });
+    // A commitment and a proof of knowledge for each coefficient of the
+    // guardian's polynomial.
+    let public_key_size = k * (GROUP_ELEMENT_SIZE + 2 * FIELD_ELEMENT_SIZE);
+    estimates.push(CostEstimate::update(
+        "Register public key",
+        "guardian",
+        n,
+        public_key_size,
+    ));
+    // An encrypted share for each of the other guardians.
+    let encrypted_shares_size = n.saturating_sub(1) * (GROUP_ELEMENT_SIZE + 3 * FIELD_ELEMENT_SIZE);
+    estimates.push(CostEstimate::update(
+        "Register encrypted shares",
+        "guardian",
+        n,
+        encrypted_shares_size,
+    ));
+    // At most a list of account addresses of the other guardians.
+    estimates.push(CostEstimate::update(
+        "Register status",
+        "guardian",
+        n,
+        n * 32,
+    ));
+    // A ciphertext and a range proof for each candidate, and a proof for the
+    // selection limit of the contest.
+    let ballot_size =
+        m * (2 * GROUP_ELEMENT_SIZE + 4 * FIELD_ELEMENT_SIZE) + 2 * FIELD_ELEMENT_SIZE * (m + 1);
+    estimates.push(CostEstimate::update(
+        "Submit ballot",
+        "voter",
+        ballots,
+        ballot_size,
+    ));
+    // A ciphertext for each candidate.
+    estimates.push(CostEstimate::update(
+        "Post encrypted tally",
+        "admin",
+        1,
+        m * 2 * GROUP_ELEMENT_SIZE,
+    ));
+    // A decryption share and a commitment for each candidate.
+    estimates.push(CostEstimate::update(
+        "Register decryption share",
+        "guardian",
+        n,
+        m * 3 * GROUP_ELEMENT_SIZE,
+    ));
+    // A response for each candidate.
+    estimates.push(CostEstimate::update(
+        "Register decryption proof",
+        "guardian",
+        n,
+        m * FIELD_ELEMENT_SIZE,
+    ));
+    // A vote count for each candidate.
+    estimates.push(CostEstimate::update(
+        "Post election result",
+        "admin",
+        1,
+        m * 8,
+    ));
+    estimates
+}
+
+/// Print an estimate of the cost of running an election, converting energy to
+/// CCD using the chain parameters of the last finalized block.
+async fn handle_estimate_cost(
+    endpoint: sdk::Endpoint,
+    args: EstimateCostArgs,
+) -> anyhow::Result<()> {
+    ensure!(args.guardians > 0, "There must be at least one guardian.");
+    ensure!(args.candidates > 0, "There must be at least one candidate.");
+    let threshold = args.threshold.unwrap_or(args.guardians);
+    ensure!(
+        (1..=args.guardians).contains(&threshold),
+        "Threshold must be between 1 and the number of guardians."
+    );
+    let module_size = args
+        .module
+        .map(|path| -> anyhow::Result<u64> {
+            let wasm_module = WasmModule::from_file(&path).context("Unable to read module.")?;
+            Ok(wasm_module.source.size())
+        })
+        .transpose()?;
+
+    let estimates = model_election_cost(
+        args.guardians.into(),
+        threshold.into(),
+        args.candidates.into(),
+        args.ballots,
+        module_size,
+    );
+
+    let mut client = sdk::Client::new(endpoint).await?;
+    let chain_parameters = client
+        .get_block_chain_parameters(BlockIdentifier::LastFinal)
+        .await?
+        .response;
+
+    println!(
+        "{:<28} {:<9} {:>8} {:>14} {:>16}",
+        "Operation", "Paid by", "Count", "Energy each", "Total CCD"
+    );
+    let mut totals: BTreeMap<&str, Amount> = BTreeMap::new();
+    for estimate in &estimates {
+        let cost = chain_parameters.ccd_cost(estimate.total_energy().into());
+        *totals.entry(estimate.paid_by).or_insert_with(Amount::zero) += cost;
+        println!(
+            "{:<28} {:<9} {:>8} {:>14} {:>16}",
+            estimate.operation, estimate.paid_by, estimate.count, estimate.energy_each, cost
+        );
+    }
+    println!();
+    for (paid_by, total) in totals {
+        println!("Total paid by {paid_by}: {total}");
+    }
+    if args.guardians > 1 {
+        eprintln!("The costs paid by guardians are shared between them.");
+    }
+    eprintln!(
+        "These are estimates based on the approximate sizes of the data registered. The actual \
+         cost depends on the data and the energy rate at the time of the transactions."
+    );
+    Ok(())
+}
+
+/// Send an update of `entrypoint` of the election contract with `param` from
+/// `wallet`, and wait for the transaction to be finalized.
+async fn update_and_wait<P: concordium_std::Serial>(
+    contract_client: &mut contract_client::ContractClient<ElectionContract>,
+    wallet: &WalletAccount,
+    entrypoint: &str,
+    param: &P,
+) -> anyhow::Result<()> {
+    let dry_run = contract_client
+        .dry_run_update::<_, ViewError>(entrypoint, Amount::zero(), wallet.address, param)
+        .await
+        .with_context(|| format!("Failed to dry run {entrypoint}."))?;
+    let handle = dry_run.send(wallet).await?;
+    if let Err(e) = handle.wait_for_finalization().await {
+        anyhow::bail!("Transaction updating {entrypoint} failed with {e:#?}");
+    }
+    Ok(())
+}
+
+/// Sleep until `time`, logging what is waited for.
+async fn wait_until(time: chrono::DateTime<chrono::Utc>, what: &str) {
+    if let Ok(duration) = (time - chrono::Utc::now()).to_std() {
+        eprintln!("Waiting {} seconds for {what}.", duration.as_secs());
+        tokio::time::sleep(duration).await;
+    }
+}
+
+/// The selections of the voter with index `voter` in the self-test, derived
+/// from the `seed`.
+fn self_test_selections(seed: &str, voter: usize, candidates: usize) -> Vec<u8> {
+    (0..candidates)
+        .map(|candidate| {
+            let digest = sha2::Sha256::new()
+                .chain_update(seed)
+                .chain_update(b"selection")
+                .chain_update((voter as u64).to_be_bytes())
+                .chain_update((candidate as u64).to_be_bytes())
+                .finalize();
+            digest[0] & 1
+        })
+        .collect()
+}
+
+/// The ballot of the voter with index `voter` in the self-test, casting the
+/// given `selections` in the only contest of the election.
+fn self_test_ballot(
+    csprng: &mut util::csprng::Csprng,
+    device: &Device,
+    seed: &str,
+    voter: usize,
+    selections: Vec<u8>,
+) -> anyhow::Result<BallotEncrypted> {
+    let primary_nonce: [u8; 32] = sha2::Sha256::new()
+        .chain_update(seed)
+        .chain_update(b"nonce")
+        .chain_update((voter as u64).to_be_bytes())
+        .finalize()
+        .into();
+    let contest = ContestIndex::from_one_based_index(1)?;
+    let selections = ContestSelection::new(selections).context("Invalid selections.")?;
+    BallotEncrypted::new_from_selections(
+        BallotStyleIndex::from_one_based_index_unchecked(1),
+        device,
+        csprng,
+        &primary_nonce,
+        &[(contest, selections)].into(),
+    )
+    .context("Unable to construct ballot.")
+}
+
+/// The decryption share of `encrypted_tally` of the guardian with
+/// `secret_share` in the self-test, together with the states needed for
+/// computing the guardian's share of the proof of correct decryption.
+fn self_test_decryption_share(
+    csprng: &mut util::csprng::Csprng,
+    parameters: &ElectionParameters,
+    secret_share: &GuardianSecretKeyShare,
+    encrypted_tally: &EncryptedTally,
+) -> (GuardianDecryption, GuardianDecryptionProofState) {
+    let mut decryption = GuardianDecryption::new();
+    let mut states = GuardianDecryptionProofState::new();
+    for (&contest, ciphertexts) in encrypted_tally {
+        let mut contest_shares = Vec::with_capacity(ciphertexts.len());
+        let mut contest_states = Vec::with_capacity(ciphertexts.len());
+        for ciphertext in ciphertexts {
+            let share =
+                DecryptionShare::from(&parameters.fixed_parameters, secret_share, ciphertext);
+            let (proof_commit, state) = DecryptionProof::generate_commit_share(
+                csprng,
+                &parameters.fixed_parameters,
+                ciphertext,
+                &share.i,
+            );
+            contest_shares.push(DecryptionShareResult {
+                share,
+                proof_commit,
+            });
+            contest_states.push(state);
+        }
+        decryption.insert(contest, contest_shares);
+        states.insert(contest, contest_states);
+    }
+    (decryption, states)
+}
+
+/// The share of the proof of correct decryption of `encrypted_tally` of the
+/// guardian with `secret_share` in the self-test, given the decryption shares
+/// `decryptions` of all guardians and the `states` of the guardian.
+fn self_test_proof_response(
+    context: &PreVotingData,
+    secret_share: &GuardianSecretKeyShare,
+    encrypted_tally: &EncryptedTally,
+    decryptions: &[GuardianDecryption],
+    states: &GuardianDecryptionProofState,
+) -> anyhow::Result<GuardianDecryptionProof> {
+    let parameters = &context.parameters;
+    let mut proof = GuardianDecryptionProof::new();
+    for (contest, contest_states) in states {
+        let ciphertexts = encrypted_tally
+            .get(contest)
+            .context("Contest not found in the encrypted tally.")?;
+        let mut responses = Vec::with_capacity(ciphertexts.len());
+        for (i, (ciphertext, state)) in ciphertexts.iter().zip(contest_states).enumerate() {
+            let mut commit_shares = Vec::with_capacity(decryptions.len());
+            let mut decryption_shares = Vec::with_capacity(decryptions.len());
+            for decryption in decryptions {
+                let result = decryption
+                    .get(contest)
+                    .and_then(|results| results.get(i))
+                    .context("Decryption share is missing a ciphertext.")?;
+                commit_shares.push(result.proof_commit.clone());
+                decryption_shares.push(result.share.clone());
+            }
+            let combined_decryption =
+                CombinedDecryptionShare::combine(parameters, decryption_shares.iter())?;
+            responses.push(DecryptionProof::generate_response_share(
+                &parameters.fixed_parameters,
+                &context.hashes_ext,
+                &context.public_key,
+                ciphertext,
+                &combined_decryption,
+                &commit_shares,
+                state,
+                secret_share,
+            )?);
+        }
+        proof.insert(*contest, responses);
+    }
+    Ok(proof)
+}
+
+/// Run a complete election and check that the decrypted result matches the
+/// votes cast. The guardians and voters are simulated with the accounts in
+/// `args.keys`, while the encrypted tally and the result are computed and
+/// registered in the same way as by the `tally` and `final-result` commands.
+async fn handle_self_test(
+    endpoint: sdk::Endpoint,
+    indexing: IndexingConfig,
+    args: SelfTestArgs,
+    show_progress: bool,
+) -> anyhow::Result<()> {
+    ensure!(args.candidates > 0, "There must be at least one candidate.");
+    ensure!(
+        (1..=args.guardians).contains(&args.threshold),
+        "The threshold must be between 1 and the number of guardians."
+    );
+    ensure!(
+        args.setup_minutes > 0 && args.election_minutes > 0 && args.decryption_minutes > 0,
+        "The durations of the phases of the election must be positive."
+    );
+
+    let mut key_files = std::fs::read_dir(&args.keys)
+        .with_context(|| format!("Unable to read {}.", args.keys.display()))?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    key_files.sort();
+    ensure!(
+        key_files.len() > args.guardians as usize,
+        "Need the keys of {} accounts, the admin and {} guardians, but found {}.",
+        args.guardians + 1,
+        args.guardians,
+        key_files.len()
+    );
+    let admin_keys = key_files[0].clone();
+    let admin = WalletAccount::from_json_file(&admin_keys)?;
+    eprintln!("Using {} as the admin account.", admin.address);
+    let guardians = key_files[1..=args.guardians as usize]
+        .iter()
+        .map(WalletAccount::from_json_file)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    for (g, guardian) in (1..).zip(&guardians) {
+        eprintln!("Using {} as guardian {g}.", guardian.address);
+    }
+    // All accounts vote, each with a different weight so that incorrect
+    // scaling of ballots is detected.
+    let voters: Vec<&WalletAccount> = std::iter::once(&admin).chain(&guardians).collect();
+    let weights: Vec<Amount> = (1..=voters.len() as u64).map(Amount::from_ccd).collect();
+
+    std::fs::create_dir_all(args.out.join("candidates"))?;
+    // Write the file at `path` relative to the output directory, and return
+    // where it is served.
+    let write_file = |path: &str, data: &[u8]| -> anyhow::Result<contract::ChecksumUrl> {
+        std::fs::write(args.out.join(path), data)
+            .with_context(|| format!("Unable to write {path}."))?;
+        Ok(contract::ChecksumUrl {
+            url:  args
+                .base_url
+                .join(path)
+                .context("Failed to construct URL")?
+                .to_string(),
+            hash: contract::HashSha2256(sha2::Sha256::digest(data).into()),
+        })
+    };
+
+    let mut candidates = Vec::with_capacity(args.candidates);
+    let mut options = Vec::with_capacity(args.candidates);
+    for c in 0..args.candidates {
+        let row = CandidateRow {
+            name:            format!("Candidate {c}"),
+            image_url:       "https://picsum.photos/300/300".into(),
+            description_url: "https://concordium.com".into(),
+        };
+        let data = serde_json::to_vec_pretty(&row)?;
+        candidates.push(write_file(&format!("candidates/{c}.json"), &data)?);
+        options.push(eg::election_manifest::ContestOption { label: row.name });
+    }
+
+    let manifest = ElectionManifest {
+        label:         "Self-test election manifest".into(),
+        contests:      [eg::election_manifest::Contest {
+            label:           "Self-test contest".into(),
+            selection_limit: args.candidates,
+            options:         options.try_into()?,
+        }]
+        .try_into()?,
+        ballot_styles: [BallotStyle {
+            label:    "Self-test ballot".into(),
+            contests: [ContestIndex::from_one_based_index_const(1).unwrap()].into(),
+        }]
+        .try_into()?,
+    };
+    let election_manifest = write_file(
+        "election-manifest.json",
+        &serde_json::to_vec_pretty(&manifest)?,
+    )?;
+
+    let parameters = ElectionParameters {
+        fixed_parameters:   eg::standard_parameters::STANDARD_PARAMETERS.clone(),
+        varying_parameters: eg::varying_parameters::VaryingParameters {
+            n:               GuardianIndex::from_one_based_index(args.guardians)
+                .context("Need at least one guardian.")?,
+            k:               GuardianIndex::from_one_based_index(args.threshold)
+                .context("Threshold must be at least 1.")?,
+            date:            chrono::Utc::now(),
+            info:            format!(
+                "Self-test election with {} out of {} threshold.",
+                args.threshold, args.guardians
+            ),
+            ballot_chaining: eg::varying_parameters::BallotChaining::Prohibited,
+        },
+    };
+    let parameters_version = fixed_parameters_version(&parameters.fixed_parameters)?;
+    let election_parameters = write_file(
+        "election-parameters.json",
+        &serde_json::to_vec_pretty(&parameters)?,
+    )?;
+
+    let eligible_voters = {
+        let mut data = vec![];
+        {
+            let mut writer = csv::Writer::from_writer(&mut data);
+            for (voter, &amount) in voters.iter().zip(&weights) {
+                writer.serialize(WeightRow {
+                    account: voter.address,
+                    amount,
+                })?;
+            }
+            writer.flush()?;
+        }
+        write_csv_metadata(
+            &args.out.join("initial-weights.csv"),
+            &INITIAL_WEIGHTS_COLUMNS,
+        )?;
+        write_file("initial-weights.csv", &data)?
+    };
+
+    // No delegations are made, so the final weights are the initial weights.
+    let final_weights_path = args.out.join("final-weights.csv");
+    {
+        write_csv_metadata(&final_weights_path, &FINAL_WEIGHTS_COLUMNS)?;
+        let mut writer = csv::Writer::from_path(&final_weights_path)?;
+        for (voter, &amount) in voters.iter().zip(&weights) {
+            writer.serialize(FinalWeightRow {
+                account: voter.address,
+                amount,
+                delegators: String::new(),
+            })?;
+        }
+        writer.flush()?;
+    }
+
+    let election_start = chrono::Utc::now() + chrono::Duration::minutes(args.setup_minutes);
+    let election_end = election_start + chrono::Duration::minutes(args.election_minutes);
+    let decryption_deadline = election_end + chrono::Duration::minutes(args.decryption_minutes);
+    let init_param = contract::InitParameter {
+        admin_account: admin.address,
+        candidates,
+        guardians: guardians.iter().map(|g| g.address).collect(),
+        eligible_voters: contract::EligibleVoters {
+            parameters: contract::EligibleVotersParameters {
+                start_time: concordium_std::Timestamp::from_timestamp_millis(0),
+                end_time:   concordium_std::Timestamp::from_timestamp_millis(0),
+            },
+            data:       eligible_voters,
+        },
+        election_manifest,
+        election_parameters,
+        election_description: "Self-test election".into(),
+        election_start: election_start.try_into()?,
+        election_end: election_end.try_into()?,
+        decryption_deadline: decryption_deadline.try_into()?,
+        delegation_string: "Self-test delegation".into(),
+        result_posting_deadline: None,
+        result_approvers: Vec::new(),
+        approval_threshold: 0,
+        eg_version: ELECTIONGUARD_VERSION.into(),
+        display_order: Vec::new(),
+        decryption_grace_seconds: 0,
+        threshold: args.threshold,
+        contest_sizes: Vec::new(),
+        max_description_length: None,
+    };
+
+    let mut client = sdk::Client::new(endpoint.clone()).await?;
+    let wasm_module = WasmModule::from_file(&args.module).context("Unable to read module.")?;
+    let module_ref = ensure_module_deployed(&mut client, &admin, wasm_module).await?;
+    let contract = init_election_contract(
+        &mut client,
+        &admin,
+        module_ref,
+        concordium_std::OwnedParameter::from_serial(&init_param)?,
+    )
+    .await?;
+    let mut contract_client =
+        contract_client::ContractClient::<ElectionContract>::create(client.clone(), contract)
+            .await?;
+
+    eprintln!("Setting up the guardian keys.");
+    let mut csprng = util::csprng::Csprng::new(args.seed.as_bytes());
+    let mut secret_keys = Vec::with_capacity(guardians.len());
+    let mut public_keys = Vec::with_capacity(guardians.len());
+    for (g, guardian) in (1..).zip(&guardians) {
+        let secret_key = GuardianSecretKey::generate(
+            &mut csprng,
+            &parameters,
+            GuardianIndex::from_one_based_index(g)?,
+            Some(format!("Self-test guardian {g}.")),
+        );
+        let public_key = secret_key.make_public_key();
+        update_and_wait(
+            &mut contract_client,
+            guardian,
+            "registerGuardianPublicKey",
+            &encode(&public_key)?,
+        )
+        .await?;
+        secret_keys.push(secret_key);
+        public_keys.push(public_key);
+    }
+    // The shares are encrypted with respect to the public keys, so these must
+    // all be registered first.
+    for (guardian, dealer_key) in guardians.iter().zip(&secret_keys) {
+        let shares: Vec<_> = public_keys
+            .iter()
+            .map(|recipient_key| {
+                GuardianEncryptedShare::encrypt(&mut csprng, &parameters, dealer_key, recipient_key)
+                    .ciphertext
+            })
+            .collect();
+        update_and_wait(
+            &mut contract_client,
+            guardian,
+            "registerGuardianEncryptedShare",
+            &encode(&shares)?,
+        )
+        .await?;
+    }
+    // Each guardian validates the shares registered for it by the other
+    // guardians, and computes its share of the joint secret key.
+    let mut guardians_state =
+        election_contract::view_guardians_state(&mut contract_client, BlockIdentifier::LastFinal)
+            .await?;
+    guardians_state.sort_by_key(|(_, g)| g.index);
+    let mut secret_shares = Vec::with_capacity(guardians.len());
+    for ((g, guardian), secret_key) in (1..).zip(&guardians).zip(&secret_keys) {
+        let index = GuardianIndex::from_one_based_index(g)?;
+        let mut key_shares = Vec::with_capacity(guardians_state.len());
+        for (dealer, dealer_state) in &guardians_state {
+            let shares = dealer_state
+                .encrypted_share
+                .as_ref()
+                .with_context(|| format!("No key shares registered by {dealer}."))?;
+            let share = decode::<Vec<GuardianEncryptedShare>>(shares)
+                .with_context(|| format!("Unable to parse key shares registered by {dealer}."))?
+                .into_iter()
+                .find(|share| share.recipient == index)
+                .with_context(|| format!("No key share for {} from {dealer}.", guardian.address))?;
+            let dealer_public_key = &public_keys[share.dealer.get_zero_based_usize()];
+            if let Err(e) = share.decrypt_and_validate(&parameters, dealer_public_key, secret_key) {
+                anyhow::bail!(
+                    "Key share for {} from {dealer} is invalid: {e:#}",
+                    guardian.address
+                );
+            }
+            key_shares.push(share);
+        }
+        secret_shares.push(GuardianSecretKeyShare::compute(
+            &parameters,
+            &public_keys,
+            &key_shares,
+            secret_key,
+        )?);
+        update_and_wait(
+            &mut contract_client,
+            guardian,
+            "registerGuardianStatus",
+            &contract::GuardianStatus::VerificationSuccessful,
+        )
+        .await?;
+    }
+
+    wait_until(election_start, "the election to start").await;
+
+    let context = PreVotingData::compute(manifest.clone(), parameters.clone(), &public_keys)
+        .context("Unable to compute the joint public key.")?;
+    let device = Device::new("self-test", context.clone());
+    let mut expected = vec![0u64; args.candidates];
+    for (v, (voter, amount)) in voters.iter().zip(&weights).enumerate() {
+        let selections = self_test_selections(&args.seed, v, args.candidates);
+        let factor = get_scaling_factor(amount);
+        for (total, &selection) in expected.iter_mut().zip(&selections) {
+            *total += factor * u64::from(selection);
+        }
+        eprintln!(
+            "Account {} with weight {factor} voting {selections:?}.",
+            voter.address
+        );
+        let ballot = self_test_ballot(&mut csprng, &device, &args.seed, v, selections)?;
+        update_and_wait(
+            &mut contract_client,
+            voter,
+            "registerVotes",
+            &contract::RegisterVotesParameter::from(encode(&ballot)?),
+        )
+        .await?;
+    }
+
+    wait_until(election_end, "the election to end").await;
+    // Tallying requires a finalized block after the end of the election.
+    while client
+        .get_block_info(BlockIdentifier::LastFinal)
+        .await?
+        .response
+        .block_slot_time
+        <= election_end
+    {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+
+    let tally_stats = handle_tally(
+        endpoint.clone(),
+        indexing,
+        TallyArgs {
+            target_address:        contract,
+            final_weights:         vec![final_weights_path],
+            keys:                  Some(admin_keys.clone()),
+            stats_out:             None,
+            check_eligible_voters: true,
+            computation_log:       Some(args.out.join("tally-log.json")),
+            weight_unit:           MICRO_CCD_PER_CCD,
+            checkpoint:            None,
+        },
+        parameters_version,
+        show_progress,
+    )
+    .await
+    .context("Failed to compute or register the encrypted tally.")?;
+    ensure!(
+        tally_stats.ballots == voters.len() as u64,
+        "Expected {} ballots in the tally, but {} were included.",
+        voters.len(),
+        tally_stats.ballots
+    );
+
+    eprintln!("Registering the decryption shares of the guardians.");
+    let encrypted_tally =
+        election_contract::view_encrypted_tally(&mut contract_client, BlockIdentifier::LastFinal)
+            .await?
+            .context("The encrypted tally is not registered.")?;
+    let encrypted_tally =
+        decode::<EncryptedTally>(&encrypted_tally).context("Unable to parse encrypted tally.")?;
+    let mut secret_states = Vec::with_capacity(guardians.len());
+    for (guardian, secret_share) in guardians.iter().zip(&secret_shares) {
+        let (decryption, states) =
+            self_test_decryption_share(&mut csprng, &parameters, secret_share, &encrypted_tally);
+        update_and_wait(
+            &mut contract_client,
+            guardian,
+            "postDecryptionShare",
+            &EncodedGuardianDecryption::encode(&decryption)?.0,
+        )
+        .await?;
+        secret_states.push(states);
+    }
+
+    eprintln!("Registering the proofs of correct decryption of the guardians.");
+    let mut guardians_state =
+        election_contract::view_guardians_state(&mut contract_client, BlockIdentifier::LastFinal)
+            .await?;
+    guardians_state.sort_by_key(|(_, g)| g.index);
+    let decryptions = guardians_state
+        .iter()
+        .map(|(address, state)| {
+            decode_guardian_decryption(state, &encrypted_tally)?
+                .with_context(|| format!("No decryption share registered by {address}."))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    for ((guardian, secret_share), states) in
+        guardians.iter().zip(&secret_shares).zip(secret_states)
+    {
+        let proof = self_test_proof_response(
+            &context,
+            secret_share,
+            &encrypted_tally,
+            &decryptions,
+            &states,
+        )?;
+        update_and_wait(
+            &mut contract_client,
+            guardian,
+            "postDecryptionProofResponseShare",
+            &encode(&proof)?,
+        )
+        .await?;
+    }
+
+    handle_decrypt(
+        endpoint,
+        contract,
+        Some(admin_keys),
+        Some(tally_stats),
+        None,
+        parameters_version,
+        OutputFormat::Human,
+        show_progress,
+    )
+    .await
+    .context("Failed to decrypt the encrypted tally.")?;
+
+    let result =
+        election_contract::view_election_result(&mut contract_client, BlockIdentifier::LastFinal)
+            .await?
+            .context("The election result is not registered.")?;
+    let result: Vec<u64> = result.iter().map(|c| c.cummulative_votes).collect();
+    ensure!(
+        result == expected,
+        "The election result {result:?} registered in {contract} does not match the votes cast, \
+         which give {expected:?}."
+    );
+    eprintln!("Self-test succeeded. The election result {result:?} matches the votes cast.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    /// The path of a file named `name` in a temporary directory specific to
+    /// this test run.
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("election-coordinator-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    /// The records of the CSV file at `path`, after checking that both its
+    /// header and its metadata list `columns`.
+    fn read_csv(path: &std::path::Path, columns: &[&str]) -> Vec<Vec<String>> {
+        assert!(csv_metadata_path(path).exists(), "Metadata is written");
+        check_csv_metadata(path, columns).expect("Metadata matches the columns");
+        let mut reader = csv::Reader::from_path(path).unwrap();
+        assert_eq!(
+            reader.headers().unwrap().iter().collect::<Vec<_>>(),
+            columns
+        );
+        reader
+            .records()
+            .map(|record| record.unwrap().iter().map(String::from).collect())
+            .collect()
+    }
+
+    /// Registrations of three guardians with a quorum of two, before the
+    /// deadline and without any shares.
+    fn registrations() -> DecryptionRegistrations {
+        DecryptionRegistrations {
+            total:               3,
+            with_share:          0,
+            with_proof:          0,
+            quorum:              2,
+            deadline_passed:     false,
+            proof_window_passed: false,
+        }
+    }
+
+    #[test]
+    fn test_decryption_wait_before_deadline() {
+        let partial = DecryptionRegistrations {
+            with_share: 3,
+            with_proof: 2,
+            ..registrations()
+        };
+        assert_eq!(
+            partial.stage(),
+            DecryptionWaitStage::Waiting,
+            "Wait for all guardians before the deadline"
+        );
+        let complete = DecryptionRegistrations {
+            with_share: 3,
+            with_proof: 3,
+            ..registrations()
+        };
+        assert_eq!(complete.stage(), DecryptionWaitStage::Ready);
+    }
+
+    #[test]
+    fn test_decryption_wait_after_deadline() {
+        let passed = DecryptionRegistrations {
+            deadline_passed: true,
+            ..registrations()
+        };
+        let too_few_shares = DecryptionRegistrations {
+            with_share: 1,
+            with_proof: 1,
+            ..passed
+        };
+        assert_eq!(too_few_shares.stage(), DecryptionWaitStage::NotEnoughShares);
+        let missing_proofs = DecryptionRegistrations {
+            with_share: 3,
+            with_proof: 1,
+            ..passed
+        };
+        assert_eq!(
+            missing_proofs.stage(),
+            DecryptionWaitStage::Waiting,
+            "Proofs can be registered after the deadline"
+        );
+        let quorum = DecryptionRegistrations {
+            with_share: 3,
+            with_proof: 2,
+            ..passed
+        };
+        assert_eq!(
+            quorum.stage(),
+            DecryptionWaitStage::Ready,
+            "A quorum of proofs suffices after the deadline, even if a proof is missing"
+        );
+    }
+
+    #[test]
+    fn test_decryption_wait_after_proof_window() {
+        let missing_proofs = DecryptionRegistrations {
+            with_share: 3,
+            with_proof: 1,
+            deadline_passed: true,
+            proof_window_passed: true,
+            ..registrations()
+        };
+        assert_eq!(missing_proofs.stage(), DecryptionWaitStage::NotEnoughProofs);
+        let quorum = DecryptionRegistrations {
+            with_proof: 2,
+            ..missing_proofs
+        };
+        assert_eq!(quorum.stage(), DecryptionWaitStage::Ready);
+    }
+
+    #[test]
+    fn test_result_history_without_approvers() {
+        let mut history = ResultHistory {
+            requires_approval:  false,
+            approval_threshold: 0,
+            proposal:           None,
+        };
+        let result = vec![(0, vec![1, 2])];
+        assert_eq!(
+            history.apply(ResultUpdate::Post(result.clone())),
+            Some((ResultHistoryKind::Posted, result.clone()))
+        );
+        assert_eq!(
+            history.apply(ResultUpdate::Approve {
+                approver: AccountAddress([1; 32]),
+                hash:     election_result_hash(&result),
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_result_history_with_approvers() {
+        let mut history = ResultHistory {
+            requires_approval:  true,
+            approval_threshold: 2,
+            proposal:           None,
+        };
+        let first = vec![(0, vec![1, 2])];
+        let second = vec![(0, vec![3, 4])];
+        let approve = |approver: u8, result: &contract::ElectionResult| ResultUpdate::Approve {
+            approver: AccountAddress([approver; 32]),
+            hash:     election_result_hash(result),
+        };
+
+        assert_eq!(
+            history.apply(approve(1, &first)),
+            None,
+            "Nothing is proposed"
+        );
+        assert_eq!(
+            history.apply(ResultUpdate::Post(first.clone())),
+            Some((ResultHistoryKind::Proposed, first.clone()))
+        );
+        assert_eq!(
+            history.apply(approve(1, &first)),
+            Some((ResultHistoryKind::Approved, first.clone()))
+        );
+        assert_eq!(
+            history.apply(approve(1, &first)),
+            None,
+            "Duplicate approval"
+        );
+        assert_eq!(history.apply(approve(2, &second)), None, "Hash mismatch");
+        assert_eq!(
+            history.apply(approve(2, &first)),
+            Some((ResultHistoryKind::Stored, first.clone()))
+        );
+        assert_eq!(
+            history.apply(approve(3, &first)),
+            Some((ResultHistoryKind::Approved, first.clone()))
+        );
+
+        // A new proposal discards the approvals of the previous one.
+        assert_eq!(
+            history.apply(ResultUpdate::Post(second.clone())),
+            Some((ResultHistoryKind::Proposed, second.clone()))
+        );
+        assert_eq!(history.apply(approve(1, &first)), None);
+        assert_eq!(
+            history.apply(approve(1, &second)),
+            Some((ResultHistoryKind::Approved, second.clone()))
+        );
+    }
+
+    #[test]
+    fn test_vote_changes() {
+        let time = |seconds| chrono::Utc.timestamp_opt(seconds, 0).unwrap();
+        let twice = AccountAddress([1; 32]);
+        let once = AccountAddress([2; 32]);
+        let mut submissions = BallotSubmissions::new();
+        submissions.insert(twice.into(), vec![
+            (time(10), TransactionHash::new([1; 32])),
+            (time(20), TransactionHash::new([2; 32])),
+        ]);
+        submissions.insert(once.into(), vec![(time(30), TransactionHash::new([3; 32]))]);
+
+        let path = temp_path("vote-changes.csv");
+        write_vote_changes(Some(&path), &submissions).unwrap();
+        let records = read_csv(&path, &VOTE_CHANGES_COLUMNS);
+        assert_eq!(records, vec![
+            vec![
+                twice.to_string(),
+                "2".into(),
+                "true".into(),
+                format!("{};{}", time(10).to_rfc3339(), time(20).to_rfc3339()),
+                format!(
+                    "{};{}",
+                    TransactionHash::new([1; 32]),
+                    TransactionHash::new([2; 32])
+                ),
+            ],
+            vec![
+                once.to_string(),
+                "1".into(),
+                "false".into(),
+                time(30).to_rfc3339(),
+                TransactionHash::new([3; 32]).to_string(),
+            ],
+        ]);
+    }
+
+    #[test]
+    fn test_result_history_output() {
+        let mut history = ResultHistory {
+            requires_approval:  false,
+            approval_threshold: 0,
+            proposal:           None,
+        };
+        let time = chrono::Utc.timestamp_opt(0, 0).unwrap();
+        // The second posting moves a vote between the candidates of the first
+        // contest.
+        let first = vec![(0, vec![1, 2]), (1, vec![3])];
+        let second = vec![(0, vec![2, 2]), (1, vec![3])];
+        let rows: Vec<_> = [first, second]
+            .into_iter()
+            .enumerate()
+            .map(|(i, result)| {
+                let (kind, result) = history.apply(ResultUpdate::Post(result)).unwrap();
+                ResultHistoryRow::new(
+                    time + chrono::Duration::seconds(i as i64),
+                    BlockHash::new([i as u8; 32]),
+                    TransactionHash::new([i as u8; 32]),
+                    kind,
+                    &result,
+                )
+            })
+            .collect();
+
+        let path = temp_path("result-history.csv");
+        write_result_history(Some(&path), &rows).unwrap();
+        let records = read_csv(&path, &RESULT_HISTORY_COLUMNS);
+        let record = |i: u8, result: &str| {
+            vec![
+                (time + chrono::Duration::seconds(i.into())).to_rfc3339(),
+                BlockHash::new([i; 32]).to_string(),
+                TransactionHash::new([i; 32]).to_string(),
+                "posted".to_string(),
+                result.to_string(),
+            ]
+        };
+        assert_eq!(records, vec![record(0, "1;2;3"), record(1, "2;2;3")]);
+    }
+
+    #[test]
+    fn test_decryption_timing_output() {
+        let time = chrono::Utc.timestamp_opt(100, 0).unwrap();
+        let rows = [
+            DecryptionTimingRow {
+                timestamp:         time.to_rfc3339(),
+                seconds_after_end: 100,
+                guardian:          AccountAddress([1; 32]),
+                index:             1,
+                entrypoint:        "postDecryptionShare".into(),
+                transaction:       TransactionHash::new([1; 32]),
+            },
+            DecryptionTimingRow {
+                timestamp:         time.to_rfc3339(),
+                seconds_after_end: 100,
+                guardian:          AccountAddress([2; 32]),
+                index:             2,
+                entrypoint:        "postDecryptionProofResponseShare".into(),
+                transaction:       TransactionHash::new([2; 32]),
+            },
+        ];
+
+        let path = temp_path("decryption-timing.csv");
+        write_decryption_timing(&path, &rows).unwrap();
+        let records = read_csv(&path, &DECRYPTION_TIMING_COLUMNS);
+        let expected: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| {
+                vec![
+                    row.timestamp.clone(),
+                    row.seconds_after_end.to_string(),
+                    row.guardian.to_string(),
+                    row.index.to_string(),
+                    row.entrypoint.clone(),
+                    row.transaction.to_string(),
+                ]
+            })
+            .collect();
+        assert_eq!(records, expected);
+    }
+
+    #[test]
+    fn test_contest_results() {
+        let result = contest_results(&[2, 1], &[10, 20, 30]).unwrap();
+        assert_eq!(result, vec![(0, vec![10, 20]), (1, vec![30])]);
+        assert_eq!(flatten_result(&result), vec![10, 20, 30]);
+        assert!(
+            contest_results(&[2, 1], &[10, 20]).is_err(),
+            "A vote is needed for each candidate"
+        );
+        assert!(contest_results(&[2, 1], &[10, 20, 30, 40]).is_err());
+    }
+
+    #[test]
+    fn test_pending_proposal() {
+        let computed = contest_results(&[2, 1], &[10, 20, 30]).unwrap();
+        let proposal = |votes: &[u64]| contract::ResultProposal {
+            result:    contest_results(&[2, 1], votes).unwrap(),
+            approvals: vec![AccountAddress([1; 32])],
+        };
+
+        let pending = pending_proposal(Some(proposal(&[10, 20, 30])), None)
+            .expect("A proposal without a stored result is pending");
+        assert_eq!(pending.result, computed, "The proposal matches");
+        assert_eq!(
+            election_result_hash(&pending.result),
+            election_result_hash(&computed),
+            "Approvers of the computed result approve the proposal"
+        );
+        assert_eq!(
+            pending_proposal(Some(proposal(&[10, 20, 30])), Some(&[10, 20, 30])),
+            None,
+            "A stored proposal is not pending"
+        );
+
+        let different = pending_proposal(Some(proposal(&[10, 20, 31])), Some(&[1, 2, 3]))
+            .expect("A proposal differing from the stored result is pending");
+        assert_ne!(different.result, computed, "The proposal does not match");
+        assert_ne!(
+            election_result_hash(&different.result),
+            election_result_hash(&computed)
+        );
+        assert_eq!(pending_proposal(None, Some(&[10, 20, 30])), None);
+    }
+
+    /// Write the final weight `rows` to a CSV file named `name`.
+    fn write_final_weights(name: &str, rows: &[(AccountAddress, u64, &str)]) -> std::path::PathBuf {
+        let path = temp_path(name);
+        let mut writer = csv::Writer::from_path(&path).unwrap();
+        for &(account, amount, delegators) in rows {
+            writer
+                .serialize(FinalWeightRow {
+                    account,
+                    amount: Amount::from_ccd(amount),
+                    delegators: delegators.into(),
+                })
+                .unwrap();
+        }
+        writer.flush().unwrap();
+        path
+    }
+
+    #[test]
+    fn test_merge_final_weight_shards() {
+        let overlapping = AccountAddress([1; 32]);
+        let first_only = AccountAddress([2; 32]);
+        let second_only = AccountAddress([3; 32]);
+        let delegator = |n| AccountAddress([n; 32]).to_string();
+        let first = write_final_weights("final-weights-shard-1.csv", &[
+            (overlapping, 100, &delegator(10)),
+            (first_only, 50, ""),
+        ]);
+        let second = write_final_weights("final-weights-shard-2.csv", &[
+            (second_only, 5, &delegator(12)),
+            (overlapping, 20, &delegator(11)),
+        ]);
+
+        let merged = read_final_weights(&[first, second]).unwrap();
+        let merged = merged
+            .into_iter()
+            .map(|(account, (amount, delegators))| {
+                let account: &AccountAddress = account.as_ref();
+                (*account, amount, delegators)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(merged, vec![
+            (
+                overlapping,
+                Amount::from_ccd(120),
+                format!("{};{}", delegator(10), delegator(11))
+            ),
+            (first_only, Amount::from_ccd(50), String::new()),
+            (second_only, Amount::from_ccd(5), delegator(12)),
+        ]);
+        assert!(read_final_weights(&[temp_path("missing-final-weights.csv")]).is_err());
+    }
+
+    /// A manifest with a single contest, where one of the given `options` can
+    /// be selected.
+    fn test_manifest(options: &[&str]) -> ElectionManifest {
+        let options: Vec<_> = options
+            .iter()
+            .map(|&label| eg::election_manifest::ContestOption {
+                label: label.into(),
+            })
+            .collect();
+        ElectionManifest {
+            label:         "Test election manifest".into(),
+            contests:      [eg::election_manifest::Contest {
+                label:           "Test contest".into(),
+                selection_limit: 1,
+                options:         options.try_into().unwrap(),
+            }]
+            .try_into()
+            .unwrap(),
+            ballot_styles: [BallotStyle {
+                label:    "Test ballot style".into(),
+                contests: [ContestIndex::from_one_based_index(1).unwrap()].into(),
+            }]
+            .try_into()
+            .unwrap(),
+        }
+    }
+
+    /// The standard parameters for `n` guardians with threshold `k`.
+    fn test_parameters(n: u32, k: u32) -> ElectionParameters {
+        ElectionParameters {
+            fixed_parameters:   eg::standard_parameters::STANDARD_PARAMETERS.clone(),
+            varying_parameters: eg::varying_parameters::VaryingParameters {
+                n:               GuardianIndex::from_one_based_index(n).unwrap(),
+                k:               GuardianIndex::from_one_based_index(k).unwrap(),
+                date:            chrono::Utc.timestamp_opt(0, 0).unwrap(),
+                info:            format!("Test election with {k} out of {n} threshold."),
+                ballot_chaining: eg::varying_parameters::BallotChaining::Prohibited,
+            },
+        }
+    }
+
+    /// An election with a single contest of two candidates and a single
+    /// guardian, together with the context for encrypting ballots and the
+    /// secret key share of the guardian.
+    fn single_guardian_election(
+        csprng: &mut util::csprng::Csprng,
+    ) -> (ElectionData, PreVotingData, GuardianSecretKeyShare) {
+        let parameters = test_parameters(1, 1);
+        let guardian = GuardianIndex::from_one_based_index(1).unwrap();
+        let secret_key = GuardianSecretKey::generate(csprng, &parameters, guardian, None);
+        let public_key = secret_key.make_public_key();
+        let key_share =
+            GuardianEncryptedShare::encrypt(csprng, &parameters, &secret_key, &public_key)
+                .ciphertext;
+        let secret_share = GuardianSecretKeyShare::compute(
+            &parameters,
+            &[public_key.clone()],
+            &[key_share],
+            &secret_key,
+        )
+        .unwrap();
+        let election_data = test_election_data(
+            test_manifest(&["Candidate 1", "Candidate 2"]),
+            parameters,
+            vec![public_key],
+        );
+        let context = election_data.verification_context().unwrap();
+        (election_data, context, secret_share)
+    }
+
+    /// A ballot of the election of [`single_guardian_election`] with the given
+    /// selection of each of the two candidates.
+    fn test_ballot(
+        csprng: &mut util::csprng::Csprng,
+        context: &PreVotingData,
+        selections: [bool; 2],
+    ) -> BallotEncrypted {
+        let contest = ContestIndex::from_one_based_index(1).unwrap();
+        let device = Device::new("test", context.clone());
+        let selections = ContestSelection::new(
+            selections
+                .into_iter()
+                .map(eg::contest_selection::ContestSelectionPlaintext::from)
+                .collect(),
+        )
+        .unwrap();
+        BallotEncrypted::new_from_selections(
+            BallotStyleIndex::from_one_based_index_unchecked(1),
+            &device,
+            csprng,
+            &[0; 32],
+            &[(contest, selections)].into(),
+        )
+        .unwrap()
+    }
+
+    /// The decryption shares and proofs a guardian with `secret_share`
+    /// registers for the `ciphertexts` when it is the only guardian.
+    fn guardian_decryption(
+        csprng: &mut util::csprng::Csprng,
+        election_data: &ElectionData,
+        context: &PreVotingData,
+        secret_share: &GuardianSecretKeyShare,
+        ciphertexts: &EncryptedTally,
+    ) -> (GuardianDecryption, GuardianDecryptionProof) {
+        let parameters = &election_data.parameters;
+        let fixed_parameters = &parameters.fixed_parameters;
+        let mut decryption_share = GuardianDecryption::new();
+        let mut decryption_proof = GuardianDecryptionProof::new();
+        for (&contest, ciphertexts) in ciphertexts {
+            let mut shares = Vec::new();
+            let mut responses = Vec::new();
+            for ciphertext in ciphertexts {
+                let share = DecryptionShare::from(fixed_parameters, secret_share, ciphertext);
+                let (proof_commit, state) = DecryptionProof::generate_commit_share(
+                    csprng,
+                    fixed_parameters,
+                    ciphertext,
+                    &share.i,
+                );
+                let commit_shares = vec![proof_commit.clone()];
+                let decryption_shares = vec![share.clone()];
+                let combined =
+                    CombinedDecryptionShare::combine(parameters, decryption_shares.iter()).unwrap();
+                responses.push(
+                    DecryptionProof::generate_response_share(
+                        fixed_parameters,
+                        &context.hashes_ext,
+                        &context.public_key,
+                        ciphertext,
+                        &combined,
+                        &commit_shares,
+                        &state,
+                        secret_share,
+                    )
+                    .unwrap(),
+                );
+                shares.push(DecryptionShareResult {
+                    share,
+                    proof_commit,
+                });
+            }
+            decryption_share.insert(contest, shares);
+            decryption_proof.insert(contest, responses);
+        }
+        (decryption_share, decryption_proof)
+    }
+
+    /// Decrypting a ballot with the decryption shares of the only guardian of
+    /// an election with a single contest of two candidates reveals its
+    /// selections.
+    #[test]
+    fn test_reveal_ballot() {
+        let contest = ContestIndex::from_one_based_index(1).unwrap();
+        let mut csprng = util::csprng::Csprng::new(b"coordinator reveal ballot test");
+        let (election_data, context, secret_share) = single_guardian_election(&mut csprng);
+        let ballot = test_ballot(&mut csprng, &context, [false, true]);
+
+        // The guardian decrypts the ciphertexts of the ballot, which are those of
+        // a tally consisting of only the ballot.
+        let mut tally =
+            eg::ballot::BallotTallyBuilder::new(&election_data.manifest, &election_data.parameters);
+        tally.update(ballot.clone());
+        let ciphertexts: EncryptedTally = tally.finalize();
+        let (decryption_share, decryption_proof) = guardian_decryption(
+            &mut csprng,
+            &election_data,
+            &context,
+            &secret_share,
+            &ciphertexts,
+        );
+
+        let revealed = reveal_ballot(&election_data, ballot.clone(), vec![BallotDecryptionFile {
+            decryption_share,
+            decryption_proof,
+        }])
+        .expect("The ballot is decrypted");
+        let selections: Vec<_> = revealed[&contest]
+            .iter()
+            .map(|selection| selection.plain_text.value().to_string())
+            .collect();
+        assert_eq!(selections, ["0", "1"]);
+
+        let error = reveal_ballot(&election_data, ballot, Vec::new())
+            .expect_err("The ballot cannot be decrypted without shares");
+        assert!(error.to_string().contains("Not enough shares"), "{error}");
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        use base64::Engine as _;
+
+        let checksum_url = |name: &str, byte| contract::ChecksumUrl {
+            url:  format!("https://election.example/{name}"),
+            hash: contract::HashSha2256([byte; 32]),
+        };
+        let timestamp = concordium_std::Timestamp::from_timestamp_millis;
+        let first_guardian = AccountAddress([2; 32]);
+        let second_guardian = AccountAddress([3; 32]);
+        let config = contract::ElectionConfig {
+            admin_account:            AccountAddress([1; 32]),
+            candidates:               vec![
+                checksum_url("alice.json", 1),
+                checksum_url("bob.json", 2),
+            ],
+            guardian_accounts:        vec![first_guardian, second_guardian],
+            eligible_voters:          contract::EligibleVoters {
+                parameters: contract::EligibleVotersParameters {
+                    start_time: timestamp(1000),
+                    end_time:   timestamp(2000),
+                },
+                data:       checksum_url("voters.csv", 3),
+            },
+            election_manifest:        checksum_url("manifest.json", 4),
+            election_parameters:      checksum_url("parameters.json", 5),
+            election_description:     "Test election".into(),
+            election_start:           timestamp(3000),
+            election_end:             timestamp(4000),
+            decryption_deadline:      timestamp(5000),
+            delegation_string:        "delegate".into(),
+            result_posting_deadline:  Some(timestamp(6000)),
+            result_approvers:         vec![AccountAddress([4; 32])],
+            approval_threshold:       1,
+            eg_version:               ELECTIONGUARD_VERSION.into(),
+            display_order:            vec![1, 0],
+            decryption_grace_seconds: 60,
+            threshold:                2,
+            contest_sizes:            vec![2],
+            locked:                   false,
+        };
+        let guardian_state = |index| contract::GuardianState {
+            index,
+            public_key: Some(vec![index as u8, 0, 255]),
+            encrypted_share: None,
+            decryption_share: None,
+            decryption_share_contests: BTreeMap::new(),
+            decryption_share_proof: None,
+            status: None,
+            excluded: false,
+            acknowledged_tally: false,
+        };
+        // The guardians are not ordered by index, as returned by the contract.
+        let guardians_state = vec![
+            (second_guardian, contract::GuardianState {
+                status: Some(contract::GuardianStatus::KeyVerificationFailed(vec![
+                    first_guardian,
+                ])),
+                excluded: true,
+                ..guardian_state(2)
+            }),
+            (first_guardian, contract::GuardianState {
+                encrypted_share: Some(vec![7; 40]),
+                decryption_share: Some(vec![8; 40]),
+                decryption_share_proof: Some(vec![9; 40]),
+                status: Some(contract::GuardianStatus::VerificationSuccessful),
+                acknowledged_tally: true,
+                ..guardian_state(1)
+            }),
+        ];
+        let election_result = vec![
+            contract::CandidateResult {
+                candidate:         checksum_url("alice.json", 1),
+                cummulative_votes: 10,
+            },
+            contract::CandidateResult {
+                candidate:         checksum_url("bob.json", 2),
+                cummulative_votes: 20,
+            },
+        ];
+
+        let contract_address = ContractAddress::new(10, 0);
+        let block_hash = BlockHash::new([5; 32]);
+        let snapshot = ContractSnapshot::new(
+            contract_address,
+            block_hash,
+            config.clone(),
+            guardians_state.clone(),
+            Some(vec![6; 100]),
+            Some(election_result),
+        );
+        let json = serde_json::to_string_pretty(&snapshot).unwrap();
+        let read: ContractSnapshot =
+            serde_json::from_str(&json).expect("The snapshot can be read back");
+
+        assert_eq!(read.contract, contract_address);
+        assert_eq!(read.block_hash, block_hash);
+        assert_eq!(read.config.candidates, config.candidates);
+        assert_eq!(read.config.eligible_voters, config.eligible_voters);
+        assert_eq!(read.config.election_manifest, config.election_manifest);
+        assert_eq!(read.config.decryption_deadline, config.decryption_deadline);
+        assert_eq!(
+            serde_json::to_value(&read.config).unwrap(),
+            serde_json::to_value(&config).unwrap()
+        );
+        assert_eq!(read.election_result, snapshot.election_result);
+
+        let blob = |data: &Option<String>| {
+            data.as_ref().map(|data| {
+                base64::engine::general_purpose::STANDARD
+                    .decode(data)
+                    .unwrap()
+            })
+        };
+        assert_eq!(blob(&read.encrypted_tally), Some(vec![6; 100]));
+        assert_eq!(read.guardians.len(), 2);
+        for (guardian, (account, state)) in read.guardians.iter().zip(guardians_state.iter().rev())
+        {
+            assert_eq!(guardian.account, *account);
+            assert_eq!(guardian.index, state.index);
+            assert_eq!(blob(&guardian.public_key), state.public_key);
+            assert_eq!(blob(&guardian.encrypted_share), state.encrypted_share);
+            assert_eq!(blob(&guardian.decryption_share), state.decryption_share);
+            assert_eq!(
+                blob(&guardian.decryption_share_proof),
+                state.decryption_share_proof
+            );
+            assert_eq!(guardian.status, state.status);
+            assert_eq!(guardian.excluded, state.excluded);
+            assert_eq!(guardian.acknowledged_tally, state.acknowledged_tally);
+        }
+    }
+
+    #[test]
+    fn test_validate_delegation_string() {
+        validate_delegation_string("Delegate my vote to").expect("A short string is valid");
+        // Strings of up to 255 bytes are CBOR encoded with a two byte header.
+        let longest = "a".repeat(transactions::MAX_MEMO_SIZE - 2);
+        validate_delegation_string(&longest).expect("The encoding fits in a memo");
+        let error = validate_delegation_string(&format!("{longest}a"))
+            .expect_err("The encoding is too long for a memo");
+        assert!(
+            error.to_string().contains("exceeds the maximum memo size"),
+            "{error}"
+        );
+        assert!(validate_delegation_string("").is_err());
+        assert!(validate_delegation_string("Delegate\nto").is_err());
+    }
+
+    #[test]
+    fn test_abstention_weight() {
+        // Ballots with weights 5, 3 and 2, where only the first selects a
+        // candidate.
+        assert_eq!(abstention_weight(10, 5, 1), 5);
+        assert_eq!(
+            abstention_weight(10, 0, 1),
+            10,
+            "No ballot selects a candidate"
+        );
+        assert_eq!(abstention_weight(10, 10, 1), 0, "No ballot abstains");
+        // With two selections allowed, a ballot of weight 4 selecting both
+        // candidates and one of weight 3 abstaining give the exact bound.
+        assert_eq!(abstention_weight(7, 8, 2), 3);
+        // Ballots of weight 11 abstain with weight 7 if a ballot of weight 4
+        // selects both candidates, but only with weight 3 if two ballots of
+        // weight 4 select one candidate each.
+        assert_eq!(abstention_weight(11, 8, 2), 7);
+    }
+
+    /// Requests to a node which accepts connections but never responds time
+    /// out, except for streaming queries.
+    #[tokio::test]
+    async fn test_node_endpoint_timeouts() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            // Keep the connections open without responding.
+            let mut connections = Vec::new();
+            for connection in listener.incoming() {
+                connections.push(connection);
+            }
+        });
+        let node = sdk::Endpoint::from_shared(format!("http://{address}")).unwrap();
+        let (endpoint, stream_endpoint) =
+            node_endpoints(node, Duration::from_secs(1), Duration::from_secs(1)).unwrap();
+
+        let mut client = sdk::Client::new(endpoint).await.unwrap();
+        let start = Instant::now();
+        let response = tokio::time::timeout(Duration::from_secs(5), client.get_consensus_info())
+            .await
+            .expect("The request timeout applies");
+        assert!(response.is_err(), "The request fails");
+        assert!(start.elapsed() >= Duration::from_secs(1));
+
+        let mut client = sdk::Client::new(stream_endpoint).await.unwrap();
+        assert!(
+            tokio::time::timeout(Duration::from_secs(3), client.get_consensus_info())
+                .await
+                .is_err(),
+            "Streaming queries are not subject to the request timeout"
+        );
+    }
+
+    #[test]
+    fn test_read_versioned_final_weights() {
+        let account = AccountAddress([1; 32]);
+        let rows = [(account, 10, "")];
+
+        let unversioned = write_final_weights("final-weights-unversioned.csv", &rows);
+        let _ = std::fs::remove_file(csv_metadata_path(&unversioned));
+        let weights = read_final_weights(&[unversioned]).expect("Files without metadata are read");
+        assert_eq!(weights.len(), 1);
+
+        let versioned = write_final_weights("final-weights-versioned.csv", &rows);
+        write_csv_metadata(&versioned, &FINAL_WEIGHTS_COLUMNS).unwrap();
+        let weights = read_final_weights(&[versioned.clone()]).expect("The version is supported");
+        assert_eq!(weights.len(), 1);
+
+        let metadata = CsvMetadata {
+            version:      CSV_FORMAT_VERSION + 1,
+            columns:      FINAL_WEIGHTS_COLUMNS.map(String::from).into(),
+            truncated_at: None,
+        };
+        std::fs::write(
+            csv_metadata_path(&versioned),
+            serde_json::to_vec(&metadata).unwrap(),
+        )
+        .unwrap();
+        let error =
+            read_final_weights(&[versioned.clone()]).expect_err("Newer versions are rejected");
+        assert!(error.to_string().contains("format version"), "{error}");
+
+        write_csv_metadata(&versioned, &INITIAL_WEIGHTS_COLUMNS).unwrap();
+        let error = read_final_weights(&[versioned]).expect_err("Other columns are rejected");
+        assert!(error.to_string().contains("columns"), "{error}");
+    }
+
+    #[test]
+    fn test_guardian_statuses_output() {
+        let guardian = |n| AccountAddress([n; 32]);
+        let state = |index, status| contract::GuardianState {
+            index,
+            public_key: None,
+            encrypted_share: None,
+            decryption_share: None,
+            decryption_share_contests: BTreeMap::new(),
+            decryption_share_proof: None,
+            status,
+            excluded: false,
+            acknowledged_tally: false,
+        };
+        let guardians_state = vec![
+            (
+                guardian(1),
+                state(1, Some(contract::GuardianStatus::VerificationSuccessful)),
+            ),
+            (
+                guardian(2),
+                state(
+                    2,
+                    Some(contract::GuardianStatus::KeyVerificationFailed(vec![
+                        guardian(3),
+                        guardian(4),
+                    ])),
+                ),
+            ),
+            (
+                guardian(3),
+                state(
+                    3,
+                    Some(contract::GuardianStatus::SharesVerificationFailed(vec![
+                        guardian(1),
+                    ])),
+                ),
+            ),
+            (guardian(4), state(4, None)),
+        ];
+
+        let path = temp_path("guardian-statuses.csv");
+        let failed = write_guardian_statuses(&path, &guardians_state).unwrap();
+        assert_eq!(failed, 2);
+        let records = read_csv(&path, &GUARDIAN_STATUSES_COLUMNS);
+        assert_eq!(records, vec![
+            vec![
+                "1".into(),
+                guardian(1).to_string(),
+                "verificationSuccessful".into(),
+                String::new(),
+            ],
+            vec![
+                "2".into(),
+                guardian(2).to_string(),
+                "keyVerificationFailed".into(),
+                format!("{} {}", guardian(3), guardian(4)),
+            ],
+            vec![
+                "3".into(),
+                guardian(3).to_string(),
+                "sharesVerificationFailed".into(),
+                guardian(1).to_string(),
+            ],
+            vec![
+                "4".into(),
+                guardian(4).to_string(),
+                "missing".into(),
+                String::new(),
+            ],
+        ]);
+    }
+
+    #[test]
+    fn test_check_election_files() {
+        let candidates = ["Alice".to_string(), "Bob".to_string()];
+        let guardians = [AccountAddress([1; 32]), AccountAddress([2; 32])];
+        let manifest = test_manifest(&["Alice", "Bob"]);
+        assert!(
+            check_election_files(
+                &manifest,
+                &test_parameters(2, 2),
+                &candidates,
+                &guardians,
+                Some(2)
+            )
+            .is_empty(),
+            "Consistent files are accepted"
+        );
+
+        let problems = check_election_files(
+            &manifest,
+            &test_parameters(2, 3),
+            &candidates,
+            &guardians,
+            None,
+        );
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(problems[0].contains("exceeds the number of guardians"));
+
+        let problems = check_election_files(
+            &test_manifest(&["Alice", "Bob", "Carol"]),
+            &test_parameters(2, 1),
+            &candidates,
+            &guardians,
+            None,
+        );
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(problems[0].contains("has 3 options, but 2 candidates are given"));
+
+        let problems = check_election_files(
+            &test_manifest(&["Bob", "Alice"]),
+            &test_parameters(2, 1),
+            &candidates,
+            &guardians,
+            None,
+        );
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(problems[0].contains("do not match the names of the candidates"));
+    }
+
+    /// A transfer from `sender` to `to` with the CBOR encoded `memo`.
+    fn transfer_with_memo(
+        sender: AccountAddress,
+        to: AccountAddress,
+        memo: &str,
+        hash: u8,
+    ) -> BlockItemSummary {
+        use concordium_rust_sdk::types::{AccountTransactionDetails, Energy, TransactionIndex};
+
+        let memo = transactions::Memo::try_from(serde_cbor::to_vec(memo).unwrap()).unwrap();
+        BlockItemSummary {
+            index:       TransactionIndex { index: 0 },
+            energy_cost: Energy { energy: 0 },
+            hash:        TransactionHash::new([hash; 32]),
+            details:     BlockItemSummaryDetails::AccountTransaction(AccountTransactionDetails {
+                cost: Amount::zero(),
+                sender,
+                effects: AccountTransactionEffects::AccountTransferWithMemo {
+                    amount: Amount::from_ccd(1),
+                    to,
+                    memo,
+                },
+            }),
+        }
+    }
+
+    /// Memos are decoded according to the memo encoding, where `auto` accepts
+    /// both CBOR encoded strings and raw UTF-8 strings.
+    #[test]
+    fn test_memo_encoding() {
+        let delegation_string = "Delegate to";
+        let cbor = serde_cbor::to_vec(delegation_string).unwrap();
+        let raw = delegation_string.as_bytes();
+        let invalid = [0xff, 0xfe];
+
+        assert_eq!(
+            MemoEncoding::Cbor.decode(&cbor).as_deref(),
+            Some(delegation_string)
+        );
+        assert_eq!(MemoEncoding::Cbor.decode(raw), None);
+        assert_eq!(MemoEncoding::Cbor.decode(&invalid), None);
+
+        assert_eq!(
+            MemoEncoding::Raw.decode(raw).as_deref(),
+            Some(delegation_string)
+        );
+        assert_ne!(
+            MemoEncoding::Raw.decode(&cbor).as_deref(),
+            Some(delegation_string),
+            "The CBOR framing is part of the raw string."
+        );
+        assert_eq!(MemoEncoding::Raw.decode(&invalid), None);
+
+        assert_eq!(
+            MemoEncoding::Auto.decode(&cbor).as_deref(),
+            Some(delegation_string)
+        );
+        assert_eq!(
+            MemoEncoding::Auto.decode(raw).as_deref(),
+            Some(delegation_string)
+        );
+        assert_eq!(MemoEncoding::Auto.decode(&invalid), None);
+    }
+
+    /// Delegations are only detected in memos with the expected encoding.
+    #[test]
+    fn test_record_delegation_memo_encoding() {
+        let account = |n| AccountAddress([n; 32]);
+        let delegation_string = "Delegate to";
+        let cbor = transfer_with_memo(account(1), account(3), delegation_string, 1);
+        let mut raw = transfer_with_memo(account(2), account(3), delegation_string, 2);
+        if let BlockItemSummaryDetails::AccountTransaction(atx) = &mut raw.details {
+            if let AccountTransactionEffects::AccountTransferWithMemo { memo, .. } =
+                &mut atx.effects
+            {
+                *memo =
+                    transactions::Memo::try_from(delegation_string.as_bytes().to_vec()).unwrap();
+            }
+        }
+
+        let delegators = |encoding| {
+            let mut mapping = Delegations::new();
+            for tx in [cbor.clone(), raw.clone()] {
+                record_delegation(&mut mapping, tx, delegation_string, encoding);
+            }
+            mapping
+                .keys()
+                .map(|from| {
+                    let from: &AccountAddress = from.as_ref();
+                    *from
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(delegators(MemoEncoding::Cbor), [account(1)]);
+        assert_eq!(delegators(MemoEncoding::Raw), [account(2)]);
+        assert_eq!(delegators(MemoEncoding::Auto), [account(1), account(2)]);
+    }
+
+    /// Final weights computed from a delegations snapshot agree with the ones
+    /// computed from the delegations found on chain.
+    #[test]
+    fn test_final_weights_from_delegations_snapshot() {
+        let account = |n| AccountAddress([n; 32]);
+        let delegation_string = "Delegate to";
+        let mut live = Delegations::new();
+        for tx in [
+            transfer_with_memo(account(1), account(3), delegation_string, 1),
+            transfer_with_memo(account(2), account(4), "Not a delegation", 2),
+            transfer_with_memo(account(2), account(3), delegation_string, 3),
+            // Only the latest delegation of an account is in effect.
+            transfer_with_memo(account(1), account(4), delegation_string, 4),
+        ] {
+            record_delegation(&mut live, tx, delegation_string, MemoEncoding::Cbor);
+        }
+
+        let path = temp_path("delegations-snapshot.csv");
+        write_delegations(Some(&path), &live).unwrap();
+        let snapshot = read_delegations(&path).expect("The snapshot is read back");
+        let delegations = |mapping: &Delegations| {
+            mapping
+                .iter()
+                .map(|(from, &(hash, to))| {
+                    let from: &AccountAddress = from.as_ref();
+                    (*from, hash, to)
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(delegations(&snapshot), delegations(&live));
+        assert_eq!(delegations(&live), vec![
+            (account(1), TransactionHash::new([4; 32]), account(4)),
+            (account(2), TransactionHash::new([3; 32]), account(3)),
+        ]);
+
+        let final_weights = |mapping: &Delegations| {
+            let initial_weights = (1..=4)
+                .map(|n| WeightRow {
+                    account: account(n),
+                    amount:  Amount::from_ccd(u64::from(n)),
+                })
+                .collect();
+            compute_final_weights(initial_weights, mapping)
+                .into_iter()
+                .map(|(target, weight)| {
+                    let target: &AccountAddress = target.as_ref();
+                    (*target, weight)
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(final_weights(&snapshot), final_weights(&live));
+        assert_eq!(final_weights(&live), vec![
+            (account(3), (Amount::from_ccd(5), vec![account(2)])),
+            (account(4), (Amount::from_ccd(5), vec![account(1)])),
+        ]);
+    }
+
+    #[test]
+    fn test_model_election_cost() {
+        let estimates = model_election_cost(3, 2, 4, 10, Some(1000));
+        let modeled: Vec<_> = estimates
+            .iter()
+            .map(|e| (e.operation, e.paid_by, e.count, e.energy_each))
+            .collect();
+        assert_eq!(modeled, [
+            ("Deploy module", "admin", 1, 300 + 1000 + 100),
+            ("Initialize contract", "admin", 1, INIT_ENERGY),
+            // Updates cost 300 energy plus 2 energy per byte of the parameter.
+            // Two coefficients, each of 512 + 2 * 32 bytes.
+            ("Register public key", "guardian", 3, 300 + 2 * 1152),
+            // Shares for two other guardians, each of 512 + 3 * 32 bytes.
+            ("Register encrypted shares", "guardian", 3, 300 + 2 * 1216),
+            ("Register status", "guardian", 3, 300 + 2 * 96),
+            // Four ciphertexts with range proofs, and a proof for the selection
+            // limit: 4 * (2 * 512 + 4 * 32) + 2 * 32 * 5 bytes.
+            ("Submit ballot", "voter", 10, 300 + 2 * 4928),
+            ("Post encrypted tally", "admin", 1, 300 + 2 * 4096),
+            ("Register decryption share", "guardian", 3, 300 + 2 * 6144),
+            ("Register decryption proof", "guardian", 3, 300 + 2 * 128),
+            ("Post election result", "admin", 1, 300 + 2 * 32),
+        ]);
+        let total: u64 = estimates.iter().map(CostEstimate::total_energy).sum();
+        assert_eq!(
+            total,
+            1400 + 20_000
+                + 3 * 2604
+                + 3 * 2732
+                + 3 * 492
+                + 10 * 10_156
+                + 8492
+                + 3 * 12_588
+                + 3 * 556
+                + 364
+        );
+
+        let estimates = model_election_cost(1, 1, 1, 0, None);
+        assert_eq!(
+            estimates[0].operation, "Initialize contract",
+            "The module is not deployed"
+        );
+        let shares = estimates
+            .iter()
+            .find(|e| e.operation == "Register encrypted shares")
+            .unwrap();
+        assert_eq!(
+            shares.energy_each, TRANSACTION_BASE_ENERGY,
+            "A single guardian has no shares for others"
+        );
+        let ballots = estimates
+            .iter()
+            .find(|e| e.operation == "Submit ballot")
+            .unwrap();
+        assert_eq!(ballots.total_energy(), 0);
+    }
+
+    /// Start a webhook on localhost which responds with `200 OK` to every
+    /// request, and sends the JSON body of each request on the returned
+    /// channel.
+    fn mock_webhook() -> (url::Url, std::sync::mpsc::Receiver<serde_json::Value>) {
+        use std::io::{BufRead, Read};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/progress", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    return;
+                };
+                let mut reader = std::io::BufReader::new(stream.try_clone().unwrap());
+                // Requests are read one at a time, since the connection is kept
+                // alive between them.
+                'requests: loop {
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                            break 'requests;
+                        }
+                        let line = line.trim_end();
+                        if line.is_empty() {
+                            break;
+                        }
+                        if let Some((name, value)) = line.split_once(':') {
+                            if name.eq_ignore_ascii_case("content-length") {
+                                content_length = value.trim().parse().unwrap();
+                            }
+                        }
+                    }
+                    let mut body = vec![0; content_length];
+                    reader.read_exact(&mut body).unwrap();
+                    sender.send(serde_json::from_slice(&body).unwrap()).unwrap();
+                    stream
+                        .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                        .unwrap();
+                }
+            }
+        });
+        (url, receiver)
+    }
+
+    #[tokio::test]
+    async fn test_progress_notifier() {
+        let (url, received) = mock_webhook();
+        let contract = ContractAddress::new(3, 0);
+        let notifier = ProgressNotifier::new(contract, Some(url)).unwrap();
+        notifier.notify(DecryptionProgressEvent::Started {
+            guardians_used: vec![1, 2],
+            total:          2,
+        });
+        for option in 0..2 {
+            notifier.notify(DecryptionProgressEvent::OptionDecrypted {
+                contest: ContestIndex::from_one_based_index(1).unwrap(),
+                option,
+                completed: option as u64 + 1,
+                total: 2,
+            });
+        }
+        notifier.notify(DecryptionProgressEvent::Finished { success: true });
+        notifier.finish().await;
+
+        let events: Vec<_> = received.try_iter().collect();
+        assert_eq!(events.len(), 4, "All events are posted: {events:?}");
+        let contract_json = serde_json::json!({"index": 3, "subindex": 0});
+        assert_eq!(
+            events[0],
+            serde_json::json!({
+                "contract": contract_json,
+                "event": "started",
+                "guardiansUsed": [1, 2],
+                "total": 2,
+            })
+        );
+        for (option, event) in events[1..3].iter().enumerate() {
+            assert_eq!(event["contract"], contract_json);
+            assert_eq!(event["event"], "optionDecrypted");
+            assert_eq!(event["option"], option);
+            assert_eq!(event["completed"], option + 1);
+            assert_eq!(event["total"], 2);
+        }
+        assert_eq!(
+            events[3],
+            serde_json::json!({
+                "contract": contract_json,
+                "event": "finished",
+                "success": true,
+            })
+        );
+    }
+
+    /// Failing to post progress updates does not stop the notifier.
+    #[tokio::test]
+    async fn test_progress_notifier_unreachable() {
+        let address = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let url = format!("http://{address}/progress").parse().unwrap();
+        let notifier = ProgressNotifier::new(ContractAddress::new(3, 0), Some(url)).unwrap();
+        notifier.notify(DecryptionProgressEvent::Finished { success: false });
+        notifier.finish().await;
+    }
+
+    fn indexing_config(max_blocks: Option<u64>) -> IndexingConfig {
+        IndexingConfig {
+            endpoint: sdk::Endpoint::from_static("http://localhost:20000"),
+            max_blocks,
+            block_batch_size: 1,
+        }
+    }
+
+    /// Send the blocks `0..10` on a channel, returning the number of blocks
+    /// which were sent before the receiver was dropped.
+    fn index_blocks() -> (
+        tokio::task::JoinHandle<u64>,
+        tokio::sync::mpsc::Receiver<u64>,
+    ) {
+        let (sender, receiver) = tokio::sync::mpsc::channel(1);
+        let indexer = tokio::spawn(async move {
+            for block in 0..10 {
+                if sender.send(block).await.is_err() {
+                    return block;
+                }
+            }
+            10
+        });
+        (indexer, receiver)
+    }
+
+    /// Indexing stops after `--max-blocks` blocks.
+    #[tokio::test]
+    async fn test_limit_blocks() {
+        let (indexer, receiver) = index_blocks();
+        let mut receiver = indexing_config(Some(3)).limit_blocks(receiver);
+        let mut blocks = Vec::new();
+        while let Some(block) = receiver.recv().await {
+            blocks.push(block);
+        }
+        assert_eq!(blocks, [0, 1, 2]);
+        assert!(
+            indexer.await.unwrap() < 10,
+            "The indexer is stopped when the limit is reached."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_limit_blocks_unset() {
+        let (indexer, receiver) = index_blocks();
+        let mut receiver = indexing_config(None).limit_blocks(receiver);
+        let mut blocks = Vec::new();
+        while let Some(block) = receiver.recv().await {
+            blocks.push(block);
+        }
+        assert_eq!(blocks, (0..10).collect::<Vec<_>>());
+        assert_eq!(indexer.await.unwrap(), 10);
+    }
+
+    /// The data of an election with two candidates.
+    fn test_election_data(
+        manifest: ElectionManifest,
+        parameters: ElectionParameters,
+        guardian_public_keys: Vec<GuardianPublicKey>,
+    ) -> ElectionData {
+        ElectionData {
+            manifest,
+            parameters,
+            guardian_public_keys,
+            candidates: (1..=2)
+                .map(|c| contract::ChecksumUrl {
+                    url:  format!("https://candidates.example/{c}.json"),
+                    hash: contract::HashSha2256([c; 32]),
+                })
+                .collect(),
+            contest_sizes: vec![2],
+            start: chrono::Utc.timestamp_opt(0, 0).unwrap(),
+            end: chrono::Utc.timestamp_opt(3600, 0).unwrap(),
+            delegation_string: "delegate".into(),
+        }
+    }
+
+    /// Elections using the standard parameters pass the version check against
+    /// the version of the standard parameters, and fail it against any other
+    /// version.
+    #[test]
+    fn test_check_parameters_version() {
+        let election_data = test_election_data(
+            test_manifest(&["Candidate 1", "Candidate 2"]),
+            test_parameters(1, 1),
+            Vec::new(),
+        );
+        let standard = standard_parameters_version().unwrap();
+        assert_eq!(
+            fixed_parameters_version(&election_data.parameters.fixed_parameters).unwrap(),
+            standard,
+            "The version is deterministic."
+        );
+        election_data
+            .check_parameters_version(&standard)
+            .expect("The standard parameters have the standard version.");
+
+        let error = election_data
+            .check_parameters_version(&contract::HashSha2256([0; 32]))
+            .unwrap_err();
+        assert!(
+            error.to_string().contains("--parameters-version"),
+            "{error}"
+        );
+    }
+
+    /// The fingerprint of the joint public key of an election whose two
+    /// guardians generated their keys from `seed`.
+    fn fingerprint_from_seed(seed: &[u8]) -> String {
+        let parameters = test_parameters(2, 2);
+        let mut csprng = util::csprng::Csprng::new(seed);
+        let guardian_public_keys = (1..=2)
+            .map(|i| {
+                let index = GuardianIndex::from_one_based_index(i).unwrap();
+                GuardianSecretKey::generate(&mut csprng, &parameters, index, None).make_public_key()
+            })
+            .collect();
+        let election_data = test_election_data(
+            test_manifest(&["Candidate 1", "Candidate 2"]),
+            parameters,
+            guardian_public_keys,
+        );
+        let context = election_data.verification_context().unwrap();
+        joint_public_key_fingerprint(&context.public_key).unwrap()
+    }
+
+    /// The fingerprint of the joint public key only depends on the keys of the
+    /// guardians, and is 4 groups of 4 hex digits.
+    #[test]
+    fn test_joint_key_fingerprint() {
+        let fingerprint = fingerprint_from_seed(b"coordinator fingerprint test");
+        assert_eq!(
+            fingerprint,
+            fingerprint_from_seed(b"coordinator fingerprint test"),
+            "The fingerprint is stable for fixed keys."
+        );
+        let groups: Vec<_> = fingerprint.split('-').collect();
+        assert_eq!(groups.len(), 4, "{fingerprint}");
+        assert!(
+            groups.iter().all(|group| group.len() == 4
+                && group
+                    .chars()
+                    .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase())),
+            "{fingerprint}"
+        );
+        assert_ne!(
+            fingerprint,
+            fingerprint_from_seed(b"other coordinator fingerprint test"),
+            "Different keys have different fingerprints."
+        );
+    }
+
+    /// A transaction by `sender` updating the election contract at `contract`
+    /// through `receive_name` with `parameter`.
+    fn contract_update(
+        sender: AccountAddress,
+        contract: ContractAddress,
+        receive_name: &str,
+        parameter: Vec<u8>,
+    ) -> BlockItemSummary {
+        use concordium_rust_sdk::types::{
+            smart_contracts::{OwnedParameter, OwnedReceiveName, WasmVersion},
+            AccountTransactionDetails, Address, Energy, InstanceUpdatedEvent, TransactionIndex,
+        };
+
+        let update = InstanceUpdatedEvent {
+            contract_version: WasmVersion::V1,
+            address:          contract,
+            instigator:       Address::Account(sender),
+            amount:           Amount::zero(),
+            message:          OwnedParameter::new_unchecked(parameter),
+            receive_name:     OwnedReceiveName::new_unchecked(receive_name.into()),
+            events:           Vec::new(),
+        };
+        BlockItemSummary {
+            index:       TransactionIndex { index: 0 },
+            energy_cost: Energy { energy: 0 },
+            hash:        TransactionHash::new([1; 32]),
+            details:     BlockItemSummaryDetails::AccountTransaction(AccountTransactionDetails {
+                cost: Amount::zero(),
+                sender,
+                effects: AccountTransactionEffects::ContractUpdateIssued {
+                    effects: vec![ContractTraceElement::Updated { data: update }],
+                },
+            }),
+        }
+    }
+
+    /// A ballot transaction is decoded into a ballot which verifies against the
+    /// election parameters and has an encrypted selection for each candidate.
+    #[test]
+    fn test_inspect_ballot() {
+        let contest = ContestIndex::from_one_based_index(1).unwrap();
+        let manifest = test_manifest(&["Candidate 1", "Candidate 2"]);
+        let parameters = test_parameters(1, 1);
+        let guardian = GuardianIndex::from_one_based_index(1).unwrap();
+
+        let mut csprng = util::csprng::Csprng::new(b"coordinator inspect ballot test");
+        let secret_key = GuardianSecretKey::generate(&mut csprng, &parameters, guardian, None);
+        let public_key = secret_key.make_public_key();
+        let election_data = test_election_data(manifest, parameters, vec![public_key]);
+        let context = election_data.verification_context().unwrap();
+
+        let device = Device::new("test", context.clone());
+        let selections = ContestSelection::new(
+            [true, false]
+                .into_iter()
+                .map(eg::contest_selection::ContestSelectionPlaintext::from)
+                .collect(),
+        )
+        .unwrap();
+        let ballot = BallotEncrypted::new_from_selections(
+            BallotStyleIndex::from_one_based_index_unchecked(1),
+            &device,
+            &mut csprng,
+            &[0; 32],
+            &[(contest, selections)].into(),
+        )
+        .unwrap();
+        let encoded = encode(&ballot).unwrap();
+
+        let sender = AccountAddress([1; 32]);
+        let election = ContractAddress::new(5, 0);
+        let parameter =
+            concordium_std::to_bytes(&contract::RegisterVotesParameter::from(encoded.clone()));
+        let transaction = contract_update(
+            sender,
+            election,
+            "election.registerVotes",
+            parameter.clone(),
+        );
+
+        let param = ballot_parameter(&transaction, election).unwrap();
+        assert_eq!(param.inner, encoded);
+        let ballot = decode::<BallotEncrypted>(&param.inner).unwrap();
+        assert!(ballot.verify(&context));
+        assert_eq!(ballot_structure(&election_data, ballot), [(contest, 2, 2)]);
+
+        // Only ballots submitted to the given election contract are decoded.
+        assert!(ballot_parameter(&transaction, ContractAddress::new(6, 0)).is_err());
+        let other_entrypoint =
+            contract_update(sender, election, "election.postDecryptionShare", parameter);
+        assert!(ballot_parameter(&other_entrypoint, election).is_err());
+    }
+
+    /// The rows of a candidates CSV file are written to candidate files which
+    /// are read back as the same candidates, in order.
+    #[test]
+    fn test_candidates_from_csv() {
+        let input = temp_path("candidates.csv");
+        std::fs::write(
+            &input,
+            "name,imageUrl,descriptionUrl\n\
+             Alice,https://images.example/alice.png,https://example.com/alice\n\
+             \"Bob, Jr.\",https://images.example/bob.png,https://example.com/bob\n",
+        )
+        .unwrap();
+        let out = temp_path("candidates");
+        handle_candidates_from_csv(CandidatesFromCsvArgs {
+            input,
+            out: out.clone(),
+            base_url: "https://election.example/".parse().unwrap(),
+        })
+        .unwrap();
+
+        let expected = [
+            (
+                "Alice",
+                "https://images.example/alice.png",
+                "https://example.com/alice",
+            ),
+            (
+                "Bob, Jr.",
+                "https://images.example/bob.png",
+                "https://example.com/bob",
+            ),
+        ];
+        for (i, (name, image_url, description_url)) in expected.into_iter().enumerate() {
+            let data = std::fs::read(out.join(format!("candidate{}.json", i + 1))).unwrap();
+            let candidate: CandidateRow = serde_json::from_slice(&data).unwrap();
+            assert_eq!(candidate.name, name);
+            assert_eq!(candidate.image_url, image_url);
+            assert_eq!(candidate.description_url, description_url);
+            // The files can be read as candidates by `new-election`.
+            let metadata: CandidateMetadata = serde_json::from_slice(&data).unwrap();
+            assert_eq!(metadata.name, name);
+        }
+        assert!(!out.join("candidate3.json").exists());
+    }
+
+    #[test]
+    fn test_candidates_from_csv_duplicate_name() {
+        let input = temp_path("duplicate-candidates.csv");
+        std::fs::write(
+            &input,
+            "name,imageUrl,descriptionUrl\n\
+             Alice,https://images.example/alice.png,https://example.com/alice\n\
+             Alice,https://images.example/alice2.png,https://example.com/alice2\n",
+        )
+        .unwrap();
+        let out = temp_path("duplicate-candidates");
+        let error = handle_candidates_from_csv(CandidatesFromCsvArgs {
+            input,
+            out: out.clone(),
+            base_url: "https://election.example/".parse().unwrap(),
+        })
+        .unwrap_err();
+        assert!(error.to_string().contains("more than once"), "{error}");
+        assert!(!out.exists(), "No files are written for invalid input.");
+    }
+
+    /// Accounts in the final weights which are not eligible voters, or which
+    /// received delegations from accounts that are not, are reported.
+    #[test]
+    fn test_ineligible_final_weights() {
+        let account = |n| AccountAddress([n; 32]);
+        let eligible_voters = format!(
+            "account,amount\n{},1000000\n{},2000000\n",
+            account(1),
+            account(2)
+        );
+        let final_weights = write_final_weights("final-weights-eligibility.csv", &[
+            (account(1), 1, ""),
+            (account(2), 3, &account(1).to_string()),
+            (account(3), 5, ""),
+            (account(4), 2, &format!("{};{}", account(2), account(5))),
+        ]);
+        let final_weights = read_final_weights(&[final_weights]).unwrap();
+
+        let problems =
+            ineligible_final_weights(eligible_voters.as_bytes(), &final_weights).unwrap();
+        assert_eq!(problems.len(), 2, "{problems:?}");
+        assert!(
+            problems[0].contains(&account(3).to_string())
+                && problems[0].contains("in the final weights is not an eligible voter"),
+            "{problems:?}"
+        );
+        assert!(
+            problems[1].contains(&format!(
+                "{} delegating to {} is not an eligible voter",
+                account(5),
+                account(4)
+            )),
+            "{problems:?}"
+        );
+
+        let all_eligible = write_final_weights("final-weights-all-eligible.csv", &[
+            (account(1), 1, ""),
+            (account(2), 3, &account(1).to_string()),
+        ]);
+        let all_eligible = read_final_weights(&[all_eligible]).unwrap();
+        assert!(
+            ineligible_final_weights(eligible_voters.as_bytes(), &all_eligible)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    /// With more guardians than the quorum having registered decryption data,
+    /// the shares of all guardians with usable data are used, and the others
+    /// are reported as left out.
+    #[test]
+    fn test_guardian_decryptions() {
+        let tally = EncryptedTally::new();
+        let share = encode(&GuardianDecryption::new()).unwrap();
+        let proof = encode(&GuardianDecryptionProof::new()).unwrap();
+        let address = |index| AccountAddress([index as u8; 32]);
+        let guardian = |index| {
+            (address(index), contract::GuardianState {
+                index,
+                public_key: Some(vec![index as u8]),
+                encrypted_share: None,
+                decryption_share: Some(share.clone()),
+                decryption_share_contests: BTreeMap::new(),
+                decryption_share_proof: Some(proof.clone()),
+                status: None,
+                excluded: false,
+                acknowledged_tally: true,
+            })
+        };
+        let without = |index, share: bool, proof: bool| {
+            let (address, mut state) = guardian(index);
+            if !share {
+                state.decryption_share = None;
+            }
+            if !proof {
+                state.decryption_share_proof = None;
+            }
+            (address, state)
+        };
+        let mut unreadable_proof = guardian(5);
+        unreadable_proof.1.decryption_share_proof = Some(vec![0xc1]);
+        let guardians_state = vec![
+            guardian(3),
+            without(4, true, false),
+            guardian(1),
+            unreadable_proof,
+            without(6, false, false),
+            guardian(2),
+            without(7, false, true),
+        ];
+
+        let decryptions = GuardianDecryptions::collect(guardians_state, &tally);
+        assert_eq!(decryptions.shares.len(), 3);
+        assert_eq!(decryptions.proofs.len(), 3);
+        assert_eq!(decryptions.used, [
+            (1, address(1)),
+            (2, address(2)),
+            (3, address(3))
+        ]);
+        assert_eq!(
+            decryptions
+                .left_out
+                .iter()
+                .map(|(index, address, reason)| (*index, *address, reason.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (4, address(4), "no proof"),
+                (5, address(5), "unreadable proof"),
+                (7, address(7), "no share"),
+            ]
+        );
+
+        let lines = decryptions.log_lines();
+        assert_eq!(lines.len(), 6, "{lines:?}");
+        for (line, index) in lines[..3].iter().zip(1..) {
+            assert_eq!(
+                line,
+                &format!(
+                    "Using the decryption share and proof of guardian {index} ({}).",
+                    address(index)
+                )
+            );
+        }
+        assert_eq!(
+            lines[3],
+            format!(
+                "Not using the decryption data of guardian 4 ({}): no proof.",
+                address(4)
+            )
+        );
+    }
+
+    /// Write an initial weights file with the given `(account, micro CCD)`
+    /// rows to a temporary file.
+    fn write_initial_weights(name: &str, rows: &[(AccountAddress, u64)]) -> std::path::PathBuf {
+        let path = temp_path(name);
+        let mut writer = csv::Writer::from_path(&path).unwrap();
+        for &(account, amount) in rows {
+            writer
+                .serialize(WeightRow {
+                    account,
+                    amount: Amount::from_micro_ccd(amount),
+                })
+                .unwrap();
+        }
+        writer.flush().unwrap();
+        path
+    }
+
+    /// Weights files differing by one account are accepted if the difference
+    /// is within the tolerance, and rejected otherwise.
+    #[test]
+    fn test_compare_weights() {
+        let account = |n| AccountAddress([n; 32]);
+        let a = write_initial_weights("compare-weights-a.csv", &[
+            (account(1), 100_000_000),
+            (account(2), 50_000_000),
+        ]);
+        let b = write_initial_weights("compare-weights-b.csv", &[
+            (account(1), 100_000_000),
+            (account(2), 50_000_003),
+        ]);
+
+        handle_compare_weights(a.clone(), a.clone(), 0).expect("A file matches itself.");
+        handle_compare_weights(a.clone(), b.clone(), 3).expect("The difference is tolerated.");
+        let error = handle_compare_weights(a.clone(), b.clone(), 2).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("The weights of 1 accounts differ"),
+            "{error}"
+        );
+
+        // An account missing from one of the files has weight 0 there.
+        let missing =
+            write_initial_weights("compare-weights-missing.csv", &[(account(1), 100_000_000)]);
+        let error = handle_compare_weights(a, missing, 3).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("The weights of 1 accounts differ"),
+            "{error}"
+        );
+    }
+
+    /// The tally is decrypted with the decryption shares and proofs read from
+    /// files, as long as there are enough of them.
+    #[test]
+    fn test_decrypt_from_files() {
+        let mut csprng = util::csprng::Csprng::new(b"coordinator decrypt from files test");
+        let (election_data, context, secret_share) = single_guardian_election(&mut csprng);
+        let mut tally =
+            eg::ballot::BallotTallyBuilder::new(&election_data.manifest, &election_data.parameters);
+        for selections in [[false, true], [true, false], [false, true]] {
+            tally.update(test_ballot(&mut csprng, &context, selections));
+        }
+        let tally: EncryptedTally = tally.finalize();
+        let (share, proof) =
+            guardian_decryption(&mut csprng, &election_data, &context, &secret_share, &tally);
 
-    anyhow::ensure!(
-        !app.guardians.is_empty(),
-        "The set of guardians must have at least one address."
-    );
+        let shares_dir = temp_path("decrypt-from-files-shares");
+        let proofs_dir = temp_path("decrypt-from-files-proofs");
+        for dir in [&shares_dir, &proofs_dir] {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+        std::fs::write(shares_dir.join("guardian1.bin"), encode(&share).unwrap()).unwrap();
+        std::fs::write(proofs_dir.join("guardian1.bin"), encode(&proof).unwrap()).unwrap();
 
-    anyhow::ensure!(
-        !app.candidates.is_empty(),
-        "There must be at least one candidate."
-    );
+        let weights = decrypt_from_files(
+            &election_data,
+            tally.clone(),
+            &shares_dir,
+            &proofs_dir,
+            false,
+        )
+        .expect("The tally is decrypted");
+        assert_eq!(weights, [1, 2]);
 
-    // Construct the manifest and candidates.
-    let (options, candidates) = {
-        let mut candidates = Vec::with_capacity(app.candidates.len());
-        let mut options = Vec::with_capacity(app.candidates.len());
-        for candidate in app.candidates {
-            let (candidate_url, data) = match candidate {
-                CandidateLocation::Remote(url) => {
-                    let candidate_url = url.to_string();
-                    let r = reqwest::get(url)
-                        .await
-                        .context("Unable to get data for candidate.")?;
+        let empty_dir = temp_path("decrypt-from-files-empty");
+        std::fs::create_dir_all(&empty_dir).unwrap();
+        let error = decrypt_from_files(&election_data, tally, &empty_dir, &proofs_dir, false)
+            .expect_err("The tally cannot be decrypted without shares");
+        assert!(error.to_string().contains("Not enough shares"), "{error}");
+    }
 
-                    anyhow::ensure!(r.status().is_success(), "Unable to get data for candidate.");
-                    let data: Vec<_> = r.bytes().await?.into();
+    /// Tallying blocks in batches records the same ballots as tallying them one
+    /// at a time, including ballots replacing earlier ones of the same account.
+    #[tokio::test]
+    async fn test_block_batches() {
+        let mut csprng = util::csprng::Csprng::new(b"coordinator block batches test");
+        let (_, context, _) = single_guardian_election(&mut csprng);
+        let mut other_csprng = util::csprng::Csprng::new(b"other coordinator election");
+        let (_, other_context, _) = single_guardian_election(&mut other_csprng);
 
-                    (candidate_url, data)
+        let account = |n| AccountAddress([n; 32]);
+        let register_votes = |sender: AccountAddress, hash: u8, ballot: Vec<u8>| {
+            (
+                sender,
+                TransactionHash::new([hash; 32]),
+                concordium_std::to_bytes(&contract::RegisterVotesParameter::from(ballot)),
+            )
+        };
+        let mut ballot = |context: &PreVotingData, selections: [bool; 2]| {
+            encode(&test_ballot(&mut csprng, context, selections)).unwrap()
+        };
+        let blocks = vec![
+            vec![register_votes(
+                account(1),
+                1,
+                ballot(&context, [true, false]),
+            )],
+            vec![
+                register_votes(account(2), 2, ballot(&context, [false, true])),
+                // A ballot for another election does not verify.
+                register_votes(account(3), 3, ballot(&other_context, [true, false])),
+            ],
+            vec![],
+            // The latest ballot of an account is the one counted.
+            vec![register_votes(
+                account(1),
+                4,
+                ballot(&context, [false, true]),
+            )],
+            vec![register_votes(account(4), 5, vec![1, 2, 3])],
+        ];
+
+        let tally = |block_batch_size| {
+            let blocks = blocks.clone();
+            let context = &context;
+            async move {
+                let indexing = IndexingConfig {
+                    endpoint: sdk::Endpoint::from_static("http://localhost:20000"),
+                    max_blocks: None,
+                    block_batch_size,
+                };
+                let (sender, mut receiver) = tokio::sync::mpsc::channel(blocks.len());
+                for block in blocks {
+                    sender.send(block).await.unwrap();
                 }
-                CandidateLocation::Disk(path) => {
-                    let candidate_file = path
-                        .file_name()
-                        .and_then(OsStr::to_str)
-                        .with_context(|| format!("Invalid filename for path {:?}", &path))?;
-                    let candidate_url = make_url(format!("candidates/{}", candidate_file))?;
-                    let data = std::fs::read(&path).context("Unable to read voters file.")?;
+                drop(sender);
 
-                    (candidate_url, data)
+                let mut ballots = BTreeMap::new();
+                let mut batches = 0;
+                while let Some(batch) = indexing.recv_batch(&mut receiver).await {
+                    batches += 1;
+                    record_ballots(&mut ballots, batch.into_iter().flatten().collect(), context);
                 }
-            };
+                let ballots = ballots
+                    .into_iter()
+                    .map(|(account, (_, hash))| {
+                        let account: &AccountAddress = account.as_ref();
+                        (*account, hash)
+                    })
+                    .collect::<Vec<_>>();
+                (batches, ballots)
+            }
+        };
 
-            let hash = contract::HashSha2256(sha2::Sha256::digest(&data).into());
-            candidates.push(contract::ChecksumUrl {
-                url: candidate_url,
-                hash,
-            });
-            let candidate_meta = serde_json::from_slice::<CandidateMetadata>(&data)
-                .context("Unable to parse guardian's information")?;
-            options.push(eg::election_manifest::ContestOption {
-                label: candidate_meta.name,
-            });
+        let (batches, one_at_a_time) = tally(1).await;
+        assert_eq!(batches, 5);
+        assert_eq!(one_at_a_time, [
+            (account(1), TransactionHash::new([4; 32])),
+            (account(2), TransactionHash::new([2; 32])),
+        ]);
+        let (batches, batched) = tally(3).await;
+        assert_eq!(batches, 2);
+        assert_eq!(batched, one_at_a_time);
+    }
+
+    /// Nodes of chains with a protocol version older than the supported one
+    /// are rejected with a clear message.
+    #[test]
+    fn test_check_protocol_version() {
+        check_protocol_version(MIN_PROTOCOL_VERSION).expect("The minimum version is supported.");
+        check_protocol_version(ProtocolVersion::P7).expect("Newer versions are supported.");
+        let error = check_protocol_version(ProtocolVersion::P5).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The chain has protocol version P5, but at least version P6 is required. Use a node \
+             of a chain with a newer protocol version."
+        );
+    }
+
+    /// The computation log lists every ballot included in the tally with the
+    /// factor it is scaled by, and leaves out ballots of accounts without a
+    /// final weight.
+    #[test]
+    fn test_tally_computation_log() {
+        let mut csprng = util::csprng::Csprng::new(b"coordinator computation log test");
+        let (election_data, context, _) = single_guardian_election(&mut csprng);
+        let account = |n| AccountAddress([n; 32]);
+        let hash = |n| TransactionHash::new([n; 32]);
+        let mut ballots = BTreeMap::new();
+        for n in 1..=3 {
+            ballots.insert(
+                AccountAddressEq::from(account(n)),
+                (test_ballot(&mut csprng, &context, [true, false]), hash(n)),
+            );
         }
-        (options, candidates)
-    };
+        let delegators = format!("{};{}", account(5), account(6));
+        let final_weights = BTreeMap::from([
+            (
+                AccountAddressEq::from(account(1)),
+                (Amount::from_micro_ccd(250_500_000), delegators),
+            ),
+            (
+                AccountAddressEq::from(account(2)),
+                (Amount::from_ccd(40), String::new()),
+            ),
+            // The account did not vote.
+            (
+                AccountAddressEq::from(account(4)),
+                (Amount::from_ccd(100), String::new()),
+            ),
+        ]);
 
-    let manifest_hash = {
-        let contest = eg::election_manifest::Contest {
-            label:           "Governance committee member".into(),
-            selection_limit: options.len(),
-            options:         options.try_into()?,
-        };
+        let (tally, stats, log) =
+            scale_ballots(&election_data, ballots, final_weights, MICRO_CCD_PER_CCD);
+        let log: Vec<_> = log
+            .into_iter()
+            .map(|entry| {
+                (
+                    entry.account,
+                    entry.transaction_hash,
+                    entry.amount,
+                    entry.scaling_factor,
+                    entry.delegators,
+                )
+            })
+            .collect();
+        assert_eq!(log, [
+            (
+                account(1),
+                hash(1),
+                Amount::from_micro_ccd(250_500_000),
+                250,
+                vec![account(5).to_string(), account(6).to_string()]
+            ),
+            (account(2), hash(2), Amount::from_ccd(40), 40, Vec::new()),
+        ]);
+        assert_eq!(stats.ballots, 2);
+        assert_eq!(stats.weighted_ballots, 290);
+        let contest = ContestIndex::from_one_based_index(1).unwrap();
+        assert_eq!(tally[&contest].len(), 2);
+    }
 
-        let manifest = eg::election_manifest::ElectionManifest {
-            label:         "Governance commitee election 2024 election manifest".into(),
-            contests:      [contest].try_into()?,
-            ballot_styles: [eg::ballot_style::BallotStyle {
-                label:    "Governance committee vote".into(),
-                contests: [ContestIndex::from_one_based_index_const(1).unwrap()].into(),
+    /// The cryptographic steps of the self-test with two guardians, run
+    /// without a node, give a decrypted result matching the weighted votes
+    /// cast.
+    #[test]
+    fn test_self_test_result() {
+        let seed = "coordinator self-test";
+        let candidates = 3;
+        let parameters = test_parameters(2, 2);
+        let mut csprng = util::csprng::Csprng::new(seed.as_bytes());
+
+        let secret_keys: Vec<_> = (1..=2)
+            .map(|g| {
+                let index = GuardianIndex::from_one_based_index(g).unwrap();
+                GuardianSecretKey::generate(&mut csprng, &parameters, index, None)
+            })
+            .collect();
+        let public_keys: Vec<_> = secret_keys.iter().map(|k| k.make_public_key()).collect();
+        let shares: Vec<Vec<_>> = secret_keys
+            .iter()
+            .map(|dealer_key| {
+                public_keys
+                    .iter()
+                    .map(|recipient_key| {
+                        GuardianEncryptedShare::encrypt(
+                            &mut csprng,
+                            &parameters,
+                            dealer_key,
+                            recipient_key,
+                        )
+                        .ciphertext
+                    })
+                    .collect()
+            })
+            .collect();
+        let secret_shares: Vec<_> = secret_keys
+            .iter()
+            .enumerate()
+            .map(|(recipient, secret_key)| {
+                let key_shares: Vec<_> = shares
+                    .iter()
+                    .map(|dealer_shares| dealer_shares[recipient].clone())
+                    .collect();
+                GuardianSecretKeyShare::compute(&parameters, &public_keys, &key_shares, secret_key)
+                    .unwrap()
+            })
+            .collect();
+
+        let options: Vec<_> = (0..candidates)
+            .map(|c| eg::election_manifest::ContestOption {
+                label: format!("Candidate {c}"),
+            })
+            .collect();
+        let manifest = ElectionManifest {
+            label:         "Self-test election manifest".into(),
+            contests:      [eg::election_manifest::Contest {
+                label:           "Self-test contest".into(),
+                selection_limit: candidates,
+                options:         options.try_into().unwrap(),
             }]
-            .try_into()?,
+            .try_into()
+            .unwrap(),
+            ballot_styles: [BallotStyle {
+                label:    "Self-test ballot".into(),
+                contests: [ContestIndex::from_one_based_index(1).unwrap()].into(),
+            }]
+            .try_into()
+            .unwrap(),
         };
-        let manifest_json = serde_json::to_vec_pretty(&manifest)?;
-        let digest: [u8; 32] = sha2::Sha256::digest(&manifest_json).into();
-        let manifest_path = app.out.join("election-manifest.json");
-        std::fs::write(manifest_path, manifest_json)?;
-        contract::HashSha2256(digest)
-    };
+        let election_data = ElectionData {
+            contest_sizes: vec![candidates as u32],
+            ..test_election_data(manifest, parameters, public_keys)
+        };
+        let context = election_data.verification_context().unwrap();
 
-    let parameters_hash = {
-        let n = GuardianIndex::from_one_based_index(app.guardians.len().try_into()?)
-            .context("Need at least one guardian.")?;
+        let device = Device::new("self-test", context.clone());
+        let account = |n| AccountAddressEq::from(AccountAddress([n; 32]));
+        let mut ballots = BTreeMap::new();
+        let mut final_weights = BTreeMap::new();
+        let mut expected = vec![0u64; candidates];
+        for voter in 0..3 {
+            let selections = self_test_selections(seed, voter, candidates);
+            let weight = voter as u64 + 1;
+            for (total, &selection) in expected.iter_mut().zip(&selections) {
+                *total += weight * u64::from(selection);
+            }
+            let ballot = self_test_ballot(&mut csprng, &device, seed, voter, selections).unwrap();
+            assert!(ballot.verify(&context));
+            ballots.insert(
+                account(voter as u8),
+                (ballot, TransactionHash::new([voter as u8; 32])),
+            );
+            final_weights.insert(
+                account(voter as u8),
+                (Amount::from_ccd(weight), String::new()),
+            );
+        }
+        let (tally, stats, _) =
+            scale_ballots(&election_data, ballots, final_weights, MICRO_CCD_PER_CCD);
+        assert_eq!(stats.ballots, 3);
 
-        let k = GuardianIndex::from_one_based_index_const(app.threshold)
-            .context("Threshold must be at least 1.")?;
-        anyhow::ensure!(
-            k <= n,
-            "Threshold must be less than total number of guardians."
+        let (decryptions, states): (Vec<_>, Vec<_>) = secret_shares
+            .iter()
+            .map(|secret_share| {
+                self_test_decryption_share(
+                    &mut csprng,
+                    &election_data.parameters,
+                    secret_share,
+                    &tally,
+                )
+            })
+            .unzip();
+        let proofs: Vec<_> = secret_shares
+            .iter()
+            .zip(&states)
+            .map(|(secret_share, states)| {
+                self_test_proof_response(&context, secret_share, &tally, &decryptions, states)
+                    .unwrap()
+            })
+            .collect();
+
+        let decryption = decrypt_ciphertexts(
+            &election_data,
+            tally,
+            &decryptions,
+            &proofs,
+            &Progress::new(candidates as u64, false).unwrap(),
+            &ProgressNotifier::default(),
+        )
+        .expect("The tally is decrypted");
+        let result = flatten_result(&decrypted_weights(&election_data, decryption).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    /// The latest valid ballot of an account is counted, superseding its
+    /// earlier valid ballots, while invalid ballots are never counted.
+    #[test]
+    fn test_counted_ballot() {
+        let time = |secs| chrono::Utc.timestamp_opt(secs, 0).unwrap();
+        let hash = |n| TransactionHash::new([n; 32]);
+        let submitted = [
+            (time(10), hash(1), true),
+            (time(20), hash(2), false),
+            (time(30), hash(3), true),
+            (time(40), hash(4), false),
+        ];
+        assert_eq!(counted_ballot(&submitted), Some((hash(3), 1)));
+        assert_eq!(counted_ballot(&submitted[..1]), Some((hash(1), 0)));
+        assert_eq!(counted_ballot(&[(time(20), hash(2), false)]), None);
+        assert_eq!(counted_ballot(&[]), None);
+    }
+
+    #[test]
+    fn test_output_layout() {
+        let base_url = url::Url::parse("https://example.com/election/").unwrap();
+        let cases = [
+            (OutputLayout::Flat, "layout-flat", [
+                "election-manifest.json",
+                "election-parameters.json",
+                "eligible-voters.json",
+                "candidates/alice.json",
+            ]),
+            (OutputLayout::Static, "layout-static", [
+                "static/electionguard/election-manifest.json",
+                "static/electionguard/election-parameters.json",
+                "static/concordium/eligible-voters.json",
+                "candidates/alice.json",
+            ]),
+        ];
+        for (layout, name, expected) in cases {
+            let out = temp_path(name);
+            let paths = [
+                layout.manifest_path(),
+                layout.parameters_path(),
+                layout.eligible_voters_path("eligible-voters.json"),
+                layout.candidate_path("alice.json"),
+            ];
+            assert_eq!(paths, expected.map(String::from), "{layout:?}");
+            for path in &paths {
+                write_output(&out, path, path.as_bytes()).unwrap();
+            }
+            for path in expected {
+                // The files are served under the base URL at the same relative path.
+                let file = out.join(path);
+                assert_eq!(std::fs::read(&file).unwrap(), path.as_bytes(), "{layout:?}");
+                assert_eq!(
+                    base_url.join(path).unwrap().as_str(),
+                    format!("https://example.com/election/{path}")
+                );
+            }
+        }
+        assert!(!OutputLayout::Flat.copy_inputs());
+        assert!(OutputLayout::Static.copy_inputs());
+    }
+
+    #[tokio::test]
+    async fn test_indexing_benchmark() {
+        let mut csprng = util::csprng::Csprng::new(b"coordinator indexing benchmark test");
+        let (_, context, _) = single_guardian_election(&mut csprng);
+        let mut ballot = |selections: [bool; 2]| {
+            let ballot = encode(&test_ballot(&mut csprng, &context, selections)).unwrap();
+            concordium_std::to_bytes(&contract::RegisterVotesParameter::from(ballot))
+        };
+        let blocks = vec![
+            vec![ballot([true, false])],
+            vec![],
+            vec![ballot([false, true]), vec![1, 2, 3]],
+            vec![ballot([true, false])],
+        ];
+        let indexing = IndexingConfig {
+            endpoint:         sdk::Endpoint::from_static("http://localhost:20000"),
+            max_blocks:       None,
+            block_batch_size: 2,
+        };
+
+        let benchmark = |max_blocks| {
+            let blocks = blocks.clone();
+            let (indexing, context) = (&indexing, &context);
+            async move {
+                let (sender, mut receiver) = tokio::sync::mpsc::channel(blocks.len());
+                for block in blocks {
+                    sender.send(block).await.unwrap();
+                }
+                drop(sender);
+                IndexingBenchmark::run(indexing, &mut receiver, max_blocks, context, |block| block)
+                    .await
+            }
+        };
+
+        let all = benchmark(10).await;
+        assert_eq!(all.blocks, 4);
+        assert_eq!(all.ballots, 4);
+        assert_eq!(all.valid, 3, "Malformed parameters are not valid ballots.");
+        assert!(all.elapsed > Duration::ZERO);
+        assert!(all.verification_time > Duration::ZERO);
+        assert!(all.blocks_per_second() > 0.0);
+        assert!(all.ballots_per_second() > 0.0);
+        let report = all.report();
+        assert_eq!(report.len(), 4);
+        assert!(
+            report[0].starts_with("Indexed 4 blocks containing 4 ballots, 3 of which are valid")
         );
 
-        let parameters = eg::election_parameters::ElectionParameters {
-            fixed_parameters:   eg::standard_parameters::STANDARD_PARAMETERS.clone(),
-            varying_parameters: eg::varying_parameters::VaryingParameters {
-                n,
-                k,
-                date: chrono::Utc::now(),
-                info: format!(
-                    "Governance committee election from {} to {} with {k} out of {n} threshold.",
-                    app.election_start, app.election_end
+        // Indexing stops after the requested number of blocks.
+        let partial = benchmark(2).await;
+        assert_eq!(partial.blocks, 2);
+        assert_eq!(partial.ballots, 1);
+        assert_eq!(partial.valid, 1);
+        assert!(partial.blocks_per_second() > 0.0);
+    }
+
+    #[test]
+    fn test_check_admin_balance() {
+        let admin = AccountAddress([1; 32]);
+        let missing = check_admin_balance(admin, None).unwrap_err().to_string();
+        assert!(missing.contains("does not exist"), "{missing}");
+        assert!(missing.contains(&admin.to_string()), "{missing}");
+        let empty = check_admin_balance(admin, Some(Amount::zero()))
+            .unwrap_err()
+            .to_string();
+        assert!(empty.contains("has no CCD"), "{empty}");
+        check_admin_balance(admin, Some(Amount::from_ccd(1))).unwrap();
+    }
+
+    /// Scaling the ballots with a unit smaller than a CCD takes fractional
+    /// balances into account, and the unit is recorded in the computation log.
+    #[test]
+    fn test_tally_weight_unit() {
+        let mut csprng = util::csprng::Csprng::new(b"coordinator weight unit test");
+        let (election_data, context, _) = single_guardian_election(&mut csprng);
+        let account = |n| AccountAddressEq::from(AccountAddress([n; 32]));
+        let mut ballots = BTreeMap::new();
+        for n in 1..=2 {
+            ballots.insert(
+                account(n),
+                (
+                    test_ballot(&mut csprng, &context, [true, false]),
+                    TransactionHash::new([n; 32]),
                 ),
-                ballot_chaining: eg::varying_parameters::BallotChaining::Prohibited,
-            },
+            );
+        }
+        let final_weights = BTreeMap::from([
+            (
+                account(1),
+                (Amount::from_micro_ccd(1_999_999), String::new()),
+            ),
+            (account(2), (Amount::from_micro_ccd(500_000), String::new())),
+        ]);
+
+        let scale = |weight_unit| {
+            let (_, stats, log) = scale_ballots(
+                &election_data,
+                ballots.clone(),
+                final_weights.clone(),
+                weight_unit,
+            );
+            let factors = log
+                .iter()
+                .map(|entry| entry.scaling_factor)
+                .collect::<Vec<_>>();
+            let log = TallyComputationLog {
+                ballots: log,
+                weight_unit,
+                tally_hash: contract::HashSha2256([0; 32]),
+            };
+            let log = serde_json::to_value(&log).unwrap();
+            assert_eq!(log["weightUnit"], weight_unit);
+            (stats, factors)
         };
-        let parameters_json = serde_json::to_vec_pretty(&parameters)?;
-        let digest: [u8; 32] = sha2::Sha256::digest(&parameters_json).into();
-        let parameters_path = app.out.join("election-parameters.json");
-        std::fs::write(parameters_path, parameters_json)?;
-        contract::HashSha2256(digest)
-    };
 
-    let eligible_voters_hash = {
-        let data = std::fs::read(&app.voters_file).context("Unable to read voters file.")?;
-        contract::HashSha2256(sha2::Sha256::digest(data).into())
-    };
+        // With whole CCD, the fractions are discarded, and the second ballot
+        // carries no weight.
+        let (whole, whole_factors) = scale(MICRO_CCD_PER_CCD);
+        assert_eq!(whole_factors, [1, 0]);
+        assert_eq!(whole.weighted_ballots, 1);
 
-    let eligible_voters_filename = app
-        .voters_file
-        .file_name()
-        .context("voters-file must be a path to a file")?
-        .to_str()
-        .context("voters-file path is not valid unicode")?
+        let (milli, milli_factors) = scale(1_000);
+        assert_eq!(milli_factors, [1_999, 500]);
+        assert_eq!(milli.weighted_ballots, 2_499);
+        assert_eq!(milli.ballots, whole.ballots);
+    }
+
+    /// Interrupting the indexing of `initial-weights` stops it before the next
+    /// block, and the partial weights are written with a hint on resuming.
+    #[tokio::test]
+    async fn test_interrupted_indexing() {
+        let block = |height: u64| {
+            (
+                AbsoluteBlockHeight::from(height),
+                chrono::Utc
+                    .timestamp_opt(1_700_000_000 + height as i64, 0)
+                    .unwrap(),
+            )
+        };
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(10);
+        for height in 11..=15 {
+            sender.send(block(height)).await.unwrap();
+        }
+        drop(sender);
+
+        let (first_height, first_time) = block(10);
+        let mut progress = InterruptibleIndexing::new(first_height, first_time);
+        let mut processed = Vec::new();
+        let mut interrupted_at = None;
+        while let Some((height, time)) = receiver.recv().await {
+            if let Some(last_processed) = progress.interrupted_at() {
+                interrupted_at = Some(last_processed);
+                break;
+            }
+            processed.push(height);
+            progress.processed(height, time);
+            if height == AbsoluteBlockHeight::from(12) {
+                // The interrupt arrives while the block is processed.
+                progress.interrupted.store(true, Ordering::Release);
+            }
+        }
+        assert_eq!(processed, [11, 12].map(AbsoluteBlockHeight::from));
+        assert_eq!(interrupted_at, Some(block(12)));
+
+        let out = temp_path("interrupted-indexing");
+        std::fs::create_dir_all(&out).unwrap();
+        let (last_height, end) = block(20);
+        let error = write_partial_initial_weights(
+            &out,
+            b"account,amount\n",
+            interrupted_at.unwrap(),
+            end,
+            last_height,
+        )
+        .unwrap()
         .to_string();
-    let voters_params_file = std::fs::File::open(app.voters_params_file)
-        .context("Failed to open `voters-params-file`.")?;
-    let voters_params: contract::EligibleVotersParameters =
-        serde_json::from_reader(&voters_params_file)
-            .context("Failed to deserialize voters params")?;
+        assert!(
+            error.starts_with(&format!(
+                "Interrupted after processing block 12 at {}.",
+                block(12).1
+            )),
+            "{error}"
+        );
+        assert!(error.contains("Run the command again"), "{error}");
+        assert!(error.contains("i.e. block 20."), "{error}");
 
-    let init_param = contract::InitParameter {
-        admin_account: wallet.address,
-        candidates,
-        guardians: app.guardians,
-        eligible_voters: contract::EligibleVoters {
-            parameters: voters_params,
-            data:       contract::ChecksumUrl {
-                url:  make_url(eligible_voters_filename)?,
-                hash: eligible_voters_hash,
-            },
-        },
-        election_manifest: contract::ChecksumUrl {
-            url:  make_url("election-manifest.json".to_string())?,
-            hash: manifest_hash,
-        },
-        election_parameters: contract::ChecksumUrl {
-            url:  make_url("election-parameters.json".to_string())?,
-            hash: parameters_hash,
-        },
-        election_description: app.election_description,
-        election_start: app.election_start.try_into()?,
-        election_end: app.election_end.try_into()?,
-        decryption_deadline: app.decryption_deadline.try_into()?,
-        delegation_string: app.delegation_string,
-    };
+        let partial = out.join("initial-weights.partial.csv");
+        assert_eq!(std::fs::read(&partial).unwrap(), b"account,amount\n");
+        let rejected = check_csv_metadata(&partial, &INITIAL_WEIGHTS_COLUMNS)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            rejected.contains("interrupted after block 12"),
+            "{rejected}"
+        );
+    }
 
-    let param = concordium_std::OwnedParameter::from_serial(&init_param)?; // Example
-    let param_json = contract::InitParameter::get_type()
-        .to_json_string_pretty(&concordium_std::to_bytes(&init_param))?;
-    eprintln!("JSON parameter that will be used to initialize the contract.");
-    println!("{}", param_json);
+    #[test]
+    fn test_guardian_account_problems() {
+        let guardian = AccountAddress([1; 32]);
+        let mut alias = guardian;
+        alias.0[31] = 7;
+        let other = AccountAddress([2; 32]);
+        let missing = AccountAddress([3; 32]);
+        let existing = BTreeSet::from([guardian, other].map(AccountAddressEq::from));
 
-    let confirm = dialoguer::Confirm::new()
-        .report(true)
-        .wait_for_newline(true)
-        .with_prompt("Do you want to initialize the contract?")
-        .interact()?;
-    anyhow::ensure!(confirm, "Aborting.");
+        assert!(guardian_account_problems(&[guardian, other], &existing).is_empty());
+        assert_eq!(
+            guardian_account_problems(&[guardian, missing, other, guardian, alias], &existing),
+            [
+                format!("The guardian account {missing} does not exist."),
+                format!("The guardian account {guardian} is listed more than once."),
+                format!(
+                    "The guardian accounts {guardian} and {alias} are aliases of the same account."
+                ),
+            ]
+        );
+    }
 
-    let payload = transactions::InitContractPayload {
-        init_name: OwnedContractName::new("init_election".into())?,
-        amount: Amount::from_micro_ccd(0),
-        mod_ref: module_ref,
-        param,
-    };
+    /// The roster lists the same accounts and weights as the final weights
+    /// computed from the same initial weights and delegations.
+    #[test]
+    fn test_roster_matches_final_weights() {
+        use sha2::Digest as _;
 
-    let nonce = client
-        .get_next_account_sequence_number(&wallet.address)
-        .await?;
+        let account = |n| AccountAddress([n; 32]);
+        let initial_weights = write_initial_weights("roster-initial-weights.csv", &[
+            (account(1), 1_000_000),
+            (account(2), 2_500_000),
+            (account(3), 3_000_000),
+            (account(4), 0),
+        ]);
+        let mapping = Delegations::from([
+            (
+                AccountAddressEq::from(account(1)),
+                (TransactionHash::new([1; 32]), account(3)),
+            ),
+            (
+                AccountAddressEq::from(account(2)),
+                (TransactionHash::new([2; 32]), account(3)),
+            ),
+        ]);
+        let compute = || {
+            compute_final_weights(
+                read_initial_weights(&[initial_weights.clone()]).unwrap(),
+                &mapping,
+            )
+        };
 
-    let at = transactions::send::init_contract(
-        &wallet,
-        wallet.address,
-        nonce.nonce,
-        TransactionTime::hours_after(1),
-        payload,
-        20_000.into(),
-    );
-    let tx_hash = client.send_account_transaction(at).await?;
-    eprintln!("Submitted transaction with hash {tx_hash}.");
-    let (_, result) = client
-        .wait_until_finalized(&tx_hash)
-        .await
-        .context("Failed to initialize contract instance.")?;
-    let result = result
-        .contract_init()
-        .context("Unexpected response from transaction.")?;
+        let final_weights_path = temp_path("roster-final-weights.csv");
+        write_final_weights_csv(&final_weights_path, compute()).unwrap();
+        let roster_path = temp_path("roster.csv");
+        let hash = write_roster(&roster_path, compute()).unwrap();
 
-    eprintln!(
-        "Deployed new contract instance with address {} using transaction hash {}.",
-        result.address, tx_hash
-    );
-    Ok(())
+        let final_weights = read_csv(&final_weights_path, &FINAL_WEIGHTS_COLUMNS);
+        let roster = read_csv(&roster_path, &ROSTER_COLUMNS);
+        assert_eq!(
+            roster,
+            final_weights
+                .iter()
+                .map(|row| row[..2].to_vec())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(roster, [
+            vec![account(3).to_string(), "6500000".to_string()],
+            vec![account(4).to_string(), "0".to_string()],
+        ]);
+        assert_eq!(
+            hash.0,
+            <[u8; 32]>::from(sha2::Sha256::digest(std::fs::read(&roster_path).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_progress_without_bars() {
+        let progress = Progress::new(3, false).unwrap();
+        assert!(progress.bar.is_hidden(), "No progress bar is drawn");
+        progress.enable_steady_tick(Duration::from_millis(1));
+        progress.set_message("decrypting");
+        progress.inc(2);
+        let line = progress.log_line();
+        assert_eq!(line, "Progress: 2/3 decrypting");
+        assert!(
+            !line.contains('\x1b'),
+            "The logged progress contains no terminal escape codes"
+        );
+        assert!(progress.last_logged.is_some(), "Progress is logged instead");
+    }
+
+    /// A balance series over ten hours ending at the returned time. The
+    /// account holds 100 CCD, except for a spike to 320 CCD held for two hours,
+    /// so its time-weighted average is 144 CCD.
+    fn balance_series() -> (
+        Vec<(chrono::DateTime<chrono::Utc>, Amount)>,
+        chrono::DateTime<chrono::Utc>,
+    ) {
+        let start = chrono::Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let hours = |n| start + chrono::Duration::hours(n);
+        let series = vec![
+            (start, Amount::from_ccd(100)),
+            (hours(6), Amount::from_ccd(320)),
+            (hours(8), Amount::from_ccd(100)),
+        ];
+        (series, hours(10))
+    }
+
+    #[test]
+    fn test_linear_average_strategy() {
+        let (series, end) = balance_series();
+        assert_eq!(
+            LinearAverage.weight(&series, end).unwrap(),
+            Amount::from_ccd(144)
+        );
+        let constant = [(series[0].0, Amount::from_ccd(7))];
+        assert_eq!(
+            LinearAverage.weight(&constant, end).unwrap(),
+            Amount::from_ccd(7),
+            "A constant balance is its own average"
+        );
+        assert!(LinearAverage.weight(&[], end).is_err());
+    }
+
+    #[test]
+    fn test_weight_metrics_with_spike() {
+        let (series, end) = balance_series();
+        let weight = |kind: WeightStrategyKind| kind.strategy().weight(&series, end).unwrap();
+        assert_eq!(
+            weight(WeightStrategyKind::LinearAverage),
+            Amount::from_ccd(144),
+            "The spike raises the average"
+        );
+        assert_eq!(
+            weight(WeightStrategyKind::Median),
+            Amount::from_ccd(100),
+            "The spike is held for less than half of the window"
+        );
+        assert_eq!(weight(WeightStrategyKind::Min), Amount::from_ccd(100));
+
+        // A "spike" held for most of the window is the median, but not the minimum.
+        let start = series[0].0;
+        let held = [
+            (start, Amount::from_ccd(100)),
+            (start + chrono::Duration::hours(4), Amount::from_ccd(320)),
+        ];
+        assert_eq!(Median.weight(&held, end).unwrap(), Amount::from_ccd(320));
+        assert_eq!(Min.weight(&held, end).unwrap(), Amount::from_ccd(100));
+        assert!(Median.weight(&[], end).is_err());
+        assert!(Min.weight(&[], end).is_err());
+    }
+
+    #[test]
+    fn test_quadratic_strategy() {
+        let (series, end) = balance_series();
+        assert_eq!(
+            Quadratic.weight(&series, end).unwrap(),
+            Amount::from_ccd(12),
+            "The weight is the root of the average balance in CCD"
+        );
+        let fraction = [(series[0].0, Amount::from_micro_ccd(2_000_000))];
+        assert_eq!(
+            Quadratic.weight(&fraction, end).unwrap(),
+            Amount::from_micro_ccd(1_414_213),
+            "The root is rounded down to whole micro CCD"
+        );
+        assert!(
+            WeightStrategyKind::Quadratic
+                .strategy()
+                .weight(&series, end)
+                .unwrap()
+                < WeightStrategyKind::LinearAverage
+                    .strategy()
+                    .weight(&series, end)
+                    .unwrap()
+        );
+    }
 }
@@ -0,0 +1,330 @@
+//! Typed wrappers around the view entrypoints of the election contract. These
+//! allow consumers of the election contract to query it without re-specifying
+//! the entrypoint names and the corresponding return types.
+
+use concordium_base::contracts_common::AccountAddress;
+use concordium_governance_committee_election::{
+    CanDecrypt, ElectionConfig, ElectionPhase, GuardianProgress, GuardianState, GuardiansState,
+    HashSha2256, ResultProposal, ViewContestResultsQueryResponse, ViewElectionResultQueryResponse,
+    ViewGuardiansStateRangeParameter,
+};
+use concordium_rust_sdk::{
+    contract_client::{ContractClient, ViewError},
+    v2::IntoBlockIdentifier,
+};
+
+/// The name of the entrypoint returning the [`ElectionConfig`].
+pub const CONFIG_VIEW: &str = "viewConfig";
+/// The name of the entrypoint returning the [`GuardiansState`].
+pub const GUARDIANS_VIEW: &str = "viewGuardiansState";
+/// The name of the entrypoint returning the [`GuardianState`] of a single
+/// guardian.
+pub const GUARDIAN_VIEW: &str = "viewGuardian";
+/// The name of the entrypoint returning the [`GuardiansState`] of a window of
+/// guardian indices.
+pub const GUARDIANS_RANGE_VIEW: &str = "viewGuardiansStateRange";
+/// The name of the entrypoint returning the [`GuardianProgress`].
+pub const GUARDIAN_PROGRESS_VIEW: &str = "viewGuardianProgress";
+/// The name of the entrypoint returning [`CanDecrypt`].
+pub const CAN_DECRYPT_VIEW: &str = "viewCanDecrypt";
+/// The name of the entrypoint returning the [`ElectionPhase`].
+pub const ELECTION_PHASE_VIEW: &str = "viewElectionPhase";
+/// The name of the entrypoint returning the encrypted tally.
+pub const ENCRYPTED_TALLY_VIEW: &str = "viewEncryptedTally";
+/// The name of the entrypoint returning the hash of the encrypted tally.
+pub const ENCRYPTED_TALLY_HASH_VIEW: &str = "viewEncryptedTallyHash";
+/// The name of the entrypoint returning the election result.
+pub const RESULT_VIEW: &str = "viewElectionResult";
+/// The name of the entrypoint returning the hash of the election result.
+pub const RESULT_HASH_VIEW: &str = "viewElectionResultHash";
+/// The name of the entrypoint returning the election result grouped by contest.
+pub const CONTEST_RESULTS_VIEW: &str = "viewContestResults";
+/// The name of the entrypoint returning the [`ResultProposal`].
+pub const RESULT_PROPOSAL_VIEW: &str = "viewResultProposal";
+
+/// Gets the [`ElectionConfig`] from the contract at the block identified by
+/// `block`.
+pub async fn view_config<Type>(
+    client: &mut ContractClient<Type>,
+    block: impl IntoBlockIdentifier,
+) -> Result<ElectionConfig, ViewError> {
+    client
+        .view::<_, ElectionConfig, ViewError>(CONFIG_VIEW, &(), block)
+        .await
+}
+
+/// Gets the [`GuardiansState`] from the contract at the block identified by
+/// `block`.
+pub async fn view_guardians_state<Type>(
+    client: &mut ContractClient<Type>,
+    block: impl IntoBlockIdentifier,
+) -> Result<GuardiansState, ViewError> {
+    client
+        .view::<_, GuardiansState, ViewError>(GUARDIANS_VIEW, &(), block)
+        .await
+}
+
+/// Gets the [`GuardianState`] of the guardian with account address `guardian`
+/// from the contract at the block identified by `block`, or `None` if the
+/// account is not a guardian.
+pub async fn view_guardian<Type>(
+    client: &mut ContractClient<Type>,
+    guardian: &AccountAddress,
+    block: impl IntoBlockIdentifier,
+) -> Result<Option<GuardianState>, ViewError> {
+    client
+        .view::<_, Option<GuardianState>, ViewError>(GUARDIAN_VIEW, guardian, block)
+        .await
+}
+
+/// Gets the [`GuardiansState`] of the (at most `limit`) guardians with an index
+/// of at least `start_index` from the contract at the block identified by
+/// `block`.
+pub async fn view_guardians_state_range<Type>(
+    client: &mut ContractClient<Type>,
+    start_index: u32,
+    limit: u32,
+    block: impl IntoBlockIdentifier,
+) -> Result<GuardiansState, ViewError> {
+    let param: ViewGuardiansStateRangeParameter = (start_index, limit);
+    client
+        .view::<_, GuardiansState, ViewError>(GUARDIANS_RANGE_VIEW, &param, block)
+        .await
+}
+
+/// Gets the [`GuardianProgress`] from the contract at the block identified by
+/// `block`.
+pub async fn view_guardian_progress<Type>(
+    client: &mut ContractClient<Type>,
+    block: impl IntoBlockIdentifier,
+) -> Result<GuardianProgress, ViewError> {
+    client
+        .view::<_, GuardianProgress, ViewError>(GUARDIAN_PROGRESS_VIEW, &(), block)
+        .await
+}
+
+/// Gets [`CanDecrypt`] from the contract at the block identified by `block`,
+/// reporting whether enough guardians have contributed to the decryption.
+pub async fn view_can_decrypt<Type>(
+    client: &mut ContractClient<Type>,
+    block: impl IntoBlockIdentifier,
+) -> Result<CanDecrypt, ViewError> {
+    client
+        .view::<_, CanDecrypt, ViewError>(CAN_DECRYPT_VIEW, &(), block)
+        .await
+}
+
+/// Gets the [`ElectionPhase`] from the contract at the block identified by
+/// `block`.
+pub async fn view_election_phase<Type>(
+    client: &mut ContractClient<Type>,
+    block: impl IntoBlockIdentifier,
+) -> Result<ElectionPhase, ViewError> {
+    client
+        .view::<_, ElectionPhase, ViewError>(ELECTION_PHASE_VIEW, &(), block)
+        .await
+}
+
+/// Gets the serialized encrypted tally (if registered) from the contract at the
+/// block identified by `block`. The value is expected to be decodable as an
+/// [`EncryptedTally`](crate::EncryptedTally) through [`decode`](crate::decode).
+pub async fn view_encrypted_tally<Type>(
+    client: &mut ContractClient<Type>,
+    block: impl IntoBlockIdentifier,
+) -> Result<Option<Vec<u8>>, ViewError> {
+    client
+        .view::<_, Option<Vec<u8>>, ViewError>(ENCRYPTED_TALLY_VIEW, &(), block)
+        .await
+}
+
+/// Gets the SHA256 hash of the serialized encrypted tally (if registered) from
+/// the contract at the block identified by `block`.
+pub async fn view_encrypted_tally_hash<Type>(
+    client: &mut ContractClient<Type>,
+    block: impl IntoBlockIdentifier,
+) -> Result<Option<HashSha2256>, ViewError> {
+    client
+        .view::<_, Option<HashSha2256>, ViewError>(ENCRYPTED_TALLY_HASH_VIEW, &(), block)
+        .await
+}
+
+/// Gets the SHA256 hash of the election result (if registered) from the
+/// contract at the block identified by `block`.
+pub async fn view_election_result_hash<Type>(
+    client: &mut ContractClient<Type>,
+    block: impl IntoBlockIdentifier,
+) -> Result<Option<HashSha2256>, ViewError> {
+    client
+        .view::<_, Option<HashSha2256>, ViewError>(RESULT_HASH_VIEW, &(), block)
+        .await
+}
+
+/// Gets the election result (if registered) from the contract at the block
+/// identified by `block`.
+pub async fn view_election_result<Type>(
+    client: &mut ContractClient<Type>,
+    block: impl IntoBlockIdentifier,
+) -> Result<ViewElectionResultQueryResponse, ViewError> {
+    client
+        .view::<_, ViewElectionResultQueryResponse, ViewError>(RESULT_VIEW, &(), block)
+        .await
+}
+
+/// Gets the election result (if any) grouped by the contests of the election
+/// from the contract at the block identified by `block`.
+pub async fn view_contest_results<Type>(
+    client: &mut ContractClient<Type>,
+    block: impl IntoBlockIdentifier,
+) -> Result<ViewContestResultsQueryResponse, ViewError> {
+    client
+        .view::<_, ViewContestResultsQueryResponse, ViewError>(CONTEST_RESULTS_VIEW, &(), block)
+        .await
+}
+
+/// Gets the election result proposed for approval (if any) from the contract
+/// at the block identified by `block`.
+pub async fn view_result_proposal<Type>(
+    client: &mut ContractClient<Type>,
+    block: impl IntoBlockIdentifier,
+) -> Result<Option<ResultProposal>, ViewError> {
+    client
+        .view::<_, Option<ResultProposal>, ViewError>(RESULT_PROPOSAL_VIEW, &(), block)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use concordium_base::contracts_common::{
+        from_bytes,
+        schema::{SchemaType, Type, VersionedModuleSchema},
+    };
+
+    /// The election contract module with embedded schema, built as described
+    /// in the README of the contract.
+    const MODULE_PATH: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../contracts/concordium-governance-committee-election/concordium-out/module.wasm.v1"
+    );
+
+    /// Reads an unsigned LEB128 integer from the start of `bytes`, as used for
+    /// the sizes in the binary format of wasm modules.
+    fn read_leb128(bytes: &mut &[u8]) -> usize {
+        let mut value = 0;
+        for shift in (0..).step_by(7) {
+            let (&byte, rest) = bytes.split_first().expect("Unexpected end of module");
+            *bytes = rest;
+            value |= usize::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        value
+    }
+
+    /// Gets the schema embedded in the custom section of the contract module.
+    fn embedded_schema() -> VersionedModuleSchema {
+        let module = std::fs::read(MODULE_PATH).unwrap_or_else(|e| {
+            panic!(
+                "The contract module must be built with an embedded schema at {MODULE_PATH}: {e}"
+            )
+        });
+        // Skip the version and length of the module, and the magic and version of
+        // the wasm binary.
+        let mut sections = &module[16..];
+        while !sections.is_empty() {
+            let id = sections[0];
+            sections = &sections[1..];
+            let size = read_leb128(&mut sections);
+            let (mut section, rest) = sections.split_at(size);
+            sections = rest;
+            if id != 0 {
+                continue;
+            }
+            let name_len = read_leb128(&mut section);
+            let (name, contents) = section.split_at(name_len);
+            if name == b"concordium-schema" {
+                return from_bytes(contents).expect("Schema can be parsed");
+            }
+        }
+        panic!("The contract module at {MODULE_PATH} has no embedded schema");
+    }
+
+    /// The entrypoints queried by the wrappers of this module, with the
+    /// schema types of their parameters and return values.
+    fn views() -> Vec<(&'static str, Type, Type)> {
+        let unit = <()>::get_type();
+        vec![
+            (CONFIG_VIEW, unit.clone(), ElectionConfig::get_type()),
+            (GUARDIANS_VIEW, unit.clone(), GuardiansState::get_type()),
+            (
+                GUARDIAN_VIEW,
+                AccountAddress::get_type(),
+                Option::<GuardianState>::get_type(),
+            ),
+            (
+                GUARDIANS_RANGE_VIEW,
+                ViewGuardiansStateRangeParameter::get_type(),
+                GuardiansState::get_type(),
+            ),
+            (
+                GUARDIAN_PROGRESS_VIEW,
+                unit.clone(),
+                GuardianProgress::get_type(),
+            ),
+            (CAN_DECRYPT_VIEW, unit.clone(), CanDecrypt::get_type()),
+            (ELECTION_PHASE_VIEW, unit.clone(), ElectionPhase::get_type()),
+            (
+                ENCRYPTED_TALLY_VIEW,
+                unit.clone(),
+                Option::<Vec<u8>>::get_type(),
+            ),
+            (
+                ENCRYPTED_TALLY_HASH_VIEW,
+                unit.clone(),
+                Option::<HashSha2256>::get_type(),
+            ),
+            (
+                RESULT_VIEW,
+                unit.clone(),
+                ViewElectionResultQueryResponse::get_type(),
+            ),
+            (
+                RESULT_HASH_VIEW,
+                unit.clone(),
+                Option::<HashSha2256>::get_type(),
+            ),
+            (
+                CONTEST_RESULTS_VIEW,
+                unit.clone(),
+                ViewContestResultsQueryResponse::get_type(),
+            ),
+            (
+                RESULT_PROPOSAL_VIEW,
+                unit,
+                Option::<ResultProposal>::get_type(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_views_match_contract_schema() {
+        let schema = embedded_schema();
+        for (entrypoint, parameter, return_value) in views() {
+            let expected_return_value = schema
+                .get_receive_return_value_schema("election", entrypoint)
+                .unwrap_or_else(|e| panic!("No return value schema for {entrypoint}: {e:?}"));
+            assert_eq!(
+                return_value, expected_return_value,
+                "Return value of {entrypoint}"
+            );
+            // Entrypoints without a parameter have no parameter schema.
+            match schema.get_receive_param_schema("election", entrypoint) {
+                Ok(expected_parameter) => {
+                    assert_eq!(parameter, expected_parameter, "Parameter of {entrypoint}")
+                }
+                Err(_) => assert_eq!(parameter, <()>::get_type(), "Parameter of {entrypoint}"),
+            }
+        }
+    }
+}
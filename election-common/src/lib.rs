@@ -2,10 +2,14 @@
 use anyhow::{ensure, Context};
 use concordium_base::contracts_common::{AccountAddress, Amount};
 #[cfg(feature = "http")]
-use concordium_governance_committee_election::{ChecksumUrl, HashSha2256};
+use concordium_governance_committee_election::ChecksumUrl;
+use concordium_governance_committee_election::{ElectionResult, GuardianState, HashSha2256};
 use eg::{
     election_manifest::ContestIndex,
-    joint_election_public_key::Ciphertext,
+    election_parameters::ElectionParameters,
+    fixed_parameters::FixedParameters,
+    guardian_public_key::GuardianPublicKey,
+    joint_election_public_key::{Ciphertext, JointElectionPublicKey},
     verifiable_decryption::{
         DecryptionProofResponseShare, DecryptionProofStateShare, DecryptionShareResult,
     },
@@ -13,6 +17,9 @@ use eg::{
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
+#[cfg(feature = "async")]
+pub mod contract;
+
 /// The representation of an encrypted tally, i.e. one [`Ciphertext`] per
 /// candidate.
 pub type EncryptedTally = BTreeMap<ContestIndex, Vec<Ciphertext>>;
@@ -45,6 +52,206 @@ pub fn decode<'de, T: Deserialize<'de>>(value: &'de [u8]) -> Result<T, rmp_serde
     rmp_serde::from_slice(value)
 }
 
+/// A [`GuardianDecryption`] encoded with [`encode`], as registered by a
+/// guardian in the election contract through `postDecryptionShare`. Decoding
+/// through [`EncodedGuardianDecryption::decode`] checks the shape of the shares
+/// against the encrypted tally, so malformed shares are reported the same way
+/// by all consumers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedGuardianDecryption(pub Vec<u8>);
+
+impl From<Vec<u8>> for EncodedGuardianDecryption {
+    fn from(value: Vec<u8>) -> Self { Self(value) }
+}
+
+impl AsRef<[u8]> for EncodedGuardianDecryption {
+    fn as_ref(&self) -> &[u8] { &self.0 }
+}
+
+impl EncodedGuardianDecryption {
+    /// Encodes the decryption shares.
+    ///
+    /// ## Errors
+    /// Fails if serialization fails
+    pub fn encode(decryption: &GuardianDecryption) -> Result<Self, rmp_serde::encode::Error> {
+        encode(decryption).map(Self)
+    }
+
+    /// Decodes the decryption shares, checking that they contain exactly one
+    /// share for each ciphertext of `tally`.
+    ///
+    /// ## Errors
+    /// Fails if the shares cannot be decoded, or do not match `tally`.
+    pub fn decode(&self, tally: &EncryptedTally) -> anyhow::Result<GuardianDecryption> {
+        decode_decryption_checked(&self.0, tally)
+    }
+}
+
+/// Decodes `bytes` as a [`GuardianDecryption`] and checks it against `tally`,
+/// see [`EncodedGuardianDecryption::decode`].
+fn decode_decryption_checked(
+    bytes: &[u8],
+    tally: &EncryptedTally,
+) -> anyhow::Result<GuardianDecryption> {
+    let decryption = decode(bytes)
+        .map_err(|e| anyhow::anyhow!("The decryption shares cannot be decoded: {e}"))?;
+    check_decryption_shape(&decryption, tally)?;
+    Ok(decryption)
+}
+
+/// Checks that `decryption` contains exactly one share for each ciphertext of
+/// `tally`, which is required to combine it with the shares of other
+/// guardians.
+///
+/// ## Errors
+/// Fails if a contest is missing or unexpected, or if the number of shares
+/// for a contest differs from the number of ciphertexts.
+pub fn check_decryption_shape(
+    decryption: &GuardianDecryption,
+    tally: &EncryptedTally,
+) -> anyhow::Result<()> {
+    for contest in decryption.keys() {
+        anyhow::ensure!(
+            tally.contains_key(contest),
+            "The decryption shares include contest {}, which is not in the encrypted tally.",
+            contest.get_one_based_usize()
+        );
+    }
+    for (contest, ciphertexts) in tally {
+        let shares = decryption.get(contest).ok_or_else(|| {
+            anyhow::anyhow!(
+                "The decryption shares do not include contest {}.",
+                contest.get_one_based_usize()
+            )
+        })?;
+        anyhow::ensure!(
+            shares.len() == ciphertexts.len(),
+            "Expected {} decryption shares for contest {}, but found {}.",
+            ciphertexts.len(),
+            contest.get_one_based_usize(),
+            shares.len()
+        );
+    }
+    Ok(())
+}
+
+/// Decodes the decryption shares registered by a guardian. Guardians either
+/// register a single share covering all contests, or a share for each contest
+/// separately. Returns `None` if no shares have been registered. The shares
+/// are checked against `tally` as by [`EncodedGuardianDecryption::decode`].
+///
+/// ## Errors
+/// Fails if any of the registered shares cannot be decoded, or if the shares
+/// do not match `tally`.
+pub fn decode_guardian_decryption(
+    guardian_state: &GuardianState,
+    tally: &EncryptedTally,
+) -> anyhow::Result<Option<GuardianDecryption>> {
+    if let Some(share) = &guardian_state.decryption_share {
+        return Ok(Some(decode_decryption_checked(share, tally)?));
+    }
+    if guardian_state.decryption_share_contests.is_empty() {
+        return Ok(None);
+    }
+
+    let mut decryption = GuardianDecryption::new();
+    // The contract keys the shares by the zero-based index of the contest.
+    for (&index, share) in &guardian_state.decryption_share_contests {
+        let contest = index
+            .checked_add(1)
+            .and_then(|index| ContestIndex::from_one_based_index(index).ok())
+            .ok_or_else(|| anyhow::anyhow!("Invalid contest index {index}"))?;
+        let shares = decode(share).map_err(|e| {
+            anyhow::anyhow!("The decryption shares for contest {index} cannot be decoded: {e}")
+        })?;
+        decryption.insert(contest, shares);
+    }
+    check_decryption_shape(&decryption, tally)?;
+    Ok(Some(decryption))
+}
+
+/// Whether the guardian has registered any decryption shares, either for all
+/// contests or for individual contests.
+pub fn has_decryption_share(guardian_state: &GuardianState) -> bool {
+    guardian_state.decryption_share.is_some()
+        || !guardian_state.decryption_share_contests.is_empty()
+}
+
+/// Finds the one-based indices of the guardians of an election with the given
+/// parameters that have no key among `guardian_public_keys`. The joint election
+/// public key can only be computed if there are none, so this allows reporting
+/// an incomplete guardian setup before attempting to compute it.
+pub fn missing_guardian_keys(
+    parameters: &ElectionParameters,
+    guardian_public_keys: &[GuardianPublicKey],
+) -> Vec<usize> {
+    let n = parameters.varying_parameters.n.get_one_based_usize();
+    (1..=n)
+        .filter(|&i| {
+            !guardian_public_keys
+                .iter()
+                .any(|key| key.i.get_one_based_usize() == i)
+        })
+        .collect()
+}
+
+/// The version of electionguard the tools of this repository are built with.
+/// This is registered in the election contract when the election is created,
+/// and must be updated whenever the electionguard dependency is updated.
+pub const ELECTIONGUARD_VERSION: &str = "0.1";
+
+/// Computes the version of the given fixed parameters, which is the SHA256 hash
+/// of their JSON serialization. This identifies the parameters independently of
+/// the version of electionguard used to generate them, and allows detecting if
+/// an upgrade of electionguard changes the standard parameters.
+///
+/// ## Errors
+/// Fails if serialization fails
+pub fn fixed_parameters_version(parameters: &FixedParameters) -> anyhow::Result<HashSha2256> {
+    use sha2::Digest;
+
+    let bytes = serde_json::to_vec(parameters)?;
+    Ok(HashSha2256(sha2::Sha256::digest(bytes).into()))
+}
+
+/// The version of the standard parameters of the electionguard library in use,
+/// as computed by [`fixed_parameters_version`].
+///
+/// ## Errors
+/// Fails if serialization fails
+pub fn standard_parameters_version() -> anyhow::Result<HashSha2256> {
+    fixed_parameters_version(&eg::standard_parameters::STANDARD_PARAMETERS)
+}
+
+/// Computes the hash of an election result, which is the SHA256 hash of its
+/// serialization as stored in the election contract. This allows detecting
+/// whether a published result has changed without comparing every entry.
+pub fn election_result_hash(result: &ElectionResult) -> HashSha2256 {
+    use sha2::Digest;
+
+    let bytes = concordium_base::contracts_common::to_bytes(result);
+    HashSha2256(sha2::Sha256::digest(bytes).into())
+}
+
+/// Computes a short fingerprint of the joint election public key, which allows
+/// humans to confirm out-of-band that all tools agree on the key. The
+/// fingerprint consists of the first 8 bytes of the SHA256 hash of the encoded
+/// key, written as groups of 4 hex digits.
+///
+/// ## Errors
+/// Fails if serialization fails
+pub fn joint_public_key_fingerprint(key: &JointElectionPublicKey) -> anyhow::Result<String> {
+    use sha2::Digest;
+
+    let hash = sha2::Sha256::digest(encode(key)?);
+    let fingerprint = hash[..8]
+        .chunks(2)
+        .map(|group| format!("{:02x}{:02x}", group[0], group[1]))
+        .collect::<Vec<_>>()
+        .join("-");
+    Ok(fingerprint)
+}
+
 /// Represents a row in the eligible voters table written the csv file
 /// containing the initial weights for each account
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -53,18 +260,60 @@ pub struct WeightRow {
     pub amount:  Amount,
 }
 
-/// Get the scaling factor used to scale the encrypted ballots
-pub fn get_scaling_factor(amount: &Amount) -> u64 { amount.micro_ccd() / 1_000_000u64 }
+/// The number of micro CCD in one CCD, which is the default unit of weight
+/// used when scaling the encrypted ballots.
+pub const MICRO_CCD_PER_CCD: u64 = 1_000_000;
+
+/// Get the scaling factor used to scale the encrypted ballots, counting whole
+/// CCD.
+pub fn get_scaling_factor(amount: &Amount) -> u64 {
+    get_scaling_factor_with_unit(amount, MICRO_CCD_PER_CCD)
+}
+
+/// Get the scaling factor used to scale the encrypted ballots, counting the
+/// number of whole `unit_micro_ccd` in `amount`. A smaller unit takes
+/// fractional CCD into account, at the cost of larger scaling factors and thus
+/// larger totals in the decrypted result.
+///
+/// Panics if `unit_micro_ccd` is zero.
+pub fn get_scaling_factor_with_unit(amount: &Amount, unit_micro_ccd: u64) -> u64 {
+    amount.micro_ccd() / unit_micro_ccd
+}
+
+/// The number of times [`HttpClient::get_resource_checked`] retries getting a
+/// resource by default, see [`HttpClient::try_create_with_retries`].
+#[cfg(feature = "http")]
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// The delay before the first retry of a failed request. The delay is doubled
+/// for each subsequent retry.
+#[cfg(feature = "http")]
+const INITIAL_RETRY_DELAY: core::time::Duration = core::time::Duration::from_millis(500);
 
 /// Wrapper around [`reqwest::Client`] to provide
 /// `HttpClient::get_resource_checked`
 #[cfg(feature = "http")]
 #[derive(Debug, Clone)]
-pub struct HttpClient(reqwest::Client);
+pub struct HttpClient {
+    client:      reqwest::Client,
+    /// The number of times getting a resource is retried on transient failures.
+    max_retries: u32,
+}
 
 #[cfg(feature = "http")]
 impl HttpClient {
+    /// Creates a client which retries getting resources
+    /// [`DEFAULT_MAX_RETRIES`] times, see
+    /// [`try_create_with_retries`](Self::try_create_with_retries).
     pub fn try_create(timeout_ms: u64) -> anyhow::Result<Self> {
+        Self::try_create_with_retries(timeout_ms, DEFAULT_MAX_RETRIES)
+    }
+
+    /// Creates a client with requests timing out after `timeout_ms`
+    /// milliseconds. Getting a resource is retried up to `max_retries` times
+    /// on connection errors, timeouts, and server errors, waiting 500ms
+    /// before the first retry and doubling the wait for each further retry.
+    pub fn try_create_with_retries(timeout_ms: u64, max_retries: u32) -> anyhow::Result<Self> {
         let timeout = core::time::Duration::from_millis(timeout_ms);
         let client = reqwest::Client::builder()
             .connect_timeout(timeout)
@@ -72,29 +321,115 @@ impl HttpClient {
             .build()
             .context("Failed to construct http client")?;
 
-        Ok(Self(client))
+        Ok(Self {
+            client,
+            max_retries,
+        })
+    }
+
+    /// Performs `request` for the resource at `url`, retrying failures which
+    /// are likely to be transient.
+    async fn with_retries<T, F>(&self, url: &str, request: impl Fn() -> F) -> anyhow::Result<T>
+    where
+        F: std::future::Future<Output = reqwest::Result<T>>, {
+        let mut delay = INITIAL_RETRY_DELAY;
+        let mut retries_left = self.max_retries;
+        loop {
+            let error = match request().await {
+                Ok(result) => return Ok(result),
+                Err(error) => error,
+            };
+            let transient = error.is_connect()
+                || error.is_timeout()
+                || error
+                    .status()
+                    .is_some_and(|status| status.is_server_error());
+            if !transient || retries_left == 0 {
+                return Err(error).with_context(|| format!("Failed to get resource at {url}"));
+            }
+            retries_left -= 1;
+            tokio::time::sleep(delay).await;
+            delay *= 2;
+        }
+    }
+
+    /// Gets the resource at `url`, retrying failures which are likely to be
+    /// transient.
+    async fn get_resource(&self, url: &str) -> anyhow::Result<Vec<u8>> {
+        let client = &self.client;
+        let data = self
+            .with_retries(url, move || async move {
+                client
+                    .get(url)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .bytes()
+                    .await
+            })
+            .await?;
+        Ok(data.into())
     }
 
     /// get the resource behind [`ChecksumUrl`] while checking the integrity of
-    /// it.
+    /// it. Transient failures are retried, whereas a checksum mismatch fails
+    /// immediately.
     pub async fn get_resource_checked(&self, url: &ChecksumUrl) -> anyhow::Result<Vec<u8>> {
         use sha2::Digest;
 
-        let response = self
-            .0
-            .get(&url.url)
-            .send()
-            .await
-            .with_context(|| format!("Failed to get resource at {}", &url.url))?;
+        let data = self.get_resource(&url.url).await?;
+        let hash = HashSha2256(sha2::Sha256::digest(&data).into());
         ensure!(
-            response.status().is_success(),
-            "Failed to get resource at {}, server responded with {}",
-            &url.url,
-            response.status()
+            hash == url.hash,
+            "Failed to verify resource at {}, checksum mismatch (expected {}, computed {})",
+            url.url,
+            url.hash,
+            hash
         );
 
-        let data = response.bytes().await?;
-        let hash = HashSha2256(sha2::Sha256::digest(&data).into());
+        Ok(data)
+    }
+
+    /// Gets the resource behind [`ChecksumUrl`] while checking its integrity,
+    /// writing it to `sink` as it is received. In contrast to
+    /// [`get_resource_checked`](Self::get_resource_checked), the resource is
+    /// never held in memory in full, which makes this suitable for large
+    /// resources such as the list of eligible voters. Use [`std::io::sink`] to
+    /// only check the integrity of the resource.
+    ///
+    /// ## Errors
+    /// Fails if the resource cannot be retrieved, writing to `sink` fails, or
+    /// the checksum does not match. Since the resource is written to `sink`
+    /// before its integrity can be checked, the data written must be discarded
+    /// on errors.
+    pub async fn get_resource_checked_streaming(
+        &self,
+        url: &ChecksumUrl,
+        mut sink: impl std::io::Write,
+    ) -> anyhow::Result<()> {
+        use sha2::Digest;
+
+        let client = &self.client;
+        let resource_url = url.url.as_str();
+        let mut response = self
+            .with_retries(resource_url, move || async move {
+                client.get(resource_url).send().await?.error_for_status()
+            })
+            .await?;
+
+        let mut hasher = sha2::Sha256::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .with_context(|| format!("Failed to get resource at {}", url.url))?
+        {
+            hasher.update(&chunk);
+            sink.write_all(&chunk)
+                .with_context(|| format!("Failed to write resource at {}", url.url))?;
+        }
+        sink.flush()?;
+
+        let hash = HashSha2256(hasher.finalize().into());
         ensure!(
             hash == url.hash,
             "Failed to verify resource at {}, checksum mismatch (expected {}, computed {})",
@@ -102,8 +437,30 @@ impl HttpClient {
             url.hash,
             hash
         );
+        Ok(())
+    }
 
-        Ok(data.into())
+    /// Posts `body` serialized as JSON to `url`.
+    ///
+    /// ## Errors
+    /// Fails if the request cannot be sent, or the server does not respond
+    /// with a success status.
+    pub async fn post_json<B: Serialize>(&self, url: &str, body: &B) -> anyhow::Result<()> {
+        let body = serde_json::to_vec(body).context("Failed to serialize request body")?;
+        let response = self
+            .client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await
+            .with_context(|| format!("Failed to post to {url}"))?;
+        ensure!(
+            response.status().is_success(),
+            "Failed to post to {url}, server responded with {}",
+            response.status()
+        );
+        Ok(())
     }
 
     /// Gets the remote resource at `url` while also checking the content
@@ -118,3 +475,211 @@ impl HttpClient {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use eg::{
+        ballot::{BallotEncrypted, BallotTallyBuilder},
+        ballot_style::{BallotStyle, BallotStyleIndex},
+        contest_selection::{ContestSelection, ContestSelectionPlaintext},
+        device::Device,
+        election_manifest::{Contest, ContestOption, ElectionManifest},
+        election_record::PreVotingData,
+        guardian::GuardianIndex,
+        guardian_secret_key::GuardianSecretKey,
+        guardian_share::{GuardianEncryptedShare, GuardianSecretKeyShare},
+        varying_parameters::{BallotChaining, VaryingParameters},
+        verifiable_decryption::{DecryptionProof, DecryptionShare},
+    };
+    use util::csprng::Csprng;
+
+    /// The encrypted tally of a single ballot in an election with a single
+    /// guardian and a single contest with two options, together with the
+    /// decryption shares of the guardian.
+    fn tally_and_decryption() -> (EncryptedTally, GuardianDecryption) {
+        let contest = ContestIndex::from_one_based_index(1).unwrap();
+        let manifest = ElectionManifest {
+            label:         "Test election manifest".into(),
+            contests:      [Contest {
+                label:           "Test contest".into(),
+                selection_limit: 1,
+                options:         [
+                    ContestOption {
+                        label: "Candidate 1".into(),
+                    },
+                    ContestOption {
+                        label: "Candidate 2".into(),
+                    },
+                ]
+                .try_into()
+                .unwrap(),
+            }]
+            .try_into()
+            .unwrap(),
+            ballot_styles: [BallotStyle {
+                label:    "Test ballot style".into(),
+                contests: [contest].into(),
+            }]
+            .try_into()
+            .unwrap(),
+        };
+        let guardian = GuardianIndex::from_one_based_index(1).unwrap();
+        let parameters = ElectionParameters {
+            fixed_parameters:   eg::standard_parameters::STANDARD_PARAMETERS.clone(),
+            varying_parameters: VaryingParameters {
+                n:               guardian,
+                k:               guardian,
+                date:            chrono::Utc.timestamp_opt(0, 0).unwrap(),
+                info:            "Test election with a single guardian.".into(),
+                ballot_chaining: BallotChaining::Prohibited,
+            },
+        };
+
+        let mut csprng = Csprng::new(b"election-common test");
+        let secret_key = GuardianSecretKey::generate(&mut csprng, &parameters, guardian, None);
+        let public_key = secret_key.make_public_key();
+        let key_share =
+            GuardianEncryptedShare::encrypt(&mut csprng, &parameters, &secret_key, &public_key)
+                .ciphertext;
+        let secret_share = GuardianSecretKeyShare::compute(
+            &parameters,
+            &[public_key.clone()],
+            &[key_share],
+            &secret_key,
+        )
+        .unwrap();
+
+        let context =
+            PreVotingData::compute(manifest.clone(), parameters.clone(), &[public_key]).unwrap();
+        let device = Device::new("test", context);
+        let selections = ContestSelection::new(
+            [true, false]
+                .into_iter()
+                .map(ContestSelectionPlaintext::from)
+                .collect(),
+        )
+        .unwrap();
+        let ballot = BallotEncrypted::new_from_selections(
+            BallotStyleIndex::from_one_based_index_unchecked(1),
+            &device,
+            &mut csprng,
+            &[0; 32],
+            &[(contest, selections)].into(),
+        )
+        .unwrap();
+        let mut tally = BallotTallyBuilder::new(&manifest, &parameters);
+        tally.update(ballot);
+        let tally: EncryptedTally = tally.finalize();
+
+        let fixed_parameters = &parameters.fixed_parameters;
+        let decryption = tally
+            .iter()
+            .map(|(&contest, ciphertexts)| {
+                let shares = ciphertexts
+                    .iter()
+                    .map(|ciphertext| {
+                        let share =
+                            DecryptionShare::from(fixed_parameters, &secret_share, ciphertext);
+                        let (proof_commit, _) = DecryptionProof::generate_commit_share(
+                            &mut csprng,
+                            fixed_parameters,
+                            ciphertext,
+                            &share.i,
+                        );
+                        DecryptionShareResult {
+                            share,
+                            proof_commit,
+                        }
+                    })
+                    .collect();
+                (contest, shares)
+            })
+            .collect();
+        (tally, decryption)
+    }
+
+    #[test]
+    fn test_decode_guardian_decryption() {
+        let (tally, decryption) = tally_and_decryption();
+        let encoded = EncodedGuardianDecryption::encode(&decryption).unwrap();
+        let decoded = encoded
+            .decode(&tally)
+            .expect("Decryption shares match the tally");
+        assert_eq!(encode(&decoded).unwrap(), encoded.0);
+    }
+
+    #[test]
+    fn test_decode_guardian_decryption_undecodable() {
+        let (tally, _) = tally_and_decryption();
+        // 0xc1 is never used in the MessagePack format.
+        let error = EncodedGuardianDecryption(vec![0xc1])
+            .decode(&tally)
+            .expect_err("Bytes cannot be decoded");
+        assert!(error.to_string().contains("cannot be decoded"), "{error}");
+    }
+
+    #[test]
+    fn test_decode_guardian_decryption_shape_mismatch() {
+        let (tally, decryption) = tally_and_decryption();
+        let contest = ContestIndex::from_one_based_index(1).unwrap();
+        let check = |decryption: &GuardianDecryption| {
+            EncodedGuardianDecryption::encode(decryption)
+                .unwrap()
+                .decode(&tally)
+                .expect_err("Decryption shares do not match the tally")
+                .to_string()
+        };
+
+        let mut missing_share = decryption.clone();
+        missing_share.get_mut(&contest).unwrap().pop();
+        assert!(check(&missing_share).contains("Expected 2 decryption shares for contest 1"));
+
+        let mut extra_contest = decryption.clone();
+        extra_contest.insert(
+            ContestIndex::from_one_based_index(2).unwrap(),
+            decryption[&contest].clone(),
+        );
+        assert!(check(&extra_contest).contains("include contest 2"));
+
+        assert!(check(&GuardianDecryption::new()).contains("do not include contest 1"));
+    }
+
+    #[test]
+    fn test_decode_guardian_decryption_per_contest() {
+        let (tally, decryption) = tally_and_decryption();
+        let contest = ContestIndex::from_one_based_index(1).unwrap();
+        let mut guardian_state = GuardianState::new(1);
+        assert!(decode_guardian_decryption(&guardian_state, &tally)
+            .unwrap()
+            .is_none());
+
+        // The contract keys the shares by the zero-based index of the contest.
+        guardian_state
+            .decryption_share_contests
+            .insert(0, encode(&decryption[&contest]).unwrap());
+        let decoded = decode_guardian_decryption(&guardian_state, &tally)
+            .unwrap()
+            .expect("Decryption shares are registered");
+        assert_eq!(encode(&decoded).unwrap(), encode(&decryption).unwrap());
+
+        guardian_state
+            .decryption_share_contests
+            .insert(1, encode(&decryption[&contest]).unwrap());
+        assert!(decode_guardian_decryption(&guardian_state, &tally).is_err());
+    }
+
+    #[test]
+    fn test_scaling_factor_with_unit() {
+        let amount = Amount::from_micro_ccd(2_750_000);
+        assert_eq!(get_scaling_factor(&amount), 2);
+        assert_eq!(get_scaling_factor_with_unit(&amount, MICRO_CCD_PER_CCD), 2);
+        assert_eq!(get_scaling_factor_with_unit(&amount, 1_000), 2_750);
+        assert_eq!(get_scaling_factor_with_unit(&amount, 1), 2_750_000);
+        // Less than a whole unit does not count.
+        let fraction = Amount::from_micro_ccd(999_999);
+        assert_eq!(get_scaling_factor(&fraction), 0);
+        assert_eq!(get_scaling_factor_with_unit(&fraction, 1_000), 999);
+    }
+}
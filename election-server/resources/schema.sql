@@ -6,6 +6,9 @@ CREATE TABLE IF NOT EXISTS settings (
   contract_subindex INT8 NOT NULL
 );
 
+-- The block time of the latest recorded block. Added after the settings table, hence not part of its definition.
+ALTER TABLE settings ADD COLUMN IF NOT EXISTS latest_block_time TIMESTAMP WITH TIME ZONE;
+
 -- Table containing ballots successfully submitted to the contract monitored.
 CREATE TABLE IF NOT EXISTS ballots (
   id INT8 PRIMARY KEY, -- For pagination
@@ -16,6 +19,18 @@ CREATE TABLE IF NOT EXISTS ballots (
   verified BOOL NOT NULL
 );
 
+-- The height of the block each ballot was submitted in. Added after the ballots table, hence not part of its definition.
+-- Ballots indexed before the column was added have no height until the database is rebuilt.
+ALTER TABLE ballots ADD COLUMN IF NOT EXISTS block_height INT8;
+
+-- Table containing submissions to the contract monitored which could not be parsed as ballots.
+CREATE TABLE IF NOT EXISTS malformed_ballots (
+  transaction_hash BYTEA PRIMARY KEY,
+  block_time TIMESTAMP WITH TIME ZONE NOT NULL,
+  account BYTEA NOT NULL,
+  error TEXT NOT NULL
+);
+
 -- Table containing voting weight delegations
 CREATE TABLE IF NOT EXISTS delegations (
   id INT8 PRIMARY KEY, -- For pagination
@@ -25,8 +40,30 @@ CREATE TABLE IF NOT EXISTS delegations (
   to_account BYTEA NOT NULL
 );
 
+-- Table containing the election result published in the contract monitored, with a row per candidate.
+CREATE TABLE IF NOT EXISTS results (
+  candidate_index INT4 PRIMARY KEY, -- The position of the candidate in the published result
+  candidate_url TEXT NOT NULL,
+  candidate_hash BYTEA NOT NULL,
+  votes INT8 NOT NULL
+);
+
+-- The hash of the election result stored in the results table, see `election_common::election_result_hash`. Added after
+-- the settings table, hence not part of its definition.
+ALTER TABLE settings ADD COLUMN IF NOT EXISTS result_hash BYTEA;
+
+-- Each transaction submits at most one ballot. This makes re-inserting the ballots of a block, e.g. when retrying after
+-- a database error, idempotent. Ballots recorded more than once before the index was added are removed first, keeping
+-- the one recorded first.
+DELETE FROM ballots duplicate USING ballots original
+  WHERE duplicate.transaction_hash = original.transaction_hash AND duplicate.id > original.id;
+CREATE UNIQUE INDEX IF NOT EXISTS ballots_transaction_hash_idx ON ballots (transaction_hash);
+
 -- Improve performance on queries for ballots within id range for an account.
 CREATE INDEX IF NOT EXISTS ballots_account_id_idx ON ballots (account, id);
 
+-- Improve performance on queries for ballots from a block height.
+CREATE INDEX IF NOT EXISTS ballots_block_height_idx ON ballots (block_height, id);
+
 -- Improve performance on queries for delegations within id range for an account.
 CREATE INDEX IF NOT EXISTS delegations_to_account_id_idx ON delegations (to_account, id);
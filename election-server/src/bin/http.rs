@@ -21,7 +21,7 @@ use concordium_rust_sdk::{
 };
 use election_common::{get_scaling_factor, HttpClient, WeightRow};
 use election_server::{
-    db::{DatabasePool, StoredBallotSubmission, StoredDelegation},
+    db::{DatabasePool, StoredBallotSubmission, StoredDelegation, StoredElectionResult},
     util::{
         create_client, get_election_config, get_election_result, get_guardians_state,
         verify_contract, ElectionContract,
@@ -61,6 +61,15 @@ struct AppConfig {
         env = "CCD_ELECTION_DB_CONNECTION"
     )]
     db_connection:      tokio_postgres::config::Config,
+    /// Connection string of a read replica of the database.
+    #[arg(
+        long = "db-read-connection",
+        help = "A connection string detailing the connection to a read replica of the database. \
+                If given, all queries of the API are made against it instead of the database \
+                given by `--db-connection`, which is then only used for setting up the tables.",
+        env = "CCD_ELECTION_DB_READ_CONNECTION"
+    )]
+    db_read_connection: Option<tokio_postgres::config::Config>,
     /// Maximum size of the database connection pool
     #[clap(
         long = "db-pool-size",
@@ -311,6 +320,9 @@ struct ApiState {
     db_pool:         DatabasePool,
     /// The computed initial weights of each eligible voter.
     initial_weights: HashMap<AccountAddress, Amount>,
+    /// The latest [`TurnoutResponse`] together with the time it was computed,
+    /// see [`TURNOUT_CACHE_TTL`].
+    turnout_cache:   Arc<Mutex<Option<(std::time::Instant, TurnoutResponse)>>>,
 }
 
 impl ApiState {
@@ -390,6 +402,61 @@ async fn get_ballot_submissions_by_account(
     Ok(Json(response))
 }
 
+/// Get ballot submissions of all accounts, most recent first. Returns
+/// [`StatusCode`] signaling error if database connection or lookup fails.
+#[tracing::instrument(skip(state))]
+async fn get_ballot_submissions(
+    State(state): State<ApiState>,
+    Query(query_params): Query<PaginatedQueryParams>,
+) -> Result<Json<PaginationResponse<StoredBallotSubmission>>, StatusCode> {
+    let db = state.db_pool.get().await.map_err(|e| {
+        tracing::error!("Could not get db connection from pool: {}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let page_size = query_params.page_size();
+    let mut results = db
+        // Add 1 to the page size to identify if there are more results on the next "page"
+        .get_all_ballot_submissions(query_params.from, page_size + 1)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get ballot submissions: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let has_more = results.len() > page_size;
+    if has_more {
+        // Pop the last item of results, which will be the first item on the next page.
+        results.pop();
+    }
+
+    let response = PaginationResponse { results, has_more };
+    Ok(Json(response))
+}
+
+/// Get the latest verified ballot submission (if any) registered for
+/// `account_address`, which is the ballot counted in the tally for the account.
+/// Returns [`StatusCode`] signaling error if database connection or lookup
+/// fails.
+#[tracing::instrument(skip(state))]
+async fn get_latest_ballot_submission_by_account(
+    State(state): State<ApiState>,
+    Path(account_address): Path<AccountAddress>,
+) -> Result<Json<Option<StoredBallotSubmission>>, StatusCode> {
+    let db = state.db_pool.get().await.map_err(|e| {
+        tracing::error!("Could not get db connection from pool: {}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let ballot_submission = db
+        .get_latest_verified_ballot_submission(&account_address)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get ballot submission: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    Ok(Json(ballot_submission))
+}
+
 /// Describes each row returned in [`get_delegations_by_account`]
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -614,6 +681,136 @@ async fn get_account_weight(
     Ok(Json(response))
 }
 
+/// The response returned by the `/api/participation/:account` endpoint.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ParticipationResponse {
+    /// Whether a verified ballot counting for the account has been indexed.
+    has_effective_vote: bool,
+    /// The account whose ballot counts for the queried account. This is the
+    /// delegation target if the account has delegated its voting weight, and
+    /// the account itself otherwise.
+    voted_by:           AccountAddress,
+    /// The transaction hash of the ballot submission counting for the account,
+    /// if any.
+    transaction_hash:   Option<TransactionHash>,
+}
+
+/// Get whether an account has an effective vote in the election, i.e. whether
+/// a verified ballot counting for the account has been indexed. If the account
+/// has delegated its voting weight, the latest verified ballot of the
+/// delegation target is used.
+#[tracing::instrument(skip(state))]
+async fn get_participation(
+    State(state): State<ApiState>,
+    Path(account): Path<AccountAddress>,
+) -> Result<Json<ParticipationResponse>, StatusCode> {
+    let db = state.db_pool.get().await.map_err(|e| {
+        tracing::error!("Could not get db connection from pool: {}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let voted_by = db
+        .get_delegation_out(&account)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get delegations: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .map_or(account, |d| d.to_account);
+    let transaction_hash = db
+        .get_latest_verified_ballot_transaction_hash(&voted_by)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get ballot submission: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let response = ParticipationResponse {
+        has_effective_vote: transaction_hash.is_some(),
+        voted_by,
+        transaction_hash,
+    };
+    Ok(Json(response))
+}
+
+/// The response returned by the `/api/turnout` endpoint.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TurnoutResponse {
+    /// The number of distinct accounts with a verified ballot.
+    voters:            u64,
+    /// The height of the latest block indexed.
+    latest_height:     Option<u64>,
+    /// The block time of the latest block indexed.
+    latest_block_time: Option<DateTime<Utc>>,
+}
+
+/// How long a computed [`TurnoutResponse`] is served before it is computed
+/// again. This bounds the load put on the DB by clients polling the turnout.
+const TURNOUT_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Get the current turnout of the election, i.e. the number of accounts which
+/// have submitted a verified ballot, together with how far the chain has been
+/// indexed.
+#[tracing::instrument(skip(state))]
+async fn get_turnout(State(state): State<ApiState>) -> Result<Json<TurnoutResponse>, StatusCode> {
+    let response = get_turnout_at(&state, std::time::Instant::now()).await?;
+    Ok(Json(response))
+}
+
+/// Get the turnout of the election at time `now`. A response computed less than
+/// [`TURNOUT_CACHE_TTL`] before `now` is served from the cache.
+async fn get_turnout_at(
+    state: &ApiState,
+    now: std::time::Instant,
+) -> Result<TurnoutResponse, StatusCode> {
+    let mut cache = state.turnout_cache.lock().await;
+    if let Some((computed_at, response)) = cache.as_ref() {
+        if now.saturating_duration_since(*computed_at) < TURNOUT_CACHE_TTL {
+            return Ok(response.clone());
+        }
+    }
+
+    let db = state.db_pool.get().await.map_err(|e| {
+        tracing::error!("Could not get db connection from pool: {}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let voters = db.get_voter_count().await.map_err(|e| {
+        tracing::error!("Failed to get voter count: {}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let settings = db.get_settings().await.map_err(|e| {
+        tracing::error!("Failed to get settings: {}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let response = TurnoutResponse {
+        voters,
+        latest_height: settings.latest_height.map(|h| h.height),
+        latest_block_time: settings.latest_block_time,
+    };
+    *cache = Some((now, response.clone()));
+    Ok(response)
+}
+
+/// Get the election result recorded by the indexer, once it has been published
+/// in the election contract. Returns `null` if no result has been published
+/// yet.
+#[tracing::instrument(skip(state))]
+async fn get_results(
+    State(state): State<ApiState>,
+) -> Result<Json<Option<StoredElectionResult>>, StatusCode> {
+    let db = state.db_pool.get().await.map_err(|e| {
+        tracing::error!("Could not get db connection from pool: {}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let result = db.get_election_result().await.map_err(|e| {
+        tracing::error!("Failed to get election result: {}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    Ok(Json(result))
+}
+
 type PrometheusLayer = GenericMetricLayer<'static, PrometheusHandle, axum_prometheus::Handle>;
 
 /// Configures the prometheus server (if enabled through [`AppConfig`]). Returns
@@ -660,6 +857,59 @@ fn setup_prometheus(
     (prometheus_layer, prometheus_handle)
 }
 
+/// Check that the database read from is a replica of the database written by
+/// the indexer, i.e. that both are set up for the election contract at
+/// `contract_address`.
+async fn check_read_database(
+    write_pool: &DatabasePool,
+    read_pool: &DatabasePool,
+    contract_address: ContractAddress,
+) -> anyhow::Result<()> {
+    let write_settings =
+        write_pool.get().await?.get_settings().await.context(
+            "Unable to read the settings of the database. Has the indexer been started?",
+        )?;
+    let read_settings = read_pool.get().await?.get_settings().await.context(
+        "Unable to read the settings of the read database. Is it a replica of the database?",
+    )?;
+    anyhow::ensure!(
+        write_settings.contract_address == contract_address,
+        "The database is for contract {}, but {contract_address} was expected.",
+        write_settings.contract_address
+    );
+    anyhow::ensure!(
+        read_settings.contract_address == write_settings.contract_address,
+        "The read database is for contract {}, but the database is for contract {}.",
+        read_settings.contract_address,
+        write_settings.contract_address
+    );
+    Ok(())
+}
+
+/// Connects to the database queried by the API. This is the read replica given
+/// by `db_read_connection` if any, which is checked against the database given
+/// by `db_connection`, and otherwise the database given by `db_connection`.
+async fn connect_api_database(
+    db_connection: tokio_postgres::config::Config,
+    db_read_connection: Option<tokio_postgres::config::Config>,
+    pool_size: usize,
+    contract_address: ContractAddress,
+) -> anyhow::Result<DatabasePool> {
+    let db_pool = DatabasePool::create(db_connection, pool_size, true)
+        .await
+        .context("Failed to connect to the database")?;
+    let Some(db_read_connection) = db_read_connection else {
+        return Ok(db_pool);
+    };
+    // Replicas are read-only, so the tables are only created in the primary
+    // database.
+    let read_pool = DatabasePool::create(db_read_connection, pool_size, false)
+        .await
+        .context("Failed to connect to the read database")?;
+    check_read_database(&db_pool, &read_pool, contract_address).await?;
+    Ok(read_pool)
+}
+
 /// Configures the HTTP server which serves as an API for election components.
 /// Returns a handle for the corresponding process spawned, or an error if
 /// configuration fails.
@@ -684,11 +934,17 @@ async fn setup_http(
 
     let index_template = std::fs::read_to_string(config.frontend_dir.join("index.html"))
         .context("Frontend was not built.")?;
+    let db_pool = connect_api_database(
+        config.db_connection.clone(),
+        config.db_read_connection.clone(),
+        config.pool_size,
+        config.contract_address,
+    )
+    .await?;
     let api_state = ApiState {
-        db_pool: DatabasePool::create(config.db_connection.clone(), config.pool_size, true)
-            .await
-            .context("Failed to connect to the database")?,
+        db_pool,
         initial_weights,
+        turnout_cache: Arc::new(Mutex::new(None)),
     };
 
     let fe_state = FrontendState {
@@ -703,12 +959,20 @@ async fn setup_http(
             "/api/submission-status/:transaction",
             get(get_ballot_submission_by_transaction),
         )
+        .route("/api/submissions", get(get_ballot_submissions))
         .route(
             "/api/submissions/:account",
             get(get_ballot_submissions_by_account),
         )
+        .route(
+            "/api/submissions/:account/latest",
+            get(get_latest_ballot_submission_by_account),
+        )
         .route("/api/delegations/:account", get(get_delegations_by_account))
         .route("/api/weight/:account", get(get_account_weight))
+        .route("/api/participation/:account", get(get_participation))
+        .route("/api/turnout", get(get_turnout))
+        .route("/api/results", get(get_results))
         .with_state(api_state)
         // Serve everything frontend-related
         .route_service("/assets/*path", ServeDir::new(&config.frontend_dir))
@@ -799,3 +1063,336 @@ async fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use concordium_governance_committee_election::{CandidateResult, HashSha2256};
+    use election_server::{
+        db::{Database, Transaction},
+        util::VotingWeightDelegation,
+    };
+
+    /// Inserts a ballot submission by `account` in transaction
+    /// `transaction_hash`. The ballot itself is left empty, as it is not read
+    /// by the participation queries.
+    async fn insert_ballot(
+        db: &Database,
+        id: i64,
+        account: AccountAddress,
+        transaction_hash: TransactionHash,
+        verified: bool,
+    ) {
+        let ballot: &[u8] = &[];
+        db.client
+            .execute(
+                "INSERT INTO ballots (id, transaction_hash, block_time, ballot, account, \
+                 verified) VALUES ($1, $2, now(), $3, $4, $5)",
+                &[
+                    &id,
+                    &transaction_hash.as_ref(),
+                    &ballot,
+                    &account.0.as_ref(),
+                    &verified,
+                ],
+            )
+            .await
+            .unwrap();
+    }
+
+    /// The participation of `account` returned by [`get_participation`].
+    async fn participation(state: &ApiState, account: AccountAddress) -> ParticipationResponse {
+        let Json(response) = get_participation(State(state.clone()), Path(account))
+            .await
+            .expect("Can get participation");
+        response
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a postgres database given by CCD_ELECTION_TEST_DB_CONNECTION"]
+    async fn test_get_participation() {
+        let db_connection = std::env::var("CCD_ELECTION_TEST_DB_CONNECTION")
+            .expect("CCD_ELECTION_TEST_DB_CONNECTION is set")
+            .parse()
+            .expect("Valid connection string");
+        let db_pool = DatabasePool::create(db_connection, 1, true).await.unwrap();
+        let mut db = db_pool.get().await.unwrap();
+        db.clear().await.unwrap();
+
+        let direct = AccountAddress([1; 32]);
+        let delegator = AccountAddress([2; 32]);
+        let delegatee = AccountAddress([3; 32]);
+        let unverified = AccountAddress([4; 32]);
+        let direct_ballot = TransactionHash::new([1; 32]);
+        let delegatee_ballot = TransactionHash::new([3; 32]);
+        insert_ballot(&db, 0, direct, direct_ballot, true).await;
+        insert_ballot(&db, 1, delegatee, delegatee_ballot, true).await;
+        // A later ballot which could not be verified does not replace the
+        // verified one.
+        insert_ballot(&db, 2, delegatee, TransactionHash::new([5; 32]), false).await;
+        insert_ballot(&db, 3, unverified, TransactionHash::new([4; 32]), false).await;
+        let transaction = Transaction::from(db.client.transaction().await.unwrap());
+        transaction
+            .insert_delegation(
+                &VotingWeightDelegation {
+                    from_account:     delegator,
+                    to_account:       delegatee,
+                    transaction_hash: TransactionHash::new([2; 32]),
+                },
+                Utc::now(),
+            )
+            .await
+            .unwrap();
+        transaction.inner.commit().await.unwrap();
+
+        let state = ApiState {
+            db_pool,
+            initial_weights: HashMap::new(),
+            turnout_cache: Arc::new(Mutex::new(None)),
+        };
+
+        let response = participation(&state, direct).await;
+        assert!(response.has_effective_vote);
+        assert_eq!(response.voted_by, direct);
+        assert_eq!(response.transaction_hash, Some(direct_ballot));
+
+        let response = participation(&state, delegator).await;
+        assert!(response.has_effective_vote);
+        assert_eq!(response.voted_by, delegatee);
+        assert_eq!(response.transaction_hash, Some(delegatee_ballot));
+
+        let response = participation(&state, delegatee).await;
+        assert_eq!(response.voted_by, delegatee);
+        assert_eq!(response.transaction_hash, Some(delegatee_ballot));
+
+        let response = participation(&state, unverified).await;
+        assert!(!response.has_effective_vote);
+        assert_eq!(response.voted_by, unverified);
+        assert_eq!(response.transaction_hash, None);
+    }
+
+    /// The turnout is computed from the distinct accounts with a verified
+    /// ballot, and is served from the cache until it expires.
+    #[tokio::test]
+    #[ignore = "requires a postgres database given by CCD_ELECTION_TEST_DB_CONNECTION"]
+    async fn test_get_turnout() {
+        let db_connection = std::env::var("CCD_ELECTION_TEST_DB_CONNECTION")
+            .expect("CCD_ELECTION_TEST_DB_CONNECTION is set")
+            .parse()
+            .expect("Valid connection string");
+        let db_pool = DatabasePool::create(db_connection, 1, true).await.unwrap();
+        let mut db = db_pool.get().await.unwrap();
+        db.clear().await.unwrap();
+        db.init_settings(&ContractAddress::new(1, 0)).await.unwrap();
+
+        let first = AccountAddress([1; 32]);
+        let second = AccountAddress([2; 32]);
+        insert_ballot(&db, 0, first, TransactionHash::new([0; 32]), true).await;
+        // Voting again does not add to the turnout.
+        insert_ballot(&db, 1, first, TransactionHash::new([1; 32]), true).await;
+        insert_ballot(&db, 2, second, TransactionHash::new([2; 32]), false).await;
+        let block_time = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let transaction = Transaction::from(db.client.transaction().await.unwrap());
+        transaction
+            .set_latest_height(10.into(), block_time)
+            .await
+            .unwrap();
+        transaction.inner.commit().await.unwrap();
+
+        let state = ApiState {
+            db_pool,
+            initial_weights: HashMap::new(),
+            turnout_cache: Arc::new(Mutex::new(None)),
+        };
+        let start = std::time::Instant::now();
+        let response = get_turnout_at(&state, start).await.unwrap();
+        assert_eq!(response.voters, 1);
+        assert_eq!(response.latest_height, Some(10));
+        assert_eq!(response.latest_block_time, Some(block_time));
+
+        insert_ballot(&db, 3, second, TransactionHash::new([3; 32]), true).await;
+        let cached_until = start + TURNOUT_CACHE_TTL - std::time::Duration::from_millis(1);
+        let response = get_turnout_at(&state, cached_until).await.unwrap();
+        assert_eq!(response.voters, 1, "The cached turnout is served");
+
+        let response = get_turnout_at(&state, start + TURNOUT_CACHE_TTL)
+            .await
+            .unwrap();
+        assert_eq!(response.voters, 2, "The turnout is computed again");
+    }
+
+    /// Without a read replica the API reads from the database, and otherwise
+    /// from the replica as long as it is set up for the same contract.
+    #[tokio::test]
+    #[ignore = "requires a postgres database given by CCD_ELECTION_TEST_DB_CONNECTION"]
+    async fn test_connect_api_database() {
+        let db_connection: tokio_postgres::config::Config =
+            std::env::var("CCD_ELECTION_TEST_DB_CONNECTION")
+                .expect("CCD_ELECTION_TEST_DB_CONNECTION is set")
+                .parse()
+                .expect("Valid connection string");
+        let contract_address = ContractAddress::new(1, 0);
+        let db_pool = DatabasePool::create(db_connection.clone(), 1, true)
+            .await
+            .unwrap();
+        let db = db_pool.get().await.unwrap();
+        db.clear().await.unwrap();
+        db.init_settings(&contract_address).await.unwrap();
+        insert_ballot(
+            &db,
+            0,
+            AccountAddress([1; 32]),
+            TransactionHash::new([0; 32]),
+            true,
+        )
+        .await;
+
+        // The replica is emulated by a separate schema of the test database.
+        db.client
+            .batch_execute("CREATE SCHEMA IF NOT EXISTS replica_test")
+            .await
+            .unwrap();
+        let mut replica_connection = db_connection.clone();
+        replica_connection.options("-c search_path=replica_test");
+        let replica_pool = DatabasePool::create(replica_connection.clone(), 1, true)
+            .await
+            .unwrap();
+        let replica = replica_pool.get().await.unwrap();
+        replica.clear().await.unwrap();
+        replica
+            .init_settings(&ContractAddress::new(2, 0))
+            .await
+            .unwrap();
+
+        let read_pool = connect_api_database(db_connection.clone(), None, 1, contract_address)
+            .await
+            .unwrap();
+        assert_eq!(
+            read_pool
+                .get()
+                .await
+                .unwrap()
+                .get_voter_count()
+                .await
+                .unwrap(),
+            1
+        );
+
+        let error = connect_api_database(
+            db_connection.clone(),
+            Some(replica_connection.clone()),
+            1,
+            contract_address,
+        )
+        .await
+        .expect_err("The replica is for another contract");
+        assert_eq!(
+            error.to_string(),
+            "The read database is for contract <2,0>, but the database is for contract <1,0>."
+        );
+        let error = connect_api_database(
+            db_connection.clone(),
+            Some(replica_connection.clone()),
+            1,
+            ContractAddress::new(2, 0),
+        )
+        .await
+        .expect_err("The database is for another contract");
+        assert_eq!(
+            error.to_string(),
+            "The database is for contract <1,0>, but <2,0> was expected."
+        );
+
+        replica.clear().await.unwrap();
+        replica.init_settings(&contract_address).await.unwrap();
+        let read_pool =
+            connect_api_database(db_connection, Some(replica_connection), 1, contract_address)
+                .await
+                .unwrap();
+        assert_eq!(
+            read_pool
+                .get()
+                .await
+                .unwrap()
+                .get_voter_count()
+                .await
+                .unwrap(),
+            0,
+            "Queries are made against the replica"
+        );
+    }
+
+    /// The JSON returned by [`get_results`].
+    async fn results(state: &ApiState) -> serde_json::Value {
+        let Json(response) = get_results(State(state.clone()))
+            .await
+            .expect("Can get results");
+        serde_json::to_value(response).unwrap()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a postgres database given by CCD_ELECTION_TEST_DB_CONNECTION"]
+    async fn test_get_results() {
+        let db_connection = std::env::var("CCD_ELECTION_TEST_DB_CONNECTION")
+            .expect("CCD_ELECTION_TEST_DB_CONNECTION is set")
+            .parse()
+            .expect("Valid connection string");
+        let db_pool = DatabasePool::create(db_connection, 1, true).await.unwrap();
+        let mut db = db_pool.get().await.unwrap();
+        db.clear().await.unwrap();
+        db.init_settings(&ContractAddress::new(1, 0)).await.unwrap();
+
+        let state = ApiState {
+            db_pool,
+            initial_weights: HashMap::new(),
+            turnout_cache: Arc::new(Mutex::new(None)),
+        };
+        assert_eq!(results(&state).await, serde_json::Value::Null);
+
+        let result = [
+            CandidateResult {
+                candidate:         ChecksumUrl {
+                    url:  "https://candidates.example/0.json".to_string(),
+                    hash: HashSha2256([1; 32]),
+                },
+                cummulative_votes: 12,
+            },
+            CandidateResult {
+                candidate:         ChecksumUrl {
+                    url:  "https://candidates.example/1.json".to_string(),
+                    hash: HashSha2256([2; 32]),
+                },
+                cummulative_votes: 7,
+            },
+        ];
+        let result_hash = HashSha2256([3; 32]);
+        let transaction = Transaction::from(db.client.transaction().await.unwrap());
+        transaction
+            .set_election_result(&result, &result_hash)
+            .await
+            .unwrap();
+        transaction.inner.commit().await.unwrap();
+
+        let expected = serde_json::json!({
+            "resultHash": result_hash.to_string(),
+            "candidates": [
+                {
+                    "candidate": {
+                        "url": "https://candidates.example/0.json",
+                        "hash": HashSha2256([1; 32]).to_string(),
+                    },
+                    "votes": 12,
+                },
+                {
+                    "candidate": {
+                        "url": "https://candidates.example/1.json",
+                        "hash": HashSha2256([2; 32]).to_string(),
+                    },
+                    "votes": 7,
+                },
+            ],
+        });
+        assert_eq!(results(&state).await, expected);
+    }
+}
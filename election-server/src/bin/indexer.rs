@@ -1,7 +1,15 @@
 use anyhow::{anyhow, Context};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
 use chrono::{DateTime, Utc};
 use clap::Parser;
-use concordium_governance_committee_election::{ElectionConfig, RegisterVotesParameter};
+use concordium_governance_committee_election::{
+    ElectionConfig, ElectionResult, Event, RegisterVotesParameter, ViewContestResultsQueryResponse,
+};
 use concordium_rust_sdk::{
     smart_contracts::common as contracts_common,
     types::{
@@ -17,12 +25,13 @@ use eg::{
     guardian_public_key::GuardianPublicKey, hashes::Hashes, hashes_ext::HashesExt,
     joint_election_public_key::JointElectionPublicKey,
 };
-use election_common::{decode, HttpClient};
+use election_common::{decode, election_result_hash, HttpClient};
 use election_server::{
     db::{Database, DatabasePool, Transaction},
     util::{
-        create_client, get_election_config, get_guardians_state, verify_contract, BallotSubmission,
-        ElectionContract, VotingWeightDelegation, REGISTER_VOTES_RECEIVE,
+        create_client, get_contest_results, get_election_config, get_guardians_state,
+        verify_contract, BallotSubmission, ElectionContract, MalformedBallotSubmission,
+        VotingWeightDelegation, REGISTER_VOTES_RECEIVE,
     },
 };
 use futures::{future, TryStreamExt};
@@ -57,6 +66,13 @@ struct AppConfig {
         env = "CCD_ELECTION_DB_CONNECTION"
     )]
     db_connection:      tokio_postgres::config::Config,
+    /// Maximum size of the database connection pool
+    #[clap(
+        long = "db-pool-size",
+        default_value_t = 2,
+        env = "CCD_ELECTION_INDEXER_DB_POOL_SIZE"
+    )]
+    pool_size:          usize,
     /// The contract address used to filter contract updates
     #[arg(long = "contract-address", env = "CCD_ELECTION_CONTRACT_ADDRESS")]
     contract_address:   ContractAddress,
@@ -82,6 +98,40 @@ struct AppConfig {
         env = "CCD_ELECTION_REQUEST_TIMEOUT_MS"
     )]
     request_timeout_ms: u64,
+    /// Rebuild the database from the chain.
+    #[clap(
+        long = "rebuild",
+        help = "Delete all data stored in the database and index the election contract from the \
+                start of the election. This is meant for recovering from a lost or inconsistent \
+                database."
+    )]
+    rebuild:            bool,
+    /// Address the ballot query API will listen on
+    #[clap(
+        long = "listen-address",
+        help = "If given, the ballots indexed are served on this address while indexing.",
+        env = "CCD_ELECTION_INDEXER_LISTEN_ADDRESS"
+    )]
+    listen_address:     Option<std::net::SocketAddr>,
+    #[command(subcommand)]
+    command:            Option<Command>,
+}
+
+/// Commands run instead of indexing the election contract.
+#[derive(Debug, clap::Subcommand, Clone)]
+enum Command {
+    /// Verify the ballots stored in the database against the keys currently
+    /// registered by the guardians, and update the verification status of the
+    /// ballots for which it changed. This is meant for recovering from ballots
+    /// having been indexed before the keys of the guardians were final.
+    #[command(name = "update-verification")]
+    UpdateVerification {
+        #[arg(
+            long = "all",
+            help = "Verify all ballots stored, instead of only those which could not be verified."
+        )]
+        all: bool,
+    },
 }
 
 impl AppConfig {
@@ -108,6 +158,8 @@ impl AppConfig {
 enum TransactionData {
     /// Represents a ballot submission
     BallotSubmission(BallotSubmission),
+    /// Represents a submission which could not be parsed as a ballot
+    MalformedBallot(MalformedBallotSubmission),
     /// Represents a voting weight delegation
     Delegation(VotingWeightDelegation),
 }
@@ -134,36 +186,27 @@ struct BlockData {
 }
 
 /// Runs a process of inserting data coming in on `block_receiver` in a database
-/// defined in `db_connection`
+/// defined in `db_connection`, using a connection pool of size `pool_size`.
 async fn run_db_process(
     db_connection: tokio_postgres::config::Config,
+    pool_size: usize,
     contract_address: ContractAddress,
     mut block_receiver: tokio::sync::mpsc::Receiver<BlockData>,
     height_sender: tokio::sync::oneshot::Sender<Option<AbsoluteBlockHeight>>,
     stop_flag: Arc<AtomicBool>,
+    rebuild: bool,
 ) -> anyhow::Result<()> {
-    let db_pool = DatabasePool::create(db_connection.clone(), 2, true)
+    let db_pool = DatabasePool::create(db_connection.clone(), pool_size, true)
         .await
         .context("Could not create database pool")?;
     let mut db = db_pool
         .get()
         .await
         .context("Could not get database connection from pool")?;
-    db.init_settings(&contract_address)
-        .await
-        .context("Could not init settings for database")?;
-    let settings = db
-        .get_settings()
-        .await
-        .context("Could not get settings from database")?;
-
-    anyhow::ensure!(
-        settings.contract_address == contract_address,
-        "Contract address does not match the contract address found in the database"
-    );
+    let latest_height = prepare_database(&db, &contract_address, rebuild).await?;
 
     height_sender
-        .send(settings.latest_height)
+        .send(latest_height)
         .map_err(|_| anyhow!("Best block height could not be sent to node process"))?;
 
     // In case of DB errors, this is used to store the value to retry insertion for
@@ -230,6 +273,33 @@ async fn run_db_process(
     Ok(())
 }
 
+/// Prepares the database defined by `db` for indexing the election contract at
+/// `contract_address`, deleting all data stored first if `rebuild` is true.
+/// Returns the latest block height recorded in the database, if any.
+async fn prepare_database(
+    db: &Database,
+    contract_address: &ContractAddress,
+    rebuild: bool,
+) -> anyhow::Result<Option<AbsoluteBlockHeight>> {
+    if rebuild {
+        tracing::warn!("Deleting all data stored in the database to rebuild it from the chain.");
+        db.clear().await.context("Could not clear the database")?;
+    }
+    db.init_settings(contract_address)
+        .await
+        .context("Could not init settings for database")?;
+    let settings = db
+        .get_settings()
+        .await
+        .context("Could not get settings from database")?;
+
+    anyhow::ensure!(
+        settings.contract_address == *contract_address,
+        "Contract address does not match the contract address found in the database"
+    );
+    Ok(settings.latest_height)
+}
+
 /// Inserts the `block_data` collected for a single block into the database
 /// defined by `db`. Everything is commited as a single transactions allowing
 /// for easy restoration from the last recorded block (by height) inserted into
@@ -247,13 +317,20 @@ async fn db_insert_block<'a>(
         .context("Failed to build DB transaction")?;
 
     let transaction = Transaction::from(transaction);
-    transaction.set_latest_height(block_data.height).await?;
+    transaction
+        .set_latest_height(block_data.height, block_data.block_time)
+        .await?;
 
     for transaction_data in block_data.transactions.iter() {
         match transaction_data {
             TransactionData::BallotSubmission(ballot) => {
                 transaction
-                    .insert_ballot(ballot, block_data.block_time)
+                    .insert_ballot(ballot, block_data.height, block_data.block_time)
+                    .await?;
+            }
+            TransactionData::MalformedBallot(submission) => {
+                transaction
+                    .insert_malformed_ballot(submission, block_data.block_time)
                     .await?;
             }
             TransactionData::Delegation(delegation) => {
@@ -276,10 +353,121 @@ async fn db_insert_block<'a>(
     Ok(end)
 }
 
+/// The interval at which the election contract is polled for the election
+/// result after the election has ended.
+const RESULT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Polls the election contract for the election result until the election is
+/// locked, i.e. the result is final, or the process is stopped. Whenever the
+/// result published differs from the one stored, it is written to the database
+/// defined in `db_connection`.
+async fn run_result_process(
+    client: &mut ElectionContract,
+    db_connection: tokio_postgres::config::Config,
+    stop_flag: &AtomicBool,
+) -> anyhow::Result<()> {
+    let db_pool = DatabasePool::create(db_connection, 1, false)
+        .await
+        .context("Could not create database pool")?;
+    let mut stored_hash = db_pool
+        .get()
+        .await
+        .context("Could not get database connection from pool")?
+        .get_election_result()
+        .await
+        .context("Could not get election result from database")?
+        .map(|result| result.result_hash);
+
+    tracing::info!("Election has ended; waiting for the election result to be published.");
+    while !stop_flag.load(Ordering::Acquire) {
+        match store_election_result(client, &db_pool, &mut stored_hash).await {
+            Ok(true) => {
+                tracing::info!("Election is locked; the stored election result is final.");
+                break;
+            }
+            Ok(false) => {}
+            Err(e) => tracing::warn!("Could not update the election result: {:#}", e),
+        }
+        tokio::time::sleep(RESULT_POLL_INTERVAL).await;
+    }
+    Ok(())
+}
+
+/// Queries the election result published in the election contract, and stores
+/// it in the database if its hash differs from `stored_hash`. Returns whether
+/// the election is locked with the stored result being final.
+async fn store_election_result(
+    client: &mut ElectionContract,
+    db_pool: &DatabasePool,
+    stored_hash: &mut Option<String>,
+) -> anyhow::Result<bool> {
+    // The config is queried first, such that the result queried afterwards is the
+    // final one if the election was already locked.
+    let locked = get_election_config(client).await?.locked;
+    let contest_results = get_contest_results(client).await?;
+    update_election_result(db_pool, locked, contest_results, stored_hash).await
+}
+
+/// Stores the `contest_results` published in the election contract in the
+/// database if its hash differs from `stored_hash`. Returns whether the
+/// published result is final, i.e. whether it is published and the election is
+/// `locked`.
+async fn update_election_result(
+    db_pool: &DatabasePool,
+    locked: bool,
+    contest_results: ViewContestResultsQueryResponse,
+    stored_hash: &mut Option<String>,
+) -> anyhow::Result<bool> {
+    let Some(contest_results) = contest_results else {
+        tracing::debug!("No election result has been published yet.");
+        return Ok(false);
+    };
+
+    let votes: ElectionResult = contest_results
+        .iter()
+        .map(|contest| {
+            let votes = contest.candidates.iter().map(|r| r.cummulative_votes);
+            (contest.contest_id, votes.collect())
+        })
+        .collect();
+    let result_hash = election_result_hash(&votes);
+    let result: Vec<_> = contest_results
+        .into_iter()
+        .flat_map(|contest| contest.candidates)
+        .collect();
+    if stored_hash.as_deref() != Some(result_hash.to_string().as_str()) {
+        let mut db = db_pool
+            .get()
+            .await
+            .context("Could not get database connection from pool")?;
+        let transaction = Transaction::from(
+            db.client
+                .transaction()
+                .await
+                .context("Failed to build DB transaction")?,
+        );
+        transaction
+            .set_election_result(&result, &result_hash)
+            .await?;
+        transaction
+            .inner
+            .commit()
+            .await
+            .context("Failed to commit DB transaction.")?;
+        tracing::info!("Stored election result with hash {}", result_hash);
+        *stored_hash = Some(result_hash.to_string());
+    }
+
+    Ok(locked)
+}
+
 /// Construct a future for shutdown signals (for unix: SIGINT and SIGTERM) (for
 /// windows: ctrl c and ctrl break). The signal handler is set when the future
 /// is polled and until then the default signal handler.
-async fn set_shutdown(flag: Arc<AtomicBool>) -> anyhow::Result<()> {
+async fn set_shutdown(
+    flag: Arc<AtomicBool>,
+    stop_sender: Arc<tokio::sync::watch::Sender<bool>>,
+) -> anyhow::Result<()> {
     #[cfg(unix)]
     {
         use tokio::signal::unix as unix_signal;
@@ -289,6 +477,7 @@ async fn set_shutdown(flag: Arc<AtomicBool>) -> anyhow::Result<()> {
         let interrupt = Box::pin(interrupt_stream.recv());
         futures::future::select(terminate, interrupt).await;
         flag.store(true, Ordering::Release);
+        stop_sender.send_replace(true);
     }
     #[cfg(windows)]
     {
@@ -299,6 +488,7 @@ async fn set_shutdown(flag: Arc<AtomicBool>) -> anyhow::Result<()> {
         let ctrl_c = Box::pin(ctrl_c_stream.recv());
         futures::future::select(ctrl_break, ctrl_c).await;
         flag.store(true, Ordering::Release);
+        stop_sender.send_replace(true);
     }
     Ok(())
 }
@@ -335,6 +525,7 @@ fn get_transaction_data(
                 address,
                 receive_name,
                 message,
+                events,
                 ..
             }) = execution_tree(effects)?
             else {
@@ -352,15 +543,32 @@ fn get_transaction_data(
                     }) {
                     Ok(ballot) => ballot,
                     Err(err) => {
-                        tracing::warn!("Could not parse ballot: {}", err);
-                        return None;
+                        let error = format!("{err:#}");
+                        tracing::warn!(account = %from_account, %error, "Could not parse ballot.");
+                        return Some(TransactionData::MalformedBallot(
+                            MalformedBallotSubmission {
+                                account: from_account,
+                                transaction_hash,
+                                error,
+                            },
+                        ));
                     }
                 };
             let verified = ballot.verify(verification_context);
+            // Contract versions logging a `VoteAccepted` event state the voter
+            // directly. Older versions do not log events, in which case the
+            // sender is the voter.
+            let account = events
+                .iter()
+                .find_map(|event| match event.parse::<Event>() {
+                    Ok(Event::VoteAccepted { account, .. }) => Some(account),
+                    _ => None,
+                })
+                .unwrap_or(from_account);
             TransactionData::BallotSubmission(BallotSubmission {
                 ballot,
                 verified,
-                account: from_account,
+                account,
                 transaction_hash,
             })
         }
@@ -462,9 +670,67 @@ async fn find_election_start_height(
     Ok(block_info.block_height)
 }
 
+/// The minimum interval at which the progress of a rebuild is logged.
+const REBUILD_PROGRESS_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Tracks the progress of rebuilding the database, i.e. of indexing the blocks
+/// from the start of the election up to the block which was the last finalized
+/// block when the rebuild started.
+#[derive(Debug)]
+struct RebuildProgress {
+    /// The height the rebuild started from.
+    start:       AbsoluteBlockHeight,
+    /// The height at which the rebuild has caught up with the chain.
+    target:      AbsoluteBlockHeight,
+    /// When the progress was last logged.
+    last_logged: Option<std::time::Instant>,
+}
+
+impl RebuildProgress {
+    fn new(start: AbsoluteBlockHeight, target: AbsoluteBlockHeight) -> Self {
+        Self {
+            start,
+            target,
+            last_logged: None,
+        }
+    }
+
+    /// The percentage of the blocks up to the target which have been indexed
+    /// once the block at `height` is.
+    fn percentage(&self, height: AbsoluteBlockHeight) -> f64 {
+        if height >= self.target {
+            return 100.0;
+        }
+        let indexed = height.height.saturating_sub(self.start.height) + 1;
+        let total = self.target.height - self.start.height + 1;
+        100.0 * indexed as f64 / total as f64
+    }
+
+    /// Registers that the block at `height` has been indexed at time `now`.
+    /// Returns the percentage to log if the progress is due to be logged, which
+    /// is the case for the first block, once [`REBUILD_PROGRESS_INTERVAL`] has
+    /// passed since the progress was last logged, and for the target block.
+    fn update(&mut self, height: AbsoluteBlockHeight, now: std::time::Instant) -> Option<f64> {
+        let due = self.last_logged.map_or(true, |last| {
+            now.duration_since(last) >= REBUILD_PROGRESS_INTERVAL
+        });
+        if !due && height < self.target {
+            return None;
+        }
+        self.last_logged = Some(now);
+        Some(self.percentage(height))
+    }
+
+    /// Whether the rebuild has caught up with the chain once the block at
+    /// `height` is indexed.
+    fn is_done(&self, height: AbsoluteBlockHeight) -> bool { height >= self.target }
+}
+
 /// Queries the node available at `node_endpoint` from `latest_height` until
 /// stopped. Sends the data structured by block to DB process through
 /// `block_sender`. Process runs until stopped or an error happens internally.
+/// While rebuilding the database, progress is logged through
+/// `rebuild_progress`, which is cleared once the rebuild has caught up.
 #[allow(clippy::too_many_arguments)]
 #[tracing::instrument(skip_all, fields(node_endpoint = %node_endpoint.uri(), from_height = ?from_height))]
 async fn node_process(
@@ -478,6 +744,7 @@ async fn node_process(
     max_behind_s: u32,
     stop_flag: &AtomicBool,
     run_until: DateTime<Utc>,
+    rebuild_progress: &mut Option<RebuildProgress>,
 ) -> anyhow::Result<()> {
     let node_uri = node_endpoint.uri().clone();
     let mut node = create_client(node_endpoint, request_timeout).await?;
@@ -522,12 +789,101 @@ async fn node_process(
         }
 
         *from_height = block.height;
+
+        if let Some(progress) = rebuild_progress {
+            if let Some(percentage) = progress.update(block.height, std::time::Instant::now()) {
+                tracing::info!(
+                    "Rebuilding the database: indexed block at height {} of {} ({:.1}%).",
+                    block.height.height,
+                    progress.target.height,
+                    percentage
+                );
+            }
+            if progress.is_done(block.height) {
+                tracing::info!("Rebuild of the database has caught up with the chain.");
+                *rebuild_progress = None;
+            }
+        }
     }
 
     tracing::info!("Service stopped gracefully from exit signal.");
     Ok(())
 }
 
+/// Gets the public keys registered by the guardians in the election contract.
+/// Fails if any guardian has not registered a key.
+async fn get_guardian_public_keys(
+    contract_client: &mut ElectionContract,
+) -> anyhow::Result<Vec<GuardianPublicKey>> {
+    let guardians = get_guardians_state(contract_client).await?;
+    guardians
+        .iter()
+        .map(|(ga, gs)| {
+            let bytes = gs
+                .public_key
+                .clone()
+                .with_context(|| format!("No public key found for guardian {ga}"))?;
+            let key: GuardianPublicKey = decode(&bytes)
+                .with_context(|| format!("Failed to decode public key for guardian {ga}"))?;
+            anyhow::Ok(key)
+        })
+        .collect::<Result<Vec<GuardianPublicKey>, _>>()
+        .context("Could not deserialize guardian public key")
+}
+
+/// Verifies the ballots stored in the database defined by `db_connection`
+/// against `verification_context`, and updates the verification status of the
+/// ballots for which it changed. Only the ballots which could not be verified
+/// are checked, unless `all` is true. All updates are committed as a single
+/// transaction.
+async fn update_verification(
+    db_connection: tokio_postgres::config::Config,
+    verification_context: &PreVotingData,
+    all: bool,
+) -> anyhow::Result<()> {
+    let db_pool = DatabasePool::create(db_connection, 1, false)
+        .await
+        .context("Could not create database pool")?;
+    let mut db = db_pool
+        .get()
+        .await
+        .context("Could not get database connection from pool")?;
+    let ballots = db
+        .get_ballot_submissions_for_verification(all)
+        .await
+        .context("Could not get ballot submissions from database")?;
+    tracing::info!("Verifying {} ballot submissions.", ballots.len());
+
+    let transaction = Transaction::from(
+        db.client
+            .transaction()
+            .await
+            .context("Failed to build DB transaction")?,
+    );
+    let mut updated = 0;
+    for ballot in ballots {
+        let verified = ballot.ballot.verify(verification_context);
+        if verified != ballot.verified {
+            tracing::info!(
+                transaction_hash = %ballot.transaction_hash,
+                "Updating verification status of ballot {} to {}.",
+                ballot.id,
+                verified
+            );
+            transaction.set_ballot_verified(ballot.id, verified).await?;
+            updated += 1;
+        }
+    }
+    transaction
+        .inner
+        .commit()
+        .await
+        .context("Failed to commit DB transaction.")?;
+
+    tracing::info!("Updated the verification status of {updated} ballot submissions.");
+    Ok(())
+}
+
 /// Constructs the [`PreVotingData`] necessary for ballot verification with
 /// election guard.
 fn get_verification_context(
@@ -555,6 +911,112 @@ fn get_verification_context(
     Ok(pre_voting_data)
 }
 
+/// The maximal number of ballot submissions returned by [`get_ballots`].
+const MAX_BALLOTS_LIMIT: usize = 100;
+
+fn default_ballots_limit() -> usize { MAX_BALLOTS_LIMIT }
+
+/// Query parameters passed to [`get_ballots`].
+#[derive(serde::Deserialize, Debug)]
+struct BallotsQueryParams {
+    /// The height of the first block to get ballot submissions from.
+    #[serde(default)]
+    from_height: u64,
+    /// The maximal number of ballot submissions to get, capped by
+    /// [`MAX_BALLOTS_LIMIT`].
+    #[serde(default = "default_ballots_limit")]
+    limit:       usize,
+}
+
+/// A ballot submission together with the height of the block it was included
+/// in, as returned by [`get_ballots`].
+#[derive(serde::Serialize, Debug)]
+struct BallotAtHeight {
+    /// The height of the block the ballot submission was included in.
+    block_height: AbsoluteBlockHeight,
+    #[serde(flatten)]
+    submission:   BallotSubmission,
+}
+
+/// Get the ballot submissions included in blocks from `from_height`, ordered by
+/// height. As a block can contain more submissions than `limit`, the next page
+/// starts at the height of the last submission returned, and repeats the
+/// submissions of that block already returned. Returns [`StatusCode`]
+/// signaling error if database connection or lookup fails.
+#[tracing::instrument(skip(db_pool))]
+async fn get_ballots(
+    State(db_pool): State<DatabasePool>,
+    Query(query_params): Query<BallotsQueryParams>,
+) -> Result<Json<Vec<BallotAtHeight>>, StatusCode> {
+    let db = db_pool.get().await.map_err(|e| {
+        tracing::error!("Could not get db connection from pool: {}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let limit = std::cmp::min(query_params.limit, MAX_BALLOTS_LIMIT);
+    let ballots = db
+        .get_ballot_submissions_from_height(query_params.from_height.into(), limit)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get ballot submissions: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    let ballots = ballots
+        .into_iter()
+        .map(|(block_height, submission)| BallotAtHeight {
+            block_height,
+            submission: submission.into(),
+        })
+        .collect();
+    Ok(Json(ballots))
+}
+
+/// Get the latest verified ballot submission (if any) of `account`, which is
+/// the ballot counted in the tally for the account. Returns [`StatusCode`]
+/// signaling error if database connection or lookup fails.
+#[tracing::instrument(skip(db_pool))]
+async fn get_account_ballot(
+    State(db_pool): State<DatabasePool>,
+    Path(account): Path<contracts_common::AccountAddress>,
+) -> Result<Json<Option<BallotSubmission>>, StatusCode> {
+    let db = db_pool.get().await.map_err(|e| {
+        tracing::error!("Could not get db connection from pool: {}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let ballot = db
+        .get_latest_verified_ballot_submission(&account)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get ballot submission: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    Ok(Json(ballot.map(BallotSubmission::from)))
+}
+
+/// Serves the ballot query API on `listener`, reading the ballots from the
+/// database defined in `db_connection`, until `stop_receiver` signals that the
+/// indexer is stopping.
+async fn run_api(
+    listener: tokio::net::TcpListener,
+    db_connection: tokio_postgres::config::Config,
+    pool_size: usize,
+    mut stop_receiver: tokio::sync::watch::Receiver<bool>,
+) -> anyhow::Result<()> {
+    let db_pool = DatabasePool::create(db_connection, pool_size, false)
+        .await
+        .context("Could not create database pool")?;
+    let api = Router::new()
+        .route("/ballots", get(get_ballots))
+        .route("/ballots/:account", get(get_account_ballot))
+        .with_state(db_pool);
+    axum::serve(listener, api)
+        .with_graceful_shutdown(async move {
+            // An error means the indexer has stopped, hence the API should stop as well.
+            let _ = stop_receiver.wait_for(|stop| *stop).await;
+        })
+        .await
+        .context("Ballot query API has shut down")
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let config = AppConfig::parse();
@@ -588,6 +1050,13 @@ async fn main() -> anyhow::Result<()> {
         .request_and_verify_config_files(&contract_config)
         .await?;
 
+    if let Some(Command::UpdateVerification { all }) = config.command {
+        let guardian_public_keys = get_guardian_public_keys(&mut contract_client).await?;
+        let verification_context =
+            get_verification_context(election_parameters, election_manifest, guardian_public_keys)?;
+        return update_verification(config.db_connection, &verification_context, all).await;
+    }
+
     // Since the database connection is managed by the background task we use a
     // oneshot channel to get the height we should start querying at. First the
     // background database task is started which then sends the height over this
@@ -596,18 +1065,46 @@ async fn main() -> anyhow::Result<()> {
     // Create a channel between the task querying the node and the task logging
     // transactions.
     let (block_sender, block_receiver) = tokio::sync::mpsc::channel(100);
-    // node/db processes run until the stop flag is triggered.
+    // node/db processes run until the stop flag is triggered. The ballot query API
+    // is notified through the stop channel.
     let stop_flag = Arc::new(AtomicBool::new(false));
-    let shutdown_handle = tokio::spawn(set_shutdown(stop_flag.clone()));
+    let (stop_sender, stop_receiver) = tokio::sync::watch::channel(false);
+    let stop_sender = Arc::new(stop_sender);
+    let shutdown_handle = tokio::spawn(set_shutdown(stop_flag.clone(), stop_sender.clone()));
 
+    let api_handle = if let Some(listen_address) = config.listen_address {
+        let listener = tokio::net::TcpListener::bind(listen_address)
+            .await
+            .with_context(|| format!("Could not bind ballot query API to {listen_address}"))?;
+        tracing::info!("Serving ballot queries on {}", listen_address);
+        let api_db_connection = config.db_connection.clone();
+        let pool_size = config.pool_size;
+        Some(tokio::spawn(async move {
+            let result = run_api(listener, api_db_connection, pool_size, stop_receiver).await;
+            if let Err(err) = result {
+                tracing::error!(
+                    "Error happened while running the ballot query API: {:?}",
+                    err
+                );
+            }
+        }))
+    } else {
+        None
+    };
+
+    // The result process runs after the db process has stopped, hence needs its own
+    // connection.
+    let result_db_connection = config.db_connection.clone();
     let db_stop = stop_flag.clone();
     let db_handle = tokio::spawn(async move {
         let result = run_db_process(
             config.db_connection,
+            config.pool_size,
             config.contract_address,
             block_receiver,
             height_sender,
             db_stop,
+            config.rebuild,
         )
         .await;
 
@@ -631,24 +1128,26 @@ async fn main() -> anyhow::Result<()> {
     // election guardians should have registered their keys needed for ballot
     // verification at this point.
     let contract_config = get_election_config(&mut contract_client).await?;
-    let guardians = get_guardians_state(&mut contract_client).await?;
-    let guardian_public_keys = guardians
-        .iter()
-        .map(|(ga, gs)| {
-            let bytes = gs
-                .public_key
-                .clone()
-                .with_context(|| format!("No public key found for guardian {ga}"))?;
-            let key: GuardianPublicKey = decode(&bytes)
-                .with_context(|| format!("Failed to decode public key for guardian {ga}"))?;
-            anyhow::Ok(key)
-        })
-        .collect::<Result<Vec<GuardianPublicKey>, _>>()
-        .context("Could not deserialize guardian public key")?;
+    let guardian_public_keys = get_guardian_public_keys(&mut contract_client).await?;
     let verification_context =
         get_verification_context(election_parameters, election_manifest, guardian_public_keys)?;
     let delegation_string = contract_config.delegation_string;
 
+    // A rebuild is done once the blocks up to the last finalized block at this
+    // point have been indexed.
+    let mut rebuild_progress = if config.rebuild {
+        let target = contract_client
+            .client
+            .get_consensus_info()
+            .await
+            .context("Could not get consensus info")?
+            .last_finalized_block_height;
+        Some(RebuildProgress::new(from_height, target))
+    } else {
+        None
+    };
+
+    let mut election_ended = false;
     let mut latest_successful_node: u64 = 0;
     let num_nodes = config.node_endpoints.len() as u64;
     for (node, i) in config.node_endpoints.into_iter().cycle().zip(0u64..) {
@@ -683,6 +1182,7 @@ async fn main() -> anyhow::Result<()> {
             config.max_behind_s,
             stop_flag.as_ref(),
             contract_config.election_end.try_into()?,
+            &mut rebuild_progress,
         )
         .await;
 
@@ -695,6 +1195,7 @@ async fn main() -> anyhow::Result<()> {
         } else {
             // `node_process` terminated with `Ok`, meaning we should stop the service
             // entirely.
+            election_ended = true;
             drop(block_sender);
             break;
         }
@@ -705,6 +1206,658 @@ async fn main() -> anyhow::Result<()> {
     }
 
     db_handle.await?;
+
+    if election_ended {
+        run_result_process(
+            &mut contract_client,
+            result_db_connection,
+            stop_flag.as_ref(),
+        )
+        .await?;
+    }
     shutdown_handle.abort();
+    // The indexer can also stop without a shutdown signal, e.g. once the result is
+    // final.
+    stop_sender.send_replace(true);
+    if let Some(api_handle) = api_handle {
+        api_handle.await?;
+    }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use concordium_governance_committee_election::{
+        CandidateResult, ChecksumUrl, ContestResultView, HashSha2256,
+    };
+    use concordium_rust_sdk::{
+        common::types::Amount,
+        smart_contracts::common::AccountAddress,
+        types::{
+            hashes::TransactionHash,
+            smart_contracts::{OwnedParameter, OwnedReceiveName, WasmVersion},
+            AccountTransactionDetails, Address, ContractTraceElement, Energy, InstanceUpdatedEvent,
+            TransactionIndex,
+        },
+    };
+    use eg::{
+        ballot_style::{BallotStyle, BallotStyleIndex},
+        contest_selection::{ContestSelection, ContestSelectionPlaintext},
+        device::Device,
+        election_manifest::{Contest, ContestIndex, ContestOption},
+        guardian::GuardianIndex,
+        guardian_secret_key::GuardianSecretKey,
+        varying_parameters::{BallotChaining, VaryingParameters},
+    };
+    use util::csprng::Csprng;
+
+    #[test]
+    fn test_rebuild_progress() {
+        let mut progress = RebuildProgress::new(100.into(), 199.into());
+        let start = std::time::Instant::now();
+        assert_eq!(progress.update(100.into(), start), Some(1.0));
+        assert_eq!(progress.update(101.into(), start), None);
+        assert_eq!(
+            progress.update(149.into(), start + REBUILD_PROGRESS_INTERVAL),
+            Some(50.0)
+        );
+        assert!(!progress.is_done(198.into()));
+        // The target is always logged, regardless of the interval.
+        assert_eq!(
+            progress.update(199.into(), start + REBUILD_PROGRESS_INTERVAL),
+            Some(100.0)
+        );
+        assert!(progress.is_done(199.into()));
+        assert!(progress.is_done(200.into()));
+    }
+
+    /// The ballot verification context of an election with a single guardian
+    /// and a single contest with two options.
+    fn verification_context() -> PreVotingData {
+        verification_context_with_guardian(b"indexer test guardian")
+    }
+
+    /// The context of the test election, where the key of the single guardian
+    /// is generated from `seed`.
+    fn verification_context_with_guardian(seed: &[u8]) -> PreVotingData {
+        let contest = ContestIndex::from_one_based_index(1).unwrap();
+        let manifest = ElectionManifest {
+            label:         "Test election manifest".into(),
+            contests:      [Contest {
+                label:           "Test contest".into(),
+                selection_limit: 1,
+                options:         [
+                    ContestOption {
+                        label: "Candidate 1".into(),
+                    },
+                    ContestOption {
+                        label: "Candidate 2".into(),
+                    },
+                ]
+                .try_into()
+                .unwrap(),
+            }]
+            .try_into()
+            .unwrap(),
+            ballot_styles: [BallotStyle {
+                label:    "Test ballot style".into(),
+                contests: [contest].into(),
+            }]
+            .try_into()
+            .unwrap(),
+        };
+        let guardian = GuardianIndex::from_one_based_index(1).unwrap();
+        let parameters = ElectionParameters {
+            fixed_parameters:   eg::standard_parameters::STANDARD_PARAMETERS.clone(),
+            varying_parameters: VaryingParameters {
+                n:               guardian,
+                k:               guardian,
+                date:            Utc.timestamp_opt(0, 0).unwrap(),
+                info:            "Test election with a single guardian.".into(),
+                ballot_chaining: BallotChaining::Prohibited,
+            },
+        };
+        let secret_key =
+            GuardianSecretKey::generate(&mut Csprng::new(seed), &parameters, guardian, None);
+        get_verification_context(parameters, manifest, vec![secret_key.make_public_key()]).unwrap()
+    }
+
+    /// A block at `height` with a ballot submission for each of `ballots`,
+    /// given by the byte the address of the voter is filled with and the
+    /// selection of the ballot.
+    fn block(
+        height: u64,
+        ballots: &[(u8, [bool; 2])],
+        device: &Device,
+        context: &PreVotingData,
+        csprng: &mut Csprng,
+    ) -> BlockData {
+        let contest = ContestIndex::from_one_based_index(1).unwrap();
+        let transactions = ballots
+            .iter()
+            .enumerate()
+            .map(|(i, &(account, selection))| {
+                let selection = ContestSelection::new(
+                    selection
+                        .into_iter()
+                        .map(ContestSelectionPlaintext::from)
+                        .collect(),
+                )
+                .unwrap();
+                let ballot = BallotEncrypted::new_from_selections(
+                    BallotStyleIndex::from_one_based_index_unchecked(1),
+                    device,
+                    csprng,
+                    &[0; 32],
+                    &[(contest, selection)].into(),
+                )
+                .unwrap();
+                let verified = ballot.verify(context);
+                TransactionData::BallotSubmission(BallotSubmission {
+                    account: AccountAddress([account; 32]),
+                    ballot,
+                    transaction_hash: TransactionHash::new([height as u8 * 16 + i as u8; 32]),
+                    verified,
+                })
+            })
+            .collect();
+        BlockData {
+            block_hash: BlockHash::new([height as u8; 32]),
+            height: height.into(),
+            block_time: Utc.timestamp_opt(height as i64 * 2, 0).unwrap(),
+            transactions,
+        }
+    }
+
+    /// All ballot rows stored in `db`, in the order they were indexed.
+    async fn ballot_rows(db: &Database) -> serde_json::Value {
+        let mut rows = db.get_all_ballot_submissions(None, 100).await.unwrap();
+        rows.reverse();
+        serde_json::to_value(rows).unwrap()
+    }
+
+    /// Indexing from the start of the election after a rebuild gives the same
+    /// ballot rows as indexing the election incrementally, with a restart
+    /// retrying the latest block in between.
+    #[tokio::test]
+    #[ignore = "requires a postgres database given by CCD_ELECTION_TEST_DB_CONNECTION"]
+    async fn test_rebuild() {
+        let db_connection = std::env::var("CCD_ELECTION_TEST_DB_CONNECTION")
+            .expect("CCD_ELECTION_TEST_DB_CONNECTION is set")
+            .parse()
+            .expect("Valid connection string");
+        let db_pool = DatabasePool::create(db_connection, 1, true).await.unwrap();
+        let mut db = db_pool.get().await.unwrap();
+        db.clear().await.unwrap();
+
+        let device = Device::new("test", verification_context());
+        let context = verification_context();
+        let mut csprng = Csprng::new(b"indexer rebuild test");
+        let blocks = [
+            block(
+                1,
+                &[(1, [true, false]), (2, [false, true])],
+                &device,
+                &context,
+                &mut csprng,
+            ),
+            block(2, &[], &device, &context, &mut csprng),
+            block(3, &[(1, [false, true])], &device, &context, &mut csprng),
+        ];
+        let contract_address = ContractAddress::new(1, 0);
+
+        assert_eq!(
+            prepare_database(&db, &contract_address, false)
+                .await
+                .unwrap(),
+            None
+        );
+        for block in &blocks[..2] {
+            db_insert_block(&mut db, block).await.unwrap();
+        }
+        // Resuming after a restart, where the latest block is inserted again.
+        assert_eq!(
+            prepare_database(&db, &contract_address, false)
+                .await
+                .unwrap(),
+            Some(2.into())
+        );
+        for block in &blocks[1..] {
+            db_insert_block(&mut db, block).await.unwrap();
+        }
+        let incremental = ballot_rows(&db).await;
+        assert_eq!(incremental.as_array().map(Vec::len), Some(3));
+
+        assert_eq!(
+            prepare_database(&db, &contract_address, true)
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(ballot_rows(&db).await, serde_json::json!([]));
+        for block in &blocks {
+            db_insert_block(&mut db, block).await.unwrap();
+        }
+        assert_eq!(ballot_rows(&db).await, incremental);
+        assert_eq!(
+            db.get_settings().await.unwrap().latest_height,
+            Some(3.into())
+        );
+    }
+
+    /// The block heights and accounts of the ballots returned by
+    /// [`get_ballots`] for `query`.
+    async fn ballots(db_pool: &DatabasePool, query: &str) -> Vec<(u64, AccountAddress)> {
+        let uri = format!("/ballots?{query}").parse().unwrap();
+        let query_params = Query::try_from_uri(&uri).expect("Valid query parameters");
+        let Json(ballots) = get_ballots(State(db_pool.clone()), query_params)
+            .await
+            .expect("Can get ballots");
+        ballots
+            .into_iter()
+            .map(|ballot| (ballot.block_height.height, ballot.submission.account))
+            .collect()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a postgres database given by CCD_ELECTION_TEST_DB_CONNECTION"]
+    async fn test_ballot_api() {
+        let db_connection = std::env::var("CCD_ELECTION_TEST_DB_CONNECTION")
+            .expect("CCD_ELECTION_TEST_DB_CONNECTION is set")
+            .parse()
+            .expect("Valid connection string");
+        let db_pool = DatabasePool::create(db_connection, 1, true).await.unwrap();
+        let mut db = db_pool.get().await.unwrap();
+        db.clear().await.unwrap();
+
+        let device = Device::new("test", verification_context());
+        let context = verification_context();
+        let mut csprng = Csprng::new(b"indexer ballot api test");
+        let blocks = [
+            block(
+                1,
+                &[(1, [true, false]), (2, [false, true])],
+                &device,
+                &context,
+                &mut csprng,
+            ),
+            block(2, &[], &device, &context, &mut csprng),
+            block(3, &[(1, [false, true])], &device, &context, &mut csprng),
+        ];
+        for block in &blocks {
+            db_insert_block(&mut db, block).await.unwrap();
+        }
+        drop(db);
+
+        let first = AccountAddress([1; 32]);
+        let second = AccountAddress([2; 32]);
+        assert_eq!(ballots(&db_pool, "").await, vec![
+            (1, first),
+            (1, second),
+            (3, first)
+        ]);
+        assert_eq!(ballots(&db_pool, "from_height=2").await, vec![(3, first)]);
+        assert_eq!(ballots(&db_pool, "from_height=1&limit=1").await, vec![(
+            1, first
+        )]);
+        assert_eq!(ballots(&db_pool, "from_height=4").await, vec![]);
+
+        let Json(latest) = get_account_ballot(State(db_pool.clone()), Path(first))
+            .await
+            .expect("Can get ballot");
+        let latest = latest.expect("The account has a verified ballot");
+        assert!(latest.verified);
+        assert_eq!(
+            latest.transaction_hash,
+            TransactionHash::new([3 * 16; 32]),
+            "The latest ballot of the account is returned"
+        );
+        let Json(none) = get_account_ballot(State(db_pool.clone()), Path(AccountAddress([9; 32])))
+            .await
+            .expect("Can get ballot");
+        assert!(none.is_none(), "An account without ballots has none");
+    }
+
+    /// The ids of the ballot rows stored in `db`, in order.
+    async fn ballot_ids(db: &Database) -> Vec<i64> {
+        db.client
+            .query("SELECT id FROM ballots ORDER BY id", &[])
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| row.get(0))
+            .collect()
+    }
+
+    /// Inserting the ballots of a block again, e.g. when retrying after a
+    /// database error, does not record them twice.
+    #[tokio::test]
+    #[ignore = "requires a postgres database given by CCD_ELECTION_TEST_DB_CONNECTION"]
+    async fn test_insert_ballot_twice() {
+        let db_connection: tokio_postgres::config::Config =
+            std::env::var("CCD_ELECTION_TEST_DB_CONNECTION")
+                .expect("CCD_ELECTION_TEST_DB_CONNECTION is set")
+                .parse()
+                .expect("Valid connection string");
+        let db_pool = DatabasePool::create(db_connection.clone(), 1, true)
+            .await
+            .unwrap();
+        let mut db = db_pool.get().await.unwrap();
+        db.clear().await.unwrap();
+
+        let device = Device::new("test", verification_context());
+        let context = verification_context();
+        let mut csprng = Csprng::new(b"indexer insert twice test");
+        let block = block(1, &[(1, [true, false])], &device, &context, &mut csprng);
+        db_insert_block(&mut db, &block).await.unwrap();
+        db_insert_block(&mut db, &block).await.unwrap();
+        assert_eq!(ballot_ids(&db).await, vec![0]);
+
+        let TransactionData::BallotSubmission(ballot) = &block.transactions[0] else {
+            panic!("The block contains a ballot submission");
+        };
+        let transaction = Transaction::from(db.client.transaction().await.unwrap());
+        transaction
+            .insert_ballot(ballot, block.height, block.block_time)
+            .await
+            .unwrap();
+        transaction
+            .insert_ballot(ballot, block.height, block.block_time)
+            .await
+            .unwrap();
+        transaction.inner.commit().await.unwrap();
+        assert_eq!(ballot_ids(&db).await, vec![0]);
+
+        // Duplicates recorded before the unique index existed are removed when the
+        // schema is applied, keeping the ballot recorded first.
+        db.client
+            .batch_execute(
+                "DROP INDEX ballots_transaction_hash_idx; INSERT INTO ballots (id, \
+                 transaction_hash, block_time, ballot, account, verified) SELECT 1, \
+                 transaction_hash, block_time, ballot, account, verified FROM ballots WHERE id = 0",
+            )
+            .await
+            .unwrap();
+        assert_eq!(ballot_ids(&db).await, vec![0, 1]);
+        drop(db);
+        let mut db = DatabasePool::create(db_connection, 1, true)
+            .await
+            .unwrap()
+            .get()
+            .await
+            .unwrap();
+        assert_eq!(ballot_ids(&db).await, vec![0]);
+        db_insert_block(&mut db, &block).await.unwrap();
+        assert_eq!(
+            ballot_ids(&db).await,
+            vec![0],
+            "The unique index is restored"
+        );
+    }
+
+    /// The index and verification status of the ballots stored in `db`.
+    async fn ballot_verification(db: &Database) -> Vec<(i64, bool)> {
+        db.client
+            .query("SELECT id, verified FROM ballots ORDER BY id", &[])
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| (row.get(0), row.get(1)))
+            .collect()
+    }
+
+    /// Ballots which could not be verified against an outdated context are
+    /// verified again against the current context, where all ballots are only
+    /// checked if requested.
+    #[tokio::test]
+    #[ignore = "requires a postgres database given by CCD_ELECTION_TEST_DB_CONNECTION"]
+    async fn test_update_verification() {
+        let db_connection: tokio_postgres::config::Config =
+            std::env::var("CCD_ELECTION_TEST_DB_CONNECTION")
+                .expect("CCD_ELECTION_TEST_DB_CONNECTION is set")
+                .parse()
+                .expect("Valid connection string");
+        let db_pool = DatabasePool::create(db_connection.clone(), 1, true)
+            .await
+            .unwrap();
+        let mut db = db_pool.get().await.unwrap();
+        db.clear().await.unwrap();
+
+        let context = verification_context();
+        let outdated_context = verification_context_with_guardian(b"outdated guardian");
+        let device = Device::new("test", verification_context());
+        let outdated_device = Device::new(
+            "test",
+            verification_context_with_guardian(b"outdated guardian"),
+        );
+        let mut csprng = Csprng::new(b"indexer update verification test");
+        let blocks = [
+            block(
+                1,
+                &[(1, [true, false]), (2, [false, true])],
+                &device,
+                &outdated_context,
+                &mut csprng,
+            ),
+            block(
+                2,
+                &[(3, [true, false])],
+                &outdated_device,
+                &outdated_context,
+                &mut csprng,
+            ),
+        ];
+        for block in &blocks {
+            db_insert_block(&mut db, block).await.unwrap();
+        }
+        assert_eq!(ballot_verification(&db).await, vec![
+            (0, false),
+            (1, false),
+            (2, true)
+        ]);
+
+        update_verification(db_connection.clone(), &context, false)
+            .await
+            .unwrap();
+        assert_eq!(ballot_verification(&db).await, vec![
+            (0, true),
+            (1, true),
+            (2, true)
+        ]);
+
+        update_verification(db_connection, &context, true)
+            .await
+            .unwrap();
+        assert_eq!(ballot_verification(&db).await, vec![
+            (0, true),
+            (1, true),
+            (2, false)
+        ]);
+    }
+
+    /// A transaction by `sender` updating `contract_address` through the
+    /// `registerVotes` entrypoint with `parameter`.
+    fn register_votes_transaction(
+        transaction_hash: TransactionHash,
+        sender: AccountAddress,
+        contract_address: ContractAddress,
+        parameter: Vec<u8>,
+    ) -> BlockItemSummary {
+        let update = InstanceUpdatedEvent {
+            contract_version: WasmVersion::V1,
+            address:          contract_address,
+            instigator:       Address::Account(sender),
+            amount:           Amount::zero(),
+            message:          OwnedParameter::new_unchecked(parameter),
+            receive_name:     OwnedReceiveName::new_unchecked(REGISTER_VOTES_RECEIVE.to_string()),
+            events:           Vec::new(),
+        };
+        BlockItemSummary {
+            index:       TransactionIndex { index: 0 },
+            energy_cost: Energy { energy: 0 },
+            hash:        transaction_hash,
+            details:     BlockItemSummaryDetails::AccountTransaction(AccountTransactionDetails {
+                cost: Amount::zero(),
+                sender,
+                effects: AccountTransactionEffects::ContractUpdateIssued {
+                    effects: vec![ContractTraceElement::Updated { data: update }],
+                },
+            }),
+        }
+    }
+
+    /// Submissions to `registerVotes` which cannot be parsed as a ballot are
+    /// recorded as malformed ballots, rather than being dropped.
+    #[tokio::test]
+    #[ignore = "requires a postgres database given by CCD_ELECTION_TEST_DB_CONNECTION"]
+    async fn test_malformed_ballot() {
+        let db_connection = std::env::var("CCD_ELECTION_TEST_DB_CONNECTION")
+            .expect("CCD_ELECTION_TEST_DB_CONNECTION is set")
+            .parse()
+            .expect("Valid connection string");
+        let db_pool = DatabasePool::create(db_connection, 1, true).await.unwrap();
+        let mut db = db_pool.get().await.unwrap();
+        db.clear().await.unwrap();
+
+        let contract_address = ContractAddress::new(1, 0);
+        let context = verification_context();
+        let sender = AccountAddress([1; 32]);
+        // The parameter is cut short of the ballot length it states, and the
+        // ballot of a well-formed parameter is not an encrypted ballot.
+        let parameters = [
+            (
+                vec![10, 0, 0, 0, 1],
+                "Failed to parse ballot from transaction message",
+            ),
+            (
+                contracts_common::to_bytes(&RegisterVotesParameter::from(vec![1, 2, 3])),
+                "Failed parse encrypted ballot",
+            ),
+        ];
+        let mut transactions = Vec::new();
+        for (i, (parameter, expected_error)) in parameters.into_iter().enumerate() {
+            let transaction_hash = TransactionHash::new([i as u8; 32]);
+            let transaction =
+                register_votes_transaction(transaction_hash, sender, contract_address, parameter);
+            let Some(TransactionData::MalformedBallot(submission)) =
+                get_transaction_data(transaction, &contract_address, &context, "delegate")
+            else {
+                panic!("The submission is recorded as a malformed ballot");
+            };
+            assert_eq!(submission.account, sender);
+            assert_eq!(submission.transaction_hash, transaction_hash);
+            assert!(
+                submission.error.starts_with(expected_error),
+                "Unexpected error: {}",
+                submission.error
+            );
+            transactions.push(TransactionData::MalformedBallot(submission));
+        }
+
+        let block = BlockData {
+            block_hash: BlockHash::new([1; 32]),
+            height: 1.into(),
+            block_time: Utc.timestamp_opt(2, 0).unwrap(),
+            transactions,
+        };
+        db_insert_block(&mut db, &block).await.unwrap();
+        let rows = db
+            .client
+            .query(
+                "SELECT transaction_hash, account FROM malformed_ballots ORDER BY transaction_hash",
+                &[],
+            )
+            .await
+            .unwrap();
+        let rows: Vec<(Vec<u8>, Vec<u8>)> =
+            rows.iter().map(|row| (row.get(0), row.get(1))).collect();
+        assert_eq!(rows, vec![
+            (vec![0; 32], vec![1; 32]),
+            (vec![1; 32], vec![1; 32])
+        ]);
+        assert_eq!(ballot_rows(&db).await, serde_json::json!([]));
+    }
+
+    /// The election result of a single contest, in which the candidates
+    /// received `votes`.
+    fn contest_results(votes: &[u64]) -> ViewContestResultsQueryResponse {
+        let candidates = votes
+            .iter()
+            .enumerate()
+            .map(|(i, &cummulative_votes)| CandidateResult {
+                candidate: ChecksumUrl {
+                    url:  format!("https://candidates.example/{i}.json"),
+                    hash: HashSha2256([i as u8; 32]),
+                },
+                cummulative_votes,
+            })
+            .collect();
+        Some(vec![ContestResultView {
+            contest_id: 0,
+            candidates,
+        }])
+    }
+
+    /// The votes of each candidate in the election result stored in `db`.
+    async fn stored_votes(db: &Database) -> Option<Vec<u64>> {
+        let result = db.get_election_result().await.unwrap()?;
+        Some(result.candidates.iter().map(|c| c.votes).collect())
+    }
+
+    /// A published result is stored unless its hash matches the stored one,
+    /// and the result process stops once the election is locked.
+    #[tokio::test]
+    #[ignore = "requires a postgres database given by CCD_ELECTION_TEST_DB_CONNECTION"]
+    async fn test_update_election_result() {
+        let db_connection = std::env::var("CCD_ELECTION_TEST_DB_CONNECTION")
+            .expect("CCD_ELECTION_TEST_DB_CONNECTION is set")
+            .parse()
+            .expect("Valid connection string");
+        let db_pool = DatabasePool::create(db_connection, 1, true).await.unwrap();
+        let db = db_pool.get().await.unwrap();
+        db.clear().await.unwrap();
+        db.init_settings(&ContractAddress::new(1, 0)).await.unwrap();
+
+        let mut stored_hash = None;
+        let finished = update_election_result(&db_pool, false, None, &mut stored_hash)
+            .await
+            .unwrap();
+        assert!(!finished);
+        assert_eq!(stored_hash, None);
+        assert_eq!(stored_votes(&db).await, None);
+
+        let finished =
+            update_election_result(&db_pool, false, contest_results(&[3, 5]), &mut stored_hash)
+                .await
+                .unwrap();
+        assert!(!finished);
+        assert_eq!(stored_votes(&db).await, Some(vec![3, 5]));
+        let expected_hash = election_result_hash(&vec![(0, vec![3, 5])]).to_string();
+        assert_eq!(stored_hash.as_ref(), Some(&expected_hash));
+
+        // The result is not written again while its hash is unchanged.
+        db.client
+            .batch_execute("DELETE FROM results")
+            .await
+            .unwrap();
+        update_election_result(&db_pool, false, contest_results(&[3, 5]), &mut stored_hash)
+            .await
+            .unwrap();
+        assert_eq!(stored_votes(&db).await, Some(vec![]));
+
+        let finished =
+            update_election_result(&db_pool, true, contest_results(&[4, 5]), &mut stored_hash)
+                .await
+                .unwrap();
+        assert!(finished, "The result is final once the election is locked");
+        assert_eq!(stored_votes(&db).await, Some(vec![4, 5]));
+        let stored = db.get_election_result().await.unwrap().unwrap();
+        assert_eq!(
+            stored.result_hash,
+            election_result_hash(&vec![(0, vec![4, 5])]).to_string()
+        );
+        assert_eq!(stored_hash, Some(stored.result_hash));
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::Context;
 use chrono::{DateTime, Utc};
+use concordium_governance_committee_election::{CandidateResult, ChecksumUrl, HashSha2256};
 use concordium_rust_sdk::{
     smart_contracts::common::{AccountAddress, ACCOUNT_ADDRESS_SIZE},
     types::{hashes::TransactionHash, AbsoluteBlockHeight, ContractAddress},
@@ -10,7 +11,7 @@ use election_common::{decode, encode};
 use serde::Serialize;
 use tokio_postgres::{types::ToSql, NoTls};
 
-use crate::util::{BallotSubmission, VotingWeightDelegation};
+use crate::util::{BallotSubmission, MalformedBallotSubmission, VotingWeightDelegation};
 
 /// Represents possible errors returned from [`Database`] or [`DatabasePool`]
 /// functions
@@ -37,9 +38,11 @@ type DatabaseResult<T> = Result<T, DatabaseError>;
 #[derive(Debug, Serialize)]
 pub struct StoredConfiguration {
     /// The latest recorded block height.
-    pub latest_height:    Option<AbsoluteBlockHeight>,
+    pub latest_height:     Option<AbsoluteBlockHeight>,
     /// The contract address of the election contract monitored.
-    pub contract_address: ContractAddress,
+    pub contract_address:  ContractAddress,
+    /// The block time of the latest recorded block.
+    pub latest_block_time: Option<DateTime<Utc>>,
 }
 
 impl TryFrom<tokio_postgres::Row> for StoredConfiguration {
@@ -49,11 +52,13 @@ impl TryFrom<tokio_postgres::Row> for StoredConfiguration {
         let raw_latest_height: Option<i64> = value.try_get(0)?;
         let raw_contract_index: i64 = value.try_get(1)?;
         let raw_contract_subindex: i64 = value.try_get(2)?;
+        let latest_block_time: Option<DateTime<Utc>> = value.try_get(3)?;
         let contract_address =
             ContractAddress::new(raw_contract_index as u64, raw_contract_subindex as u64);
         let settings = Self {
             latest_height: raw_latest_height.map(|v| (v as u64).into()),
             contract_address,
+            latest_block_time,
         };
         Ok(settings)
     }
@@ -110,6 +115,17 @@ impl TryFrom<tokio_postgres::Row> for StoredBallotSubmission {
     }
 }
 
+impl From<StoredBallotSubmission> for BallotSubmission {
+    fn from(value: StoredBallotSubmission) -> Self {
+        Self {
+            account:          value.account,
+            ballot:           value.ballot,
+            transaction_hash: value.transaction_hash,
+            verified:         value.verified,
+        }
+    }
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StoredDelegation {
@@ -156,6 +172,53 @@ impl TryFrom<tokio_postgres::Row> for StoredDelegation {
     }
 }
 
+/// Describes the votes registered for a candidate in the election result
+/// published in the election contract.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredCandidateResult {
+    /// The candidate
+    pub candidate: ChecksumUrl,
+    /// The weighted votes registered for the candidate
+    pub votes:     u64,
+}
+
+impl TryFrom<tokio_postgres::Row> for StoredCandidateResult {
+    type Error = DatabaseError;
+
+    fn try_from(value: tokio_postgres::Row) -> DatabaseResult<Self> {
+        let url: String = value.try_get(0)?;
+        let raw_hash: &[u8] = value.try_get(1)?;
+        let votes: i64 = value.try_get(2)?;
+
+        let hash: [u8; 32] = raw_hash
+            .try_into()
+            .map_err(|_| DatabaseError::TypeConversion)?;
+
+        let candidate_result = Self {
+            candidate: ChecksumUrl {
+                url,
+                hash: HashSha2256(hash),
+            },
+            votes:     votes as u64,
+        };
+        Ok(candidate_result)
+    }
+}
+
+/// Describes the election result published in the election contract as it is
+/// stored in the database.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredElectionResult {
+    /// The hash of the election result, see
+    /// [`election_common::election_result_hash`].
+    pub result_hash: String,
+    /// The results for each candidate, in the order of the candidates
+    /// registered in the election contract.
+    pub candidates:  Vec<StoredCandidateResult>,
+}
+
 /// Database client wrapper
 pub struct Database {
     /// The database client
@@ -189,15 +252,83 @@ impl Database {
         Ok(())
     }
 
+    /// Deletes all indexed data together with the settings from the DB. This
+    /// is done in a single statement, i.e. either all or none of the tables
+    /// are cleared.
+    pub async fn clear(&self) -> DatabaseResult<()> {
+        self.client
+            .batch_execute("TRUNCATE ballots, malformed_ballots, delegations, results, settings")
+            .await?;
+        Ok(())
+    }
+
     /// Get the latest block height recorded in the DB.
     pub async fn get_settings(&self) -> DatabaseResult<StoredConfiguration> {
         let get_settings = self
             .client
-            .prepare_cached("SELECT latest_height, contract_index, contract_subindex FROM settings")
+            .prepare_cached(
+                "SELECT latest_height, contract_index, contract_subindex, latest_block_time FROM \
+                 settings",
+            )
             .await?;
         self.client.query_one(&get_settings, &[]).await?.try_into()
     }
 
+    /// Get the election result stored in the DB, if it has been published in
+    /// the election contract.
+    pub async fn get_election_result(&self) -> DatabaseResult<Option<StoredElectionResult>> {
+        let get_result_hash = self
+            .client
+            .prepare_cached("SELECT result_hash FROM settings")
+            .await?;
+        let raw_hash: Option<Vec<u8>> = self
+            .client
+            .query_one(&get_result_hash, &[])
+            .await?
+            .try_get(0)?;
+        let Some(raw_hash) = raw_hash else {
+            return Ok(None);
+        };
+        let hash: [u8; 32] = raw_hash
+            .try_into()
+            .map_err(|_| DatabaseError::TypeConversion)?;
+
+        let get_results = self
+            .client
+            .prepare_cached(
+                "SELECT candidate_url, candidate_hash, votes FROM results ORDER BY candidate_index",
+            )
+            .await?;
+        let candidates = self
+            .client
+            .query(&get_results, &[])
+            .await?
+            .into_iter()
+            .map(StoredCandidateResult::try_from)
+            .collect::<DatabaseResult<Vec<_>>>()?;
+
+        let result = StoredElectionResult {
+            result_hash: HashSha2256(hash).to_string(),
+            candidates,
+        };
+        Ok(Some(result))
+    }
+
+    /// Get the number of distinct accounts which submitted a verified ballot,
+    /// i.e. the number of accounts with a ballot counted in the tally.
+    pub async fn get_voter_count(&self) -> DatabaseResult<u64> {
+        let get_voter_count = self
+            .client
+            .prepare_cached("SELECT COUNT(DISTINCT account) FROM ballots WHERE verified")
+            .await?;
+        let count: i64 = self
+            .client
+            .query_one(&get_voter_count, &[])
+            .await?
+            .try_get(0)?;
+        Ok(count as u64)
+    }
+
     /// Get ballot submission by transaction hash
     pub async fn get_ballot_submission(
         &self,
@@ -249,6 +380,138 @@ impl Database {
             .collect()
     }
 
+    /// Get the ballot submissions of all accounts within the given range. The
+    /// results returned are ordered by descending value of id, meaning the
+    /// most recently submitted ballots are returned first.
+    pub async fn get_all_ballot_submissions(
+        &self,
+        from: Option<usize>,
+        limit: usize,
+    ) -> DatabaseResult<Vec<StoredBallotSubmission>> {
+        let from = if let Some(from) = from {
+            from as i64
+        } else {
+            i64::MAX
+        };
+        let get_ballot_submissions = self
+            .client
+            .prepare_cached(
+                "SELECT id, transaction_hash, block_time, ballot, account, verified FROM ballots \
+                 WHERE id < $1 ORDER BY id DESC LIMIT $2",
+            )
+            .await?;
+
+        let params: [&(dyn ToSql + Sync); 2] = [&(from), &(limit as i64)];
+        let rows = self.client.query(&get_ballot_submissions, &params).await?;
+
+        rows.into_iter()
+            .map(StoredBallotSubmission::try_from)
+            .collect()
+    }
+
+    /// Get at most `limit` ballot submissions included in blocks from
+    /// `from_height`, together with the height of their block. The results are
+    /// ordered by height, and by the order they were submitted within a block.
+    /// Ballots indexed before their block height was recorded are not
+    /// included.
+    pub async fn get_ballot_submissions_from_height(
+        &self,
+        from_height: AbsoluteBlockHeight,
+        limit: usize,
+    ) -> DatabaseResult<Vec<(AbsoluteBlockHeight, StoredBallotSubmission)>> {
+        let get_ballot_submissions = self
+            .client
+            .prepare_cached(
+                "SELECT id, transaction_hash, block_time, ballot, account, verified, block_height \
+                 FROM ballots WHERE block_height >= $1 ORDER BY block_height, id LIMIT $2",
+            )
+            .await?;
+
+        let params: [&(dyn ToSql + Sync); 2] = [&(from_height.height as i64), &(limit as i64)];
+        let rows = self.client.query(&get_ballot_submissions, &params).await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let height: i64 = row.try_get(6)?;
+                let submission = StoredBallotSubmission::try_from(row)?;
+                Ok(((height as u64).into(), submission))
+            })
+            .collect()
+    }
+
+    /// Get the most recent ballot submission by `account_address` which could
+    /// be verified. This is the ballot which will be counted in the tally for
+    /// the account.
+    pub async fn get_latest_verified_ballot_submission(
+        &self,
+        account_address: &AccountAddress,
+    ) -> DatabaseResult<Option<StoredBallotSubmission>> {
+        let get_ballot_submission = self
+            .client
+            .prepare_cached(
+                "SELECT id, transaction_hash, block_time, ballot, account, verified FROM ballots \
+                 WHERE account = $1 AND verified ORDER BY id DESC LIMIT 1",
+            )
+            .await?;
+        let params: [&(dyn ToSql + Sync); 1] = [&account_address.0.as_ref()];
+        let row = self
+            .client
+            .query_opt(&get_ballot_submission, &params)
+            .await?;
+        row.map(StoredBallotSubmission::try_from).transpose()
+    }
+
+    /// Get the transaction hash of the most recent ballot submission by
+    /// `account_address` which could be verified. This is the same ballot as
+    /// [`Database::get_latest_verified_ballot_submission`] returns, without
+    /// fetching and decoding the ballot itself.
+    pub async fn get_latest_verified_ballot_transaction_hash(
+        &self,
+        account_address: &AccountAddress,
+    ) -> DatabaseResult<Option<TransactionHash>> {
+        let get_transaction_hash = self
+            .client
+            .prepare_cached(
+                "SELECT transaction_hash FROM ballots WHERE account = $1 AND verified ORDER BY id \
+                 DESC LIMIT 1",
+            )
+            .await?;
+        let params: [&(dyn ToSql + Sync); 1] = [&account_address.0.as_ref()];
+        let Some(row) = self
+            .client
+            .query_opt(&get_transaction_hash, &params)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let raw_transaction_hash: &[u8] = row.try_get(0)?;
+        let transaction_hash = raw_transaction_hash
+            .try_into()
+            .map_err(|_| DatabaseError::TypeConversion)?;
+        Ok(Some(transaction_hash))
+    }
+
+    /// Get the ballot submissions which could not be verified, or all ballot
+    /// submissions if `all` is true, ordered by their index.
+    pub async fn get_ballot_submissions_for_verification(
+        &self,
+        all: bool,
+    ) -> DatabaseResult<Vec<StoredBallotSubmission>> {
+        let get_ballot_submissions = self
+            .client
+            .prepare_cached(
+                "SELECT id, transaction_hash, block_time, ballot, account, verified FROM ballots \
+                 WHERE $1 OR NOT verified ORDER BY id",
+            )
+            .await?;
+        let params: [&(dyn ToSql + Sync); 1] = [&all];
+        let rows = self.client.query(&get_ballot_submissions, &params).await?;
+
+        rows.into_iter()
+            .map(StoredBallotSubmission::try_from)
+            .collect()
+    }
+
     /// Get the delegation (if any) made from the `account_address`. This will
     /// only return a single result due to the constraint on the database
     /// table.
@@ -332,33 +595,78 @@ impl<'a> From<deadpool_postgres::Transaction<'a>> for Transaction<'a> {
 }
 
 impl<'a> Transaction<'a> {
-    /// Set the latest height in the DB.
-    pub async fn set_latest_height(&self, height: AbsoluteBlockHeight) -> DatabaseResult<()> {
+    /// Set the latest height and the corresponding block time in the DB.
+    pub async fn set_latest_height(
+        &self,
+        height: AbsoluteBlockHeight,
+        block_time: DateTime<Utc>,
+    ) -> DatabaseResult<()> {
         let set_latest_height = self
             .inner
-            .prepare_cached("UPDATE settings SET latest_height = $1 WHERE id = true")
+            .prepare_cached(
+                "UPDATE settings SET latest_height = $1, latest_block_time = $2 WHERE id = true",
+            )
             .await?;
-        let params: [&(dyn ToSql + Sync); 1] = [&(height.height as i64)];
+        let params: [&(dyn ToSql + Sync); 2] = [&(height.height as i64), &block_time];
         self.inner.execute(&set_latest_height, &params).await?;
         Ok(())
     }
 
-    /// Insert a ballot submission into the DB.
+    /// Replace the election result stored in the DB with `result`, identified
+    /// by `result_hash`.
+    pub async fn set_election_result(
+        &self,
+        result: &[CandidateResult],
+        result_hash: &HashSha2256,
+    ) -> DatabaseResult<()> {
+        self.inner.execute("DELETE FROM results", &[]).await?;
+
+        let insert_result = self
+            .inner
+            .prepare_cached(
+                "INSERT INTO results (candidate_index, candidate_url, candidate_hash, votes) \
+                 VALUES ($1, $2, $3, $4)",
+            )
+            .await?;
+        for (index, candidate_result) in result.iter().enumerate() {
+            let params: [&(dyn ToSql + Sync); 4] = [
+                &(index as i32),
+                &candidate_result.candidate.url,
+                &candidate_result.candidate.hash.0.as_ref(),
+                &(candidate_result.cummulative_votes as i64),
+            ];
+            self.inner.execute(&insert_result, &params).await?;
+        }
+
+        let set_result_hash = self
+            .inner
+            .prepare_cached("UPDATE settings SET result_hash = $1 WHERE id = true")
+            .await?;
+        let params: [&(dyn ToSql + Sync); 1] = [&result_hash.0.as_ref()];
+        self.inner.execute(&set_result_hash, &params).await?;
+        Ok(())
+    }
+
+    /// Insert a ballot submission included in the block at `block_height` into
+    /// the DB. Inserting a ballot submitted in a transaction which is already
+    /// recorded has no effect.
     #[tracing::instrument(level = "debug", skip_all, fields(transaction_hash = %ballot.transaction_hash))]
     pub async fn insert_ballot(
         &self,
         ballot: &BallotSubmission,
+        block_height: AbsoluteBlockHeight,
         block_time: DateTime<Utc>,
     ) -> DatabaseResult<()> {
         let insert_ballot = self
             .inner
             .prepare_cached(
                 "INSERT INTO ballots (id, transaction_hash, block_time, ballot, account, \
-                 verified) SELECT COALESCE(MAX(id) + 1, 0), $1, $2, $3, $4, $5 FROM ballots;",
+                 verified, block_height) SELECT COALESCE(MAX(id) + 1, 0), $1, $2, $3, $4, $5, $6 \
+                 FROM ballots ON CONFLICT (transaction_hash) DO NOTHING;",
             )
             .await?;
 
-        let params: [&(dyn ToSql + Sync); 5] = [
+        let params: [&(dyn ToSql + Sync); 6] = [
             &ballot.transaction_hash.as_ref(),
             &block_time,
             &encode(&ballot.ballot)
@@ -366,6 +674,7 @@ impl<'a> Transaction<'a> {
                 .inspect_err(|e| tracing::warn!("Failed to encode encrypted ballot: {e}"))?,
             &ballot.account.0.as_ref(),
             &ballot.verified,
+            &(block_height.height as i64),
         ];
         self.inner
             .execute(&insert_ballot, &params)
@@ -374,6 +683,47 @@ impl<'a> Transaction<'a> {
         Ok(())
     }
 
+    /// Set whether the proof of the ballot submission with index `id` could be
+    /// verified.
+    pub async fn set_ballot_verified(&self, id: u64, verified: bool) -> DatabaseResult<()> {
+        let set_ballot_verified = self
+            .inner
+            .prepare_cached("UPDATE ballots SET verified = $2 WHERE id = $1")
+            .await?;
+        let params: [&(dyn ToSql + Sync); 2] = [&(id as i64), &verified];
+        self.inner.execute(&set_ballot_verified, &params).await?;
+        Ok(())
+    }
+
+    /// Insert a submission which could not be parsed as a ballot into the DB.
+    /// Inserting a submission which is already recorded has no effect.
+    #[tracing::instrument(level = "debug", skip_all, fields(transaction_hash = %submission.transaction_hash))]
+    pub async fn insert_malformed_ballot(
+        &self,
+        submission: &MalformedBallotSubmission,
+        block_time: DateTime<Utc>,
+    ) -> DatabaseResult<()> {
+        let insert_malformed_ballot = self
+            .inner
+            .prepare_cached(
+                "INSERT INTO malformed_ballots (transaction_hash, block_time, account, error) \
+                 VALUES ($1, $2, $3, $4) ON CONFLICT (transaction_hash) DO NOTHING;",
+            )
+            .await?;
+
+        let params: [&(dyn ToSql + Sync); 4] = [
+            &submission.transaction_hash.as_ref(),
+            &block_time,
+            &submission.account.0.as_ref(),
+            &submission.error,
+        ];
+        self.inner
+            .execute(&insert_malformed_ballot, &params)
+            .await
+            .inspect_err(|e| tracing::error!("Failed to execute statement: {e}"))?;
+        Ok(())
+    }
+
     /// Insert a ballot submission into the DB.
     #[tracing::instrument(skip_all, fields(transaction_hash = %delegation.transaction_hash))]
     pub async fn insert_delegation(
@@ -459,3 +809,41 @@ impl DatabasePool {
         Ok(client.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    /// Queries run concurrently through the pool each get their own connection,
+    /// and hence do not wait for each other.
+    #[tokio::test]
+    #[ignore = "requires a postgres database given by CCD_ELECTION_TEST_DB_CONNECTION"]
+    async fn test_concurrent_queries() {
+        let db_connection = std::env::var("CCD_ELECTION_TEST_DB_CONNECTION")
+            .expect("CCD_ELECTION_TEST_DB_CONNECTION is set")
+            .parse()
+            .expect("Valid connection string");
+        let db_pool = DatabasePool::create(db_connection, 2, false).await.unwrap();
+
+        // Each query returns the process ID of the connection it was run on.
+        let query = |db_pool: DatabasePool| async move {
+            let db = db_pool.get().await.unwrap();
+            let row = db
+                .client
+                .query_one("SELECT pg_backend_pid() FROM pg_sleep(1)", &[])
+                .await
+                .unwrap();
+            row.get::<_, i32>(0)
+        };
+        let start = Instant::now();
+        let (first, second) = futures::join!(query(db_pool.clone()), query(db_pool));
+        let elapsed = start.elapsed();
+
+        assert_ne!(first, second, "Queries run on separate connections");
+        assert!(
+            elapsed < Duration::from_millis(1900),
+            "Queries ran concurrently, took {elapsed:?}"
+        );
+    }
+}
@@ -1,21 +1,20 @@
 use anyhow::Context;
 use concordium_governance_committee_election::{
-    ElectionConfig, GuardiansState, ViewElectionResultQueryResponse,
+    ElectionConfig, GuardiansState, ViewContestResultsQueryResponse,
+    ViewElectionResultQueryResponse,
 };
 use concordium_rust_sdk::{
-    contract_client::{ContractClient, ViewError},
+    contract_client::ContractClient,
     smart_contracts::common as contracts_common,
     types::{hashes::TransactionHash, smart_contracts::InstanceInfo, ContractAddress},
     v2::{self, BlockIdentifier},
 };
 use eg::ballot::BallotEncrypted;
+use election_common::contract;
 use serde::Serialize;
 use tonic::transport::ClientTlsConfig;
 
 pub const REGISTER_VOTES_RECEIVE: &str = "election.registerVotes";
-pub const CONFIG_VIEW: &str = "viewConfig";
-pub const GUARDIANS_VIEW: &str = "viewGuardiansState";
-pub const RESULT_VIEW: &str = "viewElectionResult";
 
 /// Describes an election ballot submission
 #[derive(Serialize, Debug)]
@@ -30,6 +29,18 @@ pub struct BallotSubmission {
     pub verified:         bool,
 }
 
+/// Describes a submission to the election contract which could not be parsed as
+/// a ballot. These are recorded to make anomalous submissions visible.
+#[derive(Serialize, Debug)]
+pub struct MalformedBallotSubmission {
+    /// The account which submitted the ballot
+    pub account:          contracts_common::AccountAddress,
+    /// The transaction hash of the ballot submission
+    pub transaction_hash: TransactionHash,
+    /// Describes why the submission could not be parsed.
+    pub error:            String,
+}
+
 /// Describes an election voting weight delegation
 #[derive(Serialize, Debug)]
 pub struct VotingWeightDelegation {
@@ -100,17 +111,14 @@ pub async fn verify_contract(
 
 /// Gets the [`ElectionConfig`] from the contract.
 pub async fn get_election_config(client: &mut ElectionContract) -> anyhow::Result<ElectionConfig> {
-    let election_config = client
-        .view::<_, ElectionConfig, ViewError>(CONFIG_VIEW, &(), BlockIdentifier::LastFinal)
-        .await?;
+    let election_config = contract::view_config(client, BlockIdentifier::LastFinal).await?;
     Ok(election_config)
 }
 
 /// Gets the [`GuardiansState`] from the contract.
 pub async fn get_guardians_state(client: &mut ElectionContract) -> anyhow::Result<GuardiansState> {
-    let guardians_state = client
-        .view::<_, GuardiansState, ViewError>(GUARDIANS_VIEW, &(), BlockIdentifier::LastFinal)
-        .await?;
+    let guardians_state =
+        contract::view_guardians_state(client, BlockIdentifier::LastFinal).await?;
     Ok(guardians_state)
 }
 
@@ -118,12 +126,16 @@ pub async fn get_guardians_state(client: &mut ElectionContract) -> anyhow::Resul
 pub async fn get_election_result(
     client: &mut ElectionContract,
 ) -> anyhow::Result<ViewElectionResultQueryResponse> {
-    let election_result = client
-        .view::<_, ViewElectionResultQueryResponse, ViewError>(
-            RESULT_VIEW,
-            &(),
-            BlockIdentifier::LastFinal,
-        )
-        .await?;
+    let election_result =
+        contract::view_election_result(client, BlockIdentifier::LastFinal).await?;
     Ok(election_result)
 }
+
+/// Gets the election result grouped by contest from the contract.
+pub async fn get_contest_results(
+    client: &mut ElectionContract,
+) -> anyhow::Result<ViewContestResultsQueryResponse> {
+    let contest_results =
+        contract::view_contest_results(client, BlockIdentifier::LastFinal).await?;
+    Ok(contest_results)
+}
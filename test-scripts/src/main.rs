@@ -29,11 +29,18 @@ use eg::{
         CombinedDecryptionShare, DecryptionProof, DecryptionShare, DecryptionShareResult,
     },
 };
-use election_common::{decode, encode, EncryptedTally, GuardianDecryption, WeightRow};
-use futures::{stream::FuturesUnordered, TryStreamExt};
+use election_common::{decode, encode, EncodedGuardianDecryption, EncryptedTally, WeightRow};
+use futures::{Future, Stream, StreamExt, TryStreamExt};
 use rand::Rng;
 use sha2::Digest;
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
 
 /// A writer adapter that computes the hash of the written value on the fly.
 struct HashedWriter<W> {
@@ -65,6 +72,33 @@ impl<W: std::io::Write> std::io::Write for HashedWriter<W> {
     fn flush(&mut self) -> std::io::Result<()> { self.inner.flush() }
 }
 
+/// Run the given futures with at most `max_concurrent` of them in flight at
+/// any given time. The results are produced in the order the futures complete.
+///
+/// Each future is checked to not start while `max_concurrent` other futures
+/// are still running, failing with an error if that is the case.
+fn bounded<T, F: Future<Output = anyhow::Result<T>>>(
+    futs: Vec<F>,
+    max_concurrent: NonZeroUsize,
+) -> impl Stream<Item = anyhow::Result<T>> {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_concurrent = max_concurrent.get();
+    futures::stream::iter(futs.into_iter().map(move |fut| {
+        let in_flight = in_flight.clone();
+        async move {
+            let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            anyhow::ensure!(
+                current <= max_concurrent,
+                "{current} submissions in flight, but at most {max_concurrent} are allowed."
+            );
+            let result = fut.await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+            result
+        }
+    }))
+    .buffer_unordered(max_concurrent)
+}
+
 /// Command line configuration of the application.
 #[derive(Debug, clap::Parser)]
 struct Args {
@@ -112,6 +146,12 @@ struct Args {
     base_url:          url::Url,
     #[arg(long = "out", help = "Output directory for all the artifacts.")]
     out:               std::path::PathBuf,
+    #[arg(
+        long = "max-concurrent",
+        help = "The maximum number of guardian transactions submitted concurrently.",
+        default_value = "4"
+    )]
+    max_concurrent:    NonZeroUsize,
 }
 
 #[tokio::main]
@@ -343,6 +383,19 @@ async fn main() -> anyhow::Result<()> {
             election_end,
             decryption_deadline: decryption_deadline.try_into()?,
             delegation_string: delegation_string.into(),
+            result_posting_deadline: None,
+            result_approvers: Vec::new(),
+            approval_threshold: 0,
+            eg_version: election_common::ELECTIONGUARD_VERSION.into(),
+            display_order: Vec::new(),
+            decryption_grace_seconds: 0,
+            threshold: parameters
+                .varying_parameters
+                .k
+                .get_one_based_usize()
+                .try_into()?,
+            contest_sizes: Vec::new(),
+            max_description_length: None,
         };
         let nonce = client
             .get_next_account_sequence_number(&admin.address)
@@ -388,7 +441,7 @@ async fn main() -> anyhow::Result<()> {
         let mut guardian_keys = Vec::with_capacity(guardians.len());
         let mut guardian_public_keys = Vec::with_capacity(guardians.len());
         // create new guardians
-        let futs = FuturesUnordered::new();
+        let mut futs = Vec::with_capacity(guardians.len());
         for (g, g_acc) in (1..=guardians.len()).zip(&guardians) {
             let index = GuardianIndex::from_one_based_index(g as u32)?;
             let key = GuardianSecretKey::generate(
@@ -431,13 +484,16 @@ async fn main() -> anyhow::Result<()> {
             guardian_keys.push(key);
         }
 
-        futs.try_for_each(|g| async move {
-            eprintln!("Public key for guardian {g} is registered");
-            Ok(())
-        })
-        .await?;
+        // All public keys must be registered before the encrypted shares, since the
+        // shares are encrypted with respect to the public keys.
+        bounded(futs, args.max_concurrent)
+            .try_for_each(|g| async move {
+                eprintln!("Public key for guardian {g} is registered");
+                Ok(())
+            })
+            .await?;
 
-        let futs = FuturesUnordered::new();
+        let mut futs = Vec::with_capacity(guardians.len());
         for ((g, g_acc), dealer_private_key) in (1..).zip(&guardians).zip(&guardian_keys) {
             let mut shares = Vec::new();
             for dealer_public_key in &guardian_public_keys {
@@ -475,11 +531,14 @@ async fn main() -> anyhow::Result<()> {
             futs.push(fut);
         }
 
-        futs.try_for_each(|g| async move {
-            eprintln!("Key shares for guardian {g} registered");
-            Ok(())
-        })
-        .await?;
+        // All key shares must be registered before guardians can validate them
+        // and register their status.
+        bounded(futs, args.max_concurrent)
+            .try_for_each(|g| async move {
+                eprintln!("Key shares for guardian {g} registered");
+                Ok(())
+            })
+            .await?;
 
         (guardian_keys, guardian_public_keys)
     };
@@ -538,7 +597,7 @@ async fn main() -> anyhow::Result<()> {
     // Post that each guardian is happy.
     {
         eprintln!("Publishing that each guardian is happy with all the other guardian's shares.");
-        let futs = FuturesUnordered::new();
+        let mut futs = Vec::with_capacity(guardians.len());
         for guardian in &guardians {
             let mut contract_client = contract_client.clone();
             let fut = async move {
@@ -570,7 +629,11 @@ async fn main() -> anyhow::Result<()> {
             };
             futs.push(fut);
         }
-        futs.try_collect::<()>().await?;
+        // All guardians must have registered their status before the election
+        // starts.
+        bounded(futs, args.max_concurrent)
+            .try_collect::<()>()
+            .await?;
     }
 
     {
@@ -753,7 +816,7 @@ async fn main() -> anyhow::Result<()> {
             }
             secret_states.push(secret_states_map);
 
-            let decryptions = encode(&decryptions)?;
+            let decryptions = EncodedGuardianDecryption::encode(&decryptions)?.0;
 
             eprintln!("Serialized decryption share is {}B.", decryptions.len());
 
@@ -808,10 +871,11 @@ async fn main() -> anyhow::Result<()> {
                     let mut commit_shares = Vec::new();
                     let mut decryption_shares = Vec::new();
                     for gs in &guardians_state {
-                        let Some(share_result) = gs.1.decryption_share.as_ref() else {
+                        let Some(share_result) = gs.1.decryption_share.clone() else {
                             anyhow::bail!("Share not present even though it was registered.");
                         };
-                        let share_result = decode::<GuardianDecryption>(share_result)
+                        let share_result = EncodedGuardianDecryption(share_result)
+                            .decode(&ciphertexts)
                             .context("Unable to parse decryption share result.")?;
                         let result = &share_result
                             .get(index)